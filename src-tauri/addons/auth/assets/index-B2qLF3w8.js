@@ -1,5 +0,0 @@
-var e=Object.defineProperty,t=(t,n)=>{let r={};for(var i in t)e(r,i,{get:t[i],enumerable:!0});return n&&e(r,Symbol.toStringTag,{value:`Module`}),r};(function(){let e=document.createElement(`link`).relList;if(e&&e.supports&&e.supports(`modulepreload`))return;for(let e of document.querySelectorAll(`link[rel="modulepreload"]`))n(e);new MutationObserver(e=>{for(let t of e)if(t.type===`childList`)for(let e of t.addedNodes)e.tagName===`LINK`&&e.rel===`modulepreload`&&n(e)}).observe(document,{childList:!0,subtree:!0});function t(e){let t={};return e.integrity&&(t.integrity=e.integrity),e.referrerPolicy&&(t.referrerPolicy=e.referrerPolicy),e.crossOrigin===`use-credentials`?t.credentials=`include`:e.crossOrigin===`anonymous`?t.credentials=`omit`:t.credentials=`same-origin`,t}function n(e){if(e.ep)return;e.ep=!0;let n=t(e);fetch(e.href,n)}})();function n(e){let t=Object.create(null);for(let n of e.split(`,`))t[n]=1;return e=>e in t}var r={},i=[],a=()=>{},o=()=>!1,s=e=>e.charCodeAt(0)===111&&e.charCodeAt(1)===110&&(e.charCodeAt(2)>122||e.charCodeAt(2)<97),c=e=>e.startsWith(`onUpdate:`),l=Object.assign,u=(e,t)=>{let n=e.indexOf(t);n>-1&&e.splice(n,1)},d=Object.prototype.hasOwnProperty,f=(e,t)=>d.call(e,t),p=Array.isArray,m=e=>w(e)===`[object Map]`,h=e=>w(e)===`[object Set]`,g=e=>w(e)===`[object Date]`,_=e=>w(e)===`[object RegExp]`,v=e=>typeof e==`function`,y=e=>typeof e==`string`,b=e=>typeof e==`symbol`,x=e=>typeof e==`object`&&!!e,S=e=>(x(e)||v(e))&&v(e.then)&&v(e.catch),C=Object.prototype.toString,w=e=>C.call(e),T=e=>w(e).slice(8,-1),E=e=>w(e)===`[object Object]`,D=e=>y(e)&&e!==`NaN`&&e[0]!==`-`&&``+parseInt(e,10)===e,O=n(`,key,ref,ref_for,ref_key,onVnodeBeforeMount,onVnodeMounted,onVnodeBeforeUpdate,onVnodeUpdated,onVnodeBeforeUnmount,onVnodeUnmounted`),ee=e=>{let t=Object.create(null);return(n=>t[n]||(t[n]=e(n)))},te=/-\w/g,k=ee(e=>e.replace(te,e=>e.slice(1).toUpperCase())),ne=/\B([A-Z])/g,A=ee(e=>e.replace(ne,`-$1`).toLowerCase()),re=ee(e=>e.charAt(0).toUpperCase()+e.slice(1)),j=ee(e=>e?`on${re(e)}`:``),M=(e,t)=>!Object.is(e,t),ie=(e,...t)=>{for(let n=0;n<e.length;n++)e[n](...t)},ae=(e,t,n,r=!1)=>{Object.defineProperty(e,t,{configurable:!0,enumerable:!1,writable:r,value:n})},oe=e=>{let t=parseFloat(e);return isNaN(t)?e:t},se=e=>{let t=y(e)?Number(e):NaN;return isNaN(t)?e:t},ce,le=()=>ce||=typeof globalThis<`u`?globalThis:typeof self<`u`?self:typeof window<`u`?window:typeof global<`u`?global:{},ue=n(`Infinity,undefined,NaN,isFinite,isNaN,parseFloat,parseInt,decodeURI,decodeURIComponent,encodeURI,encodeURIComponent,Math,Number,Date,Array,Object,Boolean,String,RegExp,Map,Set,JSON,Intl,BigInt,console,Error,Symbol`);function de(e){if(p(e)){let t={};for(let n=0;n<e.length;n++){let r=e[n],i=y(r)?he(r):de(r);if(i)for(let e in i)t[e]=i[e]}return t}else if(y(e)||x(e))return e}var fe=/;(?![^(]*\))/g,pe=/:([^]+)/,me=/\/\*[^]*?\*\//g;function he(e){let t={};return e.replace(me,``).split(fe).forEach(e=>{if(e){let n=e.split(pe);n.length>1&&(t[n[0].trim()]=n[1].trim())}}),t}function ge(e){let t=``;if(y(e))t=e;else if(p(e))for(let n=0;n<e.length;n++){let r=ge(e[n]);r&&(t+=r+` `)}else if(x(e))for(let n in e)e[n]&&(t+=n+` `);return t.trim()}function _e(e){if(!e)return null;let{class:t,style:n}=e;return t&&!y(t)&&(e.class=ge(t)),n&&(e.style=de(n)),e}var ve=`itemscope,allowfullscreen,formnovalidate,ismap,nomodule,novalidate,readonly`,ye=n(ve);ve+``;function be(e){return!!e||e===``}function xe(e,t){if(e.length!==t.length)return!1;let n=!0;for(let r=0;n&&r<e.length;r++)n=Se(e[r],t[r]);return n}function Se(e,t){if(e===t)return!0;let n=g(e),r=g(t);if(n||r)return n&&r?e.getTime()===t.getTime():!1;if(n=b(e),r=b(t),n||r)return e===t;if(n=p(e),r=p(t),n||r)return n&&r?xe(e,t):!1;if(n=x(e),r=x(t),n||r){if(!n||!r||Object.keys(e).length!==Object.keys(t).length)return!1;for(let n in e){let r=e.hasOwnProperty(n),i=t.hasOwnProperty(n);if(r&&!i||!r&&i||!Se(e[n],t[n]))return!1}}return String(e)===String(t)}function Ce(e,t){return e.findIndex(e=>Se(e,t))}var N=e=>!!(e&&e.__v_isRef===!0),we=e=>y(e)?e:e==null?``:p(e)||x(e)&&(e.toString===C||!v(e.toString))?N(e)?we(e.value):JSON.stringify(e,Te,2):String(e),Te=(e,t)=>N(t)?Te(e,t.value):m(t)?{[`Map(${t.size})`]:[...t.entries()].reduce((e,[t,n],r)=>(e[Ee(t,r)+` =>`]=n,e),{})}:h(t)?{[`Set(${t.size})`]:[...t.values()].map(e=>Ee(e))}:b(t)?Ee(t):x(t)&&!p(t)&&!E(t)?String(t):t,Ee=(e,t=``)=>b(e)?`Symbol(${e.description??t})`:e;function De(e){return e==null?`initial`:typeof e==`string`?e===``?` `:e:String(e)}var Oe,ke=class{constructor(e=!1){this.detached=e,this._active=!0,this._on=0,this.effects=[],this.cleanups=[],this._isPaused=!1,this.parent=Oe,!e&&Oe&&(this.index=(Oe.scopes||=[]).push(this)-1)}get active(){return this._active}pause(){if(this._active){this._isPaused=!0;let e,t;if(this.scopes)for(e=0,t=this.scopes.length;e<t;e++)this.scopes[e].pause();for(e=0,t=this.effects.length;e<t;e++)this.effects[e].pause()}}resume(){if(this._active&&this._isPaused){this._isPaused=!1;let e,t;if(this.scopes)for(e=0,t=this.scopes.length;e<t;e++)this.scopes[e].resume();for(e=0,t=this.effects.length;e<t;e++)this.effects[e].resume()}}run(e){if(this._active){let t=Oe;try{return Oe=this,e()}finally{Oe=t}}}on(){++this._on===1&&(this.prevScope=Oe,Oe=this)}off(){this._on>0&&--this._on===0&&(Oe=this.prevScope,this.prevScope=void 0)}stop(e){if(this._active){this._active=!1;let t,n;for(t=0,n=this.effects.length;t<n;t++)this.effects[t].stop();for(this.effects.length=0,t=0,n=this.cleanups.length;t<n;t++)this.cleanups[t]();if(this.cleanups.length=0,this.scopes){for(t=0,n=this.scopes.length;t<n;t++)this.scopes[t].stop(!0);this.scopes.length=0}if(!this.detached&&this.parent&&!e){let e=this.parent.scopes.pop();e&&e!==this&&(this.parent.scopes[this.index]=e,e.index=this.index)}this.parent=void 0}}};function Ae(e){return new ke(e)}function je(){return Oe}function Me(e,t=!1){Oe&&Oe.cleanups.push(e)}var P,Ne=new WeakSet,Pe=class{constructor(e){this.fn=e,this.deps=void 0,this.depsTail=void 0,this.flags=5,this.next=void 0,this.cleanup=void 0,this.scheduler=void 0,Oe&&Oe.active&&Oe.effects.push(this)}pause(){this.flags|=64}resume(){this.flags&64&&(this.flags&=-65,Ne.has(this)&&(Ne.delete(this),this.trigger()))}notify(){this.flags&2&&!(this.flags&32)||this.flags&8||Re(this)}run(){if(!(this.flags&1))return this.fn();this.flags|=2,$e(this),Ve(this);let e=P,t=Ye;P=this,Ye=!0;try{return this.fn()}finally{He(this),P=e,Ye=t,this.flags&=-3}}stop(){if(this.flags&1){for(let e=this.deps;e;e=e.nextDep)Ge(e);this.deps=this.depsTail=void 0,$e(this),this.onStop&&this.onStop(),this.flags&=-2}}trigger(){this.flags&64?Ne.add(this):this.scheduler?this.scheduler():this.runIfDirty()}runIfDirty(){Ue(this)&&this.run()}get dirty(){return Ue(this)}},Fe=0,Ie,Le;function Re(e,t=!1){if(e.flags|=8,t){e.next=Le,Le=e;return}e.next=Ie,Ie=e}function ze(){Fe++}function Be(){if(--Fe>0)return;if(Le){let e=Le;for(Le=void 0;e;){let t=e.next;e.next=void 0,e.flags&=-9,e=t}}let e;for(;Ie;){let t=Ie;for(Ie=void 0;t;){let n=t.next;if(t.next=void 0,t.flags&=-9,t.flags&1)try{t.trigger()}catch(t){e||=t}t=n}}if(e)throw e}function Ve(e){for(let t=e.deps;t;t=t.nextDep)t.version=-1,t.prevActiveLink=t.dep.activeLink,t.dep.activeLink=t}function He(e){let t,n=e.depsTail,r=n;for(;r;){let e=r.prevDep;r.version===-1?(r===n&&(n=e),Ge(r),Ke(r)):t=r,r.dep.activeLink=r.prevActiveLink,r.prevActiveLink=void 0,r=e}e.deps=t,e.depsTail=n}function Ue(e){for(let t=e.deps;t;t=t.nextDep)if(t.dep.version!==t.version||t.dep.computed&&(We(t.dep.computed)||t.dep.version!==t.version))return!0;return!!e._dirty}function We(e){if(e.flags&4&&!(e.flags&16)||(e.flags&=-17,e.globalVersion===et)||(e.globalVersion=et,!e.isSSR&&e.flags&128&&(!e.deps&&!e._dirty||!Ue(e))))return;e.flags|=2;let t=e.dep,n=P,r=Ye;P=e,Ye=!0;try{Ve(e);let n=e.fn(e._value);(t.version===0||M(n,e._value))&&(e.flags|=128,e._value=n,t.version++)}catch(e){throw t.version++,e}finally{P=n,Ye=r,He(e),e.flags&=-3}}function Ge(e,t=!1){let{dep:n,prevSub:r,nextSub:i}=e;if(r&&(r.nextSub=i,e.prevSub=void 0),i&&(i.prevSub=r,e.nextSub=void 0),n.subs===e&&(n.subs=r,!r&&n.computed)){n.computed.flags&=-5;for(let e=n.computed.deps;e;e=e.nextDep)Ge(e,!0)}!t&&!--n.sc&&n.map&&n.map.delete(n.key)}function Ke(e){let{prevDep:t,nextDep:n}=e;t&&(t.nextDep=n,e.prevDep=void 0),n&&(n.prevDep=t,e.nextDep=void 0)}function qe(e,t){e.effect instanceof Pe&&(e=e.effect.fn);let n=new Pe(e);t&&l(n,t);try{n.run()}catch(e){throw n.stop(),e}let r=n.run.bind(n);return r.effect=n,r}function Je(e){e.effect.stop()}var Ye=!0,Xe=[];function Ze(){Xe.push(Ye),Ye=!1}function Qe(){let e=Xe.pop();Ye=e===void 0?!0:e}function $e(e){let{cleanup:t}=e;if(e.cleanup=void 0,t){let e=P;P=void 0;try{t()}finally{P=e}}}var et=0,tt=class{constructor(e,t){this.sub=e,this.dep=t,this.version=t.version,this.nextDep=this.prevDep=this.nextSub=this.prevSub=this.prevActiveLink=void 0}},nt=class{constructor(e){this.computed=e,this.version=0,this.activeLink=void 0,this.subs=void 0,this.map=void 0,this.key=void 0,this.sc=0,this.__v_skip=!0}track(e){if(!P||!Ye||P===this.computed)return;let t=this.activeLink;if(t===void 0||t.sub!==P)t=this.activeLink=new tt(P,this),P.deps?(t.prevDep=P.depsTail,P.depsTail.nextDep=t,P.depsTail=t):P.deps=P.depsTail=t,rt(t);else if(t.version===-1&&(t.version=this.version,t.nextDep)){let e=t.nextDep;e.prevDep=t.prevDep,t.prevDep&&(t.prevDep.nextDep=e),t.prevDep=P.depsTail,t.nextDep=void 0,P.depsTail.nextDep=t,P.depsTail=t,P.deps===t&&(P.deps=e)}return t}trigger(e){this.version++,et++,this.notify(e)}notify(e){ze();try{for(let e=this.subs;e;e=e.prevSub)e.sub.notify()&&e.sub.dep.notify()}finally{Be()}}};function rt(e){if(e.dep.sc++,e.sub.flags&4){let t=e.dep.computed;if(t&&!e.dep.subs){t.flags|=20;for(let e=t.deps;e;e=e.nextDep)rt(e)}let n=e.dep.subs;n!==e&&(e.prevSub=n,n&&(n.nextSub=e)),e.dep.subs=e}}var it=new WeakMap,at=Symbol(``),ot=Symbol(``),st=Symbol(``);function ct(e,t,n){if(Ye&&P){let t=it.get(e);t||it.set(e,t=new Map);let r=t.get(n);r||(t.set(n,r=new nt),r.map=t,r.key=n),r.track()}}function lt(e,t,n,r,i,a){let o=it.get(e);if(!o){et++;return}let s=e=>{e&&e.trigger()};if(ze(),t===`clear`)o.forEach(s);else{let i=p(e),a=i&&D(n);if(i&&n===`length`){let e=Number(r);o.forEach((t,n)=>{(n===`length`||n===st||!b(n)&&n>=e)&&s(t)})}else switch((n!==void 0||o.has(void 0))&&s(o.get(n)),a&&s(o.get(st)),t){case`add`:i?a&&s(o.get(`length`)):(s(o.get(at)),m(e)&&s(o.get(ot)));break;case`delete`:i||(s(o.get(at)),m(e)&&s(o.get(ot)));break;case`set`:m(e)&&s(o.get(at));break}}Be()}function ut(e,t){let n=it.get(e);return n&&n.get(t)}function dt(e){let t=F(e);return t===e?t:(ct(t,`iterate`,st),en(e)?t:t.map(rn))}function ft(e){return ct(e=F(e),`iterate`,st),e}function pt(e,t){return $t(e)?Qt(e)?an(rn(t)):an(t):rn(t)}var mt={__proto__:null,[Symbol.iterator](){return ht(this,Symbol.iterator,e=>pt(this,e))},concat(...e){return dt(this).concat(...e.map(e=>p(e)?dt(e):e))},entries(){return ht(this,`entries`,e=>(e[1]=pt(this,e[1]),e))},every(e,t){return _t(this,`every`,e,t,void 0,arguments)},filter(e,t){return _t(this,`filter`,e,t,e=>e.map(e=>pt(this,e)),arguments)},find(e,t){return _t(this,`find`,e,t,e=>pt(this,e),arguments)},findIndex(e,t){return _t(this,`findIndex`,e,t,void 0,arguments)},findLast(e,t){return _t(this,`findLast`,e,t,e=>pt(this,e),arguments)},findLastIndex(e,t){return _t(this,`findLastIndex`,e,t,void 0,arguments)},forEach(e,t){return _t(this,`forEach`,e,t,void 0,arguments)},includes(...e){return yt(this,`includes`,e)},indexOf(...e){return yt(this,`indexOf`,e)},join(e){return dt(this).join(e)},lastIndexOf(...e){return yt(this,`lastIndexOf`,e)},map(e,t){return _t(this,`map`,e,t,void 0,arguments)},pop(){return bt(this,`pop`)},push(...e){return bt(this,`push`,e)},reduce(e,...t){return vt(this,`reduce`,e,t)},reduceRight(e,...t){return vt(this,`reduceRight`,e,t)},shift(){return bt(this,`shift`)},some(e,t){return _t(this,`some`,e,t,void 0,arguments)},splice(...e){return bt(this,`splice`,e)},toReversed(){return dt(this).toReversed()},toSorted(e){return dt(this).toSorted(e)},toSpliced(...e){return dt(this).toSpliced(...e)},unshift(...e){return bt(this,`unshift`,e)},values(){return ht(this,`values`,e=>pt(this,e))}};function ht(e,t,n){let r=ft(e),i=r[t]();return r!==e&&!en(e)&&(i._next=i.next,i.next=()=>{let e=i._next();return e.done||(e.value=n(e.value)),e}),i}var gt=Array.prototype;function _t(e,t,n,r,i,a){let o=ft(e),s=o!==e&&!en(e),c=o[t];if(c!==gt[t]){let t=c.apply(e,a);return s?rn(t):t}let l=n;o!==e&&(s?l=function(t,r){return n.call(this,pt(e,t),r,e)}:n.length>2&&(l=function(t,r){return n.call(this,t,r,e)}));let u=c.call(o,l,r);return s&&i?i(u):u}function vt(e,t,n,r){let i=ft(e),a=n;return i!==e&&(en(e)?n.length>3&&(a=function(t,r,i){return n.call(this,t,r,i,e)}):a=function(t,r,i){return n.call(this,t,pt(e,r),i,e)}),i[t](a,...r)}function yt(e,t,n){let r=F(e);ct(r,`iterate`,st);let i=r[t](...n);return(i===-1||i===!1)&&tn(n[0])?(n[0]=F(n[0]),r[t](...n)):i}function bt(e,t,n=[]){Ze(),ze();let r=F(e)[t].apply(e,n);return Be(),Qe(),r}var xt=n(`__proto__,__v_isRef,__isVue`),St=new Set(Object.getOwnPropertyNames(Symbol).filter(e=>e!==`arguments`&&e!==`caller`).map(e=>Symbol[e]).filter(b));function Ct(e){b(e)||(e=String(e));let t=F(this);return ct(t,`has`,e),t.hasOwnProperty(e)}var wt=class{constructor(e=!1,t=!1){this._isReadonly=e,this._isShallow=t}get(e,t,n){if(t===`__v_skip`)return e.__v_skip;let r=this._isReadonly,i=this._isShallow;if(t===`__v_isReactive`)return!r;if(t===`__v_isReadonly`)return r;if(t===`__v_isShallow`)return i;if(t===`__v_raw`)return n===(r?i?Wt:Ut:i?Ht:Vt).get(e)||Object.getPrototypeOf(e)===Object.getPrototypeOf(n)?e:void 0;let a=p(e);if(!r){let e;if(a&&(e=mt[t]))return e;if(t===`hasOwnProperty`)return Ct}let o=Reflect.get(e,t,I(e)?e:n);if((b(t)?St.has(t):xt(t))||(r||ct(e,`get`,t),i))return o;if(I(o)){let e=a&&D(t)?o:o.value;return r&&x(e)?Yt(e):e}return x(o)?r?Yt(o):qt(o):o}},Tt=class extends wt{constructor(e=!1){super(!1,e)}set(e,t,n,r){let i=e[t],a=p(e)&&D(t);if(!this._isShallow){let e=$t(i);if(!en(n)&&!$t(n)&&(i=F(i),n=F(n)),!a&&I(i)&&!I(n))return e||(i.value=n),!0}let o=a?Number(t)<e.length:f(e,t),s=Reflect.set(e,t,n,I(e)?e:r);return e===F(r)&&(o?M(n,i)&&lt(e,`set`,t,n,i):lt(e,`add`,t,n)),s}deleteProperty(e,t){let n=f(e,t),r=e[t],i=Reflect.deleteProperty(e,t);return i&&n&&lt(e,`delete`,t,void 0,r),i}has(e,t){let n=Reflect.has(e,t);return(!b(t)||!St.has(t))&&ct(e,`has`,t),n}ownKeys(e){return ct(e,`iterate`,p(e)?`length`:at),Reflect.ownKeys(e)}},Et=class extends wt{constructor(e=!1){super(!0,e)}set(e,t){return!0}deleteProperty(e,t){return!0}},Dt=new Tt,Ot=new Et,kt=new Tt(!0),At=new Et(!0),jt=e=>e,Mt=e=>Reflect.getPrototypeOf(e);function Nt(e,t,n){return function(...r){let i=this.__v_raw,a=F(i),o=m(a),s=e===`entries`||e===Symbol.iterator&&o,c=e===`keys`&&o,l=i[e](...r),u=n?jt:t?an:rn;return!t&&ct(a,`iterate`,c?ot:at),{next(){let{value:e,done:t}=l.next();return t?{value:e,done:t}:{value:s?[u(e[0]),u(e[1])]:u(e),done:t}},[Symbol.iterator](){return this}}}}function Pt(e){return function(...t){return e===`delete`?!1:e===`clear`?void 0:this}}function Ft(e,t){let n={get(n){let r=this.__v_raw,i=F(r),a=F(n);e||(M(n,a)&&ct(i,`get`,n),ct(i,`get`,a));let{has:o}=Mt(i),s=t?jt:e?an:rn;if(o.call(i,n))return s(r.get(n));if(o.call(i,a))return s(r.get(a));r!==i&&r.get(n)},get size(){let t=this.__v_raw;return!e&&ct(F(t),`iterate`,at),t.size},has(t){let n=this.__v_raw,r=F(n),i=F(t);return e||(M(t,i)&&ct(r,`has`,t),ct(r,`has`,i)),t===i?n.has(t):n.has(t)||n.has(i)},forEach(n,r){let i=this,a=i.__v_raw,o=F(a),s=t?jt:e?an:rn;return!e&&ct(o,`iterate`,at),a.forEach((e,t)=>n.call(r,s(e),s(t),i))}};return l(n,e?{add:Pt(`add`),set:Pt(`set`),delete:Pt(`delete`),clear:Pt(`clear`)}:{add(e){!t&&!en(e)&&!$t(e)&&(e=F(e));let n=F(this);return Mt(n).has.call(n,e)||(n.add(e),lt(n,`add`,e,e)),this},set(e,n){!t&&!en(n)&&!$t(n)&&(n=F(n));let r=F(this),{has:i,get:a}=Mt(r),o=i.call(r,e);o||=(e=F(e),i.call(r,e));let s=a.call(r,e);return r.set(e,n),o?M(n,s)&&lt(r,`set`,e,n,s):lt(r,`add`,e,n),this},delete(e){let t=F(this),{has:n,get:r}=Mt(t),i=n.call(t,e);i||=(e=F(e),n.call(t,e));let a=r?r.call(t,e):void 0,o=t.delete(e);return i&&lt(t,`delete`,e,void 0,a),o},clear(){let e=F(this),t=e.size!==0,n=e.clear();return t&&lt(e,`clear`,void 0,void 0,void 0),n}}),[`keys`,`values`,`entries`,Symbol.iterator].forEach(r=>{n[r]=Nt(r,e,t)}),n}function It(e,t){let n=Ft(e,t);return(t,r,i)=>r===`__v_isReactive`?!e:r===`__v_isReadonly`?e:r===`__v_raw`?t:Reflect.get(f(n,r)&&r in t?n:t,r,i)}var Lt={get:It(!1,!1)},Rt={get:It(!1,!0)},zt={get:It(!0,!1)},Bt={get:It(!0,!0)},Vt=new WeakMap,Ht=new WeakMap,Ut=new WeakMap,Wt=new WeakMap;function Gt(e){switch(e){case`Object`:case`Array`:return 1;case`Map`:case`Set`:case`WeakMap`:case`WeakSet`:return 2;default:return 0}}function Kt(e){return e.__v_skip||!Object.isExtensible(e)?0:Gt(T(e))}function qt(e){return $t(e)?e:Zt(e,!1,Dt,Lt,Vt)}function Jt(e){return Zt(e,!1,kt,Rt,Ht)}function Yt(e){return Zt(e,!0,Ot,zt,Ut)}function Xt(e){return Zt(e,!0,At,Bt,Wt)}function Zt(e,t,n,r,i){if(!x(e)||e.__v_raw&&!(t&&e.__v_isReactive))return e;let a=Kt(e);if(a===0)return e;let o=i.get(e);if(o)return o;let s=new Proxy(e,a===2?r:n);return i.set(e,s),s}function Qt(e){return $t(e)?Qt(e.__v_raw):!!(e&&e.__v_isReactive)}function $t(e){return!!(e&&e.__v_isReadonly)}function en(e){return!!(e&&e.__v_isShallow)}function tn(e){return e?!!e.__v_raw:!1}function F(e){let t=e&&e.__v_raw;return t?F(t):e}function nn(e){return!f(e,`__v_skip`)&&Object.isExtensible(e)&&ae(e,`__v_skip`,!0),e}var rn=e=>x(e)?qt(e):e,an=e=>x(e)?Yt(e):e;function I(e){return e?e.__v_isRef===!0:!1}function on(e){return cn(e,!1)}function sn(e){return cn(e,!0)}function cn(e,t){return I(e)?e:new ln(e,t)}var ln=class{constructor(e,t){this.dep=new nt,this.__v_isRef=!0,this.__v_isShallow=!1,this._rawValue=t?e:F(e),this._value=t?e:rn(e),this.__v_isShallow=t}get value(){return this.dep.track(),this._value}set value(e){let t=this._rawValue,n=this.__v_isShallow||en(e)||$t(e);e=n?e:F(e),M(e,t)&&(this._rawValue=e,this._value=n?e:rn(e),this.dep.trigger())}};function un(e){e.dep&&e.dep.trigger()}function dn(e){return I(e)?e.value:e}function fn(e){return v(e)?e():dn(e)}var pn={get:(e,t,n)=>t===`__v_raw`?e:dn(Reflect.get(e,t,n)),set:(e,t,n,r)=>{let i=e[t];return I(i)&&!I(n)?(i.value=n,!0):Reflect.set(e,t,n,r)}};function mn(e){return Qt(e)?e:new Proxy(e,pn)}var hn=class{constructor(e){this.__v_isRef=!0,this._value=void 0;let t=this.dep=new nt,{get:n,set:r}=e(t.track.bind(t),t.trigger.bind(t));this._get=n,this._set=r}get value(){return this._value=this._get()}set value(e){this._set(e)}};function gn(e){return new hn(e)}function _n(e){let t=p(e)?Array(e.length):{};for(let n in e)t[n]=xn(e,n);return t}var vn=class{constructor(e,t,n){this._object=e,this._key=t,this._defaultValue=n,this.__v_isRef=!0,this._value=void 0,this._raw=F(e);let r=!0,i=e;if(!p(e)||!D(String(t)))do r=!tn(i)||en(i);while(r&&(i=i.__v_raw));this._shallow=r}get value(){let e=this._object[this._key];return this._shallow&&(e=dn(e)),this._value=e===void 0?this._defaultValue:e}set value(e){if(this._shallow&&I(this._raw[this._key])){let t=this._object[this._key];if(I(t)){t.value=e;return}}this._object[this._key]=e}get dep(){return ut(this._raw,this._key)}},yn=class{constructor(e){this._getter=e,this.__v_isRef=!0,this.__v_isReadonly=!0,this._value=void 0}get value(){return this._value=this._getter()}};function bn(e,t,n){return I(e)?e:v(e)?new yn(e):x(e)&&arguments.length>1?xn(e,t,n):on(e)}function xn(e,t,n){return new vn(e,t,n)}var Sn=class{constructor(e,t,n){this.fn=e,this.setter=t,this._value=void 0,this.dep=new nt(this),this.__v_isRef=!0,this.deps=void 0,this.depsTail=void 0,this.flags=16,this.globalVersion=et-1,this.next=void 0,this.effect=this,this.__v_isReadonly=!t,this.isSSR=n}notify(){if(this.flags|=16,!(this.flags&8)&&P!==this)return Re(this,!0),!0}get value(){let e=this.dep.track();return We(this),e&&(e.version=this.dep.version),this._value}set value(e){this.setter&&this.setter(e)}};function Cn(e,t,n=!1){let r,i;return v(e)?r=e:(r=e.get,i=e.set),new Sn(r,i,n)}var wn={GET:`get`,HAS:`has`,ITERATE:`iterate`},Tn={SET:`set`,ADD:`add`,DELETE:`delete`,CLEAR:`clear`},En={},Dn=new WeakMap,On=void 0;function kn(){return On}function An(e,t=!1,n=On){if(n){let t=Dn.get(n);t||Dn.set(n,t=[]),t.push(e)}}function jn(e,t,n=r){let{immediate:i,deep:o,once:s,scheduler:c,augmentJob:l,call:d}=n,f=e=>o?e:en(e)||o===!1||o===0?Mn(e,1):Mn(e),m,h,g,_,y=!1,b=!1;if(I(e)?(h=()=>e.value,y=en(e)):Qt(e)?(h=()=>f(e),y=!0):p(e)?(b=!0,y=e.some(e=>Qt(e)||en(e)),h=()=>e.map(e=>{if(I(e))return e.value;if(Qt(e))return f(e);if(v(e))return d?d(e,2):e()})):h=v(e)?t?d?()=>d(e,2):e:()=>{if(g){Ze();try{g()}finally{Qe()}}let t=On;On=m;try{return d?d(e,3,[_]):e(_)}finally{On=t}}:a,t&&o){let e=h,t=o===!0?1/0:o;h=()=>Mn(e(),t)}let x=je(),S=()=>{m.stop(),x&&x.active&&u(x.effects,m)};if(s&&t){let e=t;t=(...t)=>{e(...t),S()}}let C=b?Array(e.length).fill(En):En,w=e=>{if(!(!(m.flags&1)||!m.dirty&&!e))if(t){let e=m.run();if(o||y||(b?e.some((e,t)=>M(e,C[t])):M(e,C))){g&&g();let n=On;On=m;try{let n=[e,C===En?void 0:b&&C[0]===En?[]:C,_];C=e,d?d(t,3,n):t(...n)}finally{On=n}}}else m.run()};return l&&l(w),m=new Pe(h),m.scheduler=c?()=>c(w,!1):w,_=e=>An(e,!1,m),g=m.onStop=()=>{let e=Dn.get(m);if(e){if(d)d(e,4);else for(let t of e)t();Dn.delete(m)}},t?i?w(!0):C=m.run():c?c(w.bind(null,!0),!0):m.run(),S.pause=m.pause.bind(m),S.resume=m.resume.bind(m),S.stop=S,S}function Mn(e,t=1/0,n){if(t<=0||!x(e)||e.__v_skip||(n||=new Map,(n.get(e)||0)>=t))return e;if(n.set(e,t),t--,I(e))Mn(e.value,t,n);else if(p(e))for(let r=0;r<e.length;r++)Mn(e[r],t,n);else if(h(e)||m(e))e.forEach(e=>{Mn(e,t,n)});else if(E(e)){for(let r in e)Mn(e[r],t,n);for(let r of Object.getOwnPropertySymbols(e))Object.prototype.propertyIsEnumerable.call(e,r)&&Mn(e[r],t,n)}return e}var Nn=[];function Pn(e){Nn.push(e)}function Fn(){Nn.pop()}function In(e,t){}var Ln={SETUP_FUNCTION:0,0:`SETUP_FUNCTION`,RENDER_FUNCTION:1,1:`RENDER_FUNCTION`,NATIVE_EVENT_HANDLER:5,5:`NATIVE_EVENT_HANDLER`,COMPONENT_EVENT_HANDLER:6,6:`COMPONENT_EVENT_HANDLER`,VNODE_HOOK:7,7:`VNODE_HOOK`,DIRECTIVE_HOOK:8,8:`DIRECTIVE_HOOK`,TRANSITION_HOOK:9,9:`TRANSITION_HOOK`,APP_ERROR_HANDLER:10,10:`APP_ERROR_HANDLER`,APP_WARN_HANDLER:11,11:`APP_WARN_HANDLER`,FUNCTION_REF:12,12:`FUNCTION_REF`,ASYNC_COMPONENT_LOADER:13,13:`ASYNC_COMPONENT_LOADER`,SCHEDULER:14,14:`SCHEDULER`,COMPONENT_UPDATE:15,15:`COMPONENT_UPDATE`,APP_UNMOUNT_CLEANUP:16,16:`APP_UNMOUNT_CLEANUP`},Rn={sp:`serverPrefetch hook`,bc:`beforeCreate hook`,c:`created hook`,bm:`beforeMount hook`,m:`mounted hook`,bu:`beforeUpdate hook`,u:`updated`,bum:`beforeUnmount hook`,um:`unmounted hook`,a:`activated hook`,da:`deactivated hook`,ec:`errorCaptured hook`,rtc:`renderTracked hook`,rtg:`renderTriggered hook`,0:`setup function`,1:`render function`,2:`watcher getter`,3:`watcher callback`,4:`watcher cleanup function`,5:`native event handler`,6:`component event handler`,7:`vnode hook`,8:`directive hook`,9:`transition hook`,10:`app errorHandler`,11:`app warnHandler`,12:`ref function`,13:`async component loader`,14:`scheduler flush`,15:`component update`,16:`app unmount cleanup function`};function zn(e,t,n,r){try{return r?e(...r):e()}catch(e){Vn(e,t,n)}}function Bn(e,t,n,r){if(v(e)){let i=zn(e,t,n,r);return i&&S(i)&&i.catch(e=>{Vn(e,t,n)}),i}if(p(e)){let i=[];for(let a=0;a<e.length;a++)i.push(Bn(e[a],t,n,r));return i}}function Vn(e,t,n,i=!0){let a=t?t.vnode:null,{errorHandler:o,throwUnhandledErrorInProduction:s}=t&&t.appContext.config||r;if(t){let r=t.parent,i=t.proxy,a=`https://vuejs.org/error-reference/#runtime-${n}`;for(;r;){let t=r.ec;if(t){for(let n=0;n<t.length;n++)if(t[n](e,i,a)===!1)return}r=r.parent}if(o){Ze(),zn(o,null,10,[e,i,a]),Qe();return}}Hn(e,n,a,i,s)}function Hn(e,t,n,r=!0,i=!1){if(i)throw e;console.error(e)}var Un=[],Wn=-1,Gn=[],Kn=null,qn=0,Jn=Promise.resolve(),Yn=null;function Xn(e){let t=Yn||Jn;return e?t.then(this?e.bind(this):e):t}function Zn(e){let t=Wn+1,n=Un.length;for(;t<n;){let r=t+n>>>1,i=Un[r],a=rr(i);a<e||a===e&&i.flags&2?t=r+1:n=r}return t}function Qn(e){if(!(e.flags&1)){let t=rr(e),n=Un[Un.length-1];!n||!(e.flags&2)&&t>=rr(n)?Un.push(e):Un.splice(Zn(t),0,e),e.flags|=1,$n()}}function $n(){Yn||=Jn.then(ir)}function er(e){p(e)?Gn.push(...e):Kn&&e.id===-1?Kn.splice(qn+1,0,e):e.flags&1||(Gn.push(e),e.flags|=1),$n()}function tr(e,t,n=Wn+1){for(;n<Un.length;n++){let t=Un[n];if(t&&t.flags&2){if(e&&t.id!==e.uid)continue;Un.splice(n,1),n--,t.flags&4&&(t.flags&=-2),t(),t.flags&4||(t.flags&=-2)}}}function nr(e){if(Gn.length){let e=[...new Set(Gn)].sort((e,t)=>rr(e)-rr(t));if(Gn.length=0,Kn){Kn.push(...e);return}for(Kn=e,qn=0;qn<Kn.length;qn++){let e=Kn[qn];e.flags&4&&(e.flags&=-2),e.flags&8||e(),e.flags&=-2}Kn=null,qn=0}}var rr=e=>e.id==null?e.flags&2?-1:1/0:e.id;function ir(e){try{for(Wn=0;Wn<Un.length;Wn++){let e=Un[Wn];e&&!(e.flags&8)&&(e.flags&4&&(e.flags&=-2),zn(e,e.i,e.i?15:14),e.flags&4||(e.flags&=-2))}}finally{for(;Wn<Un.length;Wn++){let e=Un[Wn];e&&(e.flags&=-2)}Wn=-1,Un.length=0,nr(e),Yn=null,(Un.length||Gn.length)&&ir(e)}}var ar,or=[];function sr(e,t){ar=e,ar?(ar.enabled=!0,or.forEach(({event:e,args:t})=>ar.emit(e,...t)),or=[]):typeof window<`u`&&window.HTMLElement&&!(window.navigator?.userAgent)?.includes(`jsdom`)?((t.__VUE_DEVTOOLS_HOOK_REPLAY__=t.__VUE_DEVTOOLS_HOOK_REPLAY__||[]).push(e=>{sr(e,t)}),setTimeout(()=>{ar||(t.__VUE_DEVTOOLS_HOOK_REPLAY__=null,or=[])},3e3)):or=[]}var L=null,cr=null;function lr(e){let t=L;return L=e,cr=e&&e.type.__scopeId||null,t}function ur(e){cr=e}function dr(){cr=null}var fr=e=>pr;function pr(e,t=L,n){if(!t||e._n)return e;let r=(...n)=>{r._d&&is(-1);let i=lr(t),a;try{a=e(...n)}finally{lr(i),r._d&&is(1)}return a};return r._n=!0,r._c=!0,r._d=!0,r}function mr(e,t){if(L===null)return e;let n=Gs(L),i=e.dirs||=[];for(let e=0;e<t.length;e++){let[a,o,s,c=r]=t[e];a&&(v(a)&&(a={mounted:a,updated:a}),a.deep&&Mn(o),i.push({dir:a,instance:n,value:o,oldValue:void 0,arg:s,modifiers:c}))}return e}function hr(e,t,n,r){let i=e.dirs,a=t&&t.dirs;for(let o=0;o<i.length;o++){let s=i[o];a&&(s.oldValue=a[o].value);let c=s.dir[r];c&&(Ze(),Bn(c,n,8,[e.el,s,e,t]),Qe())}}function gr(e,t){if(H){let n=H.provides,r=H.parent&&H.parent.provides;r===n&&(n=H.provides=Object.create(r)),n[e]=t}}function _r(e,t,n=!1){let r=Os();if(r||Ja){let i=Ja?Ja._context.provides:r?r.parent==null||r.ce?r.vnode.appContext&&r.vnode.appContext.provides:r.parent.provides:void 0;if(i&&e in i)return i[e];if(arguments.length>1)return n&&v(t)?t.call(r&&r.proxy):t}}function vr(){return!!(Os()||Ja)}var yr=Symbol.for(`v-scx`),br=()=>_r(yr);function xr(e,t){return Tr(e,null,t)}function Sr(e,t){return Tr(e,null,{flush:`post`})}function Cr(e,t){return Tr(e,null,{flush:`sync`})}function wr(e,t,n){return Tr(e,t,n)}function Tr(e,t,n=r){let{immediate:i,deep:o,flush:s,once:c}=n,u=l({},n),d=t&&i||!t&&s!==`post`,f;if(Ps){if(s===`sync`){let e=br();f=e.__watcherHandles||=[]}else if(!d){let e=()=>{};return e.stop=a,e.resume=a,e.pause=a,e}}let p=H;u.call=(e,t,n)=>Bn(e,p,t,n);let m=!1;s===`post`?u.scheduler=e=>{R(e,p&&p.suspense)}:s!==`sync`&&(m=!0,u.scheduler=(e,t)=>{t?e():Qn(e)}),u.augmentJob=e=>{t&&(e.flags|=4),m&&(e.flags|=2,p&&(e.id=p.uid,e.i=p))};let h=jn(e,t,u);return Ps&&(f?f.push(h):d&&h()),h}function Er(e,t,n){let r=this.proxy,i=y(e)?e.includes(`.`)?Dr(r,e):()=>r[e]:e.bind(r,r),a;v(t)?a=t:(a=t.handler,n=t);let o=js(this),s=Tr(i,a.bind(r),n);return o(),s}function Dr(e,t){let n=t.split(`.`);return()=>{let t=e;for(let e=0;e<n.length&&t;e++)t=t[n[e]];return t}}var Or=Symbol(`_vte`),kr=e=>e.__isTeleport,Ar=e=>e&&(e.disabled||e.disabled===``),jr=e=>e&&(e.defer||e.defer===``),Mr=e=>typeof SVGElement<`u`&&e instanceof SVGElement,Nr=e=>typeof MathMLElement==`function`&&e instanceof MathMLElement,Pr=(e,t)=>{let n=e&&e.to;return y(n)?t?t(n):null:n},Fr={name:`Teleport`,__isTeleport:!0,process(e,t,n,r,i,a,o,s,c,l){let{mc:u,pc:d,pbc:f,o:{insert:p,querySelector:m,createText:h,createComment:g}}=l,_=Ar(t.props),{shapeFlag:v,children:y,dynamicChildren:b}=t;if(e==null){let e=t.el=h(``),l=t.anchor=h(``);p(e,n,r),p(l,n,r);let d=(e,t)=>{v&16&&u(y,e,t,i,a,o,s,c)},f=()=>{let e=t.target=Pr(t.props,m),n=Br(e,t,h,p);e&&(o!==`svg`&&Mr(e)?o=`svg`:o!==`mathml`&&Nr(e)&&(o=`mathml`),i&&i.isCE&&(i.ce._teleportTargets||(i.ce._teleportTargets=new Set)).add(e),_||(d(e,n),zr(t,!1)))};_&&(d(n,l),zr(t,!0)),jr(t.props)?(t.el.__isMounted=!1,R(()=>{f(),delete t.el.__isMounted},a)):f()}else{if(jr(t.props)&&e.el.__isMounted===!1){R(()=>{Fr.process(e,t,n,r,i,a,o,s,c,l)},a);return}t.el=e.el,t.targetStart=e.targetStart;let u=t.anchor=e.anchor,p=t.target=e.target,h=t.targetAnchor=e.targetAnchor,g=Ar(e.props),v=g?n:p,y=g?u:h;if(o===`svg`||Mr(p)?o=`svg`:(o===`mathml`||Nr(p))&&(o=`mathml`),b?(f(e.dynamicChildren,b,v,i,a,o,s),No(e,t,!0)):c||d(e,t,v,y,i,a,o,s,!1),_)g?t.props&&e.props&&t.props.to!==e.props.to&&(t.props.to=e.props.to):Ir(t,n,u,l,1);else if((t.props&&t.props.to)!==(e.props&&e.props.to)){let e=t.target=Pr(t.props,m);e&&Ir(t,e,null,l,0)}else g&&Ir(t,p,h,l,1);zr(t,_)}},remove(e,t,n,{um:r,o:{remove:i}},a){let{shapeFlag:o,children:s,anchor:c,targetStart:l,targetAnchor:u,target:d,props:f}=e;if(d&&(i(l),i(u)),a&&i(c),o&16){let e=a||!Ar(f);for(let i=0;i<s.length;i++){let a=s[i];r(a,t,n,e,!!a.dynamicChildren)}}},move:Ir,hydrate:Lr};function Ir(e,t,n,{o:{insert:r},m:i},a=2){a===0&&r(e.targetAnchor,t,n);let{el:o,anchor:s,shapeFlag:c,children:l,props:u}=e,d=a===2;if(d&&r(o,t,n),(!d||Ar(u))&&c&16)for(let e=0;e<l.length;e++)i(l[e],t,n,2);d&&r(s,t,n)}function Lr(e,t,n,r,i,a,{o:{nextSibling:o,parentNode:s,querySelector:c,insert:l,createText:u}},d){function f(e,t,c,l){t.anchor=d(o(e),t,s(e),n,r,i,a),t.targetStart=c,t.targetAnchor=l}let p=t.target=Pr(t.props,c),m=Ar(t.props);if(p){let s=p._lpa||p.firstChild;if(t.shapeFlag&16)if(m)f(e,t,s,s&&o(s));else{t.anchor=o(e);let c=s;for(;c;){if(c&&c.nodeType===8){if(c.data===`teleport start anchor`)t.targetStart=c;else if(c.data===`teleport anchor`){t.targetAnchor=c,p._lpa=t.targetAnchor&&o(t.targetAnchor);break}}c=o(c)}t.targetAnchor||Br(p,t,u,l),d(s&&o(s),t,p,n,r,i,a)}zr(t,m)}else m&&t.shapeFlag&16&&f(e,t,e,o(e));return t.anchor&&o(t.anchor)}var Rr=Fr;function zr(e,t){let n=e.ctx;if(n&&n.ut){let r,i;for(t?(r=e.el,i=e.anchor):(r=e.targetStart,i=e.targetAnchor);r&&r!==i;)r.nodeType===1&&r.setAttribute(`data-v-owner`,n.uid),r=r.nextSibling;n.ut()}}function Br(e,t,n,r){let i=t.targetStart=n(``),a=t.targetAnchor=n(``);return i[Or]=a,e&&(r(i,e),r(a,e)),a}var Vr=Symbol(`_leaveCb`),Hr=Symbol(`_enterCb`);function Ur(){let e={isMounted:!1,isLeaving:!1,isUnmounting:!1,leavingVNodes:new Map};return Hi(()=>{e.isMounted=!0}),Gi(()=>{e.isUnmounting=!0}),e}var Wr=[Function,Array],Gr={mode:String,appear:Boolean,persisted:Boolean,onBeforeEnter:Wr,onEnter:Wr,onAfterEnter:Wr,onEnterCancelled:Wr,onBeforeLeave:Wr,onLeave:Wr,onAfterLeave:Wr,onLeaveCancelled:Wr,onBeforeAppear:Wr,onAppear:Wr,onAfterAppear:Wr,onAppearCancelled:Wr},Kr=e=>{let t=e.subTree;return t.component?Kr(t.component):t},qr={name:`BaseTransition`,props:Gr,setup(e,{slots:t}){let n=Os(),r=Ur();return()=>{let i=t.default&&ti(t.default(),!0);if(!i||!i.length)return;let a=Jr(i),o=F(e),{mode:s}=o;if(r.isLeaving)return Qr(a);let c=$r(a);if(!c)return Qr(a);let l=Zr(c,o,r,n,e=>l=e);c.type!==B&&ei(c,l);let u=n.subTree&&$r(n.subTree);if(u&&u.type!==B&&!ls(u,c)&&Kr(n).type!==B){let e=Zr(u,o,r,n);if(ei(u,e),s===`out-in`&&c.type!==B)return r.isLeaving=!0,e.afterLeave=()=>{r.isLeaving=!1,n.job.flags&8||n.update(),delete e.afterLeave,u=void 0},Qr(a);s===`in-out`&&c.type!==B?e.delayLeave=(e,t,n)=>{let i=Xr(r,u);i[String(u.key)]=u,e[Vr]=()=>{t(),e[Vr]=void 0,delete l.delayedLeave,u=void 0},l.delayedLeave=()=>{n(),delete l.delayedLeave,u=void 0}}:u=void 0}else u&&=void 0;return a}}};function Jr(e){let t=e[0];if(e.length>1){for(let n of e)if(n.type!==B){t=n;break}}return t}var Yr=qr;function Xr(e,t){let{leavingVNodes:n}=e,r=n.get(t.type);return r||(r=Object.create(null),n.set(t.type,r)),r}function Zr(e,t,n,r,i){let{appear:a,mode:o,persisted:s=!1,onBeforeEnter:c,onEnter:l,onAfterEnter:u,onEnterCancelled:d,onBeforeLeave:f,onLeave:m,onAfterLeave:h,onLeaveCancelled:g,onBeforeAppear:_,onAppear:v,onAfterAppear:y,onAppearCancelled:b}=t,x=String(e.key),S=Xr(n,e),C=(e,t)=>{e&&Bn(e,r,9,t)},w=(e,t)=>{let n=t[1];C(e,t),p(e)?e.every(e=>e.length<=1)&&n():e.length<=1&&n()},T={mode:o,persisted:s,beforeEnter(t){let r=c;if(!n.isMounted)if(a)r=_||c;else return;t[Vr]&&t[Vr](!0);let i=S[x];i&&ls(e,i)&&i.el[Vr]&&i.el[Vr](),C(r,[t])},enter(e){let t=l,r=u,i=d;if(!n.isMounted)if(a)t=v||l,r=y||u,i=b||d;else return;let o=!1,s=e[Hr]=t=>{o||(o=!0,C(t?i:r,[e]),T.delayedLeave&&T.delayedLeave(),e[Hr]=void 0)};t?w(t,[e,s]):s()},leave(t,r){let i=String(e.key);if(t[Hr]&&t[Hr](!0),n.isUnmounting)return r();C(f,[t]);let a=!1,o=t[Vr]=n=>{a||(a=!0,r(),C(n?g:h,[t]),t[Vr]=void 0,S[i]===e&&delete S[i])};S[i]=e,m?w(m,[t,o]):o()},clone(e){let a=Zr(e,t,n,r,i);return i&&i(a),a}};return T}function Qr(e){if(Ai(e))return e=gs(e),e.children=null,e}function $r(e){if(!Ai(e))return kr(e.type)&&e.children?Jr(e.children):e;if(e.component)return e.component.subTree;let{shapeFlag:t,children:n}=e;if(n){if(t&16)return n[0];if(t&32&&v(n.default))return n.default()}}function ei(e,t){e.shapeFlag&6&&e.component?(e.transition=t,ei(e.component.subTree,t)):e.shapeFlag&128?(e.ssContent.transition=t.clone(e.ssContent),e.ssFallback.transition=t.clone(e.ssFallback)):e.transition=t}function ti(e,t=!1,n){let r=[],i=0;for(let a=0;a<e.length;a++){let o=e[a],s=n==null?o.key:String(n)+String(o.key==null?a:o.key);o.type===z?(o.patchFlag&128&&i++,r=r.concat(ti(o.children,t,s))):(t||o.type!==B)&&r.push(s==null?o:gs(o,{key:s}))}if(i>1)for(let e=0;e<r.length;e++)r[e].patchFlag=-2;return r}function ni(e,t){return v(e)?(()=>l({name:e.name},t,{setup:e}))():e}function ri(){let e=Os();return e?(e.appContext.config.idPrefix||`v`)+`-`+e.ids[0]+ e.ids[1]++:``}function ii(e){e.ids=[e.ids[0]+ e.ids[2]+++`-`,0,0]}function ai(e){let t=Os(),n=sn(null);if(t){let i=t.refs===r?t.refs={}:t.refs;Object.defineProperty(i,e,{enumerable:!0,get:()=>n.value,set:e=>n.value=e})}return n}var oi=new WeakMap;function si(e,t,n,i,a=!1){if(p(e)){e.forEach((e,r)=>si(e,t&&(p(t)?t[r]:t),n,i,a));return}if(Di(i)&&!a){i.shapeFlag&512&&i.type.__asyncResolved&&i.component.subTree.component&&si(e,t,n,i.component.subTree);return}let s=i.shapeFlag&4?Gs(i.component):i.el,c=a?null:s,{i:l,r:d}=e,m=t&&t.r,h=l.refs===r?l.refs={}:l.refs,g=l.setupState,_=F(g),b=g===r?o:e=>f(_,e),x=e=>!0;if(m!=null&&m!==d){if(ci(t),y(m))h[m]=null,b(m)&&(g[m]=null);else if(I(m)){x(m)&&(m.value=null);let e=t;e.k&&(h[e.k]=null)}}if(v(d))zn(d,l,12,[c,h]);else{let t=y(d),r=I(d);if(t||r){let i=()=>{if(e.f){let n=t?b(d)?g[d]:h[d]:x(d)||!e.k?d.value:h[e.k];if(a)p(n)&&u(n,s);else if(p(n))n.includes(s)||n.push(s);else if(t)h[d]=[s],b(d)&&(g[d]=h[d]);else{let t=[s];x(d)&&(d.value=t),e.k&&(h[e.k]=t)}}else t?(h[d]=c,b(d)&&(g[d]=c)):r&&(x(d)&&(d.value=c),e.k&&(h[e.k]=c))};if(c){let t=()=>{i(),oi.delete(e)};t.id=-1,oi.set(e,t),R(t,n)}else ci(e),i()}}}function ci(e){let t=oi.get(e);t&&(t.flags|=8,oi.delete(e))}var li=!1,ui=()=>{li||=(console.error(`Hydration completed but contains mismatches.`),!0)},di=e=>e.namespaceURI.includes(`svg`)&&e.tagName!==`foreignObject`,fi=e=>e.namespaceURI.includes(`MathML`),pi=e=>{if(e.nodeType===1){if(di(e))return`svg`;if(fi(e))return`mathml`}},mi=e=>e.nodeType===8;function hi(e){let{mt:t,p:n,o:{patchProp:r,createText:i,nextSibling:a,parentNode:o,remove:c,insert:l,createComment:u}}=e,d=(e,t)=>{if(!t.hasChildNodes()){n(null,e,t),nr(),t._vnode=e;return}f(t.firstChild,e,null,null,null),nr(),t._vnode=e},f=(n,r,s,c,u,d=!1)=>{d||=!!r.dynamicChildren;let b=mi(n)&&n.data===`[`,x=()=>g(n,r,s,c,u,b),{type:S,ref:C,shapeFlag:w,patchFlag:T}=r,E=n.nodeType;r.el=n,T===-2&&(d=!1,r.dynamicChildren=null);let D=null;switch(S){case Zo:E===3?(n.data!==r.children&&(ui(),n.data=r.children),D=a(n)):r.children===``?(l(r.el=i(``),o(n),n),D=n):D=x();break;case B:y(n)?(D=a(n),v(r.el=n.content.firstChild,n,s)):D=E!==8||b?x():a(n);break;case Qo:if(b&&(n=a(n),E=n.nodeType),E===1||E===3){D=n;let e=!r.children.length;for(let t=0;t<r.staticCount;t++)e&&(r.children+=D.nodeType===1?D.outerHTML:D.data),t===r.staticCount-1&&(r.anchor=D),D=a(D);return b?a(D):D}else x();break;case z:D=b?h(n,r,s,c,u,d):x();break;default:if(w&1)D=(E!==1||r.type.toLowerCase()!==n.tagName.toLowerCase())&&!y(n)?x():p(n,r,s,c,u,d);else if(w&6){r.slotScopeIds=u;let e=o(n);if(D=b?_(n):mi(n)&&n.data===`teleport start`?_(n,n.data,`teleport end`):a(n),t(r,e,null,s,c,pi(e),d),Di(r)&&!r.type.__asyncResolved){let t;b?(t=V(z),t.anchor=D?D.previousSibling:e.lastChild):t=n.nodeType===3?_s(``):V(`div`),t.el=n,r.component.subTree=t}}else w&64?D=E===8?r.type.hydrate(n,r,s,c,u,d,e,m):x():w&128&&(D=r.type.hydrate(n,r,s,c,pi(o(n)),u,d,e,f))}return C!=null&&si(C,null,c,r),D},p=(e,t,n,i,a,o)=>{o||=!!t.dynamicChildren;let{type:l,props:u,patchFlag:d,shapeFlag:f,dirs:p,transition:h}=t,g=l===`input`||l===`option`;if(g||d!==-1){p&&hr(t,null,n,`created`);let l=!1;if(y(e)){l=Mo(null,h)&&n&&n.vnode.props&&n.vnode.props.appear;let r=e.content.firstChild;if(l){let e=r.getAttribute(`class`);e&&(r.$cls=e),h.beforeEnter(r)}v(r,e,n),t.el=e=r}if(f&16&&!(u&&(u.innerHTML||u.textContent))){let r=m(e.firstChild,t,e,n,i,a,o);for(;r;){vi(e,1)||ui();let t=r;r=r.nextSibling,c(t)}}else if(f&8){let n=t.children;n[0]===`
-`&&(e.tagName===`PRE`||e.tagName===`TEXTAREA`)&&(n=n.slice(1));let{textContent:r}=e;r!==n&&r!==n.replace(/\r\n|\r/g,`
-`)&&(vi(e,0)||ui(),e.textContent=t.children)}if(u){if(g||!o||d&48){let t=e.tagName.includes(`-`);for(let i in u)(g&&(i.endsWith(`value`)||i===`indeterminate`)||s(i)&&!O(i)||i[0]===`.`||t)&&r(e,i,null,u[i],void 0,n)}else if(u.onClick)r(e,`onClick`,null,u.onClick,void 0,n);else if(d&4&&Qt(u.style))for(let e in u.style)u.style[e]}let _;(_=u&&u.onVnodeBeforeMount)&&ws(_,n,t),p&&hr(t,null,n,`beforeMount`),((_=u&&u.onVnodeMounted)||p||l)&&Jo(()=>{_&&ws(_,n,t),l&&h.enter(e),p&&hr(t,null,n,`mounted`)},i)}return e.nextSibling},m=(e,t,r,o,s,c,u)=>{u||=!!t.dynamicChildren;let d=t.children,p=d.length;for(let t=0;t<p;t++){let m=u?d[t]:d[t]=bs(d[t]),h=m.type===Zo;e?(h&&!u&&t+1<p&&bs(d[t+1]).type===Zo&&(l(i(e.data.slice(m.children.length)),r,a(e)),e.data=m.children),e=f(e,m,o,s,c,u)):h&&!m.children?l(m.el=i(``),r):(vi(r,1)||ui(),n(null,m,r,null,o,s,pi(r),c))}return e},h=(e,t,n,r,i,s)=>{let{slotScopeIds:c}=t;c&&(i=i?i.concat(c):c);let d=o(e),f=m(a(e),t,d,n,r,i,s);return f&&mi(f)&&f.data===`]`?a(t.anchor=f):(ui(),l(t.anchor=u(`]`),d,f),f)},g=(e,t,r,i,s,l)=>{if(vi(e.parentElement,1)||ui(),t.el=null,l){let t=_(e);for(;;){let n=a(e);if(n&&n!==t)c(n);else break}}let u=a(e),d=o(e);return c(e),n(null,t,d,u,r,i,pi(d),s),r&&(r.vnode.el=t.el,so(r,t.el)),u},_=(e,t=`[`,n=`]`)=>{let r=0;for(;e;)if(e=a(e),e&&mi(e)&&(e.data===t&&r++,e.data===n)){if(r===0)return a(e);r--}return e},v=(e,t,n)=>{let r=t.parentNode;r&&r.replaceChild(e,t);let i=n;for(;i;)i.vnode.el===t&&(i.vnode.el=i.subTree.el=e),i=i.parent},y=e=>e.nodeType===1&&e.tagName===`TEMPLATE`;return[d,f]}var gi=`data-allow-mismatch`,_i={0:`text`,1:`children`,2:`class`,3:`style`,4:`attribute`};function vi(e,t){if(t===0||t===1)for(;e&&!e.hasAttribute(gi);)e=e.parentElement;let n=e&&e.getAttribute(gi);if(n==null)return!1;if(n===``)return!0;{let e=n.split(`,`);return t===0&&e.includes(`children`)?!0:e.includes(_i[t])}}var yi=le().requestIdleCallback||(e=>setTimeout(e,1)),bi=le().cancelIdleCallback||(e=>clearTimeout(e)),xi=(e=1e4)=>t=>{let n=yi(t,{timeout:e});return()=>bi(n)};function Si(e){let{top:t,left:n,bottom:r,right:i}=e.getBoundingClientRect(),{innerHeight:a,innerWidth:o}=window;return(t>0&&t<a||r>0&&r<a)&&(n>0&&n<o||i>0&&i<o)}var Ci=e=>(t,n)=>{let r=new IntersectionObserver(e=>{for(let n of e)if(n.isIntersecting){r.disconnect(),t();break}},e);return n(e=>{if(e instanceof Element){if(Si(e))return t(),r.disconnect(),!1;r.observe(e)}}),()=>r.disconnect()},wi=e=>t=>{if(e){let n=matchMedia(e);if(n.matches)t();else return n.addEventListener(`change`,t,{once:!0}),()=>n.removeEventListener(`change`,t)}},Ti=(e=[])=>(t,n)=>{y(e)&&(e=[e]);let r=!1,i=e=>{r||(r=!0,a(),t(),e.target.dispatchEvent(new e.constructor(e.type,e)))},a=()=>{n(t=>{for(let n of e)t.removeEventListener(n,i)})};return n(t=>{for(let n of e)t.addEventListener(n,i,{once:!0})}),a};function Ei(e,t){if(mi(e)&&e.data===`[`){let n=1,r=e.nextSibling;for(;r;){if(r.nodeType===1){if(t(r)===!1)break}else if(mi(r))if(r.data===`]`){if(--n===0)break}else r.data===`[`&&n++;r=r.nextSibling}}else t(e)}var Di=e=>!!e.type.__asyncLoader;function Oi(e){v(e)&&(e={loader:e});let{loader:t,loadingComponent:n,errorComponent:r,delay:i=200,hydrate:a,timeout:o,suspensible:s=!0,onError:c}=e,l=null,u,d=0,f=()=>(d++,l=null,p()),p=()=>{let e;return l||(e=l=t().catch(e=>{if(e=e instanceof Error?e:Error(String(e)),c)return new Promise((t,n)=>{c(e,()=>t(f()),()=>n(e),d+1)});throw e}).then(t=>e!==l&&l?l:(t&&(t.__esModule||t[Symbol.toStringTag]===`Module`)&&(t=t.default),u=t,t)))};return ni({name:`AsyncComponentWrapper`,__asyncLoader:p,__asyncHydrate(e,t,n){let r=!1;(t.bu||=[]).push(()=>r=!0);let i=()=>{r||n()},o=a?()=>{let n=a(i,t=>Ei(e,t));n&&(t.bum||=[]).push(n)}:i;u?o():p().then(()=>!t.isUnmounted&&o())},get __asyncResolved(){return u},setup(){let e=H;if(ii(e),u)return()=>ki(u,e);let t=t=>{l=null,Vn(t,e,13,!r)};if(s&&e.suspense||Ps)return p().then(t=>()=>ki(t,e)).catch(e=>(t(e),()=>r?V(r,{error:e}):null));let a=on(!1),c=on(),d=on(!!i);return i&&setTimeout(()=>{d.value=!1},i),o!=null&&setTimeout(()=>{if(!a.value&&!c.value){let e=Error(`Async component timed out after ${o}ms.`);t(e),c.value=e}},o),p().then(()=>{a.value=!0,e.parent&&Ai(e.parent.vnode)&&e.parent.update()}).catch(e=>{t(e),c.value=e}),()=>{if(a.value&&u)return ki(u,e);if(c.value&&r)return V(r,{error:c.value});if(n&&!d.value)return ki(n,e)}}})}function ki(e,t){let{ref:n,props:r,children:i,ce:a}=t.vnode,o=V(e,r,i);return o.ref=n,o.ce=a,delete t.vnode.ce,o}var Ai=e=>e.type.__isKeepAlive,ji={name:`KeepAlive`,__isKeepAlive:!0,props:{include:[String,RegExp,Array],exclude:[String,RegExp,Array],max:[String,Number]},setup(e,{slots:t}){let n=Os(),r=n.ctx;if(!r.renderer)return()=>{let e=t.default&&t.default();return e&&e.length===1?e[0]:e};let i=new Map,a=new Set,o=null,s=n.suspense,{renderer:{p:c,m:l,um:u,o:{createElement:d}}}=r,f=d(`div`);r.activate=(e,t,n,r,i)=>{let a=e.component;l(e,t,n,0,s),c(a.vnode,e,t,n,a,s,r,e.slotScopeIds,i),R(()=>{a.isDeactivated=!1,a.a&&ie(a.a);let t=e.props&&e.props.onVnodeMounted;t&&ws(t,a.parent,e)},s)},r.deactivate=e=>{let t=e.component;Io(t.m),Io(t.a),l(e,f,null,1,s),R(()=>{t.da&&ie(t.da);let n=e.props&&e.props.onVnodeUnmounted;n&&ws(n,t.parent,e),t.isDeactivated=!0},s)};function p(e){Li(e),u(e,n,s,!0)}function m(e){i.forEach((t,n)=>{let r=Ks(Di(t)?t.type.__asyncResolved||{}:t.type);r&&!e(r)&&h(n)})}function h(e){let t=i.get(e);t&&(!o||!ls(t,o))?p(t):o&&Li(o),i.delete(e),a.delete(e)}wr(()=>[e.include,e.exclude],([e,t])=>{e&&m(t=>Mi(e,t)),t&&m(e=>!Mi(t,e))},{flush:`post`,deep:!0});let g=null,_=()=>{g!=null&&(Ro(n.subTree.type)?R(()=>{i.set(g,Ri(n.subTree))},n.subTree.suspense):i.set(g,Ri(n.subTree)))};return Hi(_),Wi(_),Gi(()=>{i.forEach(e=>{let{subTree:t,suspense:r}=n,i=Ri(t);if(e.type===i.type&&e.key===i.key){Li(i);let e=i.component.da;e&&R(e,r);return}p(e)})}),()=>{if(g=null,!t.default)return o=null;let n=t.default(),r=n[0];if(n.length>1)return o=null,n;if(!cs(r)||!(r.shapeFlag&4)&&!(r.shapeFlag&128))return o=null,r;let s=Ri(r);if(s.type===B)return o=null,s;let c=s.type,l=Ks(Di(s)?s.type.__asyncResolved||{}:c),{include:u,exclude:d,max:f}=e;if(u&&(!l||!Mi(u,l))||d&&l&&Mi(d,l))return s.shapeFlag&=-257,o=s,r;let p=s.key==null?c:s.key,m=i.get(p);return s.el&&(s=gs(s),r.shapeFlag&128&&(r.ssContent=s)),g=p,m?(s.el=m.el,s.component=m.component,s.transition&&ei(s,s.transition),s.shapeFlag|=512,a.delete(p),a.add(p)):(a.add(p),f&&a.size>parseInt(f,10)&&h(a.values().next().value)),s.shapeFlag|=256,o=s,Ro(r.type)?r:s}}};function Mi(e,t){return p(e)?e.some(e=>Mi(e,t)):y(e)?e.split(`,`).includes(t):_(e)?(e.lastIndex=0,e.test(t)):!1}function Ni(e,t){Fi(e,`a`,t)}function Pi(e,t){Fi(e,`da`,t)}function Fi(e,t,n=H){let r=e.__wdc||=()=>{let t=n;for(;t;){if(t.isDeactivated)return;t=t.parent}return e()};if(zi(t,r,n),n){let e=n.parent;for(;e&&e.parent;)Ai(e.parent.vnode)&&Ii(r,t,n,e),e=e.parent}}function Ii(e,t,n,r){let i=zi(t,e,r,!0);Ki(()=>{u(r[t],i)},n)}function Li(e){e.shapeFlag&=-257,e.shapeFlag&=-513}function Ri(e){return e.shapeFlag&128?e.ssContent:e}function zi(e,t,n=H,r=!1){if(n){let i=n[e]||(n[e]=[]),a=t.__weh||=(...r)=>{Ze();let i=js(n),a=Bn(t,n,e,r);return i(),Qe(),a};return r?i.unshift(a):i.push(a),a}}var Bi=e=>(t,n=H)=>{(!Ps||e===`sp`)&&zi(e,(...e)=>t(...e),n)},Vi=Bi(`bm`),Hi=Bi(`m`),Ui=Bi(`bu`),Wi=Bi(`u`),Gi=Bi(`bum`),Ki=Bi(`um`),qi=Bi(`sp`),Ji=Bi(`rtg`),Yi=Bi(`rtc`);function Xi(e,t=H){zi(`ec`,e,t)}var Zi=`components`,Qi=`directives`;function $i(e,t){return ra(Zi,e,!0,t)||e}var ea=Symbol.for(`v-ndc`);function ta(e){return y(e)?ra(Zi,e,!1)||e:e||ea}function na(e){return ra(Qi,e)}function ra(e,t,n=!0,r=!1){let i=L||H;if(i){let n=i.type;if(e===Zi){let e=Ks(n,!1);if(e&&(e===t||e===k(t)||e===re(k(t))))return n}let a=ia(i[e]||n[e],t)||ia(i.appContext[e],t);return!a&&r?n:a}}function ia(e,t){return e&&(e[t]||e[k(t)]||e[re(k(t))])}function aa(e,t,n,r){let i,a=n&&n[r],o=p(e);if(o||y(e)){let n=o&&Qt(e),r=!1,s=!1;n&&(r=!en(e),s=$t(e),e=ft(e)),i=Array(e.length);for(let n=0,o=e.length;n<o;n++)i[n]=t(r?s?an(rn(e[n])):rn(e[n]):e[n],n,void 0,a&&a[n])}else if(typeof e==`number`){i=Array(e);for(let n=0;n<e;n++)i[n]=t(n+1,n,void 0,a&&a[n])}else if(x(e))if(e[Symbol.iterator])i=Array.from(e,(e,n)=>t(e,n,void 0,a&&a[n]));else{let n=Object.keys(e);i=Array(n.length);for(let r=0,o=n.length;r<o;r++){let o=n[r];i[r]=t(e[o],o,r,a&&a[r])}}else i=[];return n&&(n[r]=i),i}function oa(e,t){for(let n=0;n<t.length;n++){let r=t[n];if(p(r))for(let t=0;t<r.length;t++)e[r[t].name]=r[t].fn;else r&&(e[r.name]=r.key?(...e)=>{let t=r.fn(...e);return t&&(t.key=r.key),t}:r.fn)}return e}function sa(e,t,n={},r,i){if(L.ce||L.parent&&Di(L.parent)&&L.parent.ce){let e=Object.keys(n).length>0;return t!==`default`&&(n.name=t),ts(),ss(z,null,[V(`slot`,n,r&&r())],e?-2:64)}let a=e[t];a&&a._c&&(a._d=!1),ts();let o=a&&ca(a(n)),s=n.key||o&&o.key,c=ss(z,{key:(s&&!b(s)?s:`_${t}`)+(!o&&r?`_fb`:``)},o||(r?r():[]),o&&e._===1?64:-2);return!i&&c.scopeId&&(c.slotScopeIds=[c.scopeId+`-s`]),a&&a._c&&(a._d=!0),c}function ca(e){return e.some(e=>cs(e)?!(e.type===B||e.type===z&&!ca(e.children)):!0)?e:null}function la(e,t){let n={};for(let r in e)n[t&&/[A-Z]/.test(r)?`on:${r}`:j(r)]=e[r];return n}var ua=e=>e?Ns(e)?Gs(e):ua(e.parent):null,da=l(Object.create(null),{$:e=>e,$el:e=>e.vnode.el,$data:e=>e.data,$props:e=>e.props,$attrs:e=>e.attrs,$slots:e=>e.slots,$refs:e=>e.refs,$parent:e=>ua(e.parent),$root:e=>ua(e.root),$host:e=>e.ce,$emit:e=>e.emit,$options:e=>Fa(e),$forceUpdate:e=>e.f||=()=>{Qn(e.update)},$nextTick:e=>e.n||=Xn.bind(e.proxy),$watch:e=>Er.bind(e)}),fa=(e,t)=>e!==r&&!e.__isScriptSetup&&f(e,t),pa={get({_:e},t){if(t===`__v_skip`)return!0;let{ctx:n,setupState:i,data:a,props:o,accessCache:s,type:c,appContext:l}=e;if(t[0]!==`$`){let e=s[t];if(e!==void 0)switch(e){case 1:return i[t];case 2:return a[t];case 4:return n[t];case 3:return o[t]}else if(fa(i,t))return s[t]=1,i[t];else if(a!==r&&f(a,t))return s[t]=2,a[t];else if(f(o,t))return s[t]=3,o[t];else if(n!==r&&f(n,t))return s[t]=4,n[t];else Aa&&(s[t]=0)}let u=da[t],d,p;if(u)return t===`$attrs`&&ct(e.attrs,`get`,``),u(e);if((d=c.__cssModules)&&(d=d[t]))return d;if(n!==r&&f(n,t))return s[t]=4,n[t];if(p=l.config.globalProperties,f(p,t))return p[t]},set({_:e},t,n){let{data:i,setupState:a,ctx:o}=e;return fa(a,t)?(a[t]=n,!0):i!==r&&f(i,t)?(i[t]=n,!0):f(e.props,t)||t[0]===`$`&&t.slice(1)in e?!1:(o[t]=n,!0)},has({_:{data:e,setupState:t,accessCache:n,ctx:i,appContext:a,props:o,type:s}},c){let l;return!!(n[c]||e!==r&&c[0]!==`$`&&f(e,c)||fa(t,c)||f(o,c)||f(i,c)||f(da,c)||f(a.config.globalProperties,c)||(l=s.__cssModules)&&l[c])},defineProperty(e,t,n){return n.get==null?f(n,`value`)&&this.set(e,t,n.value,null):e._.accessCache[t]=0,Reflect.defineProperty(e,t,n)}},ma=l({},pa,{get(e,t){if(t!==Symbol.unscopables)return pa.get(e,t,e)},has(e,t){return t[0]!==`_`&&!ue(t)}});function ha(){return null}function ga(){return null}function _a(e){}function va(e){}function ya(){return null}function ba(){}function xa(e,t){return null}function Sa(){return wa(`useSlots`).slots}function Ca(){return wa(`useAttrs`).attrs}function wa(e){let t=Os();return t.setupContext||=Ws(t)}function Ta(e){return p(e)?e.reduce((e,t)=>(e[t]=null,e),{}):e}function Ea(e,t){let n=Ta(e);for(let e in t){if(e.startsWith(`__skip`))continue;let r=n[e];r?p(r)||v(r)?r=n[e]={type:r,default:t[e]}:r.default=t[e]:r===null&&(r=n[e]={default:t[e]}),r&&t[`__skip_${e}`]&&(r.skipFactory=!0)}return n}function Da(e,t){return!e||!t?e||t:p(e)&&p(t)?e.concat(t):l({},Ta(e),Ta(t))}function Oa(e,t){let n={};for(let r in e)t.includes(r)||Object.defineProperty(n,r,{enumerable:!0,get:()=>e[r]});return n}function ka(e){let t=Os(),n=e();return Ms(),S(n)&&(n=n.catch(e=>{throw js(t),e})),[n,()=>js(t)]}var Aa=!0;function ja(e){let t=Fa(e),n=e.proxy,r=e.ctx;Aa=!1,t.beforeCreate&&Na(t.beforeCreate,e,`bc`);let{data:i,computed:o,methods:s,watch:c,provide:l,inject:u,created:d,beforeMount:f,mounted:m,beforeUpdate:h,updated:g,activated:_,deactivated:y,beforeDestroy:b,beforeUnmount:S,destroyed:C,unmounted:w,render:T,renderTracked:E,renderTriggered:D,errorCaptured:O,serverPrefetch:ee,expose:te,inheritAttrs:k,components:ne,directives:A,filters:re}=t;if(u&&Ma(u,r,null),s)for(let e in s){let t=s[e];v(t)&&(r[e]=t.bind(n))}if(i){let t=i.call(n,n);x(t)&&(e.data=qt(t))}if(Aa=!0,o)for(let e in o){let t=o[e],i=Js({get:v(t)?t.bind(n,n):v(t.get)?t.get.bind(n,n):a,set:!v(t)&&v(t.set)?t.set.bind(n):a});Object.defineProperty(r,e,{enumerable:!0,configurable:!0,get:()=>i.value,set:e=>i.value=e})}if(c)for(let e in c)Pa(c[e],r,n,e);if(l){let e=v(l)?l.call(n):l;Reflect.ownKeys(e).forEach(t=>{gr(t,e[t])})}d&&Na(d,e,`c`);function j(e,t){p(t)?t.forEach(t=>e(t.bind(n))):t&&e(t.bind(n))}if(j(Vi,f),j(Hi,m),j(Ui,h),j(Wi,g),j(Ni,_),j(Pi,y),j(Xi,O),j(Yi,E),j(Ji,D),j(Gi,S),j(Ki,w),j(qi,ee),p(te))if(te.length){let t=e.exposed||={};te.forEach(e=>{Object.defineProperty(t,e,{get:()=>n[e],set:t=>n[e]=t,enumerable:!0})})}else e.exposed||={};T&&e.render===a&&(e.render=T),k!=null&&(e.inheritAttrs=k),ne&&(e.components=ne),A&&(e.directives=A),ee&&ii(e)}function Ma(e,t,n=a){for(let n in p(e)&&(e=Ba(e)),e){let r=e[n],i;i=x(r)?`default`in r?_r(r.from||n,r.default,!0):_r(r.from||n):_r(r),I(i)?Object.defineProperty(t,n,{enumerable:!0,configurable:!0,get:()=>i.value,set:e=>i.value=e}):t[n]=i}}function Na(e,t,n){Bn(p(e)?e.map(e=>e.bind(t.proxy)):e.bind(t.proxy),t,n)}function Pa(e,t,n,r){let i=r.includes(`.`)?Dr(n,r):()=>n[r];if(y(e)){let n=t[e];v(n)&&wr(i,n)}else if(v(e))wr(i,e.bind(n));else if(x(e))if(p(e))e.forEach(e=>Pa(e,t,n,r));else{let r=v(e.handler)?e.handler.bind(n):t[e.handler];v(r)&&wr(i,r,e)}}function Fa(e){let t=e.type,{mixins:n,extends:r}=t,{mixins:i,optionsCache:a,config:{optionMergeStrategies:o}}=e.appContext,s=a.get(t),c;return s?c=s:!i.length&&!n&&!r?c=t:(c={},i.length&&i.forEach(e=>Ia(c,e,o,!0)),Ia(c,t,o)),x(t)&&a.set(t,c),c}function Ia(e,t,n,r=!1){let{mixins:i,extends:a}=t;for(let o in a&&Ia(e,a,n,!0),i&&i.forEach(t=>Ia(e,t,n,!0)),t)if(!(r&&o===`expose`)){let r=La[o]||n&&n[o];e[o]=r?r(e[o],t[o]):t[o]}return e}var La={data:Ra,props:Ua,emits:Ua,methods:Ha,computed:Ha,beforeCreate:Va,created:Va,beforeMount:Va,mounted:Va,beforeUpdate:Va,updated:Va,beforeDestroy:Va,beforeUnmount:Va,destroyed:Va,unmounted:Va,activated:Va,deactivated:Va,errorCaptured:Va,serverPrefetch:Va,components:Ha,directives:Ha,watch:Wa,provide:Ra,inject:za};function Ra(e,t){return t?e?function(){return l(v(e)?e.call(this,this):e,v(t)?t.call(this,this):t)}:t:e}function za(e,t){return Ha(Ba(e),Ba(t))}function Ba(e){if(p(e)){let t={};for(let n=0;n<e.length;n++)t[e[n]]=e[n];return t}return e}function Va(e,t){return e?[...new Set([].concat(e,t))]:t}function Ha(e,t){return e?l(Object.create(null),e,t):t}function Ua(e,t){return e?p(e)&&p(t)?[...new Set([...e,...t])]:l(Object.create(null),Ta(e),Ta(t??{})):t}function Wa(e,t){if(!e)return t;if(!t)return e;let n=l(Object.create(null),e);for(let r in t)n[r]=Va(e[r],t[r]);return n}function Ga(){return{app:null,config:{isNativeTag:o,performance:!1,globalProperties:{},optionMergeStrategies:{},errorHandler:void 0,warnHandler:void 0,compilerOptions:{}},mixins:[],components:{},directives:{},provides:Object.create(null),optionsCache:new WeakMap,propsCache:new WeakMap,emitsCache:new WeakMap}}var Ka=0;function qa(e,t){return function(n,r=null){v(n)||(n=l({},n)),r!=null&&!x(r)&&(r=null);let i=Ga(),a=new WeakSet,o=[],s=!1,c=i.app={_uid:Ka++,_component:n,_props:r,_container:null,_context:i,_instance:null,version:$s,get config(){return i.config},set config(e){},use(e,...t){return a.has(e)||(e&&v(e.install)?(a.add(e),e.install(c,...t)):v(e)&&(a.add(e),e(c,...t))),c},mixin(e){return i.mixins.includes(e)||i.mixins.push(e),c},component(e,t){return t?(i.components[e]=t,c):i.components[e]},directive(e,t){return t?(i.directives[e]=t,c):i.directives[e]},mount(a,o,l){if(!s){let u=c._ceVNode||V(n,r);return u.appContext=i,l===!0?l=`svg`:l===!1&&(l=void 0),o&&t?t(u,a):e(u,a,l),s=!0,c._container=a,a.__vue_app__=c,Gs(u.component)}},onUnmount(e){o.push(e)},unmount(){s&&(Bn(o,c._instance,16),e(null,c._container),delete c._container.__vue_app__)},provide(e,t){return i.provides[e]=t,c},runWithContext(e){let t=Ja;Ja=c;try{return e()}finally{Ja=t}}};return c}}var Ja=null;function Ya(e,t,n=r){let i=Os(),a=k(t),o=A(t),s=Xa(e,a),c=gn((s,c)=>{let l,u=r,d;return Cr(()=>{let t=e[a];M(l,t)&&(l=t,c())}),{get(){return s(),n.get?n.get(l):l},set(e){let s=n.set?n.set(e):e;if(!M(s,l)&&!(u!==r&&M(e,u)))return;let f=i.vnode.props;f&&(t in f||a in f||o in f)&&(`onUpdate:${t}`in f||`onUpdate:${a}`in f||`onUpdate:${o}`in f)||(l=e,c()),i.emit(`update:${t}`,s),M(e,s)&&M(e,u)&&!M(s,d)&&c(),u=e,d=s}}});return c[Symbol.iterator]=()=>{let e=0;return{next(){return e<2?{value:e++?s||r:c,done:!1}:{done:!0}}}},c}var Xa=(e,t)=>t===`modelValue`||t===`model-value`?e.modelModifiers:e[`${t}Modifiers`]||e[`${k(t)}Modifiers`]||e[`${A(t)}Modifiers`];function Za(e,t,...n){if(e.isUnmounted)return;let i=e.vnode.props||r,a=n,o=t.startsWith(`update:`),s=o&&Xa(i,t.slice(7));s&&(s.trim&&(a=n.map(e=>y(e)?e.trim():e)),s.number&&(a=n.map(oe)));let c,l=i[c=j(t)]||i[c=j(k(t))];!l&&o&&(l=i[c=j(A(t))]),l&&Bn(l,e,6,a);let u=i[c+`Once`];if(u){if(!e.emitted)e.emitted={};else if(e.emitted[c])return;e.emitted[c]=!0,Bn(u,e,6,a)}}var Qa=new WeakMap;function $a(e,t,n=!1){let r=n?Qa:t.emitsCache,i=r.get(e);if(i!==void 0)return i;let a=e.emits,o={},s=!1;if(!v(e)){let r=e=>{let n=$a(e,t,!0);n&&(s=!0,l(o,n))};!n&&t.mixins.length&&t.mixins.forEach(r),e.extends&&r(e.extends),e.mixins&&e.mixins.forEach(r)}return!a&&!s?(x(e)&&r.set(e,null),null):(p(a)?a.forEach(e=>o[e]=null):l(o,a),x(e)&&r.set(e,o),o)}function eo(e,t){return!e||!s(t)?!1:(t=t.slice(2).replace(/Once$/,``),f(e,t[0].toLowerCase()+t.slice(1))||f(e,A(t))||f(e,t))}function to(e){let{type:t,vnode:n,proxy:r,withProxy:i,propsOptions:[a],slots:o,attrs:s,emit:l,render:u,renderCache:d,props:f,data:p,setupState:m,ctx:h,inheritAttrs:g}=e,_=lr(e),v,y;try{if(n.shapeFlag&4){let e=i||r,t=e;v=bs(u.call(t,e,d,f,m,p,h)),y=s}else{let e=t;v=bs(e.length>1?e(f,{attrs:s,slots:o,emit:l}):e(f,null)),y=t.props?s:ro(s)}}catch(t){$o.length=0,Vn(t,e,1),v=V(B)}let b=v;if(y&&g!==!1){let e=Object.keys(y),{shapeFlag:t}=b;e.length&&t&7&&(a&&e.some(c)&&(y=io(y,a)),b=gs(b,y,!1,!0))}return n.dirs&&(b=gs(b,null,!1,!0),b.dirs=b.dirs?b.dirs.concat(n.dirs):n.dirs),n.transition&&ei(b,n.transition),v=b,lr(_),v}function no(e,t=!0){let n;for(let t=0;t<e.length;t++){let r=e[t];if(cs(r)){if(r.type!==B||r.children===`v-if`){if(n)return;n=r}}else return}return n}var ro=e=>{let t;for(let n in e)(n===`class`||n===`style`||s(n))&&((t||={})[n]=e[n]);return t},io=(e,t)=>{let n={};for(let r in e)(!c(r)||!(r.slice(9)in t))&&(n[r]=e[r]);return n};function ao(e,t,n){let{props:r,children:i,component:a}=e,{props:o,children:s,patchFlag:c}=t,l=a.emitsOptions;if(t.dirs||t.transition)return!0;if(n&&c>=0){if(c&1024)return!0;if(c&16)return r?oo(r,o,l):!!o;if(c&8){let e=t.dynamicProps;for(let t=0;t<e.length;t++){let n=e[t];if(o[n]!==r[n]&&!eo(l,n))return!0}}}else return(i||s)&&(!s||!s.$stable)?!0:r===o?!1:r?o?oo(r,o,l):!0:!!o;return!1}function oo(e,t,n){let r=Object.keys(t);if(r.length!==Object.keys(e).length)return!0;for(let i=0;i<r.length;i++){let a=r[i];if(t[a]!==e[a]&&!eo(n,a))return!0}return!1}function so({vnode:e,parent:t},n){for(;t;){let r=t.subTree;if(r.suspense&&r.suspense.activeBranch===e&&(r.el=e.el),r===e)(e=t.vnode).el=n,t=t.parent;else break}}var co={},lo=()=>Object.create(co),uo=e=>Object.getPrototypeOf(e)===co;function fo(e,t,n,r=!1){let i={},a=lo();for(let n in e.propsDefaults=Object.create(null),mo(e,t,i,a),e.propsOptions[0])n in i||(i[n]=void 0);n?e.props=r?i:Jt(i):e.type.props?e.props=i:e.props=a,e.attrs=a}function po(e,t,n,r){let{props:i,attrs:a,vnode:{patchFlag:o}}=e,s=F(i),[c]=e.propsOptions,l=!1;if((r||o>0)&&!(o&16)){if(o&8){let n=e.vnode.dynamicProps;for(let r=0;r<n.length;r++){let o=n[r];if(eo(e.emitsOptions,o))continue;let u=t[o];if(c)if(f(a,o))u!==a[o]&&(a[o]=u,l=!0);else{let t=k(o);i[t]=ho(c,s,t,u,e,!1)}else u!==a[o]&&(a[o]=u,l=!0)}}}else{mo(e,t,i,a)&&(l=!0);let r;for(let a in s)(!t||!f(t,a)&&((r=A(a))===a||!f(t,r)))&&(c?n&&(n[a]!==void 0||n[r]!==void 0)&&(i[a]=ho(c,s,a,void 0,e,!0)):delete i[a]);if(a!==s)for(let e in a)(!t||!f(t,e))&&(delete a[e],l=!0)}l&&lt(e.attrs,`set`,``)}function mo(e,t,n,i){let[a,o]=e.propsOptions,s=!1,c;if(t)for(let r in t){if(O(r))continue;let l=t[r],u;a&&f(a,u=k(r))?!o||!o.includes(u)?n[u]=l:(c||={})[u]=l:eo(e.emitsOptions,r)||(!(r in i)||l!==i[r])&&(i[r]=l,s=!0)}if(o){let t=F(n),i=c||r;for(let r=0;r<o.length;r++){let s=o[r];n[s]=ho(a,t,s,i[s],e,!f(i,s))}}return s}function ho(e,t,n,r,i,a){let o=e[n];if(o!=null){let e=f(o,`default`);if(e&&r===void 0){let e=o.default;if(o.type!==Function&&!o.skipFactory&&v(e)){let{propsDefaults:a}=i;if(n in a)r=a[n];else{let o=js(i);r=a[n]=e.call(null,t),o()}}else r=e;i.ce&&i.ce._setProp(n,r)}o[0]&&(a&&!e?r=!1:o[1]&&(r===``||r===A(n))&&(r=!0))}return r}var go=new WeakMap;function _o(e,t,n=!1){let a=n?go:t.propsCache,o=a.get(e);if(o)return o;let s=e.props,c={},u=[],d=!1;if(!v(e)){let r=e=>{d=!0;let[n,r]=_o(e,t,!0);l(c,n),r&&u.push(...r)};!n&&t.mixins.length&&t.mixins.forEach(r),e.extends&&r(e.extends),e.mixins&&e.mixins.forEach(r)}if(!s&&!d)return x(e)&&a.set(e,i),i;if(p(s))for(let e=0;e<s.length;e++){let t=k(s[e]);vo(t)&&(c[t]=r)}else if(s)for(let e in s){let t=k(e);if(vo(t)){let n=s[e],r=c[t]=p(n)||v(n)?{type:n}:l({},n),i=r.type,a=!1,o=!0;if(p(i))for(let e=0;e<i.length;++e){let t=i[e],n=v(t)&&t.name;if(n===`Boolean`){a=!0;break}else n===`String`&&(o=!1)}else a=v(i)&&i.name===`Boolean`;r[0]=a,r[1]=o,(a||f(r,`default`))&&u.push(t)}}let m=[c,u];return x(e)&&a.set(e,m),m}function vo(e){return e[0]!==`$`&&!O(e)}var yo=e=>e===`_`||e===`_ctx`||e===`$stable`,bo=e=>p(e)?e.map(bs):[bs(e)],xo=(e,t,n)=>{if(t._n)return t;let r=pr((...e)=>bo(t(...e)),n);return r._c=!1,r},So=(e,t,n)=>{let r=e._ctx;for(let n in e){if(yo(n))continue;let i=e[n];if(v(i))t[n]=xo(n,i,r);else if(i!=null){let e=bo(i);t[n]=()=>e}}},Co=(e,t)=>{let n=bo(t);e.slots.default=()=>n},wo=(e,t,n)=>{for(let r in t)(n||!yo(r))&&(e[r]=t[r])},To=(e,t,n)=>{let r=e.slots=lo();if(e.vnode.shapeFlag&32){let e=t._;e?(wo(r,t,n),n&&ae(r,`_`,e,!0)):So(t,r)}else t&&Co(e,t)},Eo=(e,t,n)=>{let{vnode:i,slots:a}=e,o=!0,s=r;if(i.shapeFlag&32){let e=t._;e?n&&e===1?o=!1:wo(a,t,n):(o=!t.$stable,So(t,a)),s=t}else t&&(Co(e,t),s={default:1});if(o)for(let e in a)!yo(e)&&s[e]==null&&delete a[e]},R=Jo;function Do(e){return ko(e)}function Oo(e){return ko(e,hi)}function ko(e,t){let n=le();n.__VUE__=!0;let{insert:o,remove:s,patchProp:c,createElement:l,createText:u,createComment:d,setText:f,setElementText:p,parentNode:m,nextSibling:h,setScopeId:g=a,insertStaticContent:_}=e,v=(e,t,n,r=null,i=null,a=null,o=void 0,s=null,c=!!t.dynamicChildren)=>{if(e===t)return;e&&!ls(e,t)&&(r=ge(e),de(e,i,a,!0),e=null),t.patchFlag===-2&&(c=!1,t.dynamicChildren=null);let{type:l,ref:u,shapeFlag:d}=t;switch(l){case Zo:y(e,t,n,r);break;case B:b(e,t,n,r);break;case Qo:e??x(t,n,r,o);break;case z:ne(e,t,n,r,i,a,o,s,c);break;default:d&1?w(e,t,n,r,i,a,o,s,c):d&6?A(e,t,n,r,i,a,o,s,c):(d&64||d&128)&&l.process(e,t,n,r,i,a,o,s,c,ye)}u!=null&&i?si(u,e&&e.ref,a,t||e,!t):u==null&&e&&e.ref!=null&&si(e.ref,null,a,e,!0)},y=(e,t,n,r)=>{if(e==null)o(t.el=u(t.children),n,r);else{let n=t.el=e.el;t.children!==e.children&&f(n,t.children)}},b=(e,t,n,r)=>{e==null?o(t.el=d(t.children||``),n,r):t.el=e.el},x=(e,t,n,r)=>{[e.el,e.anchor]=_(e.children,t,n,r,e.el,e.anchor)},S=({el:e,anchor:t},n,r)=>{let i;for(;e&&e!==t;)i=h(e),o(e,n,r),e=i;o(t,n,r)},C=({el:e,anchor:t})=>{let n;for(;e&&e!==t;)n=h(e),s(e),e=n;s(t)},w=(e,t,n,r,i,a,o,s,c)=>{if(t.type===`svg`?o=`svg`:t.type===`math`&&(o=`mathml`),e==null)T(t,n,r,i,a,o,s,c);else{let n=e.el&&e.el._isVueCE?e.el:null;try{n&&n._beginPatch(),ee(e,t,i,a,o,s,c)}finally{n&&n._endPatch()}}},T=(e,t,n,r,i,a,s,u)=>{let d,f,{props:m,shapeFlag:h,transition:g,dirs:_}=e;if(d=e.el=l(e.type,a,m&&m.is,m),h&8?p(d,e.children):h&16&&D(e.children,d,null,r,i,Ao(e,a),s,u),_&&hr(e,null,r,`created`),E(d,e,e.scopeId,s,r),m){for(let e in m)e!==`value`&&!O(e)&&c(d,e,null,m[e],a,r);`value`in m&&c(d,`value`,null,m.value,a),(f=m.onVnodeBeforeMount)&&ws(f,r,e)}_&&hr(e,null,r,`beforeMount`);let v=Mo(i,g);v&&g.beforeEnter(d),o(d,t,n),((f=m&&m.onVnodeMounted)||v||_)&&R(()=>{f&&ws(f,r,e),v&&g.enter(d),_&&hr(e,null,r,`mounted`)},i)},E=(e,t,n,r,i)=>{if(n&&g(e,n),r)for(let t=0;t<r.length;t++)g(e,r[t]);if(i){let n=i.subTree;if(t===n||Ro(n.type)&&(n.ssContent===t||n.ssFallback===t)){let t=i.vnode;E(e,t,t.scopeId,t.slotScopeIds,i.parent)}}},D=(e,t,n,r,i,a,o,s,c=0)=>{for(let l=c;l<e.length;l++)v(null,e[l]=s?xs(e[l]):bs(e[l]),t,n,r,i,a,o,s)},ee=(e,t,n,i,a,o,s)=>{let l=t.el=e.el,{patchFlag:u,dynamicChildren:d,dirs:f}=t;u|=e.patchFlag&16;let m=e.props||r,h=t.props||r,g;if(n&&jo(n,!1),(g=h.onVnodeBeforeUpdate)&&ws(g,n,t,e),f&&hr(t,e,n,`beforeUpdate`),n&&jo(n,!0),(m.innerHTML&&h.innerHTML==null||m.textContent&&h.textContent==null)&&p(l,``),d?te(e.dynamicChildren,d,l,n,i,Ao(t,a),o):s||oe(e,t,l,null,n,i,Ao(t,a),o,!1),u>0){if(u&16)k(l,m,h,n,a);else if(u&2&&m.class!==h.class&&c(l,`class`,null,h.class,a),u&4&&c(l,`style`,m.style,h.style,a),u&8){let e=t.dynamicProps;for(let t=0;t<e.length;t++){let r=e[t],i=m[r],o=h[r];(o!==i||r===`value`)&&c(l,r,i,o,a,n)}}u&1&&e.children!==t.children&&p(l,t.children)}else !s&&d==null&&k(l,m,h,n,a);((g=h.onVnodeUpdated)||f)&&R(()=>{g&&ws(g,n,t,e),f&&hr(t,e,n,`updated`)},i)},te=(e,t,n,r,i,a,o)=>{for(let s=0;s<t.length;s++){let c=e[s],l=t[s];v(c,l,c.el&&(c.type===z||!ls(c,l)||c.shapeFlag&198)?m(c.el):n,null,r,i,a,o,!0)}},k=(e,t,n,i,a)=>{if(t!==n){if(t!==r)for(let r in t)!O(r)&&!(r in n)&&c(e,r,t[r],null,a,i);for(let r in n){if(O(r))continue;let o=n[r],s=t[r];o!==s&&r!==`value`&&c(e,r,s,o,a,i)}`value`in n&&c(e,`value`,t.value,n.value,a)}},ne=(e,t,n,r,i,a,s,c,l)=>{let d=t.el=e?e.el:u(``),f=t.anchor=e?e.anchor:u(``),{patchFlag:p,dynamicChildren:m,slotScopeIds:h}=t;h&&(c=c?c.concat(h):h),e==null?(o(d,n,r),o(f,n,r),D(t.children||[],n,f,i,a,s,c,l)):p>0&&p&64&&m&&e.dynamicChildren&&e.dynamicChildren.length===m.length?(te(e.dynamicChildren,m,n,i,a,s,c),(t.key!=null||i&&t===i.subTree)&&No(e,t,!0)):oe(e,t,n,f,i,a,s,c,l)},A=(e,t,n,r,i,a,o,s,c)=>{t.slotScopeIds=s,e==null?t.shapeFlag&512?i.ctx.activate(t,n,r,o,c):re(t,n,r,i,a,o,c):j(e,t,c)},re=(e,t,n,r,i,a,o)=>{let s=e.component=Ds(e,r,i);if(Ai(e)&&(s.ctx.renderer=ye),Fs(s,!1,o),s.asyncDep){if(i&&i.registerDep(s,M,o),!e.el){let r=s.subTree=V(B);b(null,r,t,n),e.placeholder=r.el}}else M(s,e,t,n,i,a,o)},j=(e,t,n)=>{let r=t.component=e.component;if(ao(e,t,n))if(r.asyncDep&&!r.asyncResolved){ae(r,t,n);return}else r.next=t,r.update();else t.el=e.el,r.vnode=t},M=(e,t,n,r,i,a,o)=>{let s=()=>{if(e.isMounted){let{next:t,bu:n,u:r,parent:c,vnode:l}=e;{let n=Fo(e);if(n){t&&(t.el=l.el,ae(e,t,o)),n.asyncDep.then(()=>{e.isUnmounted||s()});return}}let u=t,d;jo(e,!1),t?(t.el=l.el,ae(e,t,o)):t=l,n&&ie(n),(d=t.props&&t.props.onVnodeBeforeUpdate)&&ws(d,c,t,l),jo(e,!0);let f=to(e),p=e.subTree;e.subTree=f,v(p,f,m(p.el),ge(p),e,i,a),t.el=f.el,u===null&&so(e,f.el),r&&R(r,i),(d=t.props&&t.props.onVnodeUpdated)&&R(()=>ws(d,c,t,l),i)}else{let o,{el:s,props:c}=t,{bm:l,m:u,parent:d,root:f,type:p}=e,m=Di(t);if(jo(e,!1),l&&ie(l),!m&&(o=c&&c.onVnodeBeforeMount)&&ws(o,d,t),jo(e,!0),s&&xe){let t=()=>{e.subTree=to(e),xe(s,e.subTree,e,i,null)};m&&p.__asyncHydrate?p.__asyncHydrate(s,e,t):t()}else{f.ce&&f.ce._def.shadowRoot!==!1&&f.ce._injectChildStyle(p);let o=e.subTree=to(e);v(null,o,n,r,e,i,a),t.el=o.el}if(u&&R(u,i),!m&&(o=c&&c.onVnodeMounted)){let e=t;R(()=>ws(o,d,e),i)}(t.shapeFlag&256||d&&Di(d.vnode)&&d.vnode.shapeFlag&256)&&e.a&&R(e.a,i),e.isMounted=!0,t=n=r=null}};e.scope.on();let c=e.effect=new Pe(s);e.scope.off();let l=e.update=c.run.bind(c),u=e.job=c.runIfDirty.bind(c);u.i=e,u.id=e.uid,c.scheduler=()=>Qn(u),jo(e,!0),l()},ae=(e,t,n)=>{t.component=e;let r=e.vnode.props;e.vnode=t,e.next=null,po(e,t.props,r,n),Eo(e,t.children,n),Ze(),tr(e),Qe()},oe=(e,t,n,r,i,a,o,s,c=!1)=>{let l=e&&e.children,u=e?e.shapeFlag:0,d=t.children,{patchFlag:f,shapeFlag:m}=t;if(f>0){if(f&128){ce(l,d,n,r,i,a,o,s,c);return}else if(f&256){se(l,d,n,r,i,a,o,s,c);return}}m&8?(u&16&&he(l,i,a),d!==l&&p(n,d)):u&16?m&16?ce(l,d,n,r,i,a,o,s,c):he(l,i,a,!0):(u&8&&p(n,``),m&16&&D(d,n,r,i,a,o,s,c))},se=(e,t,n,r,a,o,s,c,l)=>{e||=i,t||=i;let u=e.length,d=t.length,f=Math.min(u,d),p;for(p=0;p<f;p++){let r=t[p]=l?xs(t[p]):bs(t[p]);v(e[p],r,n,null,a,o,s,c,l)}u>d?he(e,a,o,!0,!1,f):D(t,n,r,a,o,s,c,l,f)},ce=(e,t,n,r,a,o,s,c,l)=>{let u=0,d=t.length,f=e.length-1,p=d-1;for(;u<=f&&u<=p;){let r=e[u],i=t[u]=l?xs(t[u]):bs(t[u]);if(ls(r,i))v(r,i,n,null,a,o,s,c,l);else break;u++}for(;u<=f&&u<=p;){let r=e[f],i=t[p]=l?xs(t[p]):bs(t[p]);if(ls(r,i))v(r,i,n,null,a,o,s,c,l);else break;f--,p--}if(u>f){if(u<=p){let e=p+1,i=e<d?t[e].el:r;for(;u<=p;)v(null,t[u]=l?xs(t[u]):bs(t[u]),n,i,a,o,s,c,l),u++}}else if(u>p)for(;u<=f;)de(e[u],a,o,!0),u++;else{let m=u,h=u,g=new Map;for(u=h;u<=p;u++){let e=t[u]=l?xs(t[u]):bs(t[u]);e.key!=null&&g.set(e.key,u)}let _,y=0,b=p-h+1,x=!1,S=0,C=Array(b);for(u=0;u<b;u++)C[u]=0;for(u=m;u<=f;u++){let r=e[u];if(y>=b){de(r,a,o,!0);continue}let i;if(r.key!=null)i=g.get(r.key);else for(_=h;_<=p;_++)if(C[_-h]===0&&ls(r,t[_])){i=_;break}i===void 0?de(r,a,o,!0):(C[i-h]=u+1,i>=S?S=i:x=!0,v(r,t[i],n,null,a,o,s,c,l),y++)}let w=x?Po(C):i;for(_=w.length-1,u=b-1;u>=0;u--){let e=h+u,i=t[e],f=t[e+1],p=e+1<d?f.el||Lo(f):r;C[u]===0?v(null,i,n,p,a,o,s,c,l):x&&(_<0||u!==w[_]?ue(i,n,p,2):_--)}}},ue=(e,t,n,r,i=null)=>{let{el:a,type:c,transition:l,children:u,shapeFlag:d}=e;if(d&6){ue(e.component.subTree,t,n,r);return}if(d&128){e.suspense.move(t,n,r);return}if(d&64){c.move(e,t,n,ye);return}if(c===z){o(a,t,n);for(let e=0;e<u.length;e++)ue(u[e],t,n,r);o(e.anchor,t,n);return}if(c===Qo){S(e,t,n);return}if(r!==2&&d&1&&l)if(r===0)l.beforeEnter(a),o(a,t,n),R(()=>l.enter(a),i);else{let{leave:r,delayLeave:i,afterLeave:c}=l,u=()=>{e.ctx.isUnmounted?s(a):o(a,t,n)},d=()=>{a._isLeaving&&a[Vr](!0),r(a,()=>{u(),c&&c()})};i?i(a,u,d):d()}else o(a,t,n)},de=(e,t,n,r=!1,i=!1)=>{let{type:a,props:o,ref:s,children:c,dynamicChildren:l,shapeFlag:u,patchFlag:d,dirs:f,cacheIndex:p}=e;if(d===-2&&(i=!1),s!=null&&(Ze(),si(s,null,n,e,!0),Qe()),p!=null&&(t.renderCache[p]=void 0),u&256){t.ctx.deactivate(e);return}let m=u&1&&f,h=!Di(e),g;if(h&&(g=o&&o.onVnodeBeforeUnmount)&&ws(g,t,e),u&6)me(e.component,n,r);else{if(u&128){e.suspense.unmount(n,r);return}m&&hr(e,null,t,`beforeUnmount`),u&64?e.type.remove(e,t,n,ye,r):l&&!l.hasOnce&&(a!==z||d>0&&d&64)?he(l,t,n,!1,!0):(a===z&&d&384||!i&&u&16)&&he(c,t,n),r&&fe(e)}(h&&(g=o&&o.onVnodeUnmounted)||m)&&R(()=>{g&&ws(g,t,e),m&&hr(e,null,t,`unmounted`)},n)},fe=e=>{let{type:t,el:n,anchor:r,transition:i}=e;if(t===z){pe(n,r);return}if(t===Qo){C(e);return}let a=()=>{s(n),i&&!i.persisted&&i.afterLeave&&i.afterLeave()};if(e.shapeFlag&1&&i&&!i.persisted){let{leave:t,delayLeave:r}=i,o=()=>t(n,a);r?r(e.el,a,o):o()}else a()},pe=(e,t)=>{let n;for(;e!==t;)n=h(e),s(e),e=n;s(t)},me=(e,t,n)=>{let{bum:r,scope:i,job:a,subTree:o,um:s,m:c,a:l}=e;Io(c),Io(l),r&&ie(r),i.stop(),a&&(a.flags|=8,de(o,e,t,n)),s&&R(s,t),R(()=>{e.isUnmounted=!0},t)},he=(e,t,n,r=!1,i=!1,a=0)=>{for(let o=a;o<e.length;o++)de(e[o],t,n,r,i)},ge=e=>{if(e.shapeFlag&6)return ge(e.component.subTree);if(e.shapeFlag&128)return e.suspense.next();let t=h(e.anchor||e.el),n=t&&t[Or];return n?h(n):t},_e=!1,ve=(e,t,n)=>{let r;e==null?t._vnode&&(de(t._vnode,null,null,!0),r=t._vnode.component):v(t._vnode||null,e,t,null,null,null,n),t._vnode=e,_e||=(_e=!0,tr(r),nr(),!1)},ye={p:v,um:de,m:ue,r:fe,mt:re,mc:D,pc:oe,pbc:te,n:ge,o:e},be,xe;return t&&([be,xe]=t(ye)),{render:ve,hydrate:be,createApp:qa(ve,be)}}function Ao({type:e,props:t},n){return n===`svg`&&e===`foreignObject`||n===`mathml`&&e===`annotation-xml`&&t&&t.encoding&&t.encoding.includes(`html`)?void 0:n}function jo({effect:e,job:t},n){n?(e.flags|=32,t.flags|=4):(e.flags&=-33,t.flags&=-5)}function Mo(e,t){return(!e||e&&!e.pendingBranch)&&t&&!t.persisted}function No(e,t,n=!1){let r=e.children,i=t.children;if(p(r)&&p(i))for(let t=0;t<r.length;t++){let a=r[t],o=i[t];o.shapeFlag&1&&!o.dynamicChildren&&((o.patchFlag<=0||o.patchFlag===32)&&(o=i[t]=xs(i[t]),o.el=a.el),!n&&o.patchFlag!==-2&&No(a,o)),o.type===Zo&&(o.patchFlag===-1?o.__elIndex=t+(e.type===z?1:0):o.el=a.el),o.type===B&&!o.el&&(o.el=a.el)}}function Po(e){let t=e.slice(),n=[0],r,i,a,o,s,c=e.length;for(r=0;r<c;r++){let c=e[r];if(c!==0){if(i=n[n.length-1],e[i]<c){t[r]=i,n.push(r);continue}for(a=0,o=n.length-1;a<o;)s=a+o>>1,e[n[s]]<c?a=s+1:o=s;c<e[n[a]]&&(a>0&&(t[r]=n[a-1]),n[a]=r)}}for(a=n.length,o=n[a-1];a-- >0;)n[a]=o,o=t[o];return n}function Fo(e){let t=e.subTree.component;if(t)return t.asyncDep&&!t.asyncResolved?t:Fo(t)}function Io(e){if(e)for(let t=0;t<e.length;t++)e[t].flags|=8}function Lo(e){if(e.placeholder)return e.placeholder;let t=e.component;return t?Lo(t.subTree):null}var Ro=e=>e.__isSuspense,zo=0,Bo={name:`Suspense`,__isSuspense:!0,process(e,t,n,r,i,a,o,s,c,l){if(e==null)Ho(t,n,r,i,a,o,s,c,l);else{if(a&&a.deps>0&&!e.suspense.isInFallback){t.suspense=e.suspense,t.suspense.vnode=t,t.el=e.el;return}Uo(e,t,n,r,i,o,s,c,l)}},hydrate:Go,normalize:Ko};function Vo(e,t){let n=e.props&&e.props[t];v(n)&&n()}function Ho(e,t,n,r,i,a,o,s,c){let{p:l,o:{createElement:u}}=c,d=u(`div`),f=e.suspense=Wo(e,i,r,t,d,n,a,o,s,c);l(null,f.pendingBranch=e.ssContent,d,null,r,f,a,o),f.deps>0?(Vo(e,`onPending`),Vo(e,`onFallback`),l(null,e.ssFallback,t,n,r,null,a,o),Yo(f,e.ssFallback)):f.resolve(!1,!0)}function Uo(e,t,n,r,i,a,o,s,{p:c,um:l,o:{createElement:u}}){let d=t.suspense=e.suspense;d.vnode=t,t.el=e.el;let f=t.ssContent,p=t.ssFallback,{activeBranch:m,pendingBranch:h,isInFallback:g,isHydrating:_}=d;if(h)d.pendingBranch=f,ls(h,f)?(c(h,f,d.hiddenContainer,null,i,d,a,o,s),d.deps<=0?d.resolve():g&&(_||(c(m,p,n,r,i,null,a,o,s),Yo(d,p)))):(d.pendingId=zo++,_?(d.isHydrating=!1,d.activeBranch=h):l(h,i,d),d.deps=0,d.effects.length=0,d.hiddenContainer=u(`div`),g?(c(null,f,d.hiddenContainer,null,i,d,a,o,s),d.deps<=0?d.resolve():(c(m,p,n,r,i,null,a,o,s),Yo(d,p))):m&&ls(m,f)?(c(m,f,n,r,i,d,a,o,s),d.resolve(!0)):(c(null,f,d.hiddenContainer,null,i,d,a,o,s),d.deps<=0&&d.resolve()));else if(m&&ls(m,f))c(m,f,n,r,i,d,a,o,s),Yo(d,f);else if(Vo(t,`onPending`),d.pendingBranch=f,f.shapeFlag&512?d.pendingId=f.component.suspenseId:d.pendingId=zo++,c(null,f,d.hiddenContainer,null,i,d,a,o,s),d.deps<=0)d.resolve();else{let{timeout:e,pendingId:t}=d;e>0?setTimeout(()=>{d.pendingId===t&&d.fallback(p)},e):e===0&&d.fallback(p)}}function Wo(e,t,n,r,i,a,o,s,c,l,u=!1){let{p:d,m:f,um:p,n:m,o:{parentNode:h,remove:g}}=l,_,v=Xo(e);v&&t&&t.pendingBranch&&(_=t.pendingId,t.deps++);let y=e.props?se(e.props.timeout):void 0,b=a,x={vnode:e,parent:t,parentComponent:n,namespace:o,container:r,hiddenContainer:i,deps:0,pendingId:zo++,timeout:typeof y==`number`?y:-1,activeBranch:null,pendingBranch:null,isInFallback:!u,isHydrating:u,isUnmounted:!1,effects:[],resolve(e=!1,n=!1){let{vnode:r,activeBranch:i,pendingBranch:o,pendingId:s,effects:c,parentComponent:l,container:u,isInFallback:d}=x,g=!1;x.isHydrating?x.isHydrating=!1:e||(g=i&&o.transition&&o.transition.mode===`out-in`,g&&(i.transition.afterLeave=()=>{s===x.pendingId&&(f(o,u,a===b?m(i):a,0),er(c),d&&r.ssFallback&&(r.ssFallback.el=null))}),i&&(h(i.el)===u&&(a=m(i)),p(i,l,x,!0),!g&&d&&r.ssFallback&&R(()=>r.ssFallback.el=null,x)),g||f(o,u,a,0)),Yo(x,o),x.pendingBranch=null,x.isInFallback=!1;let y=x.parent,S=!1;for(;y;){if(y.pendingBranch){y.effects.push(...c),S=!0;break}y=y.parent}!S&&!g&&er(c),x.effects=[],v&&t&&t.pendingBranch&&_===t.pendingId&&(t.deps--,t.deps===0&&!n&&t.resolve()),Vo(r,`onResolve`)},fallback(e){if(!x.pendingBranch)return;let{vnode:t,activeBranch:n,parentComponent:r,container:i,namespace:a}=x;Vo(t,`onFallback`);let o=m(n),l=()=>{x.isInFallback&&(d(null,e,i,o,r,null,a,s,c),Yo(x,e))},u=e.transition&&e.transition.mode===`out-in`;u&&(n.transition.afterLeave=l),x.isInFallback=!0,p(n,r,null,!0),u||l()},move(e,t,n){x.activeBranch&&f(x.activeBranch,e,t,n),x.container=e},next(){return x.activeBranch&&m(x.activeBranch)},registerDep(e,t,n){let r=!!x.pendingBranch;r&&x.deps++;let i=e.vnode.el;e.asyncDep.catch(t=>{Vn(t,e,0)}).then(a=>{if(e.isUnmounted||x.isUnmounted||x.pendingId!==e.suspenseId)return;e.asyncResolved=!0;let{vnode:s}=e;Ls(e,a,!1),i&&(s.el=i);let c=!i&&e.subTree.el;t(e,s,h(i||e.subTree.el),i?null:m(e.subTree),x,o,n),c&&(s.placeholder=null,g(c)),so(e,s.el),r&&--x.deps===0&&x.resolve()})},unmount(e,t){x.isUnmounted=!0,x.activeBranch&&p(x.activeBranch,n,e,t),x.pendingBranch&&p(x.pendingBranch,n,e,t)}};return x}function Go(e,t,n,r,i,a,o,s,c){let l=t.suspense=Wo(t,r,n,e.parentNode,document.createElement(`div`),null,i,a,o,s,!0),u=c(e,l.pendingBranch=t.ssContent,n,l,a,o);return l.deps===0&&l.resolve(!1,!0),u}function Ko(e){let{shapeFlag:t,children:n}=e,r=t&32;e.ssContent=qo(r?n.default:n),e.ssFallback=r?qo(n.fallback):V(B)}function qo(e){let t;if(v(e)){let n=rs&&e._c;n&&(e._d=!1,ts()),e=e(),n&&(e._d=!0,t=es,ns())}return p(e)&&(e=no(e)),e=bs(e),t&&!e.dynamicChildren&&(e.dynamicChildren=t.filter(t=>t!==e)),e}function Jo(e,t){t&&t.pendingBranch?p(e)?t.effects.push(...e):t.effects.push(e):er(e)}function Yo(e,t){e.activeBranch=t;let{vnode:n,parentComponent:r}=e,i=t.el;for(;!i&&t.component;)t=t.component.subTree,i=t.el;n.el=i,r&&r.subTree===n&&(r.vnode.el=i,so(r,i))}function Xo(e){let t=e.props&&e.props.suspensible;return t!=null&&t!==!1}var z=Symbol.for(`v-fgt`),Zo=Symbol.for(`v-txt`),B=Symbol.for(`v-cmt`),Qo=Symbol.for(`v-stc`),$o=[],es=null;function ts(e=!1){$o.push(es=e?null:[])}function ns(){$o.pop(),es=$o[$o.length-1]||null}var rs=1;function is(e,t=!1){rs+=e,e<0&&es&&t&&(es.hasOnce=!0)}function as(e){return e.dynamicChildren=rs>0?es||i:null,ns(),rs>0&&es&&es.push(e),e}function os(e,t,n,r,i,a){return as(ps(e,t,n,r,i,a,!0))}function ss(e,t,n,r,i){return as(V(e,t,n,r,i,!0))}function cs(e){return e?e.__v_isVNode===!0:!1}function ls(e,t){return e.type===t.type&&e.key===t.key}function us(e){}var ds=({key:e})=>e??null,fs=({ref:e,ref_key:t,ref_for:n})=>(typeof e==`number`&&(e=``+e),e==null?null:y(e)||I(e)||v(e)?{i:L,r:e,k:t,f:!!n}:e);function ps(e,t=null,n=null,r=0,i=null,a=e===z?0:1,o=!1,s=!1){let c={__v_isVNode:!0,__v_skip:!0,type:e,props:t,key:t&&ds(t),ref:t&&fs(t),scopeId:cr,slotScopeIds:null,children:n,component:null,suspense:null,ssContent:null,ssFallback:null,dirs:null,transition:null,el:null,anchor:null,target:null,targetStart:null,targetAnchor:null,staticCount:0,shapeFlag:a,patchFlag:r,dynamicProps:i,dynamicChildren:null,appContext:null,ctx:L};return s?(Ss(c,n),a&128&&e.normalize(c)):n&&(c.shapeFlag|=y(n)?8:16),rs>0&&!o&&es&&(c.patchFlag>0||a&6)&&c.patchFlag!==32&&es.push(c),c}var V=ms;function ms(e,t=null,n=null,r=0,i=null,a=!1){if((!e||e===ea)&&(e=B),cs(e)){let r=gs(e,t,!0);return n&&Ss(r,n),rs>0&&!a&&es&&(r.shapeFlag&6?es[es.indexOf(e)]=r:es.push(r)),r.patchFlag=-2,r}if(qs(e)&&(e=e.__vccOpts),t){t=hs(t);let{class:e,style:n}=t;e&&!y(e)&&(t.class=ge(e)),x(n)&&(tn(n)&&!p(n)&&(n=l({},n)),t.style=de(n))}let o=y(e)?1:Ro(e)?128:kr(e)?64:x(e)?4:v(e)?2:0;return ps(e,t,n,r,i,o,a,!0)}function hs(e){return e?tn(e)||uo(e)?l({},e):e:null}function gs(e,t,n=!1,r=!1){let{props:i,ref:a,patchFlag:o,children:s,transition:c}=e,l=t?Cs(i||{},t):i,u={__v_isVNode:!0,__v_skip:!0,type:e.type,props:l,key:l&&ds(l),ref:t&&t.ref?n&&a?p(a)?a.concat(fs(t)):[a,fs(t)]:fs(t):a,scopeId:e.scopeId,slotScopeIds:e.slotScopeIds,children:s,target:e.target,targetStart:e.targetStart,targetAnchor:e.targetAnchor,staticCount:e.staticCount,shapeFlag:e.shapeFlag,patchFlag:t&&e.type!==z?o===-1?16:o|16:o,dynamicProps:e.dynamicProps,dynamicChildren:e.dynamicChildren,appContext:e.appContext,dirs:e.dirs,transition:c,component:e.component,suspense:e.suspense,ssContent:e.ssContent&&gs(e.ssContent),ssFallback:e.ssFallback&&gs(e.ssFallback),placeholder:e.placeholder,el:e.el,anchor:e.anchor,ctx:e.ctx,ce:e.ce};return c&&r&&ei(u,c.clone(u)),u}function _s(e=` `,t=0){return V(Zo,null,e,t)}function vs(e,t){let n=V(Qo,null,e);return n.staticCount=t,n}function ys(e=``,t=!1){return t?(ts(),ss(B,null,e)):V(B,null,e)}function bs(e){return e==null||typeof e==`boolean`?V(B):p(e)?V(z,null,e.slice()):cs(e)?xs(e):V(Zo,null,String(e))}function xs(e){return e.el===null&&e.patchFlag!==-1||e.memo?e:gs(e)}function Ss(e,t){let n=0,{shapeFlag:r}=e;if(t==null)t=null;else if(p(t))n=16;else if(typeof t==`object`)if(r&65){let n=t.default;n&&(n._c&&(n._d=!1),Ss(e,n()),n._c&&(n._d=!0));return}else{n=32;let r=t._;!r&&!uo(t)?t._ctx=L:r===3&&L&&(L.slots._===1?t._=1:(t._=2,e.patchFlag|=1024))}else v(t)?(t={default:t,_ctx:L},n=32):(t=String(t),r&64?(n=16,t=[_s(t)]):n=8);e.children=t,e.shapeFlag|=n}function Cs(...e){let t={};for(let n=0;n<e.length;n++){let r=e[n];for(let e in r)if(e===`class`)t.class!==r.class&&(t.class=ge([t.class,r.class]));else if(e===`style`)t.style=de([t.style,r.style]);else if(s(e)){let n=t[e],i=r[e];i&&n!==i&&!(p(n)&&n.includes(i))&&(t[e]=n?[].concat(n,i):i)}else e!==``&&(t[e]=r[e])}return t}function ws(e,t,n,r=null){Bn(e,t,7,[n,r])}var Ts=Ga(),Es=0;function Ds(e,t,n){let i=e.type,a=(t?t.appContext:e.appContext)||Ts,o={uid:Es++,vnode:e,type:i,parent:t,appContext:a,root:null,next:null,subTree:null,effect:null,update:null,job:null,scope:new ke(!0),render:null,proxy:null,exposed:null,exposeProxy:null,withProxy:null,provides:t?t.provides:Object.create(a.provides),ids:t?t.ids:[``,0,0],accessCache:null,renderCache:[],components:null,directives:null,propsOptions:_o(i,a),emitsOptions:$a(i,a),emit:null,emitted:null,propsDefaults:r,inheritAttrs:i.inheritAttrs,ctx:r,data:r,props:r,attrs:r,slots:r,refs:r,setupState:r,setupContext:null,suspense:n,suspenseId:n?n.pendingId:0,asyncDep:null,asyncResolved:!1,isMounted:!1,isUnmounted:!1,isDeactivated:!1,bc:null,c:null,bm:null,m:null,bu:null,u:null,um:null,bum:null,da:null,a:null,rtg:null,rtc:null,ec:null,sp:null};return o.ctx={_:o},o.root=t?t.root:o,o.emit=Za.bind(null,o),e.ce&&e.ce(o),o}var H=null,Os=()=>H||L,ks,As;{let e=le(),t=(t,n)=>{let r;return(r=e[t])||(r=e[t]=[]),r.push(n),e=>{r.length>1?r.forEach(t=>t(e)):r[0](e)}};ks=t(`__VUE_INSTANCE_SETTERS__`,e=>H=e),As=t(`__VUE_SSR_SETTERS__`,e=>Ps=e)}var js=e=>{let t=H;return ks(e),e.scope.on(),()=>{e.scope.off(),ks(t)}},Ms=()=>{H&&H.scope.off(),ks(null)};function Ns(e){return e.vnode.shapeFlag&4}var Ps=!1;function Fs(e,t=!1,n=!1){t&&As(t);let{props:r,children:i}=e.vnode,a=Ns(e);fo(e,r,a,t),To(e,i,n||t);let o=a?Is(e,t):void 0;return t&&As(!1),o}function Is(e,t){let n=e.type;e.accessCache=Object.create(null),e.proxy=new Proxy(e.ctx,pa);let{setup:r}=n;if(r){Ze();let n=e.setupContext=r.length>1?Ws(e):null,i=js(e),a=zn(r,e,0,[e.props,n]),o=S(a);if(Qe(),i(),(o||e.sp)&&!Di(e)&&ii(e),o){if(a.then(Ms,Ms),t)return a.then(n=>{Ls(e,n,t)}).catch(t=>{Vn(t,e,0)});e.asyncDep=a}else Ls(e,a,t)}else Hs(e,t)}function Ls(e,t,n){v(t)?e.type.__ssrInlineRender?e.ssrRender=t:e.render=t:x(t)&&(e.setupState=mn(t)),Hs(e,n)}var Rs,zs;function Bs(e){Rs=e,zs=e=>{e.render._rc&&(e.withProxy=new Proxy(e.ctx,ma))}}var Vs=()=>!Rs;function Hs(e,t,n){let r=e.type;if(!e.render){if(!t&&Rs&&!r.render){let t=r.template||Fa(e).template;if(t){let{isCustomElement:n,compilerOptions:i}=e.appContext.config,{delimiters:a,compilerOptions:o}=r,s=l(l({isCustomElement:n,delimiters:a},i),o);r.render=Rs(t,s)}}e.render=r.render||a,zs&&zs(e)}{let t=js(e);Ze();try{ja(e)}finally{Qe(),t()}}}var Us={get(e,t){return ct(e,`get`,``),e[t]}};function Ws(e){return{attrs:new Proxy(e.attrs,Us),slots:e.slots,emit:e.emit,expose:t=>{e.exposed=t||{}}}}function Gs(e){return e.exposed?e.exposeProxy||=new Proxy(mn(nn(e.exposed)),{get(t,n){if(n in t)return t[n];if(n in da)return da[n](e)},has(e,t){return t in e||t in da}}):e.proxy}function Ks(e,t=!0){return v(e)?e.displayName||e.name:e.name||t&&e.__name}function qs(e){return v(e)&&`__vccOpts`in e}var Js=(e,t)=>Cn(e,t,Ps);function Ys(e,t,n){try{is(-1);let r=arguments.length;return r===2?x(t)&&!p(t)?cs(t)?V(e,null,[t]):V(e,t):V(e,null,t):(r>3?n=Array.prototype.slice.call(arguments,2):r===3&&cs(n)&&(n=[n]),V(e,t,n))}finally{is(1)}}function Xs(){return;function e(t,n,r){let i=t[r];if(p(i)&&i.includes(n)||x(i)&&n in i||t.extends&&e(t.extends,n,r)||t.mixins&&t.mixins.some(t=>e(t,n,r)))return!0}}function Zs(e,t,n,r){let i=n[r];if(i&&Qs(i,e))return i;let a=t();return a.memo=e.slice(),a.cacheIndex=r,n[r]=a}function Qs(e,t){let n=e.memo;if(n.length!=t.length)return!1;for(let e=0;e<n.length;e++)if(M(n[e],t[e]))return!1;return rs>0&&es&&es.push(e),!0}var $s=`3.5.26`,ec=a,tc=Rn,nc=ar,rc=sr,ic={createComponentInstance:Ds,setupComponent:Fs,renderComponentRoot:to,setCurrentRenderingInstance:lr,isVNode:cs,normalizeVNode:bs,getComponentPublicInstance:Gs,ensureValidVNode:ca,pushWarningContext:Pn,popWarningContext:Fn},ac=void 0,oc=typeof window<`u`&&window.trustedTypes;if(oc)try{ac=oc.createPolicy(`vue`,{createHTML:e=>e})}catch{}var sc=ac?e=>ac.createHTML(e):e=>e,cc=`http://www.w3.org/2000/svg`,lc=`http://www.w3.org/1998/Math/MathML`,uc=typeof document<`u`?document:null,dc=uc&&uc.createElement(`template`),fc={insert:(e,t,n)=>{t.insertBefore(e,n||null)},remove:e=>{let t=e.parentNode;t&&t.removeChild(e)},createElement:(e,t,n,r)=>{let i=t===`svg`?uc.createElementNS(cc,e):t===`mathml`?uc.createElementNS(lc,e):n?uc.createElement(e,{is:n}):uc.createElement(e);return e===`select`&&r&&r.multiple!=null&&i.setAttribute(`multiple`,r.multiple),i},createText:e=>uc.createTextNode(e),createComment:e=>uc.createComment(e),setText:(e,t)=>{e.nodeValue=t},setElementText:(e,t)=>{e.textContent=t},parentNode:e=>e.parentNode,nextSibling:e=>e.nextSibling,querySelector:e=>uc.querySelector(e),setScopeId(e,t){e.setAttribute(t,``)},insertStaticContent(e,t,n,r,i,a){let o=n?n.previousSibling:t.lastChild;if(i&&(i===a||i.nextSibling))for(;t.insertBefore(i.cloneNode(!0),n),!(i===a||!(i=i.nextSibling)););else{dc.innerHTML=sc(r===`svg`?`<svg>${e}</svg>`:r===`mathml`?`<math>${e}</math>`:e);let i=dc.content;if(r===`svg`||r===`mathml`){let e=i.firstChild;for(;e.firstChild;)i.appendChild(e.firstChild);i.removeChild(e)}t.insertBefore(i,n)}return[o?o.nextSibling:t.firstChild,n?n.previousSibling:t.lastChild]}},pc=`transition`,mc=`animation`,hc=Symbol(`_vtc`),gc={name:String,type:String,css:{type:Boolean,default:!0},duration:[String,Number,Object],enterFromClass:String,enterActiveClass:String,enterToClass:String,appearFromClass:String,appearActiveClass:String,appearToClass:String,leaveFromClass:String,leaveActiveClass:String,leaveToClass:String},_c=l({},Gr,gc),vc=(e=>(e.displayName=`Transition`,e.props=_c,e))((e,{slots:t})=>Ys(Yr,xc(e),t)),yc=(e,t=[])=>{p(e)?e.forEach(e=>e(...t)):e&&e(...t)},bc=e=>e?p(e)?e.some(e=>e.length>1):e.length>1:!1;function xc(e){let t={};for(let n in e)n in gc||(t[n]=e[n]);if(e.css===!1)return t;let{name:n=`v`,type:r,duration:i,enterFromClass:a=`${n}-enter-from`,enterActiveClass:o=`${n}-enter-active`,enterToClass:s=`${n}-enter-to`,appearFromClass:c=a,appearActiveClass:u=o,appearToClass:d=s,leaveFromClass:f=`${n}-leave-from`,leaveActiveClass:p=`${n}-leave-active`,leaveToClass:m=`${n}-leave-to`}=e,h=Sc(i),g=h&&h[0],_=h&&h[1],{onBeforeEnter:v,onEnter:y,onEnterCancelled:b,onLeave:x,onLeaveCancelled:S,onBeforeAppear:C=v,onAppear:w=y,onAppearCancelled:T=b}=t,E=(e,t,n,r)=>{e._enterCancelled=r,Tc(e,t?d:s),Tc(e,t?u:o),n&&n()},D=(e,t)=>{e._isLeaving=!1,Tc(e,f),Tc(e,m),Tc(e,p),t&&t()},O=e=>(t,n)=>{let i=e?w:y,o=()=>E(t,e,n);yc(i,[t,o]),Ec(()=>{Tc(t,e?c:a),wc(t,e?d:s),bc(i)||Oc(t,r,g,o)})};return l(t,{onBeforeEnter(e){yc(v,[e]),wc(e,a),wc(e,o)},onBeforeAppear(e){yc(C,[e]),wc(e,c),wc(e,u)},onEnter:O(!1),onAppear:O(!0),onLeave(e,t){e._isLeaving=!0;let n=()=>D(e,t);wc(e,f),e._enterCancelled?(wc(e,p),Mc(e)):(Mc(e),wc(e,p)),Ec(()=>{e._isLeaving&&(Tc(e,f),wc(e,m),bc(x)||Oc(e,r,_,n))}),yc(x,[e,n])},onEnterCancelled(e){E(e,!1,void 0,!0),yc(b,[e])},onAppearCancelled(e){E(e,!0,void 0,!0),yc(T,[e])},onLeaveCancelled(e){D(e),yc(S,[e])}})}function Sc(e){if(e==null)return null;if(x(e))return[Cc(e.enter),Cc(e.leave)];{let t=Cc(e);return[t,t]}}function Cc(e){return se(e)}function wc(e,t){t.split(/\s+/).forEach(t=>t&&e.classList.add(t)),(e[hc]||(e[hc]=new Set)).add(t)}function Tc(e,t){t.split(/\s+/).forEach(t=>t&&e.classList.remove(t));let n=e[hc];n&&(n.delete(t),n.size||(e[hc]=void 0))}function Ec(e){requestAnimationFrame(()=>{requestAnimationFrame(e)})}var Dc=0;function Oc(e,t,n,r){let i=e._endId=++Dc,a=()=>{i===e._endId&&r()};if(n!=null)return setTimeout(a,n);let{type:o,timeout:s,propCount:c}=kc(e,t);if(!o)return r();let l=o+`end`,u=0,d=()=>{e.removeEventListener(l,f),a()},f=t=>{t.target===e&&++u>=c&&d()};setTimeout(()=>{u<c&&d()},s+1),e.addEventListener(l,f)}function kc(e,t){let n=window.getComputedStyle(e),r=e=>(n[e]||``).split(`, `),i=r(`${pc}Delay`),a=r(`${pc}Duration`),o=Ac(i,a),s=r(`${mc}Delay`),c=r(`${mc}Duration`),l=Ac(s,c),u=null,d=0,f=0;t===pc?o>0&&(u=pc,d=o,f=a.length):t===mc?l>0&&(u=mc,d=l,f=c.length):(d=Math.max(o,l),u=d>0?o>l?pc:mc:null,f=u?u===pc?a.length:c.length:0);let p=u===pc&&/\b(?:transform|all)(?:,|$)/.test(r(`${pc}Property`).toString());return{type:u,timeout:d,propCount:f,hasTransform:p}}function Ac(e,t){for(;e.length<t.length;)e=e.concat(e);return Math.max(...t.map((t,n)=>jc(t)+jc(e[n])))}function jc(e){return e===`auto`?0:Number(e.slice(0,-1).replace(`,`,`.`))*1e3}function Mc(e){return(e?e.ownerDocument:document).body.offsetHeight}function Nc(e,t,n){let r=e[hc];r&&(t=(t?[t,...r]:[...r]).join(` `)),t==null?e.removeAttribute(`class`):n?e.setAttribute(`class`,t):e.className=t}var Pc=Symbol(`_vod`),Fc=Symbol(`_vsh`),Ic={name:`show`,beforeMount(e,{value:t},{transition:n}){e[Pc]=e.style.display===`none`?``:e.style.display,n&&t?n.beforeEnter(e):Lc(e,t)},mounted(e,{value:t},{transition:n}){n&&t&&n.enter(e)},updated(e,{value:t,oldValue:n},{transition:r}){!t!=!n&&(r?t?(r.beforeEnter(e),Lc(e,!0),r.enter(e)):r.leave(e,()=>{Lc(e,!1)}):Lc(e,t))},beforeUnmount(e,{value:t}){Lc(e,t)}};function Lc(e,t){e.style.display=t?e[Pc]:`none`,e[Fc]=!t}function Rc(){Ic.getSSRProps=({value:e})=>{if(!e)return{style:{display:`none`}}}}var zc=Symbol(``);function Bc(e){let t=Os();if(!t)return;let n=t.ut=(n=e(t.proxy))=>{Array.from(document.querySelectorAll(`[data-v-owner="${t.uid}"]`)).forEach(e=>Hc(e,n))},r=()=>{let r=e(t.proxy);t.ce?Hc(t.ce,r):Vc(t.subTree,r),n(r)};Ui(()=>{er(r)}),Hi(()=>{wr(r,a,{flush:`post`});let e=new MutationObserver(r);e.observe(t.subTree.el.parentNode,{childList:!0}),Ki(()=>e.disconnect())})}function Vc(e,t){if(e.shapeFlag&128){let n=e.suspense;e=n.activeBranch,n.pendingBranch&&!n.isHydrating&&n.effects.push(()=>{Vc(n.activeBranch,t)})}for(;e.component;)e=e.component.subTree;if(e.shapeFlag&1&&e.el)Hc(e.el,t);else if(e.type===z)e.children.forEach(e=>Vc(e,t));else if(e.type===Qo){let{el:n,anchor:r}=e;for(;n&&(Hc(n,t),n!==r);)n=n.nextSibling}}function Hc(e,t){if(e.nodeType===1){let n=e.style,r=``;for(let e in t){let i=De(t[e]);n.setProperty(`--${e}`,i),r+=`--${e}: ${i};`}n[zc]=r}}var Uc=/(?:^|;)\s*display\s*:/;function Wc(e,t,n){let r=e.style,i=y(n),a=!1;if(n&&!i){if(t)if(y(t))for(let e of t.split(`;`)){let t=e.slice(0,e.indexOf(`:`)).trim();n[t]??Kc(r,t,``)}else for(let e in t)n[e]??Kc(r,e,``);for(let e in n)e===`display`&&(a=!0),Kc(r,e,n[e])}else if(i){if(t!==n){let e=r[zc];e&&(n+=`;`+e),r.cssText=n,a=Uc.test(n)}}else t&&e.removeAttribute(`style`);Pc in e&&(e[Pc]=a?r.display:``,e[Fc]&&(r.display=`none`))}var Gc=/\s*!important$/;function Kc(e,t,n){if(p(n))n.forEach(n=>Kc(e,t,n));else if(n??=``,t.startsWith(`--`))e.setProperty(t,n);else{let r=Yc(e,t);Gc.test(n)?e.setProperty(A(r),n.replace(Gc,``),`important`):e[r]=n}}var qc=[`Webkit`,`Moz`,`ms`],Jc={};function Yc(e,t){let n=Jc[t];if(n)return n;let r=k(t);if(r!==`filter`&&r in e)return Jc[t]=r;r=re(r);for(let n=0;n<qc.length;n++){let i=qc[n]+r;if(i in e)return Jc[t]=i}return t}var Xc=`http://www.w3.org/1999/xlink`;function Zc(e,t,n,r,i,a=ye(t)){r&&t.startsWith(`xlink:`)?n==null?e.removeAttributeNS(Xc,t.slice(6,t.length)):e.setAttributeNS(Xc,t,n):n==null||a&&!be(n)?e.removeAttribute(t):e.setAttribute(t,a?``:b(n)?String(n):n)}function Qc(e,t,n,r,i){if(t===`innerHTML`||t===`textContent`){n!=null&&(e[t]=t===`innerHTML`?sc(n):n);return}let a=e.tagName;if(t===`value`&&a!==`PROGRESS`&&!a.includes(`-`)){let r=a===`OPTION`?e.getAttribute(`value`)||``:e.value,i=n==null?e.type===`checkbox`?`on`:``:String(n);(r!==i||!(`_value`in e))&&(e.value=i),n??e.removeAttribute(t),e._value=n;return}let o=!1;if(n===``||n==null){let r=typeof e[t];r===`boolean`?n=be(n):n==null&&r===`string`?(n=``,o=!0):r===`number`&&(n=0,o=!0)}try{e[t]=n}catch{}o&&e.removeAttribute(i||t)}function $c(e,t,n,r){e.addEventListener(t,n,r)}function el(e,t,n,r){e.removeEventListener(t,n,r)}var tl=Symbol(`_vei`);function nl(e,t,n,r,i=null){let a=e[tl]||(e[tl]={}),o=a[t];if(r&&o)o.value=r;else{let[n,s]=il(t);r?$c(e,n,a[t]=cl(r,i),s):o&&(el(e,n,o,s),a[t]=void 0)}}var rl=/(?:Once|Passive|Capture)$/;function il(e){let t;if(rl.test(e)){t={};let n;for(;n=e.match(rl);)e=e.slice(0,e.length-n[0].length),t[n[0].toLowerCase()]=!0}return[e[2]===`:`?e.slice(3):A(e.slice(2)),t]}var al=0,ol=Promise.resolve(),sl=()=>al||=(ol.then(()=>al=0),Date.now());function cl(e,t){let n=e=>{if(!e._vts)e._vts=Date.now();else if(e._vts<=n.attached)return;Bn(ll(e,n.value),t,5,[e])};return n.value=e,n.attached=sl(),n}function ll(e,t){if(p(t)){let n=e.stopImmediatePropagation;return e.stopImmediatePropagation=()=>{n.call(e),e._stopped=!0},t.map(e=>t=>!t._stopped&&e&&e(t))}else return t}var ul=e=>e.charCodeAt(0)===111&&e.charCodeAt(1)===110&&e.charCodeAt(2)>96&&e.charCodeAt(2)<123,dl=(e,t,n,r,i,a)=>{let o=i===`svg`;t===`class`?Nc(e,r,o):t===`style`?Wc(e,n,r):s(t)?c(t)||nl(e,t,n,r,a):(t[0]===`.`?(t=t.slice(1),!0):t[0]===`^`?(t=t.slice(1),!1):fl(e,t,r,o))?(Qc(e,t,r),!e.tagName.includes(`-`)&&(t===`value`||t===`checked`||t===`selected`)&&Zc(e,t,r,o,a,t!==`value`)):e._isVueCE&&(/[A-Z]/.test(t)||!y(r))?Qc(e,k(t),r,a,t):(t===`true-value`?e._trueValue=r:t===`false-value`&&(e._falseValue=r),Zc(e,t,r,o))};function fl(e,t,n,r){if(r)return!!(t===`innerHTML`||t===`textContent`||t in e&&ul(t)&&v(n));if(t===`spellcheck`||t===`draggable`||t===`translate`||t===`autocorrect`||t===`sandbox`&&e.tagName===`IFRAME`||t===`form`||t===`list`&&e.tagName===`INPUT`||t===`type`&&e.tagName===`TEXTAREA`)return!1;if(t===`width`||t===`height`){let t=e.tagName;if(t===`IMG`||t===`VIDEO`||t===`CANVAS`||t===`SOURCE`)return!1}return ul(t)&&y(n)?!1:t in e}var pl={};function ml(e,t,n){let r=ni(e,t);E(r)&&(r=l({},r,t));class i extends _l{constructor(e){super(r,e,n)}}return i.def=r,i}var hl=((e,t)=>ml(e,t,ou)),gl=typeof HTMLElement<`u`?HTMLElement:class{},_l=class e extends gl{constructor(e,t={},n=au){super(),this._def=e,this._props=t,this._createApp=n,this._isVueCE=!0,this._instance=null,this._app=null,this._nonce=this._def.nonce,this._connected=!1,this._resolved=!1,this._patching=!1,this._dirty=!1,this._numberProps=null,this._styleChildren=new WeakSet,this._ob=null,this.shadowRoot&&n!==au?this._root=this.shadowRoot:e.shadowRoot===!1?this._root=this:(this.attachShadow(l({},e.shadowRootOptions,{mode:`open`})),this._root=this.shadowRoot)}connectedCallback(){if(!this.isConnected)return;!this.shadowRoot&&!this._resolved&&this._parseSlots(),this._connected=!0;let t=this;for(;t&&=t.parentNode||t.host;)if(t instanceof e){this._parent=t;break}this._instance||(this._resolved?this._mount(this._def):t&&t._pendingResolve?this._pendingResolve=t._pendingResolve.then(()=>{this._pendingResolve=void 0,this._resolveDef()}):this._resolveDef())}_setParent(e=this._parent){e&&(this._instance.parent=e._instance,this._inheritParentContext(e))}_inheritParentContext(e=this._parent){e&&this._app&&Object.setPrototypeOf(this._app._context.provides,e._instance.provides)}disconnectedCallback(){this._connected=!1,Xn(()=>{this._connected||(this._ob&&=(this._ob.disconnect(),null),this._app&&this._app.unmount(),this._instance&&(this._instance.ce=void 0),this._app=this._instance=null,this._teleportTargets&&=(this._teleportTargets.clear(),void 0))})}_processMutations(e){for(let t of e)this._setAttr(t.attributeName)}_resolveDef(){if(this._pendingResolve)return;for(let e=0;e<this.attributes.length;e++)this._setAttr(this.attributes[e].name);this._ob=new MutationObserver(this._processMutations.bind(this)),this._ob.observe(this,{attributes:!0});let e=(e,t=!1)=>{this._resolved=!0,this._pendingResolve=void 0;let{props:n,styles:r}=e,i;if(n&&!p(n))for(let e in n){let t=n[e];(t===Number||t&&t.type===Number)&&(e in this._props&&(this._props[e]=se(this._props[e])),(i||=Object.create(null))[k(e)]=!0)}this._numberProps=i,this._resolveProps(e),this.shadowRoot&&this._applyStyles(r),this._mount(e)},t=this._def.__asyncLoader;t?this._pendingResolve=t().then(t=>{t.configureApp=this._def.configureApp,e(this._def=t,!0)}):e(this._def)}_mount(e){this._app=this._createApp(e),this._inheritParentContext(),e.configureApp&&e.configureApp(this._app),this._app._ceVNode=this._createVNode(),this._app.mount(this._root);let t=this._instance&&this._instance.exposed;if(t)for(let e in t)f(this,e)||Object.defineProperty(this,e,{get:()=>dn(t[e])})}_resolveProps(e){let{props:t}=e,n=p(t)?t:Object.keys(t||{});for(let e of Object.keys(this))e[0]!==`_`&&n.includes(e)&&this._setProp(e,this[e]);for(let e of n.map(k))Object.defineProperty(this,e,{get(){return this._getProp(e)},set(t){this._setProp(e,t,!0,!this._patching)}})}_setAttr(e){if(e.startsWith(`data-v-`))return;let t=this.hasAttribute(e),n=t?this.getAttribute(e):pl,r=k(e);t&&this._numberProps&&this._numberProps[r]&&(n=se(n)),this._setProp(r,n,!1,!0)}_getProp(e){return this._props[e]}_setProp(e,t,n=!0,r=!1){if(t!==this._props[e]&&(this._dirty=!0,t===pl?delete this._props[e]:(this._props[e]=t,e===`key`&&this._app&&(this._app._ceVNode.key=t)),r&&this._instance&&this._update(),n)){let n=this._ob;n&&(this._processMutations(n.takeRecords()),n.disconnect()),t===!0?this.setAttribute(A(e),``):typeof t==`string`||typeof t==`number`?this.setAttribute(A(e),t+``):t||this.removeAttribute(A(e)),n&&n.observe(this,{attributes:!0})}}_update(){let e=this._createVNode();this._app&&(e.appContext=this._app._context),ru(e,this._root)}_createVNode(){let e={};this.shadowRoot||(e.onVnodeMounted=e.onVnodeUpdated=this._renderSlots.bind(this));let t=V(this._def,l(e,this._props));return this._instance||(t.ce=e=>{this._instance=e,e.ce=this,e.isCE=!0;let t=(e,t)=>{this.dispatchEvent(new CustomEvent(e,E(t[0])?l({detail:t},t[0]):{detail:t}))};e.emit=(e,...n)=>{t(e,n),A(e)!==e&&t(A(e),n)},this._setParent()}),t}_applyStyles(e,t){if(!e)return;if(t){if(t===this._def||this._styleChildren.has(t))return;this._styleChildren.add(t)}let n=this._nonce;for(let t=e.length-1;t>=0;t--){let r=document.createElement(`style`);n&&r.setAttribute(`nonce`,n),r.textContent=e[t],this.shadowRoot.prepend(r)}}_parseSlots(){let e=this._slots={},t;for(;t=this.firstChild;){let n=t.nodeType===1&&t.getAttribute(`slot`)||`default`;(e[n]||(e[n]=[])).push(t),this.removeChild(t)}}_renderSlots(){let e=this._getSlots(),t=this._instance.type.__scopeId;for(let n=0;n<e.length;n++){let r=e[n],i=r.getAttribute(`name`)||`default`,a=this._slots[i],o=r.parentNode;if(a)for(let e of a){if(t&&e.nodeType===1){let n=t+`-s`,r=document.createTreeWalker(e,1);e.setAttribute(n,``);let i;for(;i=r.nextNode();)i.setAttribute(n,``)}o.insertBefore(e,r)}else for(;r.firstChild;)o.insertBefore(r.firstChild,r);o.removeChild(r)}}_getSlots(){let e=[this];this._teleportTargets&&e.push(...this._teleportTargets);let t=new Set;for(let n of e){let e=n.querySelectorAll(`slot`);for(let n=0;n<e.length;n++)t.add(e[n])}return Array.from(t)}_injectChildStyle(e){this._applyStyles(e.styles,e)}_beginPatch(){this._patching=!0,this._dirty=!1}_endPatch(){this._patching=!1,this._dirty&&this._instance&&this._update()}_removeChildStyle(e){}};function vl(e){let t=Os();return t&&t.ce||null}function yl(){let e=vl();return e&&e.shadowRoot}function bl(e=`$style`){{let t=Os();if(!t)return r;let n=t.type.__cssModules;return n&&n[e]||r}}var xl=new WeakMap,Sl=new WeakMap,Cl=Symbol(`_moveCb`),wl=Symbol(`_enterCb`),Tl=(e=>(delete e.props.mode,e))({name:`TransitionGroup`,props:l({},_c,{tag:String,moveClass:String}),setup(e,{slots:t}){let n=Os(),r=Ur(),i,a;return Wi(()=>{if(!i.length)return;let t=e.moveClass||`${e.name||`v`}-move`;if(!kl(i[0].el,n.vnode.el,t)){i=[];return}i.forEach(El),i.forEach(Dl);let r=i.filter(Ol);Mc(n.vnode.el),r.forEach(e=>{let n=e.el,r=n.style;wc(n,t),r.transform=r.webkitTransform=r.transitionDuration=``;let i=n[Cl]=e=>{e&&e.target!==n||(!e||e.propertyName.endsWith(`transform`))&&(n.removeEventListener(`transitionend`,i),n[Cl]=null,Tc(n,t))};n.addEventListener(`transitionend`,i)}),i=[]}),()=>{let o=F(e),s=xc(o),c=o.tag||z;if(i=[],a)for(let e=0;e<a.length;e++){let t=a[e];t.el&&t.el instanceof Element&&(i.push(t),ei(t,Zr(t,s,r,n)),xl.set(t,{left:t.el.offsetLeft,top:t.el.offsetTop}))}a=t.default?ti(t.default()):[];for(let e=0;e<a.length;e++){let t=a[e];t.key!=null&&ei(t,Zr(t,s,r,n))}return V(c,null,a)}}});function El(e){let t=e.el;t[Cl]&&t[Cl](),t[wl]&&t[wl]()}function Dl(e){Sl.set(e,{left:e.el.offsetLeft,top:e.el.offsetTop})}function Ol(e){let t=xl.get(e),n=Sl.get(e),r=t.left-n.left,i=t.top-n.top;if(r||i){let t=e.el.style;return t.transform=t.webkitTransform=`translate(${r}px,${i}px)`,t.transitionDuration=`0s`,e}}function kl(e,t,n){let r=e.cloneNode(),i=e[hc];i&&i.forEach(e=>{e.split(/\s+/).forEach(e=>e&&r.classList.remove(e))}),n.split(/\s+/).forEach(e=>e&&r.classList.add(e)),r.style.display=`none`;let a=t.nodeType===1?t:t.parentNode;a.appendChild(r);let{hasTransform:o}=kc(r);return a.removeChild(r),o}var Al=e=>{let t=e.props[`onUpdate:modelValue`]||!1;return p(t)?e=>ie(t,e):t};function jl(e){e.target.composing=!0}function Ml(e){let t=e.target;t.composing&&(t.composing=!1,t.dispatchEvent(new Event(`input`)))}var Nl=Symbol(`_assign`);function Pl(e,t,n){return t&&(e=e.trim()),n&&(e=oe(e)),e}var Fl={created(e,{modifiers:{lazy:t,trim:n,number:r}},i){e[Nl]=Al(i);let a=r||i.props&&i.props.type===`number`;$c(e,t?`change`:`input`,t=>{t.target.composing||e[Nl](Pl(e.value,n,a))}),(n||a)&&$c(e,`change`,()=>{e.value=Pl(e.value,n,a)}),t||($c(e,`compositionstart`,jl),$c(e,`compositionend`,Ml),$c(e,`change`,Ml))},mounted(e,{value:t}){e.value=t??``},beforeUpdate(e,{value:t,oldValue:n,modifiers:{lazy:r,trim:i,number:a}},o){if(e[Nl]=Al(o),e.composing)return;let s=(a||e.type===`number`)&&!/^0\d/.test(e.value)?oe(e.value):e.value,c=t??``;s!==c&&(document.activeElement===e&&e.type!==`range`&&(r&&t===n||i&&e.value.trim()===c)||(e.value=c))}},Il={deep:!0,created(e,t,n){e[Nl]=Al(n),$c(e,`change`,()=>{let t=e._modelValue,n=Vl(e),r=e.checked,i=e[Nl];if(p(t)){let e=Ce(t,n),a=e!==-1;if(r&&!a)i(t.concat(n));else if(!r&&a){let n=[...t];n.splice(e,1),i(n)}}else if(h(t)){let e=new Set(t);r?e.add(n):e.delete(n),i(e)}else i(Hl(e,r))})},mounted:Ll,beforeUpdate(e,t,n){e[Nl]=Al(n),Ll(e,t,n)}};function Ll(e,{value:t,oldValue:n},r){e._modelValue=t;let i;if(p(t))i=Ce(t,r.props.value)>-1;else if(h(t))i=t.has(r.props.value);else{if(t===n)return;i=Se(t,Hl(e,!0))}e.checked!==i&&(e.checked=i)}var Rl={created(e,{value:t},n){e.checked=Se(t,n.props.value),e[Nl]=Al(n),$c(e,`change`,()=>{e[Nl](Vl(e))})},beforeUpdate(e,{value:t,oldValue:n},r){e[Nl]=Al(r),t!==n&&(e.checked=Se(t,r.props.value))}},zl={deep:!0,created(e,{value:t,modifiers:{number:n}},r){let i=h(t);$c(e,`change`,()=>{let t=Array.prototype.filter.call(e.options,e=>e.selected).map(e=>n?oe(Vl(e)):Vl(e));e[Nl](e.multiple?i?new Set(t):t:t[0]),e._assigning=!0,Xn(()=>{e._assigning=!1})}),e[Nl]=Al(r)},mounted(e,{value:t}){Bl(e,t)},beforeUpdate(e,t,n){e[Nl]=Al(n)},updated(e,{value:t}){e._assigning||Bl(e,t)}};function Bl(e,t){let n=e.multiple,r=p(t);if(!(n&&!r&&!h(t))){for(let i=0,a=e.options.length;i<a;i++){let a=e.options[i],o=Vl(a);if(n)if(r){let e=typeof o;e===`string`||e===`number`?a.selected=t.some(e=>String(e)===String(o)):a.selected=Ce(t,o)>-1}else a.selected=t.has(o);else if(Se(Vl(a),t)){e.selectedIndex!==i&&(e.selectedIndex=i);return}}!n&&e.selectedIndex!==-1&&(e.selectedIndex=-1)}}function Vl(e){return`_value`in e?e._value:e.value}function Hl(e,t){let n=t?`_trueValue`:`_falseValue`;return n in e?e[n]:t}var Ul={created(e,t,n){Gl(e,t,n,null,`created`)},mounted(e,t,n){Gl(e,t,n,null,`mounted`)},beforeUpdate(e,t,n,r){Gl(e,t,n,r,`beforeUpdate`)},updated(e,t,n,r){Gl(e,t,n,r,`updated`)}};function Wl(e,t){switch(e){case`SELECT`:return zl;case`TEXTAREA`:return Fl;default:switch(t){case`checkbox`:return Il;case`radio`:return Rl;default:return Fl}}}function Gl(e,t,n,r,i){let a=Wl(e.tagName,n.props&&n.props.type)[i];a&&a(e,t,n,r)}function Kl(){Fl.getSSRProps=({value:e})=>({value:e}),Rl.getSSRProps=({value:e},t)=>{if(t.props&&Se(t.props.value,e))return{checked:!0}},Il.getSSRProps=({value:e},t)=>{if(p(e)){if(t.props&&Ce(e,t.props.value)>-1)return{checked:!0}}else if(h(e)){if(t.props&&e.has(t.props.value))return{checked:!0}}else if(e)return{checked:!0}},Ul.getSSRProps=(e,t)=>{if(typeof t.type!=`string`)return;let n=Wl(t.type.toUpperCase(),t.props&&t.props.type);if(n.getSSRProps)return n.getSSRProps(e,t)}}var ql=[`ctrl`,`shift`,`alt`,`meta`],Jl={stop:e=>e.stopPropagation(),prevent:e=>e.preventDefault(),self:e=>e.target!==e.currentTarget,ctrl:e=>!e.ctrlKey,shift:e=>!e.shiftKey,alt:e=>!e.altKey,meta:e=>!e.metaKey,left:e=>`button`in e&&e.button!==0,middle:e=>`button`in e&&e.button!==1,right:e=>`button`in e&&e.button!==2,exact:(e,t)=>ql.some(n=>e[`${n}Key`]&&!t.includes(n))},Yl=(e,t)=>{let n=e._withMods||={},r=t.join(`.`);return n[r]||(n[r]=((n,...r)=>{for(let e=0;e<t.length;e++){let r=Jl[t[e]];if(r&&r(n,t))return}return e(n,...r)}))},Xl={esc:`escape`,space:` `,up:`arrow-up`,left:`arrow-left`,right:`arrow-right`,down:`arrow-down`,delete:`backspace`},Zl=(e,t)=>{let n=e._withKeys||={},r=t.join(`.`);return n[r]||(n[r]=(n=>{if(!(`key`in n))return;let r=A(n.key);if(t.some(e=>e===r||Xl[e]===r))return e(n)}))},Ql=l({patchProp:dl},fc),$l,eu=!1;function tu(){return $l||=Do(Ql)}function nu(){return $l=eu?$l:Oo(Ql),eu=!0,$l}var ru=((...e)=>{tu().render(...e)}),iu=((...e)=>{nu().hydrate(...e)}),au=((...e)=>{let t=tu().createApp(...e),{mount:n}=t;return t.mount=e=>{let r=cu(e);if(!r)return;let i=t._component;!v(i)&&!i.render&&!i.template&&(i.template=r.innerHTML),r.nodeType===1&&(r.textContent=``);let a=n(r,!1,su(r));return r instanceof Element&&(r.removeAttribute(`v-cloak`),r.setAttribute(`data-v-app`,``)),a},t}),ou=((...e)=>{let t=nu().createApp(...e),{mount:n}=t;return t.mount=e=>{let t=cu(e);if(t)return n(t,!0,su(t))},t});function su(e){if(e instanceof SVGElement)return`svg`;if(typeof MathMLElement==`function`&&e instanceof MathMLElement)return`mathml`}function cu(e){return y(e)?document.querySelector(e):e}var lu=!1,uu=()=>{lu||(lu=!0,Kl(),Rc())},du=t({BaseTransition:()=>Yr,BaseTransitionPropsValidators:()=>Gr,Comment:()=>B,DeprecationTypes:()=>null,EffectScope:()=>ke,ErrorCodes:()=>Ln,ErrorTypeStrings:()=>tc,Fragment:()=>z,KeepAlive:()=>ji,ReactiveEffect:()=>Pe,Static:()=>Qo,Suspense:()=>Bo,Teleport:()=>Rr,Text:()=>Zo,TrackOpTypes:()=>wn,Transition:()=>vc,TransitionGroup:()=>Tl,TriggerOpTypes:()=>Tn,VueElement:()=>_l,assertNumber:()=>In,callWithAsyncErrorHandling:()=>Bn,callWithErrorHandling:()=>zn,camelize:()=>k,capitalize:()=>re,cloneVNode:()=>gs,compatUtils:()=>null,compile:()=>fu,computed:()=>Js,createApp:()=>au,createBlock:()=>ss,createCommentVNode:()=>ys,createElementBlock:()=>os,createElementVNode:()=>ps,createHydrationRenderer:()=>Oo,createPropsRestProxy:()=>Oa,createRenderer:()=>Do,createSSRApp:()=>ou,createSlots:()=>oa,createStaticVNode:()=>vs,createTextVNode:()=>_s,createVNode:()=>V,customRef:()=>gn,defineAsyncComponent:()=>Oi,defineComponent:()=>ni,defineCustomElement:()=>ml,defineEmits:()=>ga,defineExpose:()=>_a,defineModel:()=>ba,defineOptions:()=>va,defineProps:()=>ha,defineSSRCustomElement:()=>hl,defineSlots:()=>ya,devtools:()=>nc,effect:()=>qe,effectScope:()=>Ae,getCurrentInstance:()=>Os,getCurrentScope:()=>je,getCurrentWatcher:()=>kn,getTransitionRawChildren:()=>ti,guardReactiveProps:()=>hs,h:()=>Ys,handleError:()=>Vn,hasInjectionContext:()=>vr,hydrate:()=>iu,hydrateOnIdle:()=>xi,hydrateOnInteraction:()=>Ti,hydrateOnMediaQuery:()=>wi,hydrateOnVisible:()=>Ci,initCustomFormatter:()=>Xs,initDirectivesForSSR:()=>uu,inject:()=>_r,isMemoSame:()=>Qs,isProxy:()=>tn,isReactive:()=>Qt,isReadonly:()=>$t,isRef:()=>I,isRuntimeOnly:()=>Vs,isShallow:()=>en,isVNode:()=>cs,markRaw:()=>nn,mergeDefaults:()=>Ea,mergeModels:()=>Da,mergeProps:()=>Cs,nextTick:()=>Xn,nodeOps:()=>fc,normalizeClass:()=>ge,normalizeProps:()=>_e,normalizeStyle:()=>de,onActivated:()=>Ni,onBeforeMount:()=>Vi,onBeforeUnmount:()=>Gi,onBeforeUpdate:()=>Ui,onDeactivated:()=>Pi,onErrorCaptured:()=>Xi,onMounted:()=>Hi,onRenderTracked:()=>Yi,onRenderTriggered:()=>Ji,onScopeDispose:()=>Me,onServerPrefetch:()=>qi,onUnmounted:()=>Ki,onUpdated:()=>Wi,onWatcherCleanup:()=>An,openBlock:()=>ts,patchProp:()=>dl,popScopeId:()=>dr,provide:()=>gr,proxyRefs:()=>mn,pushScopeId:()=>ur,queuePostFlushCb:()=>er,reactive:()=>qt,readonly:()=>Yt,ref:()=>on,registerRuntimeCompiler:()=>Bs,render:()=>ru,renderList:()=>aa,renderSlot:()=>sa,resolveComponent:()=>$i,resolveDirective:()=>na,resolveDynamicComponent:()=>ta,resolveFilter:()=>null,resolveTransitionHooks:()=>Zr,setBlockTracking:()=>is,setDevtoolsHook:()=>rc,setTransitionHooks:()=>ei,shallowReactive:()=>Jt,shallowReadonly:()=>Xt,shallowRef:()=>sn,ssrContextKey:()=>yr,ssrUtils:()=>ic,stop:()=>Je,toDisplayString:()=>we,toHandlerKey:()=>j,toHandlers:()=>la,toRaw:()=>F,toRef:()=>bn,toRefs:()=>_n,toValue:()=>fn,transformVNodeArgs:()=>us,triggerRef:()=>un,unref:()=>dn,useAttrs:()=>Ca,useCssModule:()=>bl,useCssVars:()=>Bc,useHost:()=>vl,useId:()=>ri,useModel:()=>Ya,useSSRContext:()=>br,useShadowRoot:()=>yl,useSlots:()=>Sa,useTemplateRef:()=>ai,useTransitionState:()=>Ur,vModelCheckbox:()=>Il,vModelDynamic:()=>Ul,vModelRadio:()=>Rl,vModelSelect:()=>zl,vModelText:()=>Fl,vShow:()=>Ic,version:()=>$s,warn:()=>ec,watch:()=>wr,watchEffect:()=>xr,watchPostEffect:()=>Sr,watchSyncEffect:()=>Cr,withAsyncContext:()=>ka,withCtx:()=>pr,withDefaults:()=>xa,withDirectives:()=>mr,withKeys:()=>Zl,withMemo:()=>Zs,withModifiers:()=>Yl,withScopeId:()=>fr},1),fu=()=>{},pu=`/assets/logo.webp`,mu=`/assets/logodark.webp`;function hu(e,t){typeof console<`u`&&(console.warn(`[intlify] `+e),t&&console.warn(t.stack))}var gu=typeof window<`u`,_u=(e,t=!1)=>t?Symbol.for(e):Symbol(e),vu=(e,t,n)=>yu({l:e,k:t,s:n}),yu=e=>JSON.stringify(e).replace(/\u2028/g,`\\u2028`).replace(/\u2029/g,`\\u2029`).replace(/\u0027/g,`\\u0027`),U=e=>typeof e==`number`&&isFinite(e),bu=e=>Nu(e)===`[object Date]`,xu=e=>Nu(e)===`[object RegExp]`,Su=e=>Z(e)&&Object.keys(e).length===0,W=Object.assign,Cu=Object.create,G=(e=null)=>Cu(e),wu,Tu=()=>wu||=typeof globalThis<`u`?globalThis:typeof self<`u`?self:typeof window<`u`?window:typeof global<`u`?global:G();function Eu(e){return e.replace(/&/g,`&amp;`).replace(/</g,`&lt;`).replace(/>/g,`&gt;`).replace(/"/g,`&quot;`).replace(/'/g,`&apos;`).replace(/\//g,`&#x2F;`).replace(/=/g,`&#x3D;`)}function Du(e){return e.replace(/&(?![a-zA-Z0-9#]{2,6};)/g,`&amp;`).replace(/"/g,`&quot;`).replace(/'/g,`&apos;`).replace(/</g,`&lt;`).replace(/>/g,`&gt;`)}function Ou(e){return e=e.replace(/(\w+)\s*=\s*"([^"]*)"/g,(e,t,n)=>`${t}="${Du(n)}"`),e=e.replace(/(\w+)\s*=\s*'([^']*)'/g,(e,t,n)=>`${t}='${Du(n)}'`),/\s*on\w+\s*=\s*["']?[^"'>]+["']?/gi.test(e)&&(e=e.replace(/(\s+)(on)(\w+\s*=)/gi,`$1&#111;n$3`)),[/(\s+(?:href|src|action|formaction)\s*=\s*["']?)\s*javascript:/gi,/(style\s*=\s*["'][^"']*url\s*\(\s*)javascript:/gi].forEach(t=>{e=e.replace(t,`$1javascript&#58;`)}),e}var ku=Object.prototype.hasOwnProperty;function Au(e,t){return ku.call(e,t)}var K=Array.isArray,q=e=>typeof e==`function`,J=e=>typeof e==`string`,Y=e=>typeof e==`boolean`,X=e=>typeof e==`object`&&!!e,ju=e=>X(e)&&q(e.then)&&q(e.catch),Mu=Object.prototype.toString,Nu=e=>Mu.call(e),Z=e=>Nu(e)===`[object Object]`,Pu=e=>e==null?``:K(e)||Z(e)&&e.toString===Mu?JSON.stringify(e,null,2):String(e);function Fu(e,t=``){return e.reduce((e,n,r)=>r===0?e+n:e+t+n,``)}var Iu=e=>!X(e)||K(e);function Lu(e,t){if(Iu(e)||Iu(t))throw Error(`Invalid value`);let n=[{src:e,des:t}];for(;n.length;){let{src:e,des:t}=n.pop();Object.keys(e).forEach(r=>{r!==`__proto__`&&(X(e[r])&&!X(t[r])&&(t[r]=Array.isArray(e[r])?[]:G()),Iu(t[r])||Iu(e[r])?t[r]=e[r]:n.push({src:e[r],des:t[r]}))})}}function Ru(e,t,n){return{line:e,column:t,offset:n}}function zu(e,t,n){let r={start:e,end:t};return n!=null&&(r.source=n),r}var Q={EXPECTED_TOKEN:1,INVALID_TOKEN_IN_PLACEHOLDER:2,UNTERMINATED_SINGLE_QUOTE_IN_PLACEHOLDER:3,UNKNOWN_ESCAPE_SEQUENCE:4,INVALID_UNICODE_ESCAPE_SEQUENCE:5,UNBALANCED_CLOSING_BRACE:6,UNTERMINATED_CLOSING_BRACE:7,EMPTY_PLACEHOLDER:8,NOT_ALLOW_NEST_PLACEHOLDER:9,INVALID_LINKED_FORMAT:10,MUST_HAVE_MESSAGES_IN_PLURAL:11,UNEXPECTED_EMPTY_LINKED_MODIFIER:12,UNEXPECTED_EMPTY_LINKED_KEY:13,UNEXPECTED_LEXICAL_ANALYSIS:14,UNHANDLED_CODEGEN_NODE_TYPE:15,UNHANDLED_MINIFIER_NODE_TYPE:16};Q.EXPECTED_TOKEN,Q.INVALID_TOKEN_IN_PLACEHOLDER,Q.UNTERMINATED_SINGLE_QUOTE_IN_PLACEHOLDER,Q.UNKNOWN_ESCAPE_SEQUENCE,Q.INVALID_UNICODE_ESCAPE_SEQUENCE,Q.UNBALANCED_CLOSING_BRACE,Q.UNTERMINATED_CLOSING_BRACE,Q.EMPTY_PLACEHOLDER,Q.NOT_ALLOW_NEST_PLACEHOLDER,Q.INVALID_LINKED_FORMAT,Q.MUST_HAVE_MESSAGES_IN_PLURAL,Q.UNEXPECTED_EMPTY_LINKED_MODIFIER,Q.UNEXPECTED_EMPTY_LINKED_KEY,Q.UNEXPECTED_LEXICAL_ANALYSIS,Q.UNHANDLED_CODEGEN_NODE_TYPE,Q.UNHANDLED_MINIFIER_NODE_TYPE;function Bu(e,t,n={}){let{domain:r,messages:i,args:a}=n,o=e,s=SyntaxError(String(o));return s.code=e,t&&(s.location=t),s.domain=r,s}function Vu(e){throw e}var Hu=` `,Uu=`\r`,Wu=`
-`,Gu=`\u2028`,Ku=`\u2029`;function qu(e){let t=e,n=0,r=1,i=1,a=0,o=e=>t[e]===Uu&&t[e+1]===Wu,s=e=>t[e]===Wu,c=e=>t[e]===Ku,l=e=>t[e]===Gu,u=e=>o(e)||s(e)||c(e)||l(e),d=()=>n,f=()=>r,p=()=>i,m=()=>a,h=e=>o(e)||c(e)||l(e)?Wu:t[e],g=()=>h(n),_=()=>h(n+a);function v(){return a=0,u(n)&&(r++,i=0),o(n)&&n++,n++,i++,t[n]}function y(){return o(n+a)&&a++,a++,t[n+a]}function b(){n=0,r=1,i=1,a=0}function x(e=0){a=e}function S(){let e=n+a;for(;e!==n;)v();a=0}return{index:d,line:f,column:p,peekOffset:m,charAt:h,currentChar:g,currentPeek:_,next:v,peek:y,reset:b,resetPeek:x,skipToPeek:S}}var Ju=void 0,Yu=`'`,Xu=`tokenizer`;function Zu(e,t={}){let n=t.location!==!1,r=qu(e),i=()=>r.index(),a=()=>Ru(r.line(),r.column(),r.index()),o=a(),s=i(),c={currentType:13,offset:s,startLoc:o,endLoc:o,lastType:13,lastOffset:s,lastStartLoc:o,lastEndLoc:o,braceNest:0,inLinked:!1,text:``},l=()=>c,{onError:u}=t;function d(e,t,r,...i){let a=l();t.column+=r,t.offset+=r,u&&u(Bu(e,n?zu(a.startLoc,t):null,{domain:Xu,args:i}))}function f(e,t,r){e.endLoc=a(),e.currentType=t;let i={type:t};return n&&(i.loc=zu(e.startLoc,e.endLoc)),r!=null&&(i.value=r),i}let p=e=>f(e,13);function m(e,t){return e.currentChar()===t?(e.next(),t):(d(Q.EXPECTED_TOKEN,a(),0,t),``)}function h(e){let t=``;for(;e.currentPeek()===Hu||e.currentPeek()===Wu;)t+=e.currentPeek(),e.peek();return t}function g(e){let t=h(e);return e.skipToPeek(),t}function _(e){if(e===Ju)return!1;let t=e.charCodeAt(0);return t>=97&&t<=122||t>=65&&t<=90||t===95}function v(e){if(e===Ju)return!1;let t=e.charCodeAt(0);return t>=48&&t<=57}function y(e,t){let{currentType:n}=t;if(n!==2)return!1;h(e);let r=_(e.currentPeek());return e.resetPeek(),r}function b(e,t){let{currentType:n}=t;if(n!==2)return!1;h(e);let r=v(e.currentPeek()===`-`?e.peek():e.currentPeek());return e.resetPeek(),r}function x(e,t){let{currentType:n}=t;if(n!==2)return!1;h(e);let r=e.currentPeek()===Yu;return e.resetPeek(),r}function S(e,t){let{currentType:n}=t;if(n!==7)return!1;h(e);let r=e.currentPeek()===`.`;return e.resetPeek(),r}function C(e,t){let{currentType:n}=t;if(n!==8)return!1;h(e);let r=_(e.currentPeek());return e.resetPeek(),r}function w(e,t){let{currentType:n}=t;if(!(n===7||n===11))return!1;h(e);let r=e.currentPeek()===`:`;return e.resetPeek(),r}function T(e,t){let{currentType:n}=t;if(n!==9)return!1;let r=()=>{let t=e.currentPeek();return t===`{`?_(e.peek()):t===`@`||t===`|`||t===`:`||t===`.`||t===Hu||!t?!1:t===Wu?(e.peek(),r()):D(e,!1)},i=r();return e.resetPeek(),i}function E(e){h(e);let t=e.currentPeek()===`|`;return e.resetPeek(),t}function D(e,t=!0){let n=(t=!1,r=``)=>{let i=e.currentPeek();return i===`{`||i===`@`||!i?t:i===`|`?!(r===Hu||r===Wu):i===Hu?(e.peek(),n(!0,Hu)):i===Wu?(e.peek(),n(!0,Wu)):!0},r=n();return t&&e.resetPeek(),r}function O(e,t){let n=e.currentChar();return n===Ju?Ju:t(n)?(e.next(),n):null}function ee(e){let t=e.charCodeAt(0);return t>=97&&t<=122||t>=65&&t<=90||t>=48&&t<=57||t===95||t===36}function te(e){return O(e,ee)}function k(e){let t=e.charCodeAt(0);return t>=97&&t<=122||t>=65&&t<=90||t>=48&&t<=57||t===95||t===36||t===45}function ne(e){return O(e,k)}function A(e){let t=e.charCodeAt(0);return t>=48&&t<=57}function re(e){return O(e,A)}function j(e){let t=e.charCodeAt(0);return t>=48&&t<=57||t>=65&&t<=70||t>=97&&t<=102}function M(e){return O(e,j)}function ie(e){let t=``,n=``;for(;t=re(e);)n+=t;return n}function ae(e){let t=``;for(;;){let n=e.currentChar();if(n===`{`||n===`}`||n===`@`||n===`|`||!n)break;if(n===Hu||n===Wu)if(D(e))t+=n,e.next();else if(E(e))break;else t+=n,e.next();else t+=n,e.next()}return t}function oe(e){g(e);let t=``,n=``;for(;t=ne(e);)n+=t;let r=e.currentChar();if(r&&r!==`}`&&r!==Ju&&r!==Hu&&r!==Wu&&r!==`　`){let t=pe(e);return d(Q.INVALID_TOKEN_IN_PLACEHOLDER,a(),0,n+t),n+t}return e.currentChar()===Ju&&d(Q.UNTERMINATED_CLOSING_BRACE,a(),0),n}function se(e){g(e);let t=``;return e.currentChar()===`-`?(e.next(),t+=`-${ie(e)}`):t+=ie(e),e.currentChar()===Ju&&d(Q.UNTERMINATED_CLOSING_BRACE,a(),0),t}function ce(e){return e!==Yu&&e!==Wu}function le(e){g(e),m(e,`'`);let t=``,n=``;for(;t=O(e,ce);)t===`\\`?n+=ue(e):n+=t;let r=e.currentChar();return r===Wu||r===Ju?(d(Q.UNTERMINATED_SINGLE_QUOTE_IN_PLACEHOLDER,a(),0),r===Wu&&(e.next(),m(e,`'`)),n):(m(e,`'`),n)}function ue(e){let t=e.currentChar();switch(t){case`\\`:case`'`:return e.next(),`\\${t}`;case`u`:return de(e,t,4);case`U`:return de(e,t,6);default:return d(Q.UNKNOWN_ESCAPE_SEQUENCE,a(),0,t),``}}function de(e,t,n){m(e,t);let r=``;for(let i=0;i<n;i++){let n=M(e);if(!n){d(Q.INVALID_UNICODE_ESCAPE_SEQUENCE,a(),0,`\\${t}${r}${e.currentChar()}`);break}r+=n}return`\\${t}${r}`}function fe(e){return e!==`{`&&e!==`}`&&e!==Hu&&e!==Wu}function pe(e){g(e);let t=``,n=``;for(;t=O(e,fe);)n+=t;return n}function me(e){let t=``,n=``;for(;t=te(e);)n+=t;return n}function he(e){let t=n=>{let r=e.currentChar();return r===`{`||r===`@`||r===`|`||r===`(`||r===`)`||!r||r===Hu?n:(n+=r,e.next(),t(n))};return t(``)}function ge(e){g(e);let t=m(e,`|`);return g(e),t}function _e(e,t){let n=null;switch(e.currentChar()){case`{`:return t.braceNest>=1&&d(Q.NOT_ALLOW_NEST_PLACEHOLDER,a(),0),e.next(),n=f(t,2,`{`),g(e),t.braceNest++,n;case`}`:return t.braceNest>0&&t.currentType===2&&d(Q.EMPTY_PLACEHOLDER,a(),0),e.next(),n=f(t,3,`}`),t.braceNest--,t.braceNest>0&&g(e),t.inLinked&&t.braceNest===0&&(t.inLinked=!1),n;case`@`:return t.braceNest>0&&d(Q.UNTERMINATED_CLOSING_BRACE,a(),0),n=ve(e,t)||p(t),t.braceNest=0,n;default:{let r=!0,i=!0,o=!0;if(E(e))return t.braceNest>0&&d(Q.UNTERMINATED_CLOSING_BRACE,a(),0),n=f(t,1,ge(e)),t.braceNest=0,t.inLinked=!1,n;if(t.braceNest>0&&(t.currentType===4||t.currentType===5||t.currentType===6))return d(Q.UNTERMINATED_CLOSING_BRACE,a(),0),t.braceNest=0,ye(e,t);if(r=y(e,t))return n=f(t,4,oe(e)),g(e),n;if(i=b(e,t))return n=f(t,5,se(e)),g(e),n;if(o=x(e,t))return n=f(t,6,le(e)),g(e),n;if(!r&&!i&&!o)return n=f(t,12,pe(e)),d(Q.INVALID_TOKEN_IN_PLACEHOLDER,a(),0,n.value),g(e),n;break}}return n}function ve(e,t){let{currentType:n}=t,r=null,i=e.currentChar();switch((n===7||n===8||n===11||n===9)&&(i===Wu||i===Hu)&&d(Q.INVALID_LINKED_FORMAT,a(),0),i){case`@`:return e.next(),r=f(t,7,`@`),t.inLinked=!0,r;case`.`:return g(e),e.next(),f(t,8,`.`);case`:`:return g(e),e.next(),f(t,9,`:`);default:return E(e)?(r=f(t,1,ge(e)),t.braceNest=0,t.inLinked=!1,r):S(e,t)||w(e,t)?(g(e),ve(e,t)):C(e,t)?(g(e),f(t,11,me(e))):T(e,t)?(g(e),i===`{`?_e(e,t)||r:f(t,10,he(e))):(n===7&&d(Q.INVALID_LINKED_FORMAT,a(),0),t.braceNest=0,t.inLinked=!1,ye(e,t))}}function ye(e,t){let n={type:13};if(t.braceNest>0)return _e(e,t)||p(t);if(t.inLinked)return ve(e,t)||p(t);switch(e.currentChar()){case`{`:return _e(e,t)||p(t);case`}`:return d(Q.UNBALANCED_CLOSING_BRACE,a(),0),e.next(),f(t,3,`}`);case`@`:return ve(e,t)||p(t);default:if(E(e))return n=f(t,1,ge(e)),t.braceNest=0,t.inLinked=!1,n;if(D(e))return f(t,0,ae(e));break}return n}function be(){let{currentType:e,offset:t,startLoc:n,endLoc:o}=c;return c.lastType=e,c.lastOffset=t,c.lastStartLoc=n,c.lastEndLoc=o,c.offset=i(),c.startLoc=a(),r.currentChar()===Ju?f(c,13):ye(r,c)}return{nextToken:be,currentOffset:i,currentPosition:a,context:l}}var Qu=`parser`,$u=/(?:\\\\|\\'|\\u([0-9a-fA-F]{4})|\\U([0-9a-fA-F]{6}))/g;function ed(e,t,n){switch(e){case`\\\\`:return`\\`;case`\\'`:return`'`;default:{let e=parseInt(t||n,16);return e<=55295||e>=57344?String.fromCodePoint(e):`�`}}}function td(e={}){let t=e.location!==!1,{onError:n}=e;function r(e,r,i,a,...o){let s=e.currentPosition();s.offset+=a,s.column+=a,n&&n(Bu(r,t?zu(i,s):null,{domain:Qu,args:o}))}function i(e,n,r){let i={type:e};return t&&(i.start=n,i.end=n,i.loc={start:r,end:r}),i}function a(e,n,r,i){t&&(e.end=n,e.loc&&(e.loc.end=r))}function o(e,t){let n=e.context(),r=i(3,n.offset,n.startLoc);return r.value=t,a(r,e.currentOffset(),e.currentPosition()),r}function s(e,t){let{lastOffset:n,lastStartLoc:r}=e.context(),o=i(5,n,r);return o.index=parseInt(t,10),e.nextToken(),a(o,e.currentOffset(),e.currentPosition()),o}function c(e,t){let{lastOffset:n,lastStartLoc:r}=e.context(),o=i(4,n,r);return o.key=t,e.nextToken(),a(o,e.currentOffset(),e.currentPosition()),o}function l(e,t){let{lastOffset:n,lastStartLoc:r}=e.context(),o=i(9,n,r);return o.value=t.replace($u,ed),e.nextToken(),a(o,e.currentOffset(),e.currentPosition()),o}function u(e){let t=e.nextToken(),n=e.context(),{lastOffset:o,lastStartLoc:s}=n,c=i(8,o,s);return t.type===11?(t.value??r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,n.lastStartLoc,0,nd(t)),c.value=t.value||``,a(c,e.currentOffset(),e.currentPosition()),{node:c}):(r(e,Q.UNEXPECTED_EMPTY_LINKED_MODIFIER,n.lastStartLoc,0),c.value=``,a(c,o,s),{nextConsumeToken:t,node:c})}function d(e,t){let n=e.context(),r=i(7,n.offset,n.startLoc);return r.value=t,a(r,e.currentOffset(),e.currentPosition()),r}function f(e){let t=e.context(),n=i(6,t.offset,t.startLoc),o=e.nextToken();if(o.type===8){let t=u(e);n.modifier=t.node,o=t.nextConsumeToken||e.nextToken()}switch(o.type!==9&&r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,t.lastStartLoc,0,nd(o)),o=e.nextToken(),o.type===2&&(o=e.nextToken()),o.type){case 10:o.value??r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,t.lastStartLoc,0,nd(o)),n.key=d(e,o.value||``);break;case 4:o.value??r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,t.lastStartLoc,0,nd(o)),n.key=c(e,o.value||``);break;case 5:o.value??r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,t.lastStartLoc,0,nd(o)),n.key=s(e,o.value||``);break;case 6:o.value??r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,t.lastStartLoc,0,nd(o)),n.key=l(e,o.value||``);break;default:{r(e,Q.UNEXPECTED_EMPTY_LINKED_KEY,t.lastStartLoc,0);let s=e.context(),c=i(7,s.offset,s.startLoc);return c.value=``,a(c,s.offset,s.startLoc),n.key=c,a(n,s.offset,s.startLoc),{nextConsumeToken:o,node:n}}}return a(n,e.currentOffset(),e.currentPosition()),{node:n}}function p(e){let t=e.context(),n=i(2,t.currentType===1?e.currentOffset():t.offset,t.currentType===1?t.endLoc:t.startLoc);n.items=[];let u=null;do{let i=u||e.nextToken();switch(u=null,i.type){case 0:i.value??r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,t.lastStartLoc,0,nd(i)),n.items.push(o(e,i.value||``));break;case 5:i.value??r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,t.lastStartLoc,0,nd(i)),n.items.push(s(e,i.value||``));break;case 4:i.value??r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,t.lastStartLoc,0,nd(i)),n.items.push(c(e,i.value||``));break;case 6:i.value??r(e,Q.UNEXPECTED_LEXICAL_ANALYSIS,t.lastStartLoc,0,nd(i)),n.items.push(l(e,i.value||``));break;case 7:{let t=f(e);n.items.push(t.node),u=t.nextConsumeToken||null;break}}}while(t.currentType!==13&&t.currentType!==1);return a(n,t.currentType===1?t.lastOffset:e.currentOffset(),t.currentType===1?t.lastEndLoc:e.currentPosition()),n}function m(e,t,n,o){let s=e.context(),c=o.items.length===0,l=i(1,t,n);l.cases=[],l.cases.push(o);do{let t=p(e);c||=t.items.length===0,l.cases.push(t)}while(s.currentType!==13);return c&&r(e,Q.MUST_HAVE_MESSAGES_IN_PLURAL,n,0),a(l,e.currentOffset(),e.currentPosition()),l}function h(e){let t=e.context(),{offset:n,startLoc:r}=t,i=p(e);return t.currentType===13?i:m(e,n,r,i)}function g(n){let o=Zu(n,W({},e)),s=o.context(),c=i(0,s.offset,s.startLoc);return t&&c.loc&&(c.loc.source=n),c.body=h(o),e.onCacheKey&&(c.cacheKey=e.onCacheKey(n)),s.currentType!==13&&r(o,Q.UNEXPECTED_LEXICAL_ANALYSIS,s.lastStartLoc,0,n[s.offset]||``),a(c,o.currentOffset(),o.currentPosition()),c}return{parse:g}}function nd(e){if(e.type===13)return`EOF`;let t=(e.value||``).replace(/\r?\n/gu,`\\n`);return t.length>10?t.slice(0,9)+`…`:t}function rd(e,t={}){let n={ast:e,helpers:new Set};return{context:()=>n,helper:e=>(n.helpers.add(e),e)}}function id(e,t){for(let n=0;n<e.length;n++)ad(e[n],t)}function ad(e,t){switch(e.type){case 1:id(e.cases,t),t.helper(`plural`);break;case 2:id(e.items,t);break;case 6:ad(e.key,t),t.helper(`linked`),t.helper(`type`);break;case 5:t.helper(`interpolate`),t.helper(`list`);break;case 4:t.helper(`interpolate`),t.helper(`named`);break}}function od(e,t={}){let n=rd(e);n.helper(`normalize`),e.body&&ad(e.body,n);let r=n.context();e.helpers=Array.from(r.helpers)}function sd(e){let t=e.body;return t.type===2?cd(t):t.cases.forEach(e=>cd(e)),e}function cd(e){if(e.items.length===1){let t=e.items[0];(t.type===3||t.type===9)&&(e.static=t.value,delete t.value)}else{let t=[];for(let n=0;n<e.items.length;n++){let r=e.items[n];if(!(r.type===3||r.type===9)||r.value==null)break;t.push(r.value)}if(t.length===e.items.length){e.static=Fu(t);for(let t=0;t<e.items.length;t++){let n=e.items[t];(n.type===3||n.type===9)&&delete n.value}}}}function ld(e){switch(e.t=e.type,e.type){case 0:{let t=e;ld(t.body),t.b=t.body,delete t.body;break}case 1:{let t=e,n=t.cases;for(let e=0;e<n.length;e++)ld(n[e]);t.c=n,delete t.cases;break}case 2:{let t=e,n=t.items;for(let e=0;e<n.length;e++)ld(n[e]);t.i=n,delete t.items,t.static&&(t.s=t.static,delete t.static);break}case 3:case 9:case 8:case 7:{let t=e;t.value&&(t.v=t.value,delete t.value);break}case 6:{let t=e;ld(t.key),t.k=t.key,delete t.key,t.modifier&&(ld(t.modifier),t.m=t.modifier,delete t.modifier);break}case 5:{let t=e;t.i=t.index,delete t.index;break}case 4:{let t=e;t.k=t.key,delete t.key;break}default:}delete e.type}function ud(e,t){let{sourceMap:n,filename:r,breakLineCode:i,needIndent:a}=t,o=t.location!==!1,s={filename:r,code:``,column:1,line:1,offset:0,map:void 0,breakLineCode:i,needIndent:a,indentLevel:0};o&&e.loc&&(s.source=e.loc.source);let c=()=>s;function l(e,t){s.code+=e}function u(e,t=!0){let n=t?i:``;l(a?n+`  `.repeat(e):n)}function d(e=!0){let t=++s.indentLevel;e&&u(t)}function f(e=!0){let t=--s.indentLevel;e&&u(t)}function p(){u(s.indentLevel)}return{context:c,push:l,indent:d,deindent:f,newline:p,helper:e=>`_${e}`,needIndent:()=>s.needIndent}}function dd(e,t){let{helper:n}=e;e.push(`${n(`linked`)}(`),hd(e,t.key),t.modifier?(e.push(`, `),hd(e,t.modifier),e.push(`, _type`)):e.push(`, undefined, _type`),e.push(`)`)}function fd(e,t){let{helper:n,needIndent:r}=e;e.push(`${n(`normalize`)}([`),e.indent(r());let i=t.items.length;for(let n=0;n<i&&(hd(e,t.items[n]),n!==i-1);n++)e.push(`, `);e.deindent(r()),e.push(`])`)}function pd(e,t){let{helper:n,needIndent:r}=e;if(t.cases.length>1){e.push(`${n(`plural`)}([`),e.indent(r());let i=t.cases.length;for(let n=0;n<i&&(hd(e,t.cases[n]),n!==i-1);n++)e.push(`, `);e.deindent(r()),e.push(`])`)}}function md(e,t){t.body?hd(e,t.body):e.push(`null`)}function hd(e,t){let{helper:n}=e;switch(t.type){case 0:md(e,t);break;case 1:pd(e,t);break;case 2:fd(e,t);break;case 6:dd(e,t);break;case 8:e.push(JSON.stringify(t.value),t);break;case 7:e.push(JSON.stringify(t.value),t);break;case 5:e.push(`${n(`interpolate`)}(${n(`list`)}(${t.index}))`,t);break;case 4:e.push(`${n(`interpolate`)}(${n(`named`)}(${JSON.stringify(t.key)}))`,t);break;case 9:e.push(JSON.stringify(t.value),t);break;case 3:e.push(JSON.stringify(t.value),t);break;default:}}var gd=(e,t={})=>{let n=J(t.mode)?t.mode:`normal`,r=J(t.filename)?t.filename:`message.intl`,i=!!t.sourceMap,a=t.breakLineCode==null?n===`arrow`?`;`:`
-`:t.breakLineCode,o=t.needIndent?t.needIndent:n!==`arrow`,s=e.helpers||[],c=ud(e,{mode:n,filename:r,sourceMap:i,breakLineCode:a,needIndent:o});c.push(n===`normal`?`function __msg__ (ctx) {`:`(ctx) => {`),c.indent(o),s.length>0&&(c.push(`const { ${Fu(s.map(e=>`${e}: _${e}`),`, `)} } = ctx`),c.newline()),c.push(`return `),hd(c,e),c.deindent(o),c.push(`}`),delete e.helpers;let{code:l,map:u}=c.context();return{ast:e,code:l,map:u?u.toJSON():void 0}};function _d(e,t={}){let n=W({},t),r=!!n.jit,i=!!n.minify,a=n.optimize==null?!0:n.optimize,o=td(n).parse(e);return r?(a&&sd(o),i&&ld(o),{ast:o,code:``}):(od(o,n),gd(o,n))}function vd(){typeof __INTLIFY_PROD_DEVTOOLS__!=`boolean`&&(Tu().__INTLIFY_PROD_DEVTOOLS__=!1),typeof __INTLIFY_DROP_MESSAGE_COMPILER__!=`boolean`&&(Tu().__INTLIFY_DROP_MESSAGE_COMPILER__=!1)}function yd(e){return X(e)&&kd(e)===0&&(Au(e,`b`)||Au(e,`body`))}var bd=[`b`,`body`];function xd(e){return Id(e,bd)}var Sd=[`c`,`cases`];function Cd(e){return Id(e,Sd,[])}var wd=[`s`,`static`];function Td(e){return Id(e,wd)}var Ed=[`i`,`items`];function Dd(e){return Id(e,Ed,[])}var Od=[`t`,`type`];function kd(e){return Id(e,Od)}var Ad=[`v`,`value`];function jd(e,t){let n=Id(e,Ad);if(n!=null)return n;throw Rd(t)}var Md=[`m`,`modifier`];function Nd(e){return Id(e,Md)}var Pd=[`k`,`key`];function Fd(e){let t=Id(e,Pd);if(t)return t;throw Rd(6)}function Id(e,t,n){for(let n=0;n<t.length;n++){let r=t[n];if(Au(e,r)&&e[r]!=null)return e[r]}return n}var Ld=[...bd,...Sd,...wd,...Ed,...Pd,...Md,...Ad,...Od];function Rd(e){return Error(`unhandled node type: ${e}`)}function zd(e){return t=>Bd(t,e)}function Bd(e,t){let n=xd(t);if(n==null)throw Rd(0);if(kd(n)===1){let t=Cd(n);return e.plural(t.reduce((t,n)=>[...t,Vd(e,n)],[]))}else return Vd(e,n)}function Vd(e,t){let n=Td(t);if(n!=null)return e.type===`text`?n:e.normalize([n]);{let n=Dd(t).reduce((t,n)=>[...t,Hd(e,n)],[]);return e.normalize(n)}}function Hd(e,t){let n=kd(t);switch(n){case 3:return jd(t,n);case 9:return jd(t,n);case 4:{let r=t;if(Au(r,`k`)&&r.k)return e.interpolate(e.named(r.k));if(Au(r,`key`)&&r.key)return e.interpolate(e.named(r.key));throw Rd(n)}case 5:{let r=t;if(Au(r,`i`)&&U(r.i))return e.interpolate(e.list(r.i));if(Au(r,`index`)&&U(r.index))return e.interpolate(e.list(r.index));throw Rd(n)}case 6:{let n=t,r=Nd(n),i=Fd(n);return e.linked(Hd(e,i),r?Hd(e,r):void 0,e.type)}case 7:return jd(t,n);case 8:return jd(t,n);default:throw Error(`unhandled node on format message part: ${n}`)}}var Ud=e=>e,Wd=G();function Gd(e,t={}){let n=!1,r=t.onError||Vu;return t.onError=e=>{n=!0,r(e)},{..._d(e,t),detectError:n}}function Kd(e,t){if(!__INTLIFY_DROP_MESSAGE_COMPILER__&&J(e)){Y(t.warnHtmlMessage)&&t.warnHtmlMessage;let n=(t.onCacheKey||Ud)(e),r=Wd[n];if(r)return r;let{ast:i,detectError:a}=Gd(e,{...t,location:!1,jit:!0}),o=zd(i);return a?o:Wd[n]=o}else{let t=e.cacheKey;return t?Wd[t]||(Wd[t]=zd(e)):zd(e)}}var qd=null;function Jd(e){qd=e}function Yd(e,t,n){qd&&qd.emit(`i18n:init`,{timestamp:Date.now(),i18n:e,version:t,meta:n})}var Xd=Zd(`function:translate`);function Zd(e){return t=>qd&&qd.emit(e,t)}var Qd={INVALID_ARGUMENT:17,INVALID_DATE_ARGUMENT:18,INVALID_ISO_DATE_ARGUMENT:19,NOT_SUPPORT_NON_STRING_MESSAGE:20,NOT_SUPPORT_LOCALE_PROMISE_VALUE:21,NOT_SUPPORT_LOCALE_ASYNC_FUNCTION:22,NOT_SUPPORT_LOCALE_TYPE:23};function $d(e){return Bu(e,null,void 0)}Qd.INVALID_ARGUMENT,Qd.INVALID_DATE_ARGUMENT,Qd.INVALID_ISO_DATE_ARGUMENT,Qd.NOT_SUPPORT_NON_STRING_MESSAGE,Qd.NOT_SUPPORT_LOCALE_PROMISE_VALUE,Qd.NOT_SUPPORT_LOCALE_ASYNC_FUNCTION,Qd.NOT_SUPPORT_LOCALE_TYPE;function ef(e,t){return t.locale==null?nf(e.locale):nf(t.locale)}var tf;function nf(e){if(J(e))return e;if(q(e)){if(e.resolvedOnce&&tf!=null)return tf;if(e.constructor.name===`Function`){let t=e();if(ju(t))throw $d(Qd.NOT_SUPPORT_LOCALE_PROMISE_VALUE);return tf=t}else throw $d(Qd.NOT_SUPPORT_LOCALE_ASYNC_FUNCTION)}else throw $d(Qd.NOT_SUPPORT_LOCALE_TYPE)}function rf(e,t,n){return[...new Set([n,...K(t)?t:X(t)?Object.keys(t):J(t)?[t]:[n]])]}function af(e,t,n){let r=J(n)?n:xf,i=e;i.__localeChainCache||=new Map;let a=i.__localeChainCache.get(r);if(!a){a=[];let e=[n];for(;K(e);)e=of(a,e,t);let o=K(t)||!Z(t)?t:t.default?t.default:null;e=J(o)?[o]:o,K(e)&&of(a,e,!1),i.__localeChainCache.set(r,a)}return a}function of(e,t,n){let r=!0;for(let i=0;i<t.length&&Y(r);i++){let a=t[i];J(a)&&(r=sf(e,t[i],n))}return r}function sf(e,t,n){let r,i=t.split(`-`);do r=cf(e,i.join(`-`),n),i.splice(-1,1);while(i.length&&r===!0);return r}function cf(e,t,n){let r=!1;if(!e.includes(t)&&(r=!0,t)){r=t[t.length-1]!==`!`;let i=t.replace(/!/g,``);e.push(i),(K(n)||Z(n))&&n[i]&&(r=n[i])}return r}var lf=[];lf[0]={w:[0],i:[3,0],"[":[4],o:[7]},lf[1]={w:[1],".":[2],"[":[4],o:[7]},lf[2]={w:[2],i:[3,0],0:[3,0]},lf[3]={i:[3,0],0:[3,0],w:[1,1],".":[2,1],"[":[4,1],o:[7,1]},lf[4]={"'":[5,0],'"':[6,0],"[":[4,2],"]":[1,3],o:8,l:[4,0]},lf[5]={"'":[4,0],o:8,l:[5,0]},lf[6]={'"':[4,0],o:8,l:[6,0]};var uf=/^\s?(?:true|false|-?[\d.]+|'[^']*'|"[^"]*")\s?$/;function df(e){return uf.test(e)}function ff(e){let t=e.charCodeAt(0);return t===e.charCodeAt(e.length-1)&&(t===34||t===39)?e.slice(1,-1):e}function pf(e){if(e==null)return`o`;switch(e.charCodeAt(0)){case 91:case 93:case 46:case 34:case 39:return e;case 95:case 36:case 45:return`i`;case 9:case 10:case 13:case 160:case 65279:case 8232:case 8233:return`w`}return`i`}function mf(e){let t=e.trim();return e.charAt(0)===`0`&&isNaN(parseInt(e))?!1:df(t)?ff(t):`*`+t}function hf(e){let t=[],n=-1,r=0,i=0,a,o,s,c,l,u,d,f=[];f[0]=()=>{o===void 0?o=s:o+=s},f[1]=()=>{o!==void 0&&(t.push(o),o=void 0)},f[2]=()=>{f[0](),i++},f[3]=()=>{if(i>0)i--,r=4,f[0]();else{if(i=0,o===void 0||(o=mf(o),o===!1))return!1;f[1]()}};function p(){let t=e[n+1];if(r===5&&t===`'`||r===6&&t===`"`)return n++,s=`\\`+t,f[0](),!0}for(;r!==null;)if(n++,a=e[n],!(a===`\\`&&p())){if(c=pf(a),d=lf[r],l=d[c]||d.l||8,l===8||(r=l[0],l[1]!==void 0&&(u=f[l[1]],u&&(s=a,u()===!1))))return;if(r===7)return t}}var gf=new Map;function _f(e,t){return X(e)?e[t]:null}function vf(e,t){if(!X(e))return null;let n=gf.get(t);if(n||(n=hf(t),n&&gf.set(t,n)),!n)return null;let r=n.length,i=e,a=0;for(;a<r;){let e=n[a];if(Ld.includes(e)&&yd(i))return null;let t=i[e];if(t===void 0||q(i))return null;i=t,a++}return i}var yf={NOT_FOUND_KEY:1,FALLBACK_TO_TRANSLATE:2,CANNOT_FORMAT_NUMBER:3,FALLBACK_TO_NUMBER_FORMAT:4,CANNOT_FORMAT_DATE:5,FALLBACK_TO_DATE_FORMAT:6,EXPERIMENTAL_CUSTOM_MESSAGE_COMPILER:7};yf.NOT_FOUND_KEY,yf.FALLBACK_TO_TRANSLATE,yf.CANNOT_FORMAT_NUMBER,yf.FALLBACK_TO_NUMBER_FORMAT,yf.CANNOT_FORMAT_DATE,yf.FALLBACK_TO_DATE_FORMAT,yf.EXPERIMENTAL_CUSTOM_MESSAGE_COMPILER;var bf=`11.2.8`,xf=`en-US`,Sf=e=>`${e.charAt(0).toLocaleUpperCase()}${e.substr(1)}`;function Cf(){return{upper:(e,t)=>t===`text`&&J(e)?e.toUpperCase():t===`vnode`&&X(e)&&`__v_isVNode`in e?e.children.toUpperCase():e,lower:(e,t)=>t===`text`&&J(e)?e.toLowerCase():t===`vnode`&&X(e)&&`__v_isVNode`in e?e.children.toLowerCase():e,capitalize:(e,t)=>t===`text`&&J(e)?Sf(e):t===`vnode`&&X(e)&&`__v_isVNode`in e?Sf(e.children):e}}var wf;function Tf(e){wf=e}var Ef;function Df(e){Ef=e}var Of;function kf(e){Of=e}var Af=null,jf=()=>Af,Mf=null,Nf=e=>{Mf=e},Pf=()=>Mf,Ff=0;function If(e={}){let t=q(e.onWarn)?e.onWarn:hu,n=J(e.version)?e.version:bf,r=J(e.locale)||q(e.locale)?e.locale:xf,i=q(r)?xf:r,a=K(e.fallbackLocale)||Z(e.fallbackLocale)||J(e.fallbackLocale)||e.fallbackLocale===!1?e.fallbackLocale:i,o=Z(e.messages)?e.messages:Lf(i),s=Z(e.datetimeFormats)?e.datetimeFormats:Lf(i),c=Z(e.numberFormats)?e.numberFormats:Lf(i),l=W(G(),e.modifiers,Cf()),u=e.pluralRules||G(),d=q(e.missing)?e.missing:null,f=Y(e.missingWarn)||xu(e.missingWarn)?e.missingWarn:!0,p=Y(e.fallbackWarn)||xu(e.fallbackWarn)?e.fallbackWarn:!0,m=!!e.fallbackFormat,h=!!e.unresolving,g=q(e.postTranslation)?e.postTranslation:null,_=Z(e.processor)?e.processor:null,v=Y(e.warnHtmlMessage)?e.warnHtmlMessage:!0,y=!!e.escapeParameter,b=q(e.messageCompiler)?e.messageCompiler:wf,x=q(e.messageResolver)?e.messageResolver:Ef||_f,S=q(e.localeFallbacker)?e.localeFallbacker:Of||rf,C=X(e.fallbackContext)?e.fallbackContext:void 0,w=e,T=X(w.__datetimeFormatters)?w.__datetimeFormatters:new Map,E=X(w.__numberFormatters)?w.__numberFormatters:new Map,D=X(w.__meta)?w.__meta:{};Ff++;let O={version:n,cid:Ff,locale:r,fallbackLocale:a,messages:o,modifiers:l,pluralRules:u,missing:d,missingWarn:f,fallbackWarn:p,fallbackFormat:m,unresolving:h,postTranslation:g,processor:_,warnHtmlMessage:v,escapeParameter:y,messageCompiler:b,messageResolver:x,localeFallbacker:S,fallbackContext:C,onWarn:t,__meta:D};return O.datetimeFormats=s,O.numberFormats=c,O.__datetimeFormatters=T,O.__numberFormatters=E,__INTLIFY_PROD_DEVTOOLS__&&Yd(O,n,D),O}var Lf=e=>({[e]:G()});function Rf(e,t,n,r,i){let{missing:a,onWarn:o}=e;if(a!==null){let r=a(e,n,t,i);return J(r)?r:t}else return t}function zf(e,t,n){let r=e;r.__localeChainCache=new Map,e.localeFallbacker(e,n,t)}function Bf(e,t){return e===t?!1:e.split(`-`)[0]===t.split(`-`)[0]}function Vf(e,t){let n=t.indexOf(e);if(n===-1)return!1;for(let r=n+1;r<t.length;r++)if(Bf(e,t[r]))return!0;return!1}var Hf=typeof Intl<`u`;Hf&&Intl.DateTimeFormat,Hf&&Intl.NumberFormat;function Uf(e,...t){let{datetimeFormats:n,unresolving:r,fallbackLocale:i,onWarn:a,localeFallbacker:o}=e,{__datetimeFormatters:s}=e,[c,l,u,d]=Gf(...t),f=Y(u.missingWarn)?u.missingWarn:e.missingWarn;Y(u.fallbackWarn)?u.fallbackWarn:e.fallbackWarn;let p=!!u.part,m=ef(e,u),h=o(e,i,m);if(!J(c)||c===``)return new Intl.DateTimeFormat(m,d).format(l);let g={},_,v=null;for(let t=0;t<h.length&&(_=h[t],g=n[_]||{},v=g[c],!Z(v));t++)Rf(e,c,_,f,`datetime format`);if(!Z(v)||!J(_))return r?-1:c;let y=`${_}__${c}`;Su(d)||(y=`${y}__${JSON.stringify(d)}`);let b=s.get(y);return b||(b=new Intl.DateTimeFormat(_,W({},v,d)),s.set(y,b)),p?b.formatToParts(l):b.format(l)}var Wf=[`localeMatcher`,`weekday`,`era`,`year`,`month`,`day`,`hour`,`minute`,`second`,`timeZoneName`,`formatMatcher`,`hour12`,`timeZone`,`dateStyle`,`timeStyle`,`calendar`,`dayPeriod`,`numberingSystem`,`hourCycle`,`fractionalSecondDigits`];function Gf(...e){let[t,n,r,i]=e,a=G(),o=G(),s;if(J(t)){let e=t.match(/(\d{4}-\d{2}-\d{2})(T|\s)?(.*)/);if(!e)throw $d(Qd.INVALID_ISO_DATE_ARGUMENT);let n=e[3]?e[3].trim().startsWith(`T`)?`${e[1].trim()}${e[3].trim()}`:`${e[1].trim()}T${e[3].trim()}`:e[1].trim();s=new Date(n);try{s.toISOString()}catch{throw $d(Qd.INVALID_ISO_DATE_ARGUMENT)}}else if(bu(t)){if(isNaN(t.getTime()))throw $d(Qd.INVALID_DATE_ARGUMENT);s=t}else if(U(t))s=t;else throw $d(Qd.INVALID_ARGUMENT);return J(n)?a.key=n:Z(n)&&Object.keys(n).forEach(e=>{Wf.includes(e)?o[e]=n[e]:a[e]=n[e]}),J(r)?a.locale=r:Z(r)&&(o=r),Z(i)&&(o=i),[a.key||``,s,a,o]}function Kf(e,t,n){let r=e;for(let e in n){let n=`${t}__${e}`;r.__datetimeFormatters.has(n)&&r.__datetimeFormatters.delete(n)}}function qf(e,...t){let{numberFormats:n,unresolving:r,fallbackLocale:i,onWarn:a,localeFallbacker:o}=e,{__numberFormatters:s}=e,[c,l,u,d]=Yf(...t),f=Y(u.missingWarn)?u.missingWarn:e.missingWarn;Y(u.fallbackWarn)?u.fallbackWarn:e.fallbackWarn;let p=!!u.part,m=ef(e,u),h=o(e,i,m);if(!J(c)||c===``)return new Intl.NumberFormat(m,d).format(l);let g={},_,v=null;for(let t=0;t<h.length&&(_=h[t],g=n[_]||{},v=g[c],!Z(v));t++)Rf(e,c,_,f,`number format`);if(!Z(v)||!J(_))return r?-1:c;let y=`${_}__${c}`;Su(d)||(y=`${y}__${JSON.stringify(d)}`);let b=s.get(y);return b||(b=new Intl.NumberFormat(_,W({},v,d)),s.set(y,b)),p?b.formatToParts(l):b.format(l)}var Jf=[`localeMatcher`,`style`,`currency`,`currencyDisplay`,`currencySign`,`useGrouping`,`minimumIntegerDigits`,`minimumFractionDigits`,`maximumFractionDigits`,`minimumSignificantDigits`,`maximumSignificantDigits`,`compactDisplay`,`notation`,`signDisplay`,`unit`,`unitDisplay`,`roundingMode`,`roundingPriority`,`roundingIncrement`,`trailingZeroDisplay`];function Yf(...e){let[t,n,r,i]=e,a=G(),o=G();if(!U(t))throw $d(Qd.INVALID_ARGUMENT);let s=t;return J(n)?a.key=n:Z(n)&&Object.keys(n).forEach(e=>{Jf.includes(e)?o[e]=n[e]:a[e]=n[e]}),J(r)?a.locale=r:Z(r)&&(o=r),Z(i)&&(o=i),[a.key||``,s,a,o]}function Xf(e,t,n){let r=e;for(let e in n){let n=`${t}__${e}`;r.__numberFormatters.has(n)&&r.__numberFormatters.delete(n)}}var Zf=e=>e,Qf=e=>``,$f=`text`,ep=e=>e.length===0?``:Fu(e),tp=Pu;function np(e,t){return e=Math.abs(e),t===2?e?e>1?1:0:1:e?Math.min(e,2):0}function rp(e){let t=U(e.pluralIndex)?e.pluralIndex:-1;return e.named&&(U(e.named.count)||U(e.named.n))?U(e.named.count)?e.named.count:U(e.named.n)?e.named.n:t:t}function ip(e,t){t.count||=e,t.n||=e}function ap(e={}){let t=e.locale,n=rp(e),r=X(e.pluralRules)&&J(t)&&q(e.pluralRules[t])?e.pluralRules[t]:np,i=X(e.pluralRules)&&J(t)&&q(e.pluralRules[t])?np:void 0,a=e=>e[r(n,e.length,i)],o=e.list||[],s=e=>o[e],c=e.named||G();U(e.pluralIndex)&&ip(n,c);let l=e=>c[e];function u(t,n){return(q(e.messages)?e.messages(t,!!n):X(e.messages)?e.messages[t]:!1)||(e.parent?e.parent.message(t):Qf)}let d=t=>e.modifiers?e.modifiers[t]:Zf,f=Z(e.processor)&&q(e.processor.normalize)?e.processor.normalize:ep,p=Z(e.processor)&&q(e.processor.interpolate)?e.processor.interpolate:tp,m={list:s,named:l,plural:a,linked:(e,...t)=>{let[n,r]=t,i=`text`,a=``;t.length===1?X(n)?(a=n.modifier||a,i=n.type||i):J(n)&&(a=n||a):t.length===2&&(J(n)&&(a=n||a),J(r)&&(i=r||i));let o=u(e,!0)(m),s=i===`vnode`&&K(o)&&a?o[0]:o;return a?d(a)(s,i):s},message:u,type:Z(e.processor)&&J(e.processor.type)?e.processor.type:$f,interpolate:p,normalize:f,values:W(G(),o,c)};return m}var op=()=>``,sp=e=>q(e);function cp(e,...t){let{fallbackFormat:n,postTranslation:r,unresolving:i,messageCompiler:a,fallbackLocale:o,messages:s}=e,[c,l]=pp(...t),u=Y(l.missingWarn)?l.missingWarn:e.missingWarn,d=Y(l.fallbackWarn)?l.fallbackWarn:e.fallbackWarn,f=Y(l.escapeParameter)?l.escapeParameter:e.escapeParameter,p=!!l.resolvedMessage,m=J(l.default)||Y(l.default)?Y(l.default)?a?c:()=>c:l.default:n?a?c:()=>c:null,h=n||m!=null&&(J(m)||q(m)),g=ef(e,l);f&&lp(l);let[_,v,y]=p?[c,g,s[g]||G()]:up(e,c,g,o,d,u),b=_,x=c;if(!p&&!(J(b)||yd(b)||sp(b))&&h&&(b=m,x=b),!p&&(!(J(b)||yd(b)||sp(b))||!J(v)))return i?-1:c;let S=!1,C=sp(b)?b:dp(e,c,v,b,x,()=>{S=!0});if(S)return b;let w=fp(e,C,ap(hp(e,v,y,l))),T=r?r(w,c):w;if(f&&J(T)&&(T=Ou(T)),__INTLIFY_PROD_DEVTOOLS__){let t={timestamp:Date.now(),key:J(c)?c:sp(b)?b.key:``,locale:v||(sp(b)?b.locale:``),format:J(b)?b:sp(b)?b.source:``,message:T};t.meta=W({},e.__meta,jf()||{}),Xd(t)}return T}function lp(e){K(e.list)?e.list=e.list.map(e=>J(e)?Eu(e):e):X(e.named)&&Object.keys(e.named).forEach(t=>{J(e.named[t])&&(e.named[t]=Eu(e.named[t]))})}function up(e,t,n,r,i,a){let{messages:o,onWarn:s,messageResolver:c,localeFallbacker:l}=e,u=l(e,r,n),d=G(),f,p=null;for(let n=0;n<u.length&&(f=u[n],d=o[f]||G(),(p=c(d,t))===null&&(p=d[t]),!(J(p)||yd(p)||sp(p)));n++)if(!Vf(f,u)){let n=Rf(e,t,f,a,`translate`);n!==t&&(p=n)}return[p,f,d]}function dp(e,t,n,r,i,a){let{messageCompiler:o,warnHtmlMessage:s}=e;if(sp(r)){let e=r;return e.locale=e.locale||n,e.key=e.key||t,e}if(o==null){let e=(()=>r);return e.locale=n,e.key=t,e}let c=o(r,mp(e,n,i,r,s,a));return c.locale=n,c.key=t,c.source=r,c}function fp(e,t,n){return t(n)}function pp(...e){let[t,n,r]=e,i=G();if(!J(t)&&!U(t)&&!sp(t)&&!yd(t))throw $d(Qd.INVALID_ARGUMENT);let a=U(t)?String(t):(sp(t),t);return U(n)?i.plural=n:J(n)?i.default=n:Z(n)&&!Su(n)?i.named=n:K(n)&&(i.list=n),U(r)?i.plural=r:J(r)?i.default=r:Z(r)&&W(i,r),[a,i]}function mp(e,t,n,r,i,a){return{locale:t,key:n,warnHtmlMessage:i,onError:e=>{throw a&&a(e),e},onCacheKey:e=>vu(t,n,e)}}function hp(e,t,n,r){let{modifiers:i,pluralRules:a,messageResolver:o,fallbackLocale:s,fallbackWarn:c,missingWarn:l,fallbackContext:u}=e,d={locale:t,modifiers:i,pluralRules:a,messages:(r,i)=>{let a=o(n,r);if(a==null&&(u||i)){let[,,n]=up(u||e,r,t,s,c,l);a=o(n,r)}if(J(a)||yd(a)){let n=!1,i=dp(e,r,t,a,r,()=>{n=!0});return n?op:i}else if(sp(a))return a;else return op}};return e.processor&&(d.processor=e.processor),r.list&&(d.list=r.list),r.named&&(d.named=r.named),U(r.plural)&&(d.pluralIndex=r.plural),d}vd();var gp=`11.2.8`;function _p(){typeof __VUE_I18N_FULL_INSTALL__!=`boolean`&&(Tu().__VUE_I18N_FULL_INSTALL__=!0),typeof __VUE_I18N_LEGACY_API__!=`boolean`&&(Tu().__VUE_I18N_LEGACY_API__=!0),typeof __INTLIFY_DROP_MESSAGE_COMPILER__!=`boolean`&&(Tu().__INTLIFY_DROP_MESSAGE_COMPILER__=!1),typeof __INTLIFY_PROD_DEVTOOLS__!=`boolean`&&(Tu().__INTLIFY_PROD_DEVTOOLS__=!1)}var $={UNEXPECTED_RETURN_TYPE:24,INVALID_ARGUMENT:25,MUST_BE_CALL_SETUP_TOP:26,NOT_INSTALLED:27,REQUIRED_VALUE:28,INVALID_VALUE:29,CANNOT_SETUP_VUE_DEVTOOLS_PLUGIN:30,NOT_INSTALLED_WITH_PROVIDE:31,UNEXPECTED_ERROR:32,NOT_COMPATIBLE_LEGACY_VUE_I18N:33,NOT_AVAILABLE_COMPOSITION_IN_LEGACY:34};function vp(e,...t){return Bu(e,null,void 0)}$.UNEXPECTED_RETURN_TYPE,$.INVALID_ARGUMENT,$.MUST_BE_CALL_SETUP_TOP,$.NOT_INSTALLED,$.UNEXPECTED_ERROR,$.REQUIRED_VALUE,$.INVALID_VALUE,$.CANNOT_SETUP_VUE_DEVTOOLS_PLUGIN,$.NOT_INSTALLED_WITH_PROVIDE,$.NOT_COMPATIBLE_LEGACY_VUE_I18N,$.NOT_AVAILABLE_COMPOSITION_IN_LEGACY;var yp=_u(`__translateVNode`),bp=_u(`__datetimeParts`),xp=_u(`__numberParts`),Sp=_u(`__setPluralRules`);_u(`__intlifyMeta`);var Cp=_u(`__injectWithOption`),wp=_u(`__dispose`),Tp={FALLBACK_TO_ROOT:8,NOT_FOUND_PARENT_SCOPE:9,IGNORE_OBJ_FLATTEN:10,DEPRECATE_LEGACY_MODE:11,DEPRECATE_TRANSLATE_CUSTOME_DIRECTIVE:12,DUPLICATE_USE_I18N_CALLING:13};Tp.FALLBACK_TO_ROOT,Tp.NOT_FOUND_PARENT_SCOPE,Tp.IGNORE_OBJ_FLATTEN,Tp.DEPRECATE_LEGACY_MODE,Tp.DEPRECATE_TRANSLATE_CUSTOME_DIRECTIVE,Tp.DUPLICATE_USE_I18N_CALLING;function Ep(e){if(!X(e)||yd(e))return e;for(let t in e)if(Au(e,t))if(!t.includes(`.`))X(e[t])&&Ep(e[t]);else{let n=t.split(`.`),r=n.length-1,i=e,a=!1;for(let e=0;e<r;e++){if(n[e]===`__proto__`)throw Error(`unsafe key: ${n[e]}`);if(n[e]in i||(i[n[e]]=G()),!X(i[n[e]])){a=!0;break}i=i[n[e]]}if(a||(yd(i)?Ld.includes(n[r])||delete e[t]:(i[n[r]]=e[t],delete e[t])),!yd(i)){let e=i[n[r]];X(e)&&Ep(e)}}return e}function Dp(e,t){let{messages:n,__i18n:r,messageResolver:i,flatJson:a}=t,o=Z(n)?n:K(r)?G():{[e]:G()};if(K(r)&&r.forEach(e=>{if(`locale`in e&&`resource`in e){let{locale:t,resource:n}=e;t?(o[t]=o[t]||G(),Lu(n,o[t])):Lu(n,o)}else J(e)&&Lu(JSON.parse(e),o)}),i==null&&a)for(let e in o)Au(o,e)&&Ep(o[e]);return o}function Op(e){return e.type}function kp(e,t,n){let r=X(t.messages)?t.messages:G();`__i18nGlobal`in n&&(r=Dp(e.locale.value,{messages:r,__i18n:n.__i18nGlobal}));let i=Object.keys(r);if(i.length&&i.forEach(t=>{e.mergeLocaleMessage(t,r[t])}),X(t.datetimeFormats)){let n=Object.keys(t.datetimeFormats);n.length&&n.forEach(n=>{e.mergeDateTimeFormat(n,t.datetimeFormats[n])})}if(X(t.numberFormats)){let n=Object.keys(t.numberFormats);n.length&&n.forEach(n=>{e.mergeNumberFormat(n,t.numberFormats[n])})}}function Ap(e){return V(Zo,null,e,0)}function jp(){let e=`currentInstance`;return e in du?du[e]:Os()}var Mp=()=>[],Np=()=>!1,Pp=0;function Fp(e){return((t,n,r,i)=>e(n,r,jp()||void 0,i))}function Ip(e={}){let{__root:t,__injectWithOption:n}=e,r=t===void 0,i=e.flatJson,a=gu?on:sn,o=Y(e.inheritLocale)?e.inheritLocale:!0,s=a(t&&o?t.locale.value:J(e.locale)?e.locale:xf),c=a(t&&o?t.fallbackLocale.value:J(e.fallbackLocale)||K(e.fallbackLocale)||Z(e.fallbackLocale)||e.fallbackLocale===!1?e.fallbackLocale:s.value),l=a(Dp(s.value,e)),u=a(Z(e.datetimeFormats)?e.datetimeFormats:{[s.value]:{}}),d=a(Z(e.numberFormats)?e.numberFormats:{[s.value]:{}}),f=t?t.missingWarn:Y(e.missingWarn)||xu(e.missingWarn)?e.missingWarn:!0,p=t?t.fallbackWarn:Y(e.fallbackWarn)||xu(e.fallbackWarn)?e.fallbackWarn:!0,m=t?t.fallbackRoot:Y(e.fallbackRoot)?e.fallbackRoot:!0,h=!!e.fallbackFormat,g=q(e.missing)?e.missing:null,_=q(e.missing)?Fp(e.missing):null,v=q(e.postTranslation)?e.postTranslation:null,y=t?t.warnHtmlMessage:Y(e.warnHtmlMessage)?e.warnHtmlMessage:!0,b=!!e.escapeParameter,x=t?t.modifiers:Z(e.modifiers)?e.modifiers:{},S=e.pluralRules||t&&t.pluralRules,C;C=(()=>{r&&Nf(null);let t={version:gp,locale:s.value,fallbackLocale:c.value,messages:l.value,modifiers:x,pluralRules:S,missing:_===null?void 0:_,missingWarn:f,fallbackWarn:p,fallbackFormat:h,unresolving:!0,postTranslation:v===null?void 0:v,warnHtmlMessage:y,escapeParameter:b,messageResolver:e.messageResolver,messageCompiler:e.messageCompiler,__meta:{framework:`vue`}};t.datetimeFormats=u.value,t.numberFormats=d.value,t.__datetimeFormatters=Z(C)?C.__datetimeFormatters:void 0,t.__numberFormatters=Z(C)?C.__numberFormatters:void 0;let n=If(t);return r&&Nf(n),n})(),zf(C,s.value,c.value);function w(){return[s.value,c.value,l.value,u.value,d.value]}let T=Js({get:()=>s.value,set:e=>{C.locale=e,s.value=e}}),E=Js({get:()=>c.value,set:e=>{C.fallbackLocale=e,c.value=e,zf(C,s.value,e)}}),D=Js(()=>l.value),O=Js(()=>u.value),ee=Js(()=>d.value);function te(){return q(v)?v:null}function k(e){v=e,C.postTranslation=e}function ne(){return g}function A(e){e!==null&&(_=Fp(e)),g=e,C.missing=_}let re=(e,n,i,a,o,s)=>{w();let c;try{__INTLIFY_PROD_DEVTOOLS__,r||(C.fallbackContext=t?Pf():void 0),c=e(C)}finally{__INTLIFY_PROD_DEVTOOLS__,r||(C.fallbackContext=void 0)}if(i!==`translate exists`&&U(c)&&c===-1||i===`translate exists`&&!c){let[e,r]=n();return t&&m?a(t):o(e)}else if(s(c))return c;else throw vp($.UNEXPECTED_RETURN_TYPE)};function j(...e){return re(t=>Reflect.apply(cp,null,[t,...e]),()=>pp(...e),`translate`,t=>Reflect.apply(t.t,t,[...e]),e=>e,e=>J(e))}function M(...e){let[t,n,r]=e;if(r&&!X(r))throw vp($.INVALID_ARGUMENT);return j(t,n,W({resolvedMessage:!0},r||{}))}function ie(...e){return re(t=>Reflect.apply(Uf,null,[t,...e]),()=>Gf(...e),`datetime format`,t=>Reflect.apply(t.d,t,[...e]),()=>``,e=>J(e)||K(e))}function ae(...e){return re(t=>Reflect.apply(qf,null,[t,...e]),()=>Yf(...e),`number format`,t=>Reflect.apply(t.n,t,[...e]),()=>``,e=>J(e)||K(e))}function oe(e){return e.map(e=>J(e)||U(e)||Y(e)?Ap(String(e)):e)}let se={normalize:oe,interpolate:e=>e,type:`vnode`};function ce(...e){return re(t=>{let n,r=t;try{r.processor=se,n=Reflect.apply(cp,null,[r,...e])}finally{r.processor=null}return n},()=>pp(...e),`translate`,t=>t[yp](...e),e=>[Ap(e)],e=>K(e))}function le(...e){return re(t=>Reflect.apply(qf,null,[t,...e]),()=>Yf(...e),`number format`,t=>t[xp](...e),Mp,e=>J(e)||K(e))}function ue(...e){return re(t=>Reflect.apply(Uf,null,[t,...e]),()=>Gf(...e),`datetime format`,t=>t[bp](...e),Mp,e=>J(e)||K(e))}function de(e){S=e,C.pluralRules=S}function fe(e,t){return re(()=>{if(!e)return!1;let n=he(J(t)?t:s.value),r=C.messageResolver(n,e);return yd(r)||sp(r)||J(r)},()=>[e],`translate exists`,n=>Reflect.apply(n.te,n,[e,t]),Np,e=>Y(e))}function pe(e){let t=null,n=af(C,c.value,s.value);for(let r=0;r<n.length;r++){let i=l.value[n[r]]||{},a=C.messageResolver(i,e);if(a!=null){t=a;break}}return t}function me(e){return pe(e)??(t&&t.tm(e)||{})}function he(e){return l.value[e]||{}}function ge(e,t){if(i){let n={[e]:t};for(let e in n)Au(n,e)&&Ep(n[e]);t=n[e]}l.value[e]=t,C.messages=l.value}function _e(e,t){l.value[e]=l.value[e]||{};let n={[e]:t};if(i)for(let e in n)Au(n,e)&&Ep(n[e]);t=n[e],Lu(t,l.value[e]),C.messages=l.value}function ve(e){return u.value[e]||{}}function ye(e,t){u.value[e]=t,C.datetimeFormats=u.value,Kf(C,e,t)}function be(e,t){u.value[e]=W(u.value[e]||{},t),C.datetimeFormats=u.value,Kf(C,e,t)}function xe(e){return d.value[e]||{}}function Se(e,t){d.value[e]=t,C.numberFormats=d.value,Xf(C,e,t)}function Ce(e,t){d.value[e]=W(d.value[e]||{},t),C.numberFormats=d.value,Xf(C,e,t)}Pp++,t&&gu&&(wr(t.locale,e=>{o&&(s.value=e,C.locale=e,zf(C,s.value,c.value))}),wr(t.fallbackLocale,e=>{o&&(c.value=e,C.fallbackLocale=e,zf(C,s.value,c.value))}));let N={id:Pp,locale:T,fallbackLocale:E,get inheritLocale(){return o},set inheritLocale(e){o=e,e&&t&&(s.value=t.locale.value,c.value=t.fallbackLocale.value,zf(C,s.value,c.value))},get availableLocales(){return Object.keys(l.value).sort()},messages:D,get modifiers(){return x},get pluralRules(){return S||{}},get isGlobal(){return r},get missingWarn(){return f},set missingWarn(e){f=e,C.missingWarn=f},get fallbackWarn(){return p},set fallbackWarn(e){p=e,C.fallbackWarn=p},get fallbackRoot(){return m},set fallbackRoot(e){m=e},get fallbackFormat(){return h},set fallbackFormat(e){h=e,C.fallbackFormat=h},get warnHtmlMessage(){return y},set warnHtmlMessage(e){y=e,C.warnHtmlMessage=e},get escapeParameter(){return b},set escapeParameter(e){b=e,C.escapeParameter=e},t:j,getLocaleMessage:he,setLocaleMessage:ge,mergeLocaleMessage:_e,getPostTranslationHandler:te,setPostTranslationHandler:k,getMissingHandler:ne,setMissingHandler:A,[Sp]:de};return N.datetimeFormats=O,N.numberFormats=ee,N.rt=M,N.te=fe,N.tm=me,N.d=ie,N.n=ae,N.getDateTimeFormat=ve,N.setDateTimeFormat=ye,N.mergeDateTimeFormat=be,N.getNumberFormat=xe,N.setNumberFormat=Se,N.mergeNumberFormat=Ce,N[Cp]=n,N[yp]=ce,N[bp]=ue,N[xp]=le,N}function Lp(e){let t=J(e.locale)?e.locale:xf,n=J(e.fallbackLocale)||K(e.fallbackLocale)||Z(e.fallbackLocale)||e.fallbackLocale===!1?e.fallbackLocale:t,r=q(e.missing)?e.missing:void 0,i=Y(e.silentTranslationWarn)||xu(e.silentTranslationWarn)?!e.silentTranslationWarn:!0,a=Y(e.silentFallbackWarn)||xu(e.silentFallbackWarn)?!e.silentFallbackWarn:!0,o=Y(e.fallbackRoot)?e.fallbackRoot:!0,s=!!e.formatFallbackMessages,c=Z(e.modifiers)?e.modifiers:{},l=e.pluralizationRules,u=q(e.postTranslation)?e.postTranslation:void 0,d=J(e.warnHtmlInMessage)?e.warnHtmlInMessage!==`off`:!0,f=!!e.escapeParameterHtml,p=Y(e.sync)?e.sync:!0,m=e.messages;if(Z(e.sharedMessages)){let t=e.sharedMessages;m=Object.keys(t).reduce((e,n)=>(W(e[n]||(e[n]={}),t[n]),e),m||{})}let{__i18n:h,__root:g,__injectWithOption:_}=e,v=e.datetimeFormats,y=e.numberFormats,b=e.flatJson;return{locale:t,fallbackLocale:n,messages:m,flatJson:b,datetimeFormats:v,numberFormats:y,missing:r,missingWarn:i,fallbackWarn:a,fallbackRoot:o,fallbackFormat:s,modifiers:c,pluralRules:l,postTranslation:u,warnHtmlMessage:d,escapeParameter:f,messageResolver:e.messageResolver,inheritLocale:p,__i18n:h,__root:g,__injectWithOption:_}}function Rp(e={}){let t=Ip(Lp(e)),{__extender:n}=e,r={id:t.id,get locale(){return t.locale.value},set locale(e){t.locale.value=e},get fallbackLocale(){return t.fallbackLocale.value},set fallbackLocale(e){t.fallbackLocale.value=e},get messages(){return t.messages.value},get datetimeFormats(){return t.datetimeFormats.value},get numberFormats(){return t.numberFormats.value},get availableLocales(){return t.availableLocales},get missing(){return t.getMissingHandler()},set missing(e){t.setMissingHandler(e)},get silentTranslationWarn(){return Y(t.missingWarn)?!t.missingWarn:t.missingWarn},set silentTranslationWarn(e){t.missingWarn=Y(e)?!e:e},get silentFallbackWarn(){return Y(t.fallbackWarn)?!t.fallbackWarn:t.fallbackWarn},set silentFallbackWarn(e){t.fallbackWarn=Y(e)?!e:e},get modifiers(){return t.modifiers},get formatFallbackMessages(){return t.fallbackFormat},set formatFallbackMessages(e){t.fallbackFormat=e},get postTranslation(){return t.getPostTranslationHandler()},set postTranslation(e){t.setPostTranslationHandler(e)},get sync(){return t.inheritLocale},set sync(e){t.inheritLocale=e},get warnHtmlInMessage(){return t.warnHtmlMessage?`warn`:`off`},set warnHtmlInMessage(e){t.warnHtmlMessage=e!==`off`},get escapeParameterHtml(){return t.escapeParameter},set escapeParameterHtml(e){t.escapeParameter=e},get pluralizationRules(){return t.pluralRules||{}},__composer:t,t(...e){return Reflect.apply(t.t,t,[...e])},rt(...e){return Reflect.apply(t.rt,t,[...e])},te(e,n){return t.te(e,n)},tm(e){return t.tm(e)},getLocaleMessage(e){return t.getLocaleMessage(e)},setLocaleMessage(e,n){t.setLocaleMessage(e,n)},mergeLocaleMessage(e,n){t.mergeLocaleMessage(e,n)},d(...e){return Reflect.apply(t.d,t,[...e])},getDateTimeFormat(e){return t.getDateTimeFormat(e)},setDateTimeFormat(e,n){t.setDateTimeFormat(e,n)},mergeDateTimeFormat(e,n){t.mergeDateTimeFormat(e,n)},n(...e){return Reflect.apply(t.n,t,[...e])},getNumberFormat(e){return t.getNumberFormat(e)},setNumberFormat(e,n){t.setNumberFormat(e,n)},mergeNumberFormat(e,n){t.mergeNumberFormat(e,n)}};return r.__extender=n,r}function zp(e,t,n){return{beforeCreate(){let r=jp();if(!r)throw vp($.UNEXPECTED_ERROR);let i=this.$options;if(i.i18n){let r=i.i18n;if(i.__i18n&&(r.__i18n=i.__i18n),r.__root=t,this===this.$root)this.$i18n=Bp(e,r);else{r.__injectWithOption=!0,r.__extender=n.__vueI18nExtend,this.$i18n=Rp(r);let e=this.$i18n;e.__extender&&(e.__disposer=e.__extender(this.$i18n))}}else if(i.__i18n)if(this===this.$root)this.$i18n=Bp(e,i);else{this.$i18n=Rp({__i18n:i.__i18n,__injectWithOption:!0,__extender:n.__vueI18nExtend,__root:t});let e=this.$i18n;e.__extender&&(e.__disposer=e.__extender(this.$i18n))}else this.$i18n=e;i.__i18nGlobal&&kp(t,i,i),this.$t=(...e)=>this.$i18n.t(...e),this.$rt=(...e)=>this.$i18n.rt(...e),this.$te=(e,t)=>this.$i18n.te(e,t),this.$d=(...e)=>this.$i18n.d(...e),this.$n=(...e)=>this.$i18n.n(...e),this.$tm=e=>this.$i18n.tm(e),n.__setInstance(r,this.$i18n)},mounted(){},unmounted(){let e=jp();if(!e)throw vp($.UNEXPECTED_ERROR);let t=this.$i18n;delete this.$t,delete this.$rt,delete this.$te,delete this.$d,delete this.$n,delete this.$tm,t.__disposer&&(t.__disposer(),delete t.__disposer,delete t.__extender),n.__deleteInstance(e),delete this.$i18n}}}function Bp(e,t){e.locale=t.locale||e.locale,e.fallbackLocale=t.fallbackLocale||e.fallbackLocale,e.missing=t.missing||e.missing,e.silentTranslationWarn=t.silentTranslationWarn||e.silentFallbackWarn,e.silentFallbackWarn=t.silentFallbackWarn||e.silentFallbackWarn,e.formatFallbackMessages=t.formatFallbackMessages||e.formatFallbackMessages,e.postTranslation=t.postTranslation||e.postTranslation,e.warnHtmlInMessage=t.warnHtmlInMessage||e.warnHtmlInMessage,e.escapeParameterHtml=t.escapeParameterHtml||e.escapeParameterHtml,e.sync=t.sync||e.sync,e.__composer[Sp](t.pluralizationRules||e.pluralizationRules);let n=Dp(e.locale,{messages:t.messages,__i18n:t.__i18n});return Object.keys(n).forEach(t=>e.mergeLocaleMessage(t,n[t])),t.datetimeFormats&&Object.keys(t.datetimeFormats).forEach(n=>e.mergeDateTimeFormat(n,t.datetimeFormats[n])),t.numberFormats&&Object.keys(t.numberFormats).forEach(n=>e.mergeNumberFormat(n,t.numberFormats[n])),e}var Vp={tag:{type:[String,Object]},locale:{type:String},scope:{type:String,validator:e=>e===`parent`||e===`global`,default:`parent`},i18n:{type:Object}};function Hp({slots:e},t){return t.length===1&&t[0]===`default`?(e.default?e.default():[]).reduce((e,t)=>[...e,...t.type===z?t.children:[t]],[]):t.reduce((t,n)=>{let r=e[n];return r&&(t[n]=r()),t},G())}function Up(){return z}var Wp=ni({name:`i18n-t`,props:W({keypath:{type:String,required:!0},plural:{type:[Number,String],validator:e=>U(e)||!isNaN(e)}},Vp),setup(e,t){let{slots:n,attrs:r}=t,i=e.i18n||tm({useScope:e.scope,__useComponent:!0});return()=>{let a=Object.keys(n).filter(e=>e[0]!==`_`),o=G();e.locale&&(o.locale=e.locale),e.plural!==void 0&&(o.plural=J(e.plural)?+e.plural:e.plural);let s=Hp(t,a),c=i[yp](e.keypath,s,o),l=W(G(),r);return Ys(J(e.tag)||X(e.tag)?e.tag:Up(),l,c)}}});function Gp(e){return K(e)&&!J(e[0])}function Kp(e,t,n,r){let{slots:i,attrs:a}=t;return()=>{let t={part:!0},o=G();e.locale&&(t.locale=e.locale),J(e.format)?t.key=e.format:X(e.format)&&(J(e.format.key)&&(t.key=e.format.key),o=Object.keys(e.format).reduce((t,r)=>n.includes(r)?W(G(),t,{[r]:e.format[r]}):t,G()));let s=r(e.value,t,o),c=[t.key];K(s)?c=s.map((e,t)=>{let n=i[e.type],r=n?n({[e.type]:e.value,index:t,parts:s}):[e.value];return Gp(r)&&(r[0].key=`${e.type}-${t}`),r}):J(s)&&(c=[s]);let l=W(G(),a);return Ys(J(e.tag)||X(e.tag)?e.tag:Up(),l,c)}}var qp=ni({name:`i18n-n`,props:W({value:{type:Number,required:!0},format:{type:[String,Object]}},Vp),setup(e,t){let n=e.i18n||tm({useScope:e.scope,__useComponent:!0});return Kp(e,t,Jf,(...e)=>n[xp](...e))}});function Jp(e,t){let n=e;if(e.mode===`composition`)return n.__getInstance(t)||e.global;{let r=n.__getInstance(t);return r==null?e.global.__composer:r.__composer}}function Yp(e){let t=t=>{let{instance:n,value:r}=t;if(!n||!n.$)throw vp($.UNEXPECTED_ERROR);let i=Jp(e,n.$),a=Xp(r);return[Reflect.apply(i.t,i,[...Zp(a)]),i]};return{created:(n,r)=>{let[i,a]=t(r);gu&&e.global===a&&(n.__i18nWatcher=wr(a.locale,()=>{r.instance&&r.instance.$forceUpdate()})),n.__composer=a,n.textContent=i},unmounted:e=>{gu&&e.__i18nWatcher&&(e.__i18nWatcher(),e.__i18nWatcher=void 0,delete e.__i18nWatcher),e.__composer&&(e.__composer=void 0,delete e.__composer)},beforeUpdate:(e,{value:t})=>{if(e.__composer){let n=e.__composer,r=Xp(t);e.textContent=Reflect.apply(n.t,n,[...Zp(r)])}},getSSRProps:e=>{let[n]=t(e);return{textContent:n}}}}function Xp(e){if(J(e))return{path:e};if(Z(e)){if(!(`path`in e))throw vp($.REQUIRED_VALUE,`path`);return e}else throw vp($.INVALID_VALUE)}function Zp(e){let{path:t,locale:n,args:r,choice:i,plural:a}=e,o={},s=r||{};return J(n)&&(o.locale=n),U(i)&&(o.plural=i),U(a)&&(o.plural=a),[t,s,o]}function Qp(e,t,...n){let r=Z(n[0])?n[0]:{};(!Y(r.globalInstall)||r.globalInstall)&&([Wp.name,`I18nT`].forEach(t=>e.component(t,Wp)),[qp.name,`I18nN`].forEach(t=>e.component(t,qp)),[fm.name,`I18nD`].forEach(t=>e.component(t,fm))),e.directive(`t`,Yp(t))}var $p=_u(`global-vue-i18n`);function em(e={}){let t=__VUE_I18N_LEGACY_API__&&Y(e.legacy)?e.legacy:__VUE_I18N_LEGACY_API__,n=Y(e.globalInjection)?e.globalInjection:!0,r=new Map,[i,a]=nm(e,t),o=_u(``);function s(e){return r.get(e)||null}function c(e,t){r.set(e,t)}function l(e){r.delete(e)}let u={get mode(){return __VUE_I18N_LEGACY_API__&&t?`legacy`:`composition`},async install(e,...r){if(e.__VUE_I18N_SYMBOL__=o,e.provide(e.__VUE_I18N_SYMBOL__,u),Z(r[0])){let e=r[0];u.__composerExtend=e.__composerExtend,u.__vueI18nExtend=e.__vueI18nExtend}let i=null;!t&&n&&(i=dm(e,u.global)),__VUE_I18N_FULL_INSTALL__&&Qp(e,u,...r),__VUE_I18N_LEGACY_API__&&t&&e.mixin(zp(a,a.__composer,u));let s=e.unmount;e.unmount=()=>{i&&i(),u.dispose(),s()}},get global(){return a},dispose(){i.stop()},__instances:r,__getInstance:s,__setInstance:c,__deleteInstance:l};return u}function tm(e={}){let t=jp();if(t==null)throw vp($.MUST_BE_CALL_SETUP_TOP);if(!t.isCE&&t.appContext.app!=null&&!t.appContext.app.__VUE_I18N_SYMBOL__)throw vp($.NOT_INSTALLED);let n=rm(t),r=am(n),i=Op(t),a=im(e,i);if(a===`global`)return kp(r,e,i),r;if(a===`parent`){let i=om(n,t,e.__useComponent);return i??=r,i}let o=n,s=o.__getInstance(t);if(s==null){let n=W({},e);`__i18n`in i&&(n.__i18n=i.__i18n),r&&(n.__root=r),s=Ip(n),o.__composerExtend&&(s[wp]=o.__composerExtend(s)),cm(o,t,s),o.__setInstance(t,s)}return s}function nm(e,t){let n=Ae(),r=__VUE_I18N_LEGACY_API__&&t?n.run(()=>Rp(e)):n.run(()=>Ip(e));if(r==null)throw vp($.UNEXPECTED_ERROR);return[n,r]}function rm(e){let t=_r(e.isCE?$p:e.appContext.app.__VUE_I18N_SYMBOL__);if(!t)throw vp(e.isCE?$.NOT_INSTALLED_WITH_PROVIDE:$.UNEXPECTED_ERROR);return t}function im(e,t){return Su(e)?`__i18n`in t?`local`:`global`:e.useScope?e.useScope:`local`}function am(e){return e.mode===`composition`?e.global:e.global.__composer}function om(e,t,n=!1){let r=null,i=t.root,a=sm(t,n);for(;a!=null;){let t=e;if(e.mode===`composition`)r=t.__getInstance(a);else if(__VUE_I18N_LEGACY_API__){let e=t.__getInstance(a);e!=null&&(r=e.__composer,n&&r&&!r[Cp]&&(r=null))}if(r!=null||i===a)break;a=a.parent}return r}function sm(e,t=!1){return e==null?null:t&&e.vnode.ctx||e.parent}function cm(e,t,n){Hi(()=>{},t),Ki(()=>{let r=n;e.__deleteInstance(t);let i=r[wp];i&&(i(),delete r[wp])},t)}var lm=[`locale`,`fallbackLocale`,`availableLocales`],um=[`t`,`rt`,`d`,`n`,`tm`,`te`];function dm(e,t){let n=Object.create(null);return lm.forEach(e=>{let r=Object.getOwnPropertyDescriptor(t,e);if(!r)throw vp($.UNEXPECTED_ERROR);let i=I(r.value)?{get(){return r.value.value},set(e){r.value.value=e}}:{get(){return r.get&&r.get()}};Object.defineProperty(n,e,i)}),e.config.globalProperties.$i18n=n,um.forEach(n=>{let r=Object.getOwnPropertyDescriptor(t,n);if(!r||!r.value)throw vp($.UNEXPECTED_ERROR);Object.defineProperty(e.config.globalProperties,`$${n}`,r)}),()=>{delete e.config.globalProperties.$i18n,um.forEach(t=>{delete e.config.globalProperties[`$${t}`]})}}var fm=ni({name:`i18n-d`,props:W({value:{type:[Number,Date],required:!0},format:{type:[String,Object]}},Vp),setup(e,t){let n=e.i18n||tm({useScope:e.scope,__useComponent:!0});return Kp(e,t,Wf,(...e)=>n[bp](...e))}});if(_p(),Tf(Kd),Df(vf),kf(af),__INTLIFY_PROD_DEVTOOLS__){let e=Tu();e.__INTLIFY__=!0,Jd(e.__INTLIFY_DEVTOOLS_GLOBAL_HOOK__)}function pm(){function e(){return document.documentElement.hasAttribute(`data-theme`)?document.documentElement.getAttribute(`data-theme`):window.matchMedia(`(prefers-color-scheme: dark)`).matches?`dark`:`light`}return{getTheme:e,matchTheme:t=>e().toLowerCase()===t}}var mm={class:`hero min-h-screen`,style:{"background-image":`url(/assets/background.webp)`}},hm={class:`hero-content text-neutral-content text-center`},gm={class:`max-w-xl`},_m={class:`card w-xl bg-base-100 card-xl shadow-sm`},vm={class:`card-body`},ym={class:`card-title mb-5 -translate-2`},bm={key:0,src:pu,alt:`Nova.CL`,class:`size-16 inline-block`},xm={key:1,src:mu,alt:`Nova.CL`,class:`size-16 inline-block`},Sm={class:`mb-5 text-5xl font-bold`},Cm={class:`mb-5 text-xl`},wm=ni({__name:`App`,setup(e){let{t,locale:n}=tm(),{matchTheme:r}=pm();return Hi(()=>{let e=[`zh-CN`,`en-US`],r=document.querySelector(`meta[name="lang"]`)?.getAttribute(`content`)||document.querySelector(`meta[http-equiv="content-language"]`)?.getAttribute(`content`)||`en-US`;n.value=e.includes(r)?r:e.includes(navigator.language)?navigator.language:e[0],document.title=`Nova.CL `+t(`LoginSuccessful.Title`)}),document.addEventListener(`DOMContentLoaded`,()=>{setTimeout(()=>{let e=new URL(window.location.href);e.search=``,window.history.replaceState({},``,e.toString()),fetch(`/close`,{method:`GET`})},1e3)}),(e,t)=>(ts(),os(`div`,mm,[t[1]||=ps(`div`,{class:`hero-overlay backdrop-blur-lg`},null,-1),ps(`div`,hm,[ps(`div`,gm,[ps(`div`,_m,[ps(`div`,vm,[ps(`h2`,ym,[dn(r)(`light`)?(ts(),os(`img`,bm)):ys(``,!0),dn(r)(`dark`)?(ts(),os(`img`,xm)):ys(``,!0),t[0]||=ps(`span`,{class:`text-2xl font-bold`},`Nova.CL`,-1)]),ps(`h1`,Sm,we(e.$t(`LoginSuccessful.Title`)),1),ps(`p`,Cm,we(e.$t(`LoginSuccessful.Description`)),1)])])])])]))}}),Tm={LoginSuccessful:{Title:`Login Successful`,Description:`You can safely close this tab now.`},NotFound:{Title:`Uncharted Territory`,Description:`Uh-oh, you weren’t meant to see this. Please close this tab~`}},Em={LoginSuccessful:{Title:`账户登录成功`,Description:`你现在可以安全关闭这个标签页了`},NotFound:{Title:`未知领域`,Description:`你本不应来到这里的，关掉这个标签页吧`}};const Dm=em({legacy:!1,locale:`zh-CN`,fallbackLocale:`en-US`,messages:{"en-US":Tm,"zh-CN":Em}});au(wm).use(Dm).mount(`#app`);
\ No newline at end of file
@@ -0,0 +1,177 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::auth::error::YggdrasilError;
+use crate::http::client::{HttpClient, HttpClientOptions};
+use crate::http::policy::UrlPolicy;
+use crate::toml::manager::ConfigManager;
+use crate::toml::utils::write_atomic;
+
+/// authlib-injector 最新版本信息
+pub const AUTHLIB_LATEST_URL: &str = "https://authlib-injector.yushi.moe/artifact/latest.json";
+
+/// BMCLAPI 镜像
+pub const AUTHLIB_MIRROR_LATEST_URL: &str =
+    "https://bmclapi2.bangbang93.com/mirrors/authlib-injector/artifact/latest.json";
+
+/// 允许访问的主机，官方源与 BMCLAPI 镜像
+pub const AUTHLIB_HOSTS: [&str; 2] = ["authlib-injector.yushi.moe", "bmclapi2.bangbang93.com"];
+
+const JAR_NAME: &str = "authlib-injector.jar";
+const INFO_NAME: &str = "authlib-injector.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuthlibChecksums {
+    #[serde(default)]
+    pub sha256: String,
+}
+
+/// authlib-injector 构建信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthlibArtifact {
+    pub build_number: u64,
+    pub version: String,
+    pub download_url: String,
+    #[serde(default)]
+    pub checksums: AuthlibChecksums,
+}
+
+/// 本地 authlib-injector 状态
+#[derive(Debug, Clone, Serialize)]
+pub struct AuthlibInjector {
+    pub version: String,
+    pub build_number: u64,
+    pub path: PathBuf,
+    /// 本次是否下载了新版本
+    pub updated: bool,
+}
+
+/// authlib-injector 下载与更新管理
+pub struct AuthlibManager {
+    http: HttpClient,
+    dir: PathBuf,
+    latest_url: String,
+}
+
+impl AuthlibManager {
+    pub fn new(options: HttpClientOptions, dir: PathBuf, latest_url: impl Into<String>) -> Self {
+        AuthlibManager {
+            http: HttpClient::with_options(options),
+            dir,
+            latest_url: latest_url.into(),
+        }
+    }
+
+    /// 只允许访问官方源与镜像，不继承全局的允许列表
+    pub fn url_policy() -> UrlPolicy {
+        UrlPolicy::new(AUTHLIB_HOSTS.iter().map(|h| h.to_string()).collect())
+    }
+
+    /// 根据全局配置创建，下载源为 mirror 时使用 BMCLAPI
+    pub fn from_config() -> Self {
+        let manager = ConfigManager::instance();
+        let latest_url = match manager.load_global_config() {
+            Ok(config) if config.other.download.download_source == "mirror" => {
+                AUTHLIB_MIRROR_LATEST_URL
+            }
+            _ => AUTHLIB_LATEST_URL,
+        };
+        let options = HttpClientOptions {
+            use_cache: false,
+            use_cookie_jar: false,
+            url_policy: Self::url_policy(),
+            ..HttpClientOptions::from_config()
+        };
+        Self::new(
+            options,
+            manager.app_data_dir().join("authlib-injector"),
+            latest_url,
+        )
+    }
+
+    /// jar 文件路径
+    pub fn jar_path(&self) -> PathBuf {
+        self.dir.join(JAR_NAME)
+    }
+
+    /// 已安装的版本
+    pub fn installed(&self) -> Option<AuthlibArtifact> {
+        if !self.jar_path().is_file() {
+            return None;
+        }
+        let content = fs::read_to_string(self.dir.join(INFO_NAME)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// 确保本地存在 authlib-injector
+    ///
+    /// - `check_update`: 是否检查并下载新版本；检查失败时继续使用已安装版本
+    pub async fn ensure(&self, check_update: bool) -> Result<AuthlibInjector, YggdrasilError> {
+        let installed = self.installed();
+        if let (Some(installed), false) = (&installed, check_update) {
+            return Ok(self.describe(installed, false));
+        }
+
+        let latest = match self.fetch_latest().await {
+            Ok(latest) => latest,
+            Err(e) => match installed {
+                Some(installed) => {
+                    log::warn!(target: "auth", "Failed to check authlib-injector update: {}", e);
+                    return Ok(self.describe(&installed, false));
+                }
+                None => return Err(e),
+            },
+        };
+
+        if let Some(installed) = installed.filter(|i| i.build_number >= latest.build_number) {
+            return Ok(self.describe(&installed, false));
+        }
+
+        log::info!(target: "auth", "Downloading authlib-injector {}", latest.version);
+        let bytes = self
+            .http
+            .get_bytes(&latest.download_url)
+            .await
+            .map_err(YggdrasilError::Http)?;
+        if !latest.checksums.sha256.is_empty()
+            && !hex::encode(Sha256::digest(&bytes)).eq_ignore_ascii_case(&latest.checksums.sha256)
+        {
+            return Err(YggdrasilError::ChecksumMismatch);
+        }
+
+        let save = || -> Result<(), crate::toml::error::ConfigError> {
+            fs::create_dir_all(&self.dir)?;
+            write_atomic(&self.jar_path(), &bytes)?;
+            let info = serde_json::to_string_pretty(&latest).unwrap_or_default();
+            write_atomic(&self.dir.join(INFO_NAME), info)
+        };
+        save().map_err(|e| YggdrasilError::Config(e.to_string()))?;
+
+        Ok(self.describe(&latest, true))
+    }
+
+    async fn fetch_latest(&self) -> Result<AuthlibArtifact, YggdrasilError> {
+        let response = self
+            .http
+            .get(&self.latest_url, None)
+            .await
+            .and_then(|r| r.error_for_status())
+            .map_err(YggdrasilError::Http)?;
+        let body = response
+            .body
+            .ok_or_else(|| YggdrasilError::InvalidResponse("empty body".into()))?;
+        serde_json::from_value(body).map_err(|e| YggdrasilError::InvalidResponse(e.to_string()))
+    }
+
+    fn describe(&self, artifact: &AuthlibArtifact, updated: bool) -> AuthlibInjector {
+        AuthlibInjector {
+            version: artifact.version.clone(),
+            build_number: artifact.build_number,
+            path: self.jar_path(),
+            updated,
+        }
+    }
+}
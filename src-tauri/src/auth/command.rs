@@ -0,0 +1,229 @@
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use tokio::task::JoinHandle;
+
+use crate::auth::authlib::{AuthlibInjector, AuthlibManager};
+use crate::auth::error::{MsaError, YggdrasilError};
+use crate::auth::msa::{save_login, DeviceCode, MsaClient, MsaStep, TOTAL_STEPS};
+use crate::auth::offline::{create_offline_profile, OfflineSkin};
+use crate::auth::yggdrasil::{
+    save_session, GameProfile, LoginOutcome, PendingLogin, YggdrasilClient, YggdrasilServer,
+};
+use crate::http::oauth::take_grant;
+use crate::toml::error::ProfileConfigError;
+use crate::toml::manager::ConfigManager;
+use crate::toml::types::Profile;
+
+lazy_static::lazy_static! {
+    /// 正在轮询的设备代码登录
+    static ref DEVICE_FLOW: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+    /// 等待选择角色的皮肤站登录
+    static ref PENDING_YGGDRASIL: Mutex<Option<PendingLogin>> = Mutex::new(None);
+}
+
+#[derive(Serialize, Clone)]
+struct MsaStepPayload {
+    step: MsaStep,
+    /// 步骤序号（1-6）
+    index: u8,
+    total: u8,
+}
+
+fn emit_step(app_handle: &AppHandle, step: MsaStep) {
+    log::info!(target: "auth", "MSA login step {}/{}: {:?}", step.index(), TOTAL_STEPS, step);
+    let _ = app_handle.emit(
+        "msa:step",
+        MsaStepPayload {
+            step,
+            index: step.index(),
+            total: TOTAL_STEPS,
+        },
+    );
+}
+
+/// 使用回调收到的授权码完成微软登录，并保存为当前账户
+///
+/// 进度通过 `msa:step` 事件通知前端
+#[tauri::command]
+pub async fn msa_login_from_code(app_handle: AppHandle, code: String) -> Result<Profile, MsaError> {
+    let grant = take_grant(&code).ok_or(MsaError::InvalidGrant)?;
+    let client = MsaClient::from_config()?;
+
+    let result = client
+        .login_with_code(&grant, |step| emit_step(&app_handle, step))
+        .await
+        .inspect_err(|e| log::error!(target: "auth", "MSA login failed: {}", e))?;
+
+    save_login(&result)
+}
+
+/// 开始设备代码登录，返回用户代码与验证地址
+///
+/// 后台轮询授权结果，成功后保存账户并 emit `msa:device_code_success`，
+/// 失败时 emit `msa:device_code_error`
+#[tauri::command]
+pub async fn msa_device_code_start(app_handle: AppHandle) -> Result<DeviceCode, MsaError> {
+    let client = MsaClient::from_config()?;
+    let device = client.request_device_code().await?;
+    let info = device.clone();
+
+    let handle = tokio::spawn(async move {
+        let result = client
+            .login_with_device_code(&device, tokio::time::sleep, |step| {
+                emit_step(&app_handle, step)
+            })
+            .await
+            .and_then(|result| save_login(&result));
+
+        match result {
+            Ok(profile) => {
+                let _ = app_handle.emit("msa:device_code_success", profile);
+            }
+            Err(e) => {
+                log::error!(target: "auth", "Device code login failed: {}", e);
+                let payload = serde_json::to_value(&e).unwrap_or_default();
+                let _ = app_handle.emit("msa:device_code_error", payload);
+            }
+        }
+    });
+
+    // 同一时间只保留一个设备代码登录
+    if let Some(previous) = DEVICE_FLOW.lock().unwrap().replace(handle) {
+        previous.abort();
+    }
+
+    Ok(info)
+}
+
+/// 取消正在进行的设备代码登录
+#[tauri::command]
+pub fn msa_device_code_cancel() {
+    if let Some(handle) = DEVICE_FLOW.lock().unwrap().take() {
+        handle.abort();
+    }
+}
+
+/// 皮肤站登录结果
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum YggdrasilLoginResult {
+    /// 已保存为当前账户
+    Saved { profile: Box<Profile> },
+    /// 账户有多个角色，需要调用 yggdrasil_select_profile
+    SelectProfile { profiles: Vec<GameProfile> },
+}
+
+/// 解析皮肤站地址并获取元数据
+#[tauri::command]
+pub async fn yggdrasil_discover(url: String) -> Result<YggdrasilServer, YggdrasilError> {
+    YggdrasilClient::from_config().discover(&url).await
+}
+
+/// 登录皮肤站
+#[tauri::command]
+pub async fn yggdrasil_login(
+    url: String,
+    username: String,
+    password: String,
+) -> Result<YggdrasilLoginResult, YggdrasilError> {
+    let outcome = YggdrasilClient::from_config()
+        .login(&url, &username, &password)
+        .await
+        .inspect_err(|e| log::error!(target: "auth", "Yggdrasil login failed: {}", e))?;
+
+    match outcome {
+        LoginOutcome::Ready(session) => Ok(YggdrasilLoginResult::Saved {
+            profile: Box::new(save_session(&session)?),
+        }),
+        LoginOutcome::SelectProfile(pending) => {
+            let profiles = pending.profiles.clone();
+            *PENDING_YGGDRASIL.lock().unwrap() = Some(pending);
+            Ok(YggdrasilLoginResult::SelectProfile { profiles })
+        }
+    }
+}
+
+/// 为多角色账户选择角色并保存
+#[tauri::command]
+pub async fn yggdrasil_select_profile(profile_id: String) -> Result<Profile, YggdrasilError> {
+    let pending = PENDING_YGGDRASIL
+        .lock()
+        .unwrap()
+        .take()
+        .ok_or(YggdrasilError::NoPendingLogin)?;
+    let session = YggdrasilClient::from_config()
+        .select_profile(pending, &profile_id)
+        .await?;
+    save_session(&session)
+}
+
+/// 确保皮肤站账户的令牌有效，必要时刷新
+#[tauri::command]
+pub async fn yggdrasil_refresh(guid: String) -> Result<Profile, YggdrasilError> {
+    YggdrasilClient::from_config().refresh_saved(&guid).await
+}
+
+/// 吊销皮肤站账户的令牌，不删除账户
+#[tauri::command]
+pub async fn yggdrasil_logout(guid: String) -> Result<(), YggdrasilError> {
+    let profile = ConfigManager::instance()
+        .load_profile_config()
+        .map_err(|e| YggdrasilError::Config(e.to_string()))?
+        .profile
+        .into_iter()
+        .find(|p| p.guid == guid && p.r#type == "yggdrasil")
+        .ok_or(YggdrasilError::UnknownProfile(guid))?;
+    let api_root = profile.yggdrasil_site.unwrap_or_default();
+    YggdrasilClient::from_config()
+        .invalidate(&api_root, &profile.access_token, &profile.refresh_token)
+        .await
+}
+
+/// 使用账户密码吊销该账户在皮肤站的所有令牌
+#[tauri::command]
+pub async fn yggdrasil_signout(
+    url: String,
+    username: String,
+    password: String,
+) -> Result<(), YggdrasilError> {
+    let client = YggdrasilClient::from_config();
+    let api_root = client.resolve_api_root(&url).await?;
+    client.signout(&api_root, &username, &password).await
+}
+
+/// 确保 authlib-injector 已下载
+///
+/// 未指定 check_update 时按全局配置 update_authlib 决定是否检查更新
+#[tauri::command]
+pub async fn authlib_injector_ensure(
+    check_update: Option<bool>,
+) -> Result<AuthlibInjector, YggdrasilError> {
+    let check_update = check_update.unwrap_or_else(|| {
+        ConfigManager::instance()
+            .load_global_config()
+            .map(|c| c.other.download.update_authlib)
+            .unwrap_or(true)
+    });
+    AuthlibManager::from_config().ensure(check_update).await
+}
+
+/// 创建离线账户并设为当前账户
+///
+/// - `uuid`: 自定义 UUID，未指定时使用标准离线 UUID
+/// - `skin_path`: 本地皮肤 PNG，供离线皮肤加载器使用
+#[tauri::command]
+pub fn offline_profile_create(
+    name: String,
+    uuid: Option<String>,
+    skin_path: Option<String>,
+    slim: Option<bool>,
+) -> Result<Profile, ProfileConfigError> {
+    let skin = skin_path.map(|path| OfflineSkin {
+        path: path.into(),
+        slim: slim.unwrap_or(false),
+    });
+    create_offline_profile(&name, uuid.as_deref(), skin.as_ref())
+        .inspect_err(|e| log::warn!(target: "auth", "Failed to create offline profile: {}", e))
+}
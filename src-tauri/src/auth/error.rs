@@ -0,0 +1,257 @@
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+use crate::http::types::HttpError;
+
+/// XSTS 授权返回的 XErr 错误
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum XstsError {
+    /// 2148916227
+    #[error("The account is banned from Xbox Live")]
+    Banned,
+    /// 2148916229
+    #[error("The account needs guardian permission to use online play")]
+    GuardianPermissionRequired,
+    /// 2148916233
+    #[error("The account does not have an Xbox profile")]
+    NoXboxAccount,
+    /// 2148916234
+    #[error("The account has not accepted the Xbox terms of service")]
+    TermsNotAccepted,
+    /// 2148916235
+    #[error("Xbox Live is not available in the account's region")]
+    RegionUnavailable,
+    /// 2148916236 / 2148916237
+    #[error("The account needs adult verification")]
+    AdultVerificationRequired,
+    /// 2148916238
+    #[error("The account is a child account and must be added to a family")]
+    ChildAccount,
+    /// 其他错误码
+    #[error("Unknown XSTS error")]
+    Unknown,
+}
+
+impl XstsError {
+    pub fn from_xerr(xerr: u64) -> Self {
+        match xerr {
+            2148916227 => XstsError::Banned,
+            2148916229 => XstsError::GuardianPermissionRequired,
+            2148916233 => XstsError::NoXboxAccount,
+            2148916234 => XstsError::TermsNotAccepted,
+            2148916235 => XstsError::RegionUnavailable,
+            2148916236 | 2148916237 => XstsError::AdultVerificationRequired,
+            2148916238 => XstsError::ChildAccount,
+            _ => XstsError::Unknown,
+        }
+    }
+}
+
+/// 微软登录错误
+#[derive(Error, Debug)]
+pub enum MsaError {
+    /// 未完成授权或授权码无效
+    #[error("Unknown or already used authorization code")]
+    InvalidGrant,
+
+    /// OAuth 配置缺失
+    #[error("OAuth is not configured: {0}")]
+    NotConfigured(String),
+
+    /// 请求失败
+    #[error("Request failed at step {step}: {error}")]
+    Http { step: u8, error: HttpError },
+
+    /// 微软令牌端点返回错误
+    #[error("MSA token error: {error}: {description}")]
+    OAuth { error: String, description: String },
+
+    /// 用户拒绝了设备代码授权
+    #[error("The user declined the device code authorization")]
+    AuthorizationDeclined,
+
+    /// 设备代码已过期
+    #[error("The device code has expired")]
+    DeviceCodeExpired,
+
+    /// XSTS 授权被拒绝
+    #[error("XSTS authorization failed ({code}): {error}")]
+    Xsts { error: XstsError, code: u64 },
+
+    /// XBL 与 XSTS 的 uhs 不一致
+    #[error("UHS mismatch between XBL and XSTS")]
+    UhsMismatch,
+
+    /// 未购买 Minecraft: Java Edition
+    #[error("Minecraft Java Edition not purchased")]
+    NotOwned,
+
+    /// 账户尚未创建 Minecraft 档案
+    #[error("The account has no Minecraft profile")]
+    NoProfile,
+
+    /// 响应格式不符合预期
+    #[error("Unexpected response at step {step}: {message}")]
+    InvalidResponse { step: u8, message: String },
+
+    /// 保存账户失败
+    #[error("Failed to save profile: {0}")]
+    Config(String),
+}
+
+impl MsaError {
+    /// 错误类型，可作为前端本地化的稳定键
+    pub fn kind(&self) -> &'static str {
+        match self {
+            MsaError::InvalidGrant => "invalid_grant",
+            MsaError::NotConfigured(_) => "not_configured",
+            MsaError::Http { .. } => "http",
+            MsaError::OAuth { .. } => "oauth",
+            MsaError::AuthorizationDeclined => "authorization_declined",
+            MsaError::DeviceCodeExpired => "device_code_expired",
+            MsaError::Xsts { .. } => "xsts",
+            MsaError::UhsMismatch => "uhs_mismatch",
+            MsaError::NotOwned => "not_owned",
+            MsaError::NoProfile => "no_profile",
+            MsaError::InvalidResponse { .. } => "invalid_response",
+            MsaError::Config(_) => "config",
+        }
+    }
+
+    /// 出错的登录步骤（1-6），与步骤无关时为 0
+    pub fn step(&self) -> u8 {
+        match self {
+            MsaError::Http { step, .. } | MsaError::InvalidResponse { step, .. } => *step,
+            MsaError::InvalidGrant
+            | MsaError::OAuth { .. }
+            | MsaError::AuthorizationDeclined
+            | MsaError::DeviceCodeExpired => 1,
+            MsaError::Xsts { .. } | MsaError::UhsMismatch => 3,
+            MsaError::NotOwned => 5,
+            MsaError::NoProfile => 6,
+            MsaError::NotConfigured(_) | MsaError::Config(_) => 0,
+        }
+    }
+}
+
+/// 序列化为 { kind, step, message, code?, xsts? } 供前端展示
+impl Serialize for MsaError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Repr<'a> {
+            kind: &'static str,
+            step: u8,
+            message: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            code: Option<u64>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            xsts: Option<&'a XstsError>,
+        }
+
+        let (code, xsts) = match self {
+            MsaError::Http { error, .. } => (error.code.map(u64::from), None),
+            MsaError::Xsts { error, code } => (Some(*code), Some(error)),
+            _ => (None, None),
+        };
+
+        Repr {
+            kind: self.kind(),
+            step: self.step(),
+            message: self.to_string(),
+            code,
+            xsts,
+        }
+        .serialize(serializer)
+    }
+}
+
+/// Yggdrasil 皮肤站错误
+#[derive(Error, Debug)]
+pub enum YggdrasilError {
+    /// 皮肤站地址无效
+    #[error("Invalid Yggdrasil server URL: {0}")]
+    InvalidUrl(String),
+
+    /// 请求失败
+    #[error("Request failed: {0}")]
+    Http(HttpError),
+
+    /// 皮肤站返回错误，如 ForbiddenOperationException
+    #[error("{error}: {message}")]
+    Server {
+        status: u16,
+        error: String,
+        message: String,
+    },
+
+    /// 登录成功但没有可用角色
+    #[error("The account has no game profile")]
+    NoProfile,
+
+    /// 没有等待选择角色的登录
+    #[error("No pending login to select a profile for")]
+    NoPendingLogin,
+
+    /// 所选角色不属于该账户
+    #[error("Unknown game profile: {0}")]
+    UnknownProfile(String),
+
+    /// 响应格式不符合预期
+    #[error("Unexpected response: {0}")]
+    InvalidResponse(String),
+
+    /// authlib-injector 校验失败
+    #[error("authlib-injector checksum mismatch")]
+    ChecksumMismatch,
+
+    /// 读写账户配置或文件失败
+    #[error("Failed to save: {0}")]
+    Config(String),
+}
+
+impl YggdrasilError {
+    /// 错误类型，可作为前端本地化的稳定键
+    pub fn kind(&self) -> &'static str {
+        match self {
+            YggdrasilError::InvalidUrl(_) => "invalid_url",
+            YggdrasilError::Http(_) => "http",
+            YggdrasilError::Server { .. } => "server",
+            YggdrasilError::NoProfile => "no_profile",
+            YggdrasilError::NoPendingLogin => "no_pending_login",
+            YggdrasilError::UnknownProfile(_) => "unknown_profile",
+            YggdrasilError::InvalidResponse(_) => "invalid_response",
+            YggdrasilError::ChecksumMismatch => "checksum_mismatch",
+            YggdrasilError::Config(_) => "config",
+        }
+    }
+}
+
+/// 序列化为 { kind, message, code?, error? } 供前端展示
+impl Serialize for YggdrasilError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[derive(Serialize)]
+        struct Repr<'a> {
+            kind: &'static str,
+            message: String,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            code: Option<u16>,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            error: Option<&'a str>,
+        }
+
+        let (code, error) = match self {
+            YggdrasilError::Http(error) => (error.code, None),
+            YggdrasilError::Server { status, error, .. } => (Some(*status), Some(error.as_str())),
+            _ => (None, None),
+        };
+
+        Repr {
+            kind: self.kind(),
+            message: self.to_string(),
+            code,
+            error,
+        }
+        .serialize(serializer)
+    }
+}
@@ -0,0 +1,15 @@
+/// 账户认证模块
+///
+/// - 微软登录：Microsoft → Xbox Live → XSTS → Minecraft，支持回调与设备代码两种授权方式
+/// - 令牌刷新：后台定期刷新即将过期的 MSA 账户
+/// - 皮肤站登录：Yggdrasil 认证服务器与 authlib-injector
+/// - 离线账户：标准离线 UUID 与本地皮肤
+pub mod authlib;
+pub mod command;
+pub mod error;
+pub mod msa;
+pub mod offline;
+pub mod refresh;
+#[cfg(test)]
+mod test;
+pub mod yggdrasil;
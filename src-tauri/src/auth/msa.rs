@@ -0,0 +1,663 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::auth::error::{MsaError, XstsError};
+use crate::http::client::{HttpClient, HttpClientOptions};
+use crate::http::oauth::{
+    AuthorizationGrant, OAuthClientConfig, MS_DEVICE_CODE_URL, MS_SCOPE, MS_TOKEN_URL,
+};
+use crate::http::types::{HttpError, HttpResponse};
+use crate::toml::manager::ConfigManager;
+use crate::toml::types::{Profile, ProfileConfig};
+
+/// 登录步骤总数
+pub const TOTAL_STEPS: u8 = 6;
+
+/// 设备代码授权的 grant_type
+const DEVICE_CODE_GRANT: &str = "urn:ietf:params:oauth:grant-type:device_code";
+
+/// 收到 slow_down 后轮询间隔的增量（RFC 8628）
+const SLOW_DOWN_INCREMENT: Duration = Duration::from_secs(5);
+
+/// 登录步骤
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MsaStep {
+    MsaToken,
+    XboxLive,
+    Xsts,
+    MinecraftToken,
+    Entitlements,
+    Profile,
+}
+
+impl MsaStep {
+    /// 步骤序号（1-6）
+    pub fn index(self) -> u8 {
+        self as u8 + 1
+    }
+}
+
+/// 登录链路使用的端点，测试时可替换为本地地址
+#[derive(Debug, Clone)]
+pub struct MsaEndpoints {
+    pub token: String,
+    pub device_code: String,
+    pub xbl: String,
+    pub xsts: String,
+    pub mc_login: String,
+    pub entitlements: String,
+    pub profile: String,
+}
+
+impl Default for MsaEndpoints {
+    fn default() -> Self {
+        MsaEndpoints {
+            token: MS_TOKEN_URL.to_string(),
+            device_code: MS_DEVICE_CODE_URL.to_string(),
+            xbl: "https://user.auth.xboxlive.com/user/authenticate".to_string(),
+            xsts: "https://xsts.auth.xboxlive.com/xsts/authorize".to_string(),
+            mc_login: "https://api.minecraftservices.com/authentication/login_with_xbox"
+                .to_string(),
+            entitlements: "https://api.minecraftservices.com/entitlements/mcstore".to_string(),
+            profile: "https://api.minecraftservices.com/minecraft/profile".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct MsaTokenResponse {
+    access_token: String,
+    refresh_token: String,
+    expires_in: u64,
+}
+
+#[derive(Deserialize)]
+struct MsaTokenError {
+    error: String,
+    #[serde(default)]
+    error_description: String,
+}
+
+/// 设备代码授权信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceCode {
+    /// 仅用于轮询，不返回给前端
+    #[serde(skip_serializing)]
+    pub device_code: String,
+    /// 用户需要输入的代码
+    pub user_code: String,
+    /// 输入代码的地址
+    pub verification_uri: String,
+    /// 有效期（秒）
+    pub expires_in: u64,
+    /// 最短轮询间隔（秒）
+    #[serde(default = "default_poll_interval")]
+    pub interval: u64,
+    /// 提供方给出的提示文本
+    #[serde(default)]
+    pub message: String,
+}
+
+fn default_poll_interval() -> u64 {
+    5
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct XboxTokenResponse {
+    token: String,
+    display_claims: DisplayClaims,
+}
+
+#[derive(Deserialize)]
+struct DisplayClaims {
+    xui: Vec<XboxUserInfo>,
+}
+
+#[derive(Deserialize)]
+struct XboxUserInfo {
+    uhs: String,
+}
+
+impl XboxTokenResponse {
+    fn uhs(&self) -> Option<&str> {
+        self.display_claims.xui.first().map(|x| x.uhs.as_str())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct XstsErrorResponse {
+    x_err: u64,
+}
+
+#[derive(Deserialize)]
+struct McTokenResponse {
+    access_token: String,
+    expires_in: u64,
+}
+
+#[derive(Deserialize)]
+struct EntitlementsResponse {
+    #[serde(default)]
+    items: Vec<EntitlementItem>,
+}
+
+#[derive(Deserialize)]
+struct EntitlementItem {
+    name: String,
+}
+
+/// 皮肤
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McSkin {
+    pub id: String,
+    pub state: String,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+}
+
+/// 披风
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct McCape {
+    pub id: String,
+    pub state: String,
+    pub url: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct McProfileResponse {
+    id: String,
+    name: String,
+    #[serde(default)]
+    skins: Vec<McSkin>,
+    #[serde(default)]
+    capes: Vec<McCape>,
+}
+
+/// 登录结果
+#[derive(Debug, Clone)]
+pub struct MsaLoginResult {
+    pub msa_refresh_token: String,
+    /// MSA 令牌过期时间（Unix 毫秒）
+    pub msa_expires_at: u64,
+    pub mc_access_token: String,
+    /// MC 令牌过期时间（Unix 毫秒）
+    pub mc_expires_at: u64,
+    /// 带连字符的 UUID
+    pub uuid: String,
+    pub name: String,
+    pub skins: Vec<McSkin>,
+    pub capes: Vec<McCape>,
+}
+
+/// 当前 Unix 时间（毫秒），与前端 Date.now() 一致
+pub fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Microsoft → Xbox Live → XSTS → Minecraft 登录客户端
+pub struct MsaClient {
+    http: HttpClient,
+    oauth: OAuthClientConfig,
+    endpoints: MsaEndpoints,
+}
+
+impl MsaClient {
+    pub fn new(
+        oauth: OAuthClientConfig,
+        endpoints: MsaEndpoints,
+        options: HttpClientOptions,
+    ) -> Self {
+        MsaClient {
+            http: HttpClient::with_options(options),
+            oauth,
+            endpoints,
+        }
+    }
+
+    /// 根据环境变量与全局配置创建，令牌响应不写入缓存
+    pub fn from_config() -> Result<Self, MsaError> {
+        let oauth = OAuthClientConfig::from_env().map_err(MsaError::NotConfigured)?;
+        let options = HttpClientOptions {
+            use_cache: false,
+            use_cookie_jar: false,
+            ..HttpClientOptions::from_config()
+        };
+        Ok(Self::new(oauth, MsaEndpoints::default(), options))
+    }
+
+    /// 使用回调收到的授权码登录
+    pub async fn login_with_code<F>(
+        &self,
+        grant: &AuthorizationGrant,
+        on_step: F,
+    ) -> Result<MsaLoginResult, MsaError>
+    where
+        F: Fn(MsaStep) + Sync,
+    {
+        on_step(MsaStep::MsaToken);
+        let mut form = self.token_form("authorization_code");
+        form.insert("code".into(), grant.code.clone().into());
+        form.insert("code_verifier".into(), grant.code_verifier.clone().into());
+        form.insert("redirect_uri".into(), grant.redirect_uri.clone().into());
+
+        let token = self.request_msa_token(form).await?;
+        self.login_with_msa_token(token, &on_step).await
+    }
+
+    /// 使用刷新令牌重新登录
+    pub async fn login_with_refresh_token<F>(
+        &self,
+        refresh_token: &str,
+        on_step: F,
+    ) -> Result<MsaLoginResult, MsaError>
+    where
+        F: Fn(MsaStep) + Sync,
+    {
+        on_step(MsaStep::MsaToken);
+        let mut form = self.token_form("refresh_token");
+        form.insert("refresh_token".into(), refresh_token.into());
+
+        let token = self.request_msa_token(form).await?;
+        self.login_with_msa_token(token, &on_step).await
+    }
+
+    /// 申请设备代码，用户在其他设备上输入 user_code 完成授权
+    pub async fn request_device_code(&self) -> Result<DeviceCode, MsaError> {
+        let mut form = serde_json::Map::new();
+        form.insert("client_id".into(), self.oauth.client_id.clone().into());
+        form.insert("scope".into(), MS_SCOPE.into());
+
+        let response = self
+            .http
+            .post(
+                &self.endpoints.device_code,
+                Some(form_headers()),
+                Some(form.into()),
+            )
+            .await
+            .map_err(|e| http_error(MsaStep::MsaToken, e))?;
+        parse(MsaStep::MsaToken, response)
+    }
+
+    /// 轮询设备代码授权结果，完成后继续登录链路
+    ///
+    /// - `wait`: 等待轮询间隔，测试时可替换为立即返回
+    pub async fn login_with_device_code<F, W, Fut>(
+        &self,
+        device: &DeviceCode,
+        wait: W,
+        on_step: F,
+    ) -> Result<MsaLoginResult, MsaError>
+    where
+        F: Fn(MsaStep) + Sync,
+        W: Fn(Duration) -> Fut + Sync,
+        Fut: Future<Output = ()> + Send,
+    {
+        on_step(MsaStep::MsaToken);
+        let expires_in = Duration::from_secs(device.expires_in);
+        let mut interval = Duration::from_secs(device.interval);
+        let mut waited = Duration::ZERO;
+
+        loop {
+            if waited >= expires_in {
+                return Err(MsaError::DeviceCodeExpired);
+            }
+            wait(interval).await;
+            waited += interval;
+
+            // 设备代码授权属于公共客户端流程，不携带 client_secret
+            let mut form = serde_json::Map::new();
+            form.insert("client_id".into(), self.oauth.client_id.clone().into());
+            form.insert("grant_type".into(), DEVICE_CODE_GRANT.into());
+            form.insert("device_code".into(), device.device_code.clone().into());
+
+            match self.request_msa_token(form).await {
+                Ok(token) => return self.login_with_msa_token(token, &on_step).await,
+                Err(MsaError::OAuth { error, description }) => match error.as_str() {
+                    "authorization_pending" => {}
+                    "slow_down" => interval += SLOW_DOWN_INCREMENT,
+                    "authorization_declined" => return Err(MsaError::AuthorizationDeclined),
+                    "expired_token" => return Err(MsaError::DeviceCodeExpired),
+                    _ => return Err(MsaError::OAuth { error, description }),
+                },
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    fn token_form(&self, grant_type: &str) -> serde_json::Map<String, serde_json::Value> {
+        let mut form = serde_json::Map::new();
+        form.insert("client_id".into(), self.oauth.client_id.clone().into());
+        if let Some(secret) = &self.oauth.client_secret {
+            form.insert("client_secret".into(), secret.clone().into());
+        }
+        form.insert("grant_type".into(), grant_type.into());
+        form.insert("scope".into(), MS_SCOPE.into());
+        form
+    }
+
+    async fn login_with_msa_token<F>(
+        &self,
+        token: MsaTokenResponse,
+        on_step: &F,
+    ) -> Result<MsaLoginResult, MsaError>
+    where
+        F: Fn(MsaStep) + Sync,
+    {
+        let now = unix_millis();
+
+        on_step(MsaStep::XboxLive);
+        let xbl = self.authenticate_xbl(&token.access_token).await?;
+        let uhs = xbl
+            .uhs()
+            .ok_or_else(|| invalid_response(MsaStep::XboxLive, "missing uhs"))?
+            .to_string();
+
+        on_step(MsaStep::Xsts);
+        let xsts = self.authorize_xsts(&xbl.token).await?;
+        if xsts.uhs() != Some(uhs.as_str()) {
+            return Err(MsaError::UhsMismatch);
+        }
+
+        on_step(MsaStep::MinecraftToken);
+        let mc_token = self.login_minecraft(&uhs, &xsts.token).await?;
+
+        on_step(MsaStep::Entitlements);
+        self.check_entitlements(&mc_token.access_token).await?;
+
+        on_step(MsaStep::Profile);
+        let profile = self.fetch_profile(&mc_token.access_token).await?;
+        let uuid = uuid::Uuid::parse_str(&profile.id)
+            .map_err(|e| invalid_response(MsaStep::Profile, e))?
+            .hyphenated()
+            .to_string();
+
+        Ok(MsaLoginResult {
+            msa_refresh_token: token.refresh_token,
+            msa_expires_at: now + token.expires_in * 1000,
+            mc_access_token: mc_token.access_token,
+            mc_expires_at: now + mc_token.expires_in * 1000,
+            uuid,
+            name: profile.name,
+            skins: profile.skins,
+            capes: profile.capes,
+        })
+    }
+
+    async fn request_msa_token(
+        &self,
+        form: serde_json::Map<String, serde_json::Value>,
+    ) -> Result<MsaTokenResponse, MsaError> {
+        let response = self
+            .http
+            .post(
+                &self.endpoints.token,
+                Some(form_headers()),
+                Some(form.into()),
+            )
+            .await
+            .map_err(|e| http_error(MsaStep::MsaToken, e))?;
+
+        if response.status >= 400 {
+            if let Some(error) = response
+                .body
+                .as_ref()
+                .and_then(|b| MsaTokenError::deserialize(b).ok())
+            {
+                return Err(MsaError::OAuth {
+                    error: error.error,
+                    description: error.error_description,
+                });
+            }
+        }
+        parse(MsaStep::MsaToken, response)
+    }
+
+    async fn authenticate_xbl(&self, access_token: &str) -> Result<XboxTokenResponse, MsaError> {
+        let body = |ticket: String| {
+            json!({
+                "Properties": {
+                    "AuthMethod": "RPS",
+                    "SiteName": "user.auth.xboxlive.com",
+                    "RpsTicket": ticket,
+                },
+                "RelyingParty": "http://auth.xboxlive.com",
+                "TokenType": "JWT",
+            })
+        };
+
+        let response = self
+            .post_json(
+                MsaStep::XboxLive,
+                &self.endpoints.xbl,
+                body(format!("d={}", access_token)),
+            )
+            .await?;
+        // 部分账户的 RpsTicket 不能带 d= 前缀
+        let response = if response.status >= 400 {
+            self.post_json(
+                MsaStep::XboxLive,
+                &self.endpoints.xbl,
+                body(access_token.to_string()),
+            )
+            .await?
+        } else {
+            response
+        };
+        parse(MsaStep::XboxLive, response)
+    }
+
+    async fn authorize_xsts(&self, xbl_token: &str) -> Result<XboxTokenResponse, MsaError> {
+        let response = self
+            .post_json(
+                MsaStep::Xsts,
+                &self.endpoints.xsts,
+                json!({
+                    "Properties": {
+                        "SandboxId": "RETAIL",
+                        "UserTokens": [xbl_token],
+                    },
+                    "RelyingParty": "rp://api.minecraftservices.com/",
+                    "TokenType": "JWT",
+                }),
+            )
+            .await?;
+
+        if response.status == 401 {
+            if let Some(error) = response
+                .body
+                .as_ref()
+                .and_then(|b| XstsErrorResponse::deserialize(b).ok())
+            {
+                return Err(MsaError::Xsts {
+                    error: XstsError::from_xerr(error.x_err),
+                    code: error.x_err,
+                });
+            }
+        }
+        parse(MsaStep::Xsts, response)
+    }
+
+    async fn login_minecraft(
+        &self,
+        uhs: &str,
+        xsts_token: &str,
+    ) -> Result<McTokenResponse, MsaError> {
+        let response = self
+            .post_json(
+                MsaStep::MinecraftToken,
+                &self.endpoints.mc_login,
+                json!({ "identityToken": format!("XBL3.0 x={};{}", uhs, xsts_token) }),
+            )
+            .await?;
+        parse(MsaStep::MinecraftToken, response)
+    }
+
+    async fn check_entitlements(&self, mc_token: &str) -> Result<(), MsaError> {
+        let response = self
+            .get_bearer(
+                MsaStep::Entitlements,
+                &self.endpoints.entitlements,
+                mc_token,
+            )
+            .await?;
+        let entitlements: EntitlementsResponse = parse(MsaStep::Entitlements, response)?;
+
+        let owned = entitlements
+            .items
+            .iter()
+            .any(|i| i.name == "product_minecraft" || i.name == "game_minecraft");
+        if owned {
+            Ok(())
+        } else {
+            Err(MsaError::NotOwned)
+        }
+    }
+
+    async fn fetch_profile(&self, mc_token: &str) -> Result<McProfileResponse, MsaError> {
+        let response = self
+            .get_bearer(MsaStep::Profile, &self.endpoints.profile, mc_token)
+            .await?;
+        if response.status == 404 {
+            return Err(MsaError::NoProfile);
+        }
+        parse(MsaStep::Profile, response)
+    }
+
+    async fn post_json(
+        &self,
+        step: MsaStep,
+        url: &str,
+        body: serde_json::Value,
+    ) -> Result<HttpResponse, MsaError> {
+        let headers = HashMap::from([
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Accept".to_string(), "application/json".to_string()),
+        ]);
+        self.http
+            .post(url, Some(headers), Some(body))
+            .await
+            .map_err(|e| http_error(step, e))
+    }
+
+    async fn get_bearer(
+        &self,
+        step: MsaStep,
+        url: &str,
+        token: &str,
+    ) -> Result<HttpResponse, MsaError> {
+        let headers = HashMap::from([("Authorization".to_string(), format!("Bearer {}", token))]);
+        self.http
+            .get(url, Some(headers))
+            .await
+            .map_err(|e| http_error(step, e))
+    }
+}
+
+fn form_headers() -> HashMap<String, String> {
+    HashMap::from([(
+        "Content-Type".to_string(),
+        "application/x-www-form-urlencoded".to_string(),
+    )])
+}
+
+fn http_error(step: MsaStep, error: HttpError) -> MsaError {
+    MsaError::Http {
+        step: step.index(),
+        error,
+    }
+}
+
+fn invalid_response(step: MsaStep, message: impl std::fmt::Display) -> MsaError {
+    MsaError::InvalidResponse {
+        step: step.index(),
+        message: message.to_string(),
+    }
+}
+
+/// 检查状态码并解析响应体
+fn parse<T: DeserializeOwned>(step: MsaStep, response: HttpResponse) -> Result<T, MsaError> {
+    let response = response
+        .error_for_status()
+        .map_err(|e| http_error(step, e))?;
+    let body = response
+        .body
+        .ok_or_else(|| invalid_response(step, "empty body"))?;
+    serde_json::from_value(body).map_err(|e| invalid_response(step, e))
+}
+
+/// 将登录结果写入账户列表并设为当前账户
+///
+/// 同一 UUID 的 MSA 账户会被更新而不是重复添加
+pub fn apply_login(config: &mut ProfileConfig, result: &MsaLoginResult) -> Profile {
+    let index = match config
+        .profile
+        .iter()
+        .position(|p| p.r#type == "msa" && p.uuid == result.uuid)
+    {
+        Some(index) => index,
+        None => {
+            config.profile.push(Profile {
+                guid: uuid::Uuid::new_v4().to_string(),
+                r#type: "msa".to_string(),
+                uuid: result.uuid.clone(),
+                name: String::new(),
+                picked: false,
+                access_token: String::new(),
+                refresh_token: String::new(),
+                msa_expires_at: None,
+                mc_expires_at: None,
+                skin_info: None,
+                cape_info: None,
+                yggdrasil_site: None,
+                yggdrasil_register: None,
+                yggdrasil_site_name: None,
+            });
+            config.profile.len() - 1
+        }
+    };
+
+    for profile in config.profile.iter_mut() {
+        profile.picked = false;
+    }
+
+    let profile = &mut config.profile[index];
+    profile.picked = true;
+    apply_tokens(profile, result);
+    profile.clone()
+}
+
+/// 写入登录结果中的令牌、过期时间与外观信息
+pub fn apply_tokens(profile: &mut Profile, result: &MsaLoginResult) {
+    profile.name = result.name.clone();
+    // 启动游戏使用 MC 令牌，MSA 令牌仅用于刷新
+    profile.access_token = result.mc_access_token.clone();
+    profile.refresh_token = result.msa_refresh_token.clone();
+    profile.msa_expires_at = Some(result.msa_expires_at);
+    profile.mc_expires_at = Some(result.mc_expires_at);
+    profile.skin_info = serde_json::to_string(&result.skins).ok();
+    profile.cape_info = serde_json::to_string(&result.capes).ok();
+}
+
+/// 保存登录结果到 Profile.toml
+pub fn save_login(result: &MsaLoginResult) -> Result<Profile, MsaError> {
+    ConfigManager::instance()
+        .update_profile_config(|config| apply_login(config, result))
+        .map_err(|e| MsaError::Config(e.to_string()))
+}
@@ -0,0 +1,162 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use md5::{Digest, Md5};
+use uuid::Uuid;
+
+use crate::auth::msa::McSkin;
+use crate::toml::error::{ConfigError, ProfileConfigError};
+use crate::toml::manager::ConfigManager;
+use crate::toml::types::{Profile, ProfileConfig};
+
+/// 玩家名称长度范围
+pub const NAME_LENGTH: std::ops::RangeInclusive<usize> = 3..=16;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+
+/// 检查玩家名称：3-16 个字符，仅允许字母、数字与下划线
+pub fn validate_name(name: &str) -> Result<(), ProfileConfigError> {
+    if !NAME_LENGTH.contains(&name.len()) {
+        return Err(ProfileConfigError::InvalidProfileName(format!(
+            "{}: length must be between {} and {}",
+            name,
+            NAME_LENGTH.start(),
+            NAME_LENGTH.end()
+        )));
+    }
+    if let Some(c) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '_'))
+    {
+        return Err(ProfileConfigError::InvalidProfileName(format!(
+            "{}: character '{}' is not allowed",
+            name, c
+        )));
+    }
+    Ok(())
+}
+
+/// 离线玩家 UUID，与 Java 的 UUID.nameUUIDFromBytes("OfflinePlayer:<name>") 一致
+pub fn offline_uuid(name: &str) -> Uuid {
+    let digest = Md5::digest(format!("OfflinePlayer:{}", name).as_bytes());
+    uuid::Builder::from_md5_bytes(digest.into()).into_uuid()
+}
+
+/// 离线皮肤
+pub struct OfflineSkin {
+    /// 本地 PNG 文件
+    pub path: PathBuf,
+    /// 是否为纤细（Alex）模型
+    pub slim: bool,
+}
+
+/// 检查皮肤文件是否为 64x64 或 64x32 的 PNG
+pub fn validate_skin(path: &Path) -> Result<(), ProfileConfigError> {
+    let invalid = |reason: &str| {
+        ProfileConfigError::ConfigError(ConfigError::InvalidValue(
+            "skin".to_string(),
+            format!("{}: {}", path.display(), reason),
+        ))
+    };
+
+    let bytes = fs::read(path).map_err(ConfigError::from)?;
+    // 签名（8 字节）后紧跟 IHDR 块，宽高位于 16..24
+    if bytes.len() < 24 || !bytes.starts_with(PNG_SIGNATURE) || &bytes[12..16] != b"IHDR" {
+        return Err(invalid("not a PNG file"));
+    }
+    let width = u32::from_be_bytes([bytes[16], bytes[17], bytes[18], bytes[19]]);
+    let height = u32::from_be_bytes([bytes[20], bytes[21], bytes[22], bytes[23]]);
+    if width != 64 || !(height == 64 || height == 32) {
+        return Err(invalid(&format!("unsupported size {}x{}", width, height)));
+    }
+    Ok(())
+}
+
+/// 将皮肤复制到皮肤目录，返回写入 skin_info 的 JSON
+pub fn import_skin(
+    skin: &OfflineSkin,
+    uuid: &str,
+    skins_dir: &Path,
+) -> Result<String, ProfileConfigError> {
+    validate_skin(&skin.path)?;
+    fs::create_dir_all(skins_dir).map_err(ConfigError::from)?;
+    let target = skins_dir.join(format!("{}.png", uuid));
+    fs::copy(&skin.path, &target).map_err(ConfigError::from)?;
+
+    let skins = vec![McSkin {
+        id: "local".to_string(),
+        state: "ACTIVE".to_string(),
+        url: target.to_string_lossy().to_string(),
+        variant: Some(if skin.slim { "SLIM" } else { "CLASSIC" }.to_string()),
+        alias: None,
+    }];
+    Ok(serde_json::to_string(&skins).unwrap_or_default())
+}
+
+/// 校验名称与 UUID 并生成离线账户，不修改账户列表
+///
+/// - `uuid`: 自定义 UUID，未指定时使用标准离线 UUID
+pub fn new_offline_profile(
+    config: &ProfileConfig,
+    name: &str,
+    uuid: Option<&str>,
+) -> Result<Profile, ProfileConfigError> {
+    validate_name(name)?;
+    let uuid = match uuid {
+        Some(uuid) => Uuid::parse_str(uuid.trim()).map_err(|e| {
+            ConfigError::InvalidValue("uuid".to_string(), format!("{}: {}", uuid, e))
+        })?,
+        None => offline_uuid(name),
+    }
+    .hyphenated()
+    .to_string();
+
+    if config.profile.iter().any(|p| {
+        p.uuid.eq_ignore_ascii_case(&uuid)
+            || (p.r#type == "legacy" && p.name.eq_ignore_ascii_case(name))
+    }) {
+        return Err(ProfileConfigError::ProfileExists(name.to_string()));
+    }
+
+    Ok(Profile {
+        guid: Uuid::new_v4().to_string(),
+        r#type: "legacy".to_string(),
+        uuid,
+        name: name.to_string(),
+        picked: true,
+        // 保留字段
+        access_token: String::new(),
+        refresh_token: String::new(),
+        msa_expires_at: None,
+        mc_expires_at: None,
+        skin_info: None,
+        cape_info: None,
+        yggdrasil_site: None,
+        yggdrasil_register: None,
+        yggdrasil_site_name: None,
+    })
+}
+
+/// 创建离线账户，保存并设为当前账户
+pub fn create_offline_profile(
+    name: &str,
+    uuid: Option<&str>,
+    skin: Option<&OfflineSkin>,
+) -> Result<Profile, ProfileConfigError> {
+    let manager = ConfigManager::instance();
+    let skins_dir = manager.app_data_dir().join("Skins");
+
+    manager.update_profile_config(|config| {
+        let mut profile = new_offline_profile(config, name, uuid)?;
+        // 校验通过后再复制皮肤，避免为无效账户留下文件
+        if let Some(skin) = skin {
+            profile.skin_info = Some(import_skin(skin, &profile.uuid, &skins_dir)?);
+        }
+
+        for existing in config.profile.iter_mut() {
+            existing.picked = false;
+        }
+        config.profile.push(profile.clone());
+        Ok(profile)
+    })?
+}
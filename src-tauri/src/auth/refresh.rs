@@ -0,0 +1,313 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+
+use crate::auth::error::MsaError;
+use crate::auth::msa::{apply_tokens, unix_millis, MsaClient, MsaLoginResult};
+use crate::toml::manager::ConfigManager;
+use crate::toml::types::{Profile, ProfileConfig};
+
+/// 默认检查间隔
+pub const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// 默认提前刷新的时间，MC 令牌剩余有效期小于该值时刷新
+pub const DEFAULT_REFRESH_MARGIN: Duration = Duration::from_secs(30 * 60);
+
+/// 刷新失败后首次重试的等待时间，之后每次失败翻倍
+pub const RETRY_BASE_DELAY: Duration = Duration::from_secs(5 * 60);
+
+/// 重试等待时间的上限
+pub const RETRY_MAX_DELAY: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// 时钟，测试时可替换
+pub trait Clock: Send + Sync {
+    /// 当前 Unix 时间（毫秒）
+    fn now_millis(&self) -> u64;
+}
+
+/// 系统时钟
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_millis(&self) -> u64 {
+        unix_millis()
+    }
+}
+
+/// account:refreshed 事件
+#[derive(Serialize, Clone, Debug)]
+pub struct AccountRefreshedPayload {
+    pub guid: String,
+    pub uuid: String,
+    pub name: String,
+    pub mc_expires_at: u64,
+}
+
+/// account:refresh_failed 事件
+#[derive(Serialize, Clone, Debug)]
+pub struct AccountRefreshFailedPayload {
+    pub guid: String,
+    pub uuid: String,
+    pub name: String,
+    pub error: serde_json::Value,
+    /// 刷新令牌已失效，需要重新登录；重新登录前不再尝试刷新
+    pub needs_login: bool,
+}
+
+/// 单个账户的刷新结果
+pub enum RefreshOutcome {
+    Refreshed {
+        guid: String,
+        /// 刷新时使用的刷新令牌
+        refresh_token: String,
+        result: MsaLoginResult,
+    },
+    Failed {
+        profile: Profile,
+        error: MsaError,
+    },
+}
+
+/// 账户最近一次刷新失败的记录
+struct RefreshFailure {
+    /// 失败时使用的刷新令牌，重新登录后令牌改变，记录随之失效
+    refresh_token: String,
+    /// 刷新令牌已失效（invalid_grant），不再重试
+    needs_login: bool,
+    /// 连续失败次数
+    attempts: u32,
+    /// 下次重试的时间（Unix 毫秒）
+    retry_at: u64,
+}
+
+/// 刷新令牌是否已被撤销或过期，只能重新登录
+pub fn needs_login(error: &MsaError) -> bool {
+    matches!(error, MsaError::OAuth { error, .. } if error == "invalid_grant")
+}
+
+/// 第 `attempts` 次失败后的等待时间
+pub fn retry_delay(attempts: u32) -> Duration {
+    let factor = 1u32 << attempts.saturating_sub(1).min(16);
+    RETRY_BASE_DELAY.saturating_mul(factor).min(RETRY_MAX_DELAY)
+}
+
+/// MSA 令牌刷新调度器
+pub struct RefreshScheduler {
+    client: MsaClient,
+    clock: Arc<dyn Clock>,
+    /// 按 guid 记录的刷新失败，仅保存在内存中
+    failures: Mutex<HashMap<String, RefreshFailure>>,
+    /// 提前刷新的时间
+    pub margin: Duration,
+    /// 检查间隔
+    pub interval: Duration,
+}
+
+impl RefreshScheduler {
+    pub fn new(client: MsaClient, clock: Arc<dyn Clock>) -> Self {
+        Self {
+            client,
+            clock,
+            failures: Mutex::new(HashMap::new()),
+            margin: DEFAULT_REFRESH_MARGIN,
+            interval: DEFAULT_CHECK_INTERVAL,
+        }
+    }
+
+    /// 即将过期、需要刷新的 MSA 账户
+    ///
+    /// 跳过需要重新登录与尚未到重试时间的账户
+    pub fn due_profiles<'a>(&self, config: &'a ProfileConfig) -> Vec<&'a Profile> {
+        let now = self.clock.now_millis();
+        let deadline = now + self.margin.as_millis() as u64;
+        let failures = self.failures();
+        config
+            .profile
+            .iter()
+            .filter(|p| p.r#type == "msa" && !p.refresh_token.is_empty())
+            .filter(|p| {
+                p.mc_expires_at
+                    .is_none_or(|expires_at| expires_at <= deadline)
+            })
+            .filter(|p| match failures.get(&p.guid) {
+                Some(failure) if failure.refresh_token == p.refresh_token => {
+                    !failure.needs_login && failure.retry_at <= now
+                }
+                _ => true,
+            })
+            .collect()
+    }
+
+    /// 账户是否因刷新令牌失效而等待重新登录
+    pub fn needs_login(&self, profile: &Profile) -> bool {
+        self.failures()
+            .get(&profile.guid)
+            .is_some_and(|f| f.needs_login && f.refresh_token == profile.refresh_token)
+    }
+
+    fn failures(&self) -> std::sync::MutexGuard<'_, HashMap<String, RefreshFailure>> {
+        self.failures.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 记录失败并计算下次重试时间
+    fn record_failure(&self, profile: &Profile, error: &MsaError) {
+        let mut failures = self.failures();
+        let attempts = failures
+            .get(&profile.guid)
+            .filter(|f| f.refresh_token == profile.refresh_token)
+            .map_or(1, |f| f.attempts + 1);
+        let retry_at = self.clock.now_millis() + retry_delay(attempts).as_millis() as u64;
+        failures.insert(
+            profile.guid.clone(),
+            RefreshFailure {
+                refresh_token: profile.refresh_token.clone(),
+                needs_login: needs_login(error),
+                attempts,
+                retry_at,
+            },
+        );
+    }
+
+    /// 刷新所有即将过期的账户，不修改配置
+    pub async fn refresh_due(&self, config: &ProfileConfig) -> Vec<RefreshOutcome> {
+        let mut outcomes = Vec::new();
+        for profile in self.due_profiles(config) {
+            log::info!(target: "auth", "Refreshing MSA token for {}", profile.name);
+            let outcome = match self
+                .client
+                .login_with_refresh_token(&profile.refresh_token, |_| {})
+                .await
+            {
+                Ok(result) => {
+                    self.failures().remove(&profile.guid);
+                    RefreshOutcome::Refreshed {
+                        guid: profile.guid.clone(),
+                        refresh_token: profile.refresh_token.clone(),
+                        result,
+                    }
+                }
+                Err(error) => {
+                    self.record_failure(profile, &error);
+                    RefreshOutcome::Failed {
+                        profile: profile.clone(),
+                        error,
+                    }
+                }
+            };
+            outcomes.push(outcome);
+        }
+        outcomes
+    }
+
+    /// 执行一次检查：刷新、保存并通知前端
+    pub async fn run_once(&self, app_handle: &AppHandle) {
+        let manager = ConfigManager::instance();
+        let config = match manager.load_profile_config() {
+            Ok(config) => config,
+            Err(e) => {
+                log::error!(target: "auth", "Failed to load profiles for refresh: {}", e);
+                return;
+            }
+        };
+
+        let outcomes = self.refresh_due(&config).await;
+        if outcomes.is_empty() {
+            return;
+        }
+
+        // 刷新期间配置可能已被修改，重新读取后只更新对应账户
+        let saved = manager.update_profile_config(|config| apply_refresh(config, &outcomes));
+        let refreshed = match saved {
+            Ok(refreshed) => refreshed,
+            Err(e) => {
+                log::error!(target: "auth", "Failed to save refreshed profiles: {}", e);
+                return;
+            }
+        };
+
+        for profile in refreshed {
+            log::info!(target: "auth", "Refreshed MSA token for {}", profile.name);
+            let _ = app_handle.emit(
+                "account:refreshed",
+                AccountRefreshedPayload {
+                    guid: profile.guid,
+                    uuid: profile.uuid,
+                    name: profile.name,
+                    mc_expires_at: profile.mc_expires_at.unwrap_or_default(),
+                },
+            );
+        }
+
+        for outcome in outcomes {
+            if let RefreshOutcome::Failed { profile, error } = outcome {
+                let needs_login = needs_login(&error);
+                if needs_login {
+                    log::warn!(target: "auth", "Refresh token of {} is no longer valid, sign in again to refresh", profile.name);
+                } else {
+                    log::warn!(target: "auth", "Failed to refresh {}: {}", profile.name, error);
+                }
+                let _ = app_handle.emit(
+                    "account:refresh_failed",
+                    AccountRefreshFailedPayload {
+                        guid: profile.guid,
+                        uuid: profile.uuid,
+                        name: profile.name,
+                        error: serde_json::to_value(&error).unwrap_or_default(),
+                        needs_login,
+                    },
+                );
+            }
+        }
+    }
+
+    /// 启动时立即检查一次，之后按间隔定期检查
+    pub async fn run(self, app_handle: AppHandle) {
+        let mut ticker = tokio::time::interval(self.interval);
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            self.run_once(&app_handle).await;
+        }
+    }
+}
+
+/// 将刷新成功的结果写入配置，返回更新后的账户
+///
+/// 已被删除的账户，以及刷新期间重新登录、刷新令牌已改变的账户会被忽略
+pub fn apply_refresh(config: &mut ProfileConfig, outcomes: &[RefreshOutcome]) -> Vec<Profile> {
+    outcomes
+        .iter()
+        .filter_map(|outcome| match outcome {
+            RefreshOutcome::Refreshed {
+                guid,
+                refresh_token,
+                result,
+            } => {
+                let profile = config.profile.iter_mut().find(|p| &p.guid == guid)?;
+                if &profile.refresh_token != refresh_token {
+                    log::info!(target: "auth", "Skipped refresh of {}, the account signed in again", profile.name);
+                    return None;
+                }
+                apply_tokens(profile, result);
+                Some(profile.clone())
+            }
+            RefreshOutcome::Failed { .. } => None,
+        })
+        .collect()
+}
+
+/// 在后台启动令牌刷新调度器
+pub fn start_refresh_scheduler(app_handle: AppHandle) {
+    let client = match MsaClient::from_config() {
+        Ok(client) => client,
+        Err(e) => {
+            log::warn!(target: "auth", "Token refresh scheduler disabled: {}", e);
+            return;
+        }
+    };
+    let scheduler = RefreshScheduler::new(client, Arc::new(SystemClock));
+    tauri::async_runtime::spawn(scheduler.run(app_handle));
+}
@@ -0,0 +1,1023 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use axum::extract::{Form, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde_json::json;
+
+use sha2::Digest;
+
+use crate::auth::authlib::{AuthlibManager, AUTHLIB_LATEST_URL, AUTHLIB_MIRROR_LATEST_URL};
+use crate::auth::error::{MsaError, XstsError, YggdrasilError};
+use crate::auth::msa::{apply_login, DeviceCode, MsaClient, MsaEndpoints, MsaStep};
+use crate::auth::offline::{
+    import_skin, new_offline_profile, offline_uuid, validate_name, OfflineSkin,
+};
+use crate::auth::refresh::{
+    apply_refresh, retry_delay, Clock, RefreshOutcome, RefreshScheduler, RETRY_BASE_DELAY,
+    RETRY_MAX_DELAY,
+};
+use crate::auth::yggdrasil::{apply_session, LoginOutcome, YggdrasilClient};
+use crate::http::client::HttpClientOptions;
+use crate::http::oauth::{AuthorizationGrant, OAuthClientConfig};
+use crate::http::policy::UrlPolicy;
+use crate::toml::error::ProfileConfigError;
+use crate::toml::types::{Profile, ProfileConfig};
+
+/// 本地替身服务器的行为与记录
+#[derive(Default)]
+struct StandIn {
+    /// XSTS 返回的 XErr
+    xsts_xerr: Option<u64>,
+    /// 是否拥有游戏
+    not_owned: bool,
+    /// XBL 是否拒绝带 d= 前缀的 RpsTicket
+    reject_prefixed_ticket: bool,
+    /// 设备代码轮询依次返回的错误，用完后签发令牌
+    device_errors: Vec<&'static str>,
+    token_forms: Mutex<Vec<HashMap<String, String>>>,
+    rps_tickets: Mutex<Vec<String>>,
+    identity_tokens: Mutex<Vec<String>>,
+}
+
+type Shared = Arc<StandIn>;
+
+fn xbox_token(token: &str) -> serde_json::Value {
+    json!({
+        "IssueInstant": "2024-01-01T00:00:00Z",
+        "NotAfter": "2024-01-02T00:00:00Z",
+        "Token": token,
+        "DisplayClaims": { "xui": [{ "uhs": "uhs123" }] }
+    })
+}
+
+async fn start_stand_in(stand_in: StandIn) -> (MsaEndpoints, Shared) {
+    let state: Shared = Arc::new(stand_in);
+
+    let app = Router::new()
+        .route(
+            "/token",
+            post(
+                |State(s): State<Shared>, Form(form): Form<HashMap<String, String>>| async move {
+                    let refresh = form.get("refresh_token").cloned();
+                    let is_device = form.contains_key("device_code");
+                    let mut forms = s.token_forms.lock().unwrap();
+                    forms.push(form);
+                    let polls = forms.len();
+                    drop(forms);
+                    if is_device {
+                        if let Some(error) = s.device_errors.get(polls - 1) {
+                            return (StatusCode::BAD_REQUEST, Json(json!({ "error": error })));
+                        }
+                    }
+                    if refresh.as_deref() == Some("expired") {
+                        return (
+                            StatusCode::BAD_REQUEST,
+                            Json(json!({
+                                "error": "invalid_grant",
+                                "error_description": "The refresh token has expired."
+                            })),
+                        );
+                    }
+                    (
+                        StatusCode::OK,
+                        Json(json!({
+                            "token_type": "bearer",
+                            "expires_in": 3600,
+                            "scope": "XboxLive.signin offline_access",
+                            "access_token": "msa-access",
+                            "refresh_token": "msa-refresh"
+                        })),
+                    )
+                },
+            ),
+        )
+        .route(
+            "/devicecode",
+            post(|Form(form): Form<HashMap<String, String>>| async move {
+                assert_eq!(form["client_id"], "client-id");
+                assert!(!form.contains_key("client_secret"));
+                Json(json!({
+                    "device_code": "device-code",
+                    "user_code": "ABCD-EFGH",
+                    "verification_uri": "https://www.microsoft.com/link",
+                    "expires_in": 900,
+                    "interval": 5,
+                    "message": "To sign in, use a web browser..."
+                }))
+            }),
+        )
+        .route(
+            "/xbl",
+            post(
+                |State(s): State<Shared>, Json(body): Json<serde_json::Value>| async move {
+                    let ticket = body["Properties"]["RpsTicket"]
+                        .as_str()
+                        .unwrap_or_default()
+                        .to_string();
+                    s.rps_tickets.lock().unwrap().push(ticket.clone());
+                    if s.reject_prefixed_ticket && ticket.starts_with("d=") {
+                        return (StatusCode::BAD_REQUEST, Json(json!({})));
+                    }
+                    (StatusCode::OK, Json(xbox_token("xbl-token")))
+                },
+            ),
+        )
+        .route(
+            "/xsts",
+            post(|State(s): State<Shared>| async move {
+                match s.xsts_xerr {
+                    Some(xerr) => (
+                        StatusCode::UNAUTHORIZED,
+                        Json(json!({
+                            "Identity": "0",
+                            "XErr": xerr,
+                            "Message": "",
+                            "Redirect": "https://start.ui.xboxlive.com/CreateAccount"
+                        })),
+                    ),
+                    None => (StatusCode::OK, Json(xbox_token("xsts-token"))),
+                }
+            }),
+        )
+        .route(
+            "/mc_login",
+            post(
+                |State(s): State<Shared>, Json(body): Json<serde_json::Value>| async move {
+                    s.identity_tokens
+                        .lock()
+                        .unwrap()
+                        .push(body["identityToken"].as_str().unwrap_or_default().into());
+                    Json(json!({
+                        "username": "00000000-0000-0000-0000-000000000000",
+                        "roles": [],
+                        "access_token": "mc-access",
+                        "token_type": "Bearer",
+                        "expires_in": 86400
+                    }))
+                },
+            ),
+        )
+        .route(
+            "/entitlements",
+            get(|State(s): State<Shared>, h: HeaderMap| async move {
+                assert_eq!(h["authorization"], "Bearer mc-access");
+                let items = if s.not_owned {
+                    json!([])
+                } else {
+                    json!([
+                        { "name": "product_minecraft", "signature": "sig" },
+                        { "name": "game_minecraft", "signature": "sig" }
+                    ])
+                };
+                Json(json!({ "items": items, "signature": "sig", "keyId": "1" }))
+            }),
+        )
+        .route(
+            "/profile",
+            get(|| async {
+                Json(json!({
+                    "id": "069a79f444e94726a5befca90e38aaf5",
+                    "name": "Notch",
+                    "skins": [{
+                        "id": "skin-1",
+                        "state": "ACTIVE",
+                        "url": "http://textures.minecraft.net/texture/abc",
+                        "variant": "CLASSIC"
+                    }],
+                    "capes": []
+                }))
+            }),
+        )
+        .with_state(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://127.0.0.1:{}", listener.local_addr().unwrap().port());
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    let endpoints = MsaEndpoints {
+        token: format!("{}/token", base),
+        device_code: format!("{}/devicecode", base),
+        xbl: format!("{}/xbl", base),
+        xsts: format!("{}/xsts", base),
+        mc_login: format!("{}/mc_login", base),
+        entitlements: format!("{}/entitlements", base),
+        profile: format!("{}/profile", base),
+    };
+    (endpoints, state)
+}
+
+fn test_client(endpoints: MsaEndpoints) -> MsaClient {
+    let options = HttpClientOptions {
+        cache_dir: std::env::temp_dir().join("NovaCLTestMsa"),
+        use_cache: false,
+        use_cookie_jar: false,
+        cookie_domains: Vec::new(),
+        url_policy: UrlPolicy::new(vec!["127.0.0.1".to_string()]),
+    };
+    let oauth = OAuthClientConfig {
+        client_id: "client-id".to_string(),
+        client_secret: Some("client-secret".to_string()),
+    };
+    MsaClient::new(oauth, endpoints, options)
+}
+
+fn grant() -> AuthorizationGrant {
+    AuthorizationGrant {
+        code: "auth-code".to_string(),
+        code_verifier: "verifier".to_string(),
+        redirect_uri: "http://127.0.0.1:36993/oauth/callback".to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_login_with_code() {
+    let (endpoints, state) = start_stand_in(StandIn::default()).await;
+    let client = test_client(endpoints);
+
+    let steps = Mutex::new(Vec::new());
+    let result = client
+        .login_with_code(&grant(), |step| steps.lock().unwrap().push(step))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        *steps.lock().unwrap(),
+        vec![
+            MsaStep::MsaToken,
+            MsaStep::XboxLive,
+            MsaStep::Xsts,
+            MsaStep::MinecraftToken,
+            MsaStep::Entitlements,
+            MsaStep::Profile,
+        ]
+    );
+    assert_eq!(result.uuid, "069a79f4-44e9-4726-a5be-fca90e38aaf5");
+    assert_eq!(result.name, "Notch");
+    assert_eq!(result.mc_access_token, "mc-access");
+    assert_eq!(result.msa_refresh_token, "msa-refresh");
+    assert_eq!(
+        result.mc_expires_at - result.msa_expires_at,
+        (86400 - 3600) * 1000
+    );
+    assert_eq!(
+        result.skins[0].url,
+        "http://textures.minecraft.net/texture/abc"
+    );
+
+    let forms = state.token_forms.lock().unwrap();
+    assert_eq!(forms[0]["grant_type"], "authorization_code");
+    assert_eq!(forms[0]["code"], "auth-code");
+    assert_eq!(forms[0]["code_verifier"], "verifier");
+    assert_eq!(forms[0]["client_secret"], "client-secret");
+    assert_eq!(state.rps_tickets.lock().unwrap()[0], "d=msa-access");
+    assert_eq!(
+        state.identity_tokens.lock().unwrap()[0],
+        "XBL3.0 x=uhs123;xsts-token"
+    );
+}
+
+#[tokio::test]
+async fn test_refresh_with_unprefixed_ticket_fallback() {
+    let (endpoints, state) = start_stand_in(StandIn {
+        reject_prefixed_ticket: true,
+        ..StandIn::default()
+    })
+    .await;
+    let client = test_client(endpoints);
+
+    let result = client
+        .login_with_refresh_token("msa-refresh-old", |_| {})
+        .await
+        .unwrap();
+    assert_eq!(result.msa_refresh_token, "msa-refresh");
+
+    assert_eq!(
+        state.token_forms.lock().unwrap()[0]["grant_type"],
+        "refresh_token"
+    );
+    assert_eq!(
+        *state.rps_tickets.lock().unwrap(),
+        vec!["d=msa-access", "msa-access"]
+    );
+}
+
+/// 记录等待时长而不真正等待
+async fn poll_device(
+    client: &MsaClient,
+    device: &DeviceCode,
+) -> (
+    Result<crate::auth::msa::MsaLoginResult, MsaError>,
+    Vec<Duration>,
+) {
+    let waits = Mutex::new(Vec::new());
+    let result = client
+        .login_with_device_code(
+            device,
+            |interval| {
+                waits.lock().unwrap().push(interval);
+                async {}
+            },
+            |_| {},
+        )
+        .await;
+    (result, waits.into_inner().unwrap())
+}
+
+#[tokio::test]
+async fn test_device_code_polling() {
+    let (endpoints, state) = start_stand_in(StandIn {
+        device_errors: vec!["authorization_pending", "slow_down"],
+        ..StandIn::default()
+    })
+    .await;
+    let client = test_client(endpoints);
+
+    let device = client.request_device_code().await.unwrap();
+    assert_eq!(device.user_code, "ABCD-EFGH");
+    // device_code 不应返回给前端
+    assert!(serde_json::to_value(&device).unwrap()["device_code"].is_null());
+
+    let (result, waits) = poll_device(&client, &device).await;
+    assert_eq!(result.unwrap().name, "Notch");
+    assert_eq!(
+        waits,
+        vec![
+            Duration::from_secs(5),
+            Duration::from_secs(5),
+            Duration::from_secs(10)
+        ]
+    );
+
+    let forms = state.token_forms.lock().unwrap();
+    assert_eq!(forms.len(), 3);
+    assert_eq!(
+        forms[0]["grant_type"],
+        "urn:ietf:params:oauth:grant-type:device_code"
+    );
+    assert_eq!(forms[0]["device_code"], "device-code");
+    assert!(!forms[0].contains_key("client_secret"));
+}
+
+#[tokio::test]
+async fn test_device_code_declined_and_expired() {
+    let (endpoints, _state) = start_stand_in(StandIn {
+        device_errors: vec!["authorization_pending", "authorization_declined"],
+        ..StandIn::default()
+    })
+    .await;
+    let client = test_client(endpoints);
+    let device = client.request_device_code().await.unwrap();
+    let (result, _) = poll_device(&client, &device).await;
+    assert!(matches!(result, Err(MsaError::AuthorizationDeclined)));
+
+    // 轮询时间超过有效期后不再请求
+    let (endpoints, state) = start_stand_in(StandIn {
+        device_errors: vec!["authorization_pending"; 10],
+        ..StandIn::default()
+    })
+    .await;
+    let client = test_client(endpoints);
+    let device = DeviceCode {
+        expires_in: 15,
+        ..client.request_device_code().await.unwrap()
+    };
+    let (result, waits) = poll_device(&client, &device).await;
+    let error = result.unwrap_err();
+    assert!(matches!(error, MsaError::DeviceCodeExpired));
+    assert_eq!(
+        serde_json::to_value(&error).unwrap()["kind"],
+        "device_code_expired"
+    );
+    assert_eq!(waits.len(), 3);
+    assert_eq!(state.token_forms.lock().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_oauth_error() {
+    let (endpoints, _state) = start_stand_in(StandIn::default()).await;
+    let client = test_client(endpoints);
+
+    let error = client
+        .login_with_refresh_token("expired", |_| {})
+        .await
+        .unwrap_err();
+    assert!(matches!(&error, MsaError::OAuth { error, .. } if error == "invalid_grant"));
+    assert_eq!(error.step(), 1);
+}
+
+#[tokio::test]
+async fn test_xsts_xerr() {
+    let (endpoints, _state) = start_stand_in(StandIn {
+        xsts_xerr: Some(2148916233),
+        ..StandIn::default()
+    })
+    .await;
+    let client = test_client(endpoints);
+
+    let error = client.login_with_code(&grant(), |_| {}).await.unwrap_err();
+    assert!(matches!(
+        error,
+        MsaError::Xsts {
+            error: XstsError::NoXboxAccount,
+            code: 2148916233
+        }
+    ));
+
+    let value = serde_json::to_value(&error).unwrap();
+    assert_eq!(value["kind"], "xsts");
+    assert_eq!(value["step"], 3);
+    assert_eq!(value["xsts"], "no_xbox_account");
+    assert_eq!(value["code"], 2148916233u64);
+}
+
+#[tokio::test]
+async fn test_not_owned() {
+    let (endpoints, _state) = start_stand_in(StandIn {
+        not_owned: true,
+        ..StandIn::default()
+    })
+    .await;
+    let client = test_client(endpoints);
+
+    let error = client.login_with_code(&grant(), |_| {}).await.unwrap_err();
+    assert!(matches!(error, MsaError::NotOwned));
+    assert_eq!(error.step(), 5);
+}
+
+#[tokio::test]
+async fn test_apply_login_updates_existing_profile() {
+    let (endpoints, _state) = start_stand_in(StandIn::default()).await;
+    let client = test_client(endpoints);
+    let result = client.login_with_code(&grant(), |_| {}).await.unwrap();
+
+    let mut config = ProfileConfig::default();
+    let first = apply_login(&mut config, &result);
+    let second = apply_login(&mut config, &result);
+
+    assert_eq!(config.profile.len(), 1);
+    assert_eq!(first.guid, second.guid);
+    assert!(second.picked);
+    assert_eq!(second.access_token, "mc-access");
+    assert_eq!(second.refresh_token, "msa-refresh");
+    assert_eq!(second.mc_expires_at, Some(result.mc_expires_at));
+
+    let skins: serde_json::Value =
+        serde_json::from_str(second.skin_info.as_deref().unwrap()).unwrap();
+    assert_eq!(skins[0]["state"], "ACTIVE");
+}
+
+struct ManualClock(AtomicU64);
+
+impl ManualClock {
+    fn new(now: u64) -> Arc<Self> {
+        Arc::new(ManualClock(AtomicU64::new(now)))
+    }
+
+    fn advance(&self, duration: Duration) {
+        self.0
+            .fetch_add(duration.as_millis() as u64, Ordering::SeqCst);
+    }
+}
+
+impl Clock for ManualClock {
+    fn now_millis(&self) -> u64 {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+fn stored_profile(guid: &str, r#type: &str, refresh_token: &str, mc_expires_at: u64) -> Profile {
+    Profile {
+        guid: guid.to_string(),
+        r#type: r#type.to_string(),
+        uuid: format!("uuid-{}", guid),
+        name: guid.to_string(),
+        picked: false,
+        access_token: "old-access".to_string(),
+        refresh_token: refresh_token.to_string(),
+        msa_expires_at: None,
+        mc_expires_at: Some(mc_expires_at),
+        skin_info: None,
+        cape_info: None,
+        yggdrasil_site: None,
+        yggdrasil_register: None,
+        yggdrasil_site_name: None,
+    }
+}
+
+#[tokio::test]
+async fn test_refresh_scheduler() {
+    let (endpoints, state) = start_stand_in(StandIn::default()).await;
+    let now = 1_000_000_000;
+    let minute = 60 * 1000;
+    let scheduler = RefreshScheduler::new(test_client(endpoints.clone()), ManualClock::new(now));
+
+    let mut config = ProfileConfig {
+        profile: vec![
+            // 10 分钟后过期，需要刷新
+            stored_profile("due", "msa", "msa-refresh-due", now + 10 * minute),
+            // 2 小时后过期，不刷新
+            stored_profile("fresh", "msa", "msa-refresh-fresh", now + 120 * minute),
+            // 刷新令牌已失效
+            stored_profile("revoked", "msa", "expired", now - minute),
+            stored_profile("offline", "legacy", "None", 0),
+        ],
+    };
+    config.profile[1].picked = true;
+
+    let due: Vec<_> = scheduler
+        .due_profiles(&config)
+        .iter()
+        .map(|p| p.guid.as_str())
+        .collect();
+    assert_eq!(due, vec!["due", "revoked"]);
+
+    let outcomes = scheduler.refresh_due(&config).await;
+    assert_eq!(outcomes.len(), 2);
+    assert!(
+        matches!(&outcomes[1], RefreshOutcome::Failed { profile, error: MsaError::OAuth { .. } } if profile.guid == "revoked")
+    );
+
+    let refresh_tokens: Vec<_> = state
+        .token_forms
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|f| f["refresh_token"].clone())
+        .collect();
+    assert_eq!(refresh_tokens, vec!["msa-refresh-due", "expired"]);
+
+    // 失效的刷新令牌不再重试，重新登录后恢复
+    assert!(scheduler.needs_login(&config.profile[2]));
+    let due: Vec<_> = scheduler
+        .due_profiles(&config)
+        .iter()
+        .map(|p| p.guid.as_str())
+        .collect();
+    assert_eq!(due, vec!["due"]);
+    let mut signed_in = config.clone();
+    signed_in.profile[2].refresh_token = "msa-refresh-new".to_string();
+    assert!(!scheduler.needs_login(&signed_in.profile[2]));
+    assert_eq!(scheduler.due_profiles(&signed_in).len(), 2);
+
+    // 刷新期间重新登录的账户不会被旧结果覆盖
+    let mut relogged = config.clone();
+    relogged.profile[0].refresh_token = "msa-refresh-relogin".to_string();
+    assert!(apply_refresh(&mut relogged, &outcomes).is_empty());
+    assert_eq!(relogged.profile[0].refresh_token, "msa-refresh-relogin");
+    assert_eq!(relogged.profile[0].access_token, "old-access");
+
+    // 刷新期间删除的账户不会被重新写入
+    let mut current = config.clone();
+    current.profile.retain(|p| p.guid != "revoked");
+    let refreshed = apply_refresh(&mut current, &outcomes);
+    assert_eq!(refreshed.len(), 1);
+    assert_eq!(current.profile.len(), 3);
+
+    let updated = &current.profile[0];
+    assert_eq!(updated.access_token, "mc-access");
+    assert_eq!(updated.refresh_token, "msa-refresh");
+    assert_eq!(updated.name, "Notch");
+    // 刷新不改变当前选中的账户
+    assert!(!updated.picked);
+    assert!(current.profile[1].picked);
+    assert_eq!(current.profile[1].access_token, "old-access");
+}
+
+#[tokio::test]
+async fn test_refresh_backoff() {
+    assert_eq!(retry_delay(1), RETRY_BASE_DELAY);
+    assert_eq!(retry_delay(2), RETRY_BASE_DELAY * 2);
+    assert_eq!(retry_delay(3), RETRY_BASE_DELAY * 4);
+    assert_eq!(retry_delay(100), RETRY_MAX_DELAY);
+
+    // 令牌端点不可用，失败后按退避时间重试
+    let endpoints = MsaEndpoints {
+        token: "http://127.0.0.1:1/token".to_string(),
+        ..MsaEndpoints::default()
+    };
+    let now = 1_000_000_000;
+    let clock = ManualClock::new(now);
+    let scheduler = RefreshScheduler::new(test_client(endpoints), clock.clone());
+    let config = ProfileConfig {
+        profile: vec![stored_profile("offline-net", "msa", "msa-refresh", now)],
+    };
+
+    let outcomes = scheduler.refresh_due(&config).await;
+    assert!(matches!(
+        &outcomes[0],
+        RefreshOutcome::Failed {
+            error: MsaError::Http { .. },
+            ..
+        }
+    ));
+    assert!(!scheduler.needs_login(&config.profile[0]));
+    assert!(scheduler.due_profiles(&config).is_empty());
+
+    clock.advance(retry_delay(1));
+    assert_eq!(scheduler.due_profiles(&config).len(), 1);
+    scheduler.refresh_due(&config).await;
+    clock.advance(retry_delay(1));
+    assert!(scheduler.due_profiles(&config).is_empty());
+    clock.advance(retry_delay(1));
+    assert_eq!(scheduler.due_profiles(&config).len(), 1);
+}
+
+// ---------------------- Yggdrasil ----------------------
+
+/// 本地 Yggdrasil 替身服务器的记录
+#[derive(Default)]
+struct YggdrasilStandIn {
+    refresh_bodies: Mutex<Vec<serde_json::Value>>,
+    jar: Vec<u8>,
+}
+
+type YggdrasilShared = Arc<YggdrasilStandIn>;
+
+fn ygg_profile(id: &str, name: &str) -> serde_json::Value {
+    json!({ "id": id, "name": name })
+}
+
+async fn start_yggdrasil_stand_in() -> (String, YggdrasilShared) {
+    let state: YggdrasilShared = Arc::new(YggdrasilStandIn {
+        jar: b"PK\x03\x04authlib-injector".to_vec(),
+        ..YggdrasilStandIn::default()
+    });
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let base = format!("http://127.0.0.1:{}", listener.local_addr().unwrap().port());
+    let jar_sha256 = hex::encode(sha2::Sha256::digest(&state.jar));
+    let download_url = format!("{}/authlib/authlib-injector.jar", base);
+
+    let app = Router::new()
+        .route(
+            "/",
+            get(|| async { ([("X-Authlib-Injector-API-Location", "/api/yggdrasil/")], "<html></html>") }),
+        )
+        .route(
+            "/api/yggdrasil/",
+            get(|| async {
+                Json(json!({
+                    "meta": {
+                        "serverName": "Test Skin Site",
+                        "implementationName": "stand-in",
+                        "links": { "homepage": "https://skin.example", "register": "https://skin.example/register" },
+                        "feature.non_email_login": true
+                    },
+                    "skinDomains": ["skin.example"],
+                    "signaturePublickey": "-----BEGIN PUBLIC KEY-----"
+                }))
+            }),
+        )
+        .route(
+            "/api/yggdrasil/authserver/authenticate",
+            post(|Json(body): Json<serde_json::Value>| async move {
+                if body["password"] == "wrong" {
+                    return (
+                        StatusCode::FORBIDDEN,
+                        Json(json!({
+                            "error": "ForbiddenOperationException",
+                            "errorMessage": "Invalid credentials. Invalid username or password."
+                        })),
+                    );
+                }
+                let profiles = vec![
+                    ygg_profile("0b5a1b2c3d4e5f60718293a4b5c6d7e8", "Alex"),
+                    ygg_profile("1b5a1b2c3d4e5f60718293a4b5c6d7e8", "Steve"),
+                ];
+                let mut response = json!({
+                    "accessToken": "access-1",
+                    "clientToken": body["clientToken"],
+                    "availableProfiles": profiles,
+                });
+                if body["username"] == "single@example.com" {
+                    response["availableProfiles"] = json!([profiles[0]]);
+                    response["selectedProfile"] = profiles[0].clone();
+                }
+                (StatusCode::OK, Json(response))
+            }),
+        )
+        .route(
+            "/api/yggdrasil/authserver/refresh",
+            post(
+                |State(s): State<YggdrasilShared>, Json(body): Json<serde_json::Value>| async move {
+                    s.refresh_bodies.lock().unwrap().push(body.clone());
+                    let selected = match &body["selectedProfile"] {
+                        serde_json::Value::Null => ygg_profile("0b5a1b2c3d4e5f60718293a4b5c6d7e8", "Alex"),
+                        profile => profile.clone(),
+                    };
+                    Json(json!({
+                        "accessToken": "access-2",
+                        "clientToken": body["clientToken"],
+                        "selectedProfile": selected
+                    }))
+                },
+            ),
+        )
+        .route(
+            "/api/yggdrasil/authserver/validate",
+            post(|Json(body): Json<serde_json::Value>| async move {
+                if body["accessToken"] == "access-2" {
+                    StatusCode::NO_CONTENT
+                } else {
+                    StatusCode::FORBIDDEN
+                }
+            }),
+        )
+        .route(
+            "/api/yggdrasil/authserver/invalidate",
+            post(|| async { StatusCode::NO_CONTENT }),
+        )
+        .route(
+            "/api/yggdrasil/authserver/signout",
+            post(|| async { StatusCode::NO_CONTENT }),
+        )
+        .route(
+            "/authlib/latest.json",
+            get(move || async move {
+                Json(json!({
+                    "build_number": 53,
+                    "version": "1.2.5",
+                    "download_url": download_url,
+                    "checksums": { "sha256": jar_sha256 }
+                }))
+            }),
+        )
+        .route(
+            "/authlib/authlib-injector.jar",
+            get(|State(s): State<YggdrasilShared>| async move { s.jar.clone() }),
+        )
+        .with_state(state.clone());
+
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    (base, state)
+}
+
+fn test_options(name: &str) -> HttpClientOptions {
+    HttpClientOptions {
+        cache_dir: std::env::temp_dir().join(name),
+        use_cache: false,
+        use_cookie_jar: false,
+        cookie_domains: Vec::new(),
+        url_policy: UrlPolicy::new(vec!["127.0.0.1".to_string()]),
+    }
+}
+
+#[tokio::test]
+async fn test_yggdrasil_discover() {
+    let (base, _state) = start_yggdrasil_stand_in().await;
+    let client = YggdrasilClient::new(test_options("NovaCLTestYggdrasil"));
+
+    // 省略协议与末尾斜杠时同样可以解析
+    let server = client.discover(&format!("{}/", base)).await.unwrap();
+    assert_eq!(server.api_root, format!("{}/api/yggdrasil/", base));
+    assert_eq!(server.name(), "Test Skin Site");
+    assert!(server.metadata.meta.non_email_login);
+    assert_eq!(
+        server.metadata.meta.links.register.as_deref(),
+        Some("https://skin.example/register")
+    );
+
+    let direct = client
+        .resolve_api_root(&format!("{}/api/yggdrasil", base))
+        .await
+        .unwrap();
+    assert_eq!(direct, server.api_root);
+}
+
+#[tokio::test]
+async fn test_yggdrasil_login_and_select_profile() {
+    let (base, state) = start_yggdrasil_stand_in().await;
+    let client = YggdrasilClient::new(test_options("NovaCLTestYggdrasil"));
+
+    let error = client
+        .login(&base, "user@example.com", "wrong")
+        .await
+        .err()
+        .unwrap();
+    assert!(
+        matches!(&error, YggdrasilError::Server { status: 403, error, .. } if error == "ForbiddenOperationException")
+    );
+    assert_eq!(serde_json::to_value(&error).unwrap()["kind"], "server");
+
+    let LoginOutcome::Ready(single) = client
+        .login(&base, "single@example.com", "password")
+        .await
+        .unwrap()
+    else {
+        panic!("expected a selected profile");
+    };
+    assert_eq!(single.profile.name, "Alex");
+    assert!(state.refresh_bodies.lock().unwrap().is_empty());
+
+    let LoginOutcome::SelectProfile(pending) = client
+        .login(&base, "multi@example.com", "password")
+        .await
+        .unwrap()
+    else {
+        panic!("expected profile selection");
+    };
+    assert_eq!(pending.profiles.len(), 2);
+    let client_token = pending.client_token.clone();
+
+    assert!(matches!(
+        client.select_profile(pending.clone(), "unknown").await,
+        Err(YggdrasilError::UnknownProfile(_))
+    ));
+    let session = client
+        .select_profile(pending, "1b5a1b2c3d4e5f60718293a4b5c6d7e8")
+        .await
+        .unwrap();
+    assert_eq!(session.profile.name, "Steve");
+    assert_eq!(session.access_token, "access-2");
+
+    let refresh = state.refresh_bodies.lock().unwrap()[0].clone();
+    assert_eq!(refresh["accessToken"], "access-1");
+    assert_eq!(refresh["clientToken"], client_token.as_str());
+    assert_eq!(refresh["selectedProfile"]["name"], "Steve");
+
+    let api_root = &session.server.api_root;
+    assert!(client
+        .validate(api_root, "access-2", &client_token)
+        .await
+        .unwrap());
+    assert!(!client
+        .validate(api_root, "access-1", &client_token)
+        .await
+        .unwrap());
+    client
+        .invalidate(api_root, "access-2", &client_token)
+        .await
+        .unwrap();
+    client
+        .signout(api_root, "multi@example.com", "password")
+        .await
+        .unwrap();
+
+    let mut config = ProfileConfig::default();
+    let first = apply_session(&mut config, &session);
+    let second = apply_session(&mut config, &session);
+    assert_eq!(config.profile.len(), 1);
+    assert_eq!(first.guid, second.guid);
+    assert_eq!(second.uuid, "1b5a1b2c-3d4e-5f60-7182-93a4b5c6d7e8");
+    assert_eq!(second.refresh_token, client_token);
+    assert_eq!(second.yggdrasil_site.as_deref(), Some(api_root.as_str()));
+    assert_eq!(
+        second.yggdrasil_site_name.as_deref(),
+        Some("Test Skin Site")
+    );
+}
+
+#[tokio::test]
+async fn test_authlib_injector_download() {
+    let (base, state) = start_yggdrasil_stand_in().await;
+    let dir = std::env::temp_dir().join(format!("NovaCLTestAuthlib-{}", uuid::Uuid::new_v4()));
+    let manager = AuthlibManager::new(
+        test_options("NovaCLTestAuthlib"),
+        dir.clone(),
+        format!("{}/authlib/latest.json", base),
+    );
+
+    let first = manager.ensure(false).await.unwrap();
+    assert!(first.updated);
+    assert_eq!(first.build_number, 53);
+    assert_eq!(std::fs::read(&first.path).unwrap(), state.jar);
+
+    // 已是最新版本时不重复下载
+    let second = manager.ensure(true).await.unwrap();
+    assert!(!second.updated);
+    assert_eq!(second.version, "1.2.5");
+
+    // 检查更新失败时继续使用已安装版本
+    let offline = AuthlibManager::new(
+        test_options("NovaCLTestAuthlib"),
+        dir.clone(),
+        format!("{}/authlib/missing.json", base),
+    );
+    assert_eq!(offline.ensure(true).await.unwrap().build_number, 53);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[test]
+fn test_authlib_url_policy() {
+    let policy = AuthlibManager::url_policy();
+    for url in [
+        AUTHLIB_LATEST_URL,
+        AUTHLIB_MIRROR_LATEST_URL,
+        "https://authlib-injector.yushi.moe/artifact/53/authlib-injector-1.2.5.jar",
+        "https://bmclapi2.bangbang93.com/mirrors/authlib-injector/artifact/53/authlib-injector-1.2.5.jar",
+    ] {
+        assert!(policy.check_str(url).is_ok(), "{}", url);
+    }
+    // 不继承全局允许列表
+    assert!(policy
+        .check_str("https://api.minecraftservices.com/minecraft/profile")
+        .is_err());
+}
+
+// ---------------------- Offline ----------------------
+
+#[test]
+fn test_offline_uuid_and_name() {
+    assert_eq!(
+        offline_uuid("Notch").to_string(),
+        "b50ad385-829d-3141-a216-7e7d7539ba7f"
+    );
+
+    assert!(validate_name("Steve_01").is_ok());
+    for name in ["ab", "a_name_that_is_too_long", "bad-name", "中文名称"] {
+        assert!(matches!(
+            validate_name(name),
+            Err(ProfileConfigError::InvalidProfileName(_))
+        ));
+    }
+}
+
+#[test]
+fn test_new_offline_profile_rejects_duplicates() {
+    let mut config = ProfileConfig {
+        profile: vec![stored_profile("msa", "msa", "token", 0)],
+    };
+    config.profile[0].uuid = "b50ad385-829d-3141-a216-7e7d7539ba7f".to_string();
+
+    // 与已有账户 UUID 相同
+    let error = new_offline_profile(&config, "Notch", None).unwrap_err();
+    assert!(matches!(error, ProfileConfigError::ProfileExists(_)));
+    assert_eq!(
+        serde_json::to_value(&error).unwrap()["kind"],
+        "profile_exists"
+    );
+
+    let custom =
+        new_offline_profile(&config, "Notch", Some("0B5A1B2C3D4E5F60718293A4B5C6D7E8")).unwrap();
+    assert_eq!(custom.uuid, "0b5a1b2c-3d4e-5f60-7182-93a4b5c6d7e8");
+    assert_eq!(custom.r#type, "legacy");
+    assert!(custom.picked);
+
+    // 同名离线账户（不区分大小写）
+    config.profile.push(custom);
+    assert!(matches!(
+        new_offline_profile(
+            &config,
+            "notch",
+            Some("1b5a1b2c-3d4e-5f60-7182-93a4b5c6d7e8")
+        ),
+        Err(ProfileConfigError::ProfileExists(_))
+    ));
+    assert!(new_offline_profile(&config, "Steve", Some("not-a-uuid")).is_err());
+}
+
+#[test]
+fn test_import_offline_skin() {
+    let dir = std::env::temp_dir().join(format!("NovaCLTestSkin-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let png = |width: u32, height: u32| {
+        let mut bytes = b"\x89PNG\r\n\x1a\n\0\0\0\x0dIHDR".to_vec();
+        bytes.extend_from_slice(&width.to_be_bytes());
+        bytes.extend_from_slice(&height.to_be_bytes());
+        bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+        bytes
+    };
+    let valid = dir.join("valid.png");
+    let invalid = dir.join("invalid.png");
+    std::fs::write(&valid, png(64, 32)).unwrap();
+    std::fs::write(&invalid, png(128, 128)).unwrap();
+
+    let skins_dir = dir.join("Skins");
+    let skin_info = import_skin(
+        &OfflineSkin {
+            path: valid,
+            slim: true,
+        },
+        "b50ad385-829d-3141-a216-7e7d7539ba7f",
+        &skins_dir,
+    )
+    .unwrap();
+    let skins: serde_json::Value = serde_json::from_str(&skin_info).unwrap();
+    assert_eq!(skins[0]["variant"], "SLIM");
+    assert!(skins_dir
+        .join("b50ad385-829d-3141-a216-7e7d7539ba7f.png")
+        .is_file());
+
+    assert!(import_skin(
+        &OfflineSkin {
+            path: invalid,
+            slim: false,
+        },
+        "uuid",
+        &skins_dir,
+    )
+    .is_err());
+
+    let _ = std::fs::remove_dir_all(dir);
+}
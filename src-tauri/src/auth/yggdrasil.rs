@@ -0,0 +1,497 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use url::Url;
+
+use crate::auth::error::YggdrasilError;
+use crate::http::client::{HttpClient, HttpClientOptions};
+use crate::http::policy::UrlPolicy;
+use crate::http::types::HttpResponse;
+use crate::toml::manager::ConfigManager;
+use crate::toml::types::{Profile, ProfileConfig};
+
+/// authlib-injector 规范中的 API 地址指示（ALI）响应头
+pub const API_LOCATION_HEADER: &str = "x-authlib-injector-api-location";
+
+/// 游戏角色
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GameProfile {
+    /// 无符号 UUID
+    pub id: String,
+    pub name: String,
+}
+
+/// 皮肤站相关链接
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ServerLinks {
+    pub homepage: Option<String>,
+    pub register: Option<String>,
+}
+
+/// 皮肤站元数据中的 meta 部分
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerMeta {
+    pub server_name: Option<String>,
+    pub implementation_name: Option<String>,
+    pub implementation_version: Option<String>,
+    #[serde(default)]
+    pub links: ServerLinks,
+    /// 是否支持使用角色名登录
+    #[serde(default, rename = "feature.non_email_login")]
+    pub non_email_login: bool,
+}
+
+/// 皮肤站元数据
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerMetadata {
+    #[serde(default)]
+    pub meta: ServerMeta,
+    #[serde(default)]
+    pub skin_domains: Vec<String>,
+    pub signature_publickey: Option<String>,
+}
+
+/// 已解析 API 地址的皮肤站
+#[derive(Debug, Clone, Serialize)]
+pub struct YggdrasilServer {
+    /// API 根地址，以 / 结尾
+    pub api_root: String,
+    pub metadata: ServerMetadata,
+}
+
+impl YggdrasilServer {
+    /// 皮肤站名称，元数据未提供时使用主机名
+    pub fn name(&self) -> String {
+        self.metadata.meta.server_name.clone().unwrap_or_else(|| {
+            Url::parse(&self.api_root)
+                .ok()
+                .and_then(|u| u.host_str().map(str::to_string))
+                .unwrap_or_else(|| self.api_root.clone())
+        })
+    }
+}
+
+/// authenticate / refresh 响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuthResponse {
+    pub access_token: String,
+    pub client_token: String,
+    #[serde(default)]
+    pub available_profiles: Vec<GameProfile>,
+    pub selected_profile: Option<GameProfile>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ErrorResponse {
+    error: String,
+    #[serde(default)]
+    error_message: String,
+}
+
+/// 已绑定角色的登录会话
+#[derive(Debug, Clone)]
+pub struct YggdrasilSession {
+    pub server: YggdrasilServer,
+    pub access_token: String,
+    pub client_token: String,
+    pub profile: GameProfile,
+}
+
+/// 账户有多个角色，等待用户选择
+#[derive(Debug, Clone)]
+pub struct PendingLogin {
+    pub server: YggdrasilServer,
+    pub access_token: String,
+    pub client_token: String,
+    pub profiles: Vec<GameProfile>,
+}
+
+/// 登录结果
+pub enum LoginOutcome {
+    Ready(YggdrasilSession),
+    SelectProfile(PendingLogin),
+}
+
+/// Yggdrasil 认证服务器客户端
+pub struct YggdrasilClient {
+    http: HttpClient,
+}
+
+impl YggdrasilClient {
+    pub fn new(options: HttpClientOptions) -> Self {
+        YggdrasilClient {
+            http: HttpClient::with_options(options),
+        }
+    }
+
+    /// 根据全局配置创建
+    ///
+    /// 皮肤站由用户自行填写，不受前端请求白名单限制，但仍禁止访问内网地址
+    pub fn from_config() -> Self {
+        Self::new(HttpClientOptions {
+            use_cache: false,
+            use_cookie_jar: false,
+            url_policy: UrlPolicy::default(),
+            ..HttpClientOptions::from_config()
+        })
+    }
+
+    /// 按 ALI 规范解析 API 根地址
+    pub async fn resolve_api_root(&self, input: &str) -> Result<String, YggdrasilError> {
+        let url = normalize_url(input)?;
+        let response = self
+            .http
+            .get(url.as_str(), None)
+            .await
+            .map_err(YggdrasilError::Http)?;
+
+        let root = match response.headers.get(API_LOCATION_HEADER) {
+            // 可以是相对地址
+            Some(location) => url
+                .join(location)
+                .map_err(|e| YggdrasilError::InvalidUrl(e.to_string()))?,
+            None => url,
+        };
+        Ok(with_trailing_slash(root))
+    }
+
+    /// 获取皮肤站元数据
+    pub async fn fetch_metadata(&self, api_root: &str) -> Result<ServerMetadata, YggdrasilError> {
+        let response = self
+            .http
+            .get(api_root, None)
+            .await
+            .map_err(YggdrasilError::Http)?;
+        parse(response)
+    }
+
+    /// 解析地址并获取元数据
+    pub async fn discover(&self, input: &str) -> Result<YggdrasilServer, YggdrasilError> {
+        let api_root = self.resolve_api_root(input).await?;
+        let metadata = self.fetch_metadata(&api_root).await?;
+        Ok(YggdrasilServer { api_root, metadata })
+    }
+
+    /// 使用账户密码登录
+    pub async fn authenticate(
+        &self,
+        api_root: &str,
+        username: &str,
+        password: &str,
+        client_token: &str,
+    ) -> Result<AuthResponse, YggdrasilError> {
+        let body = json!({
+            "agent": { "name": "Minecraft", "version": 1 },
+            "username": username,
+            "password": password,
+            "clientToken": client_token,
+            "requestUser": false,
+        });
+        parse(self.post(api_root, "authserver/authenticate", body).await?)
+    }
+
+    /// 刷新令牌，可同时选择角色
+    pub async fn refresh(
+        &self,
+        api_root: &str,
+        access_token: &str,
+        client_token: &str,
+        selected_profile: Option<&GameProfile>,
+    ) -> Result<AuthResponse, YggdrasilError> {
+        let mut body = json!({
+            "accessToken": access_token,
+            "clientToken": client_token,
+            "requestUser": false,
+        });
+        if let Some(profile) = selected_profile {
+            body["selectedProfile"] = json!(profile);
+        }
+        parse(self.post(api_root, "authserver/refresh", body).await?)
+    }
+
+    /// 检查令牌是否有效
+    pub async fn validate(
+        &self,
+        api_root: &str,
+        access_token: &str,
+        client_token: &str,
+    ) -> Result<bool, YggdrasilError> {
+        let body = json!({ "accessToken": access_token, "clientToken": client_token });
+        let response = self.post(api_root, "authserver/validate", body).await?;
+        match response.status {
+            403 => Ok(false),
+            _ => no_content(response).map(|_| true),
+        }
+    }
+
+    /// 吊销令牌
+    pub async fn invalidate(
+        &self,
+        api_root: &str,
+        access_token: &str,
+        client_token: &str,
+    ) -> Result<(), YggdrasilError> {
+        let body = json!({ "accessToken": access_token, "clientToken": client_token });
+        no_content(self.post(api_root, "authserver/invalidate", body).await?)
+    }
+
+    /// 吊销账户的所有令牌
+    pub async fn signout(
+        &self,
+        api_root: &str,
+        username: &str,
+        password: &str,
+    ) -> Result<(), YggdrasilError> {
+        let body = json!({ "username": username, "password": password });
+        no_content(self.post(api_root, "authserver/signout", body).await?)
+    }
+
+    /// 登录皮肤站，只有一个角色时自动选择
+    pub async fn login(
+        &self,
+        input: &str,
+        username: &str,
+        password: &str,
+    ) -> Result<LoginOutcome, YggdrasilError> {
+        let server = self.discover(input).await?;
+        let client_token = uuid::Uuid::new_v4().simple().to_string();
+        let auth = self
+            .authenticate(&server.api_root, username, password, &client_token)
+            .await?;
+
+        if let Some(profile) = auth.selected_profile {
+            return Ok(LoginOutcome::Ready(YggdrasilSession {
+                server,
+                access_token: auth.access_token,
+                client_token: auth.client_token,
+                profile,
+            }));
+        }
+
+        let pending = PendingLogin {
+            server,
+            access_token: auth.access_token,
+            client_token: auth.client_token,
+            profiles: auth.available_profiles,
+        };
+        match pending.profiles.len() {
+            0 => Err(YggdrasilError::NoProfile),
+            1 => {
+                let id = pending.profiles[0].id.clone();
+                self.select_profile(pending, &id)
+                    .await
+                    .map(LoginOutcome::Ready)
+            }
+            _ => Ok(LoginOutcome::SelectProfile(pending)),
+        }
+    }
+
+    /// 为多角色账户选择角色，通过 refresh 绑定到令牌
+    pub async fn select_profile(
+        &self,
+        pending: PendingLogin,
+        profile_id: &str,
+    ) -> Result<YggdrasilSession, YggdrasilError> {
+        let profile = pending
+            .profiles
+            .into_iter()
+            .find(|p| p.id == profile_id)
+            .ok_or_else(|| YggdrasilError::UnknownProfile(profile_id.to_string()))?;
+
+        let auth = self
+            .refresh(
+                &pending.server.api_root,
+                &pending.access_token,
+                &pending.client_token,
+                Some(&profile),
+            )
+            .await?;
+
+        Ok(YggdrasilSession {
+            server: pending.server,
+            access_token: auth.access_token,
+            client_token: auth.client_token,
+            profile: auth.selected_profile.unwrap_or(profile),
+        })
+    }
+
+    /// 确保已保存账户的令牌有效，失效时刷新并保存
+    pub async fn refresh_saved(&self, guid: &str) -> Result<Profile, YggdrasilError> {
+        let manager = ConfigManager::instance();
+        let profile = manager
+            .load_profile_config()
+            .map_err(|e| YggdrasilError::Config(e.to_string()))?
+            .profile
+            .into_iter()
+            .find(|p| p.guid == guid && p.r#type == "yggdrasil")
+            .ok_or_else(|| YggdrasilError::UnknownProfile(guid.to_string()))?;
+        let api_root = profile
+            .yggdrasil_site
+            .clone()
+            .ok_or_else(|| YggdrasilError::InvalidUrl(String::new()))?;
+
+        if self
+            .validate(&api_root, &profile.access_token, &profile.refresh_token)
+            .await?
+        {
+            return Ok(profile);
+        }
+
+        let auth = self
+            .refresh(
+                &api_root,
+                &profile.access_token,
+                &profile.refresh_token,
+                None,
+            )
+            .await?;
+        manager
+            .update_profile_config(|config| {
+                let profile = config.profile.iter_mut().find(|p| p.guid == guid)?;
+                profile.access_token = auth.access_token;
+                profile.refresh_token = auth.client_token;
+                if let Some(selected) = auth.selected_profile {
+                    profile.name = selected.name;
+                }
+                Some(profile.clone())
+            })
+            .map_err(|e| YggdrasilError::Config(e.to_string()))?
+            .ok_or_else(|| YggdrasilError::UnknownProfile(guid.to_string()))
+    }
+
+    async fn post(
+        &self,
+        api_root: &str,
+        path: &str,
+        body: serde_json::Value,
+    ) -> Result<HttpResponse, YggdrasilError> {
+        let headers = HashMap::from([
+            ("Content-Type".to_string(), "application/json".to_string()),
+            ("Accept".to_string(), "application/json".to_string()),
+        ]);
+        self.http
+            .post(&format!("{}{}", api_root, path), Some(headers), Some(body))
+            .await
+            .map_err(YggdrasilError::Http)
+    }
+}
+
+/// 补全协议，未指定时使用 https
+fn normalize_url(input: &str) -> Result<Url, YggdrasilError> {
+    let input = input.trim();
+    let with_scheme = if input.contains("://") {
+        input.to_string()
+    } else {
+        format!("https://{}", input)
+    };
+    Url::parse(&with_scheme).map_err(|e| YggdrasilError::InvalidUrl(format!("{}: {}", input, e)))
+}
+
+fn with_trailing_slash(mut url: Url) -> String {
+    if !url.path().ends_with('/') {
+        let path = format!("{}/", url.path());
+        url.set_path(&path);
+    }
+    url.to_string()
+}
+
+/// 将错误响应转换为皮肤站错误
+fn server_error(response: HttpResponse) -> YggdrasilError {
+    let status = response.status;
+    match response
+        .body
+        .and_then(|body| serde_json::from_value::<ErrorResponse>(body).ok())
+    {
+        Some(body) => YggdrasilError::Server {
+            status,
+            error: body.error,
+            message: body.error_message,
+        },
+        None => YggdrasilError::Server {
+            status,
+            error: format!("HTTP {}", status),
+            message: String::new(),
+        },
+    }
+}
+
+fn parse<T: DeserializeOwned>(response: HttpResponse) -> Result<T, YggdrasilError> {
+    if response.status >= 400 {
+        return Err(server_error(response));
+    }
+    let body = response
+        .body
+        .ok_or_else(|| YggdrasilError::InvalidResponse("empty body".into()))?;
+    serde_json::from_value(body).map_err(|e| YggdrasilError::InvalidResponse(e.to_string()))
+}
+
+fn no_content(response: HttpResponse) -> Result<(), YggdrasilError> {
+    if response.status >= 400 {
+        return Err(server_error(response));
+    }
+    Ok(())
+}
+
+/// 将登录会话写入账户列表并设为当前账户
+///
+/// 同一皮肤站的同一角色会被更新而不是重复添加；
+/// Yggdrasil 账户的 refresh_token 字段保存 clientToken
+pub fn apply_session(config: &mut ProfileConfig, session: &YggdrasilSession) -> Profile {
+    let uuid = uuid::Uuid::parse_str(&session.profile.id)
+        .map(|u| u.hyphenated().to_string())
+        .unwrap_or_else(|_| session.profile.id.clone());
+    let site = Some(session.server.api_root.clone());
+
+    let index = match config
+        .profile
+        .iter()
+        .position(|p| p.r#type == "yggdrasil" && p.uuid == uuid && p.yggdrasil_site == site)
+    {
+        Some(index) => index,
+        None => {
+            config.profile.push(Profile {
+                guid: uuid::Uuid::new_v4().to_string(),
+                r#type: "yggdrasil".to_string(),
+                uuid: uuid.clone(),
+                name: String::new(),
+                picked: false,
+                access_token: String::new(),
+                refresh_token: String::new(),
+                msa_expires_at: None,
+                mc_expires_at: None,
+                skin_info: None,
+                cape_info: None,
+                yggdrasil_site: site,
+                yggdrasil_register: None,
+                yggdrasil_site_name: None,
+            });
+            config.profile.len() - 1
+        }
+    };
+
+    for profile in config.profile.iter_mut() {
+        profile.picked = false;
+    }
+
+    let profile = &mut config.profile[index];
+    profile.name = session.profile.name.clone();
+    profile.picked = true;
+    profile.access_token = session.access_token.clone();
+    profile.refresh_token = session.client_token.clone();
+    profile.yggdrasil_site_name = Some(session.server.name());
+    profile.yggdrasil_register = session.server.metadata.meta.links.register.clone();
+    profile.clone()
+}
+
+/// 保存登录会话
+pub fn save_session(session: &YggdrasilSession) -> Result<Profile, YggdrasilError> {
+    ConfigManager::instance()
+        .update_profile_config(|config| apply_session(config, session))
+        .map_err(|e| YggdrasilError::Config(e.to_string()))
+}
@@ -0,0 +1,25 @@
+use crate::deeplink::error::DeepLinkError;
+use crate::deeplink::router::{handle_url, DeepLinkRequest, DEEP_LINKS};
+
+/// 处理 `novacl://` 链接，例如用户粘贴的链接
+///
+/// 需要确认的链接返回待确认的请求，登录回调返回 `null`
+#[tauri::command]
+pub async fn deep_link_handle(
+    app_handle: tauri::AppHandle,
+    url: String,
+) -> Result<Option<DeepLinkRequest>, DeepLinkError> {
+    handle_url(&app_handle, &url).await
+}
+
+/// 等待确认的深度链接，界面启动前收到的链接也会保留在这里
+#[tauri::command]
+pub async fn deep_link_pending() -> Result<Vec<DeepLinkRequest>, DeepLinkError> {
+    Ok(DEEP_LINKS.pending())
+}
+
+/// 用户确认或拒绝后移除请求，返回被移除的请求
+#[tauri::command]
+pub async fn deep_link_resolve(id: String) -> Result<DeepLinkRequest, DeepLinkError> {
+    DEEP_LINKS.resolve(&id)
+}
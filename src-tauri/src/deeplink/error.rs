@@ -0,0 +1,62 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+/// 深度链接错误
+///
+/// 错误信息不包含原始链接，避免泄露授权码等参数
+#[derive(Error, Debug)]
+pub enum DeepLinkError {
+    /// 无法解析为 URL
+    #[error("Invalid deep link: {0}")]
+    InvalidUrl(String),
+
+    /// 不是 novacl:// 链接
+    #[error("Unsupported scheme: {0}")]
+    UnsupportedScheme(String),
+
+    /// 未知的操作
+    #[error("Unknown deep link action: {0}")]
+    UnknownAction(String),
+
+    /// 缺少必需参数
+    #[error("Missing parameter: {0}")]
+    MissingParameter(String),
+
+    /// 参数格式无效
+    #[error("Invalid parameter {0}: {1}")]
+    InvalidParameter(String, String),
+
+    /// 待确认的请求不存在或已处理
+    #[error("Unknown deep link request: {0}")]
+    UnknownRequest(String),
+
+    /// 目标模块拒绝处理
+    #[error("Deep link rejected: {0}")]
+    Rejected(String),
+}
+
+impl DeepLinkError {
+    /// 错误类型，可作为前端本地化的稳定键
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DeepLinkError::InvalidUrl(_) => "invalid_url",
+            DeepLinkError::UnsupportedScheme(_) => "unsupported_scheme",
+            DeepLinkError::UnknownAction(_) => "unknown_action",
+            DeepLinkError::MissingParameter(_) => "missing_parameter",
+            DeepLinkError::InvalidParameter(..) => "invalid_parameter",
+            DeepLinkError::UnknownRequest(_) => "unknown_request",
+            DeepLinkError::Rejected(_) => "rejected",
+        }
+    }
+}
+
+/// 序列化为 { kind, message } 供前端展示
+impl Serialize for DeepLinkError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("DeepLinkError", 2)?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
+}
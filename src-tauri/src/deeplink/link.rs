@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+use url::Url;
+
+use crate::deeplink::error::DeepLinkError;
+
+/// 深度链接协议
+pub const SCHEME: &str = "novacl";
+
+/// 项目与版本 ID 的最大长度
+const MAX_ID_LENGTH: usize = 64;
+
+/// 显示名称的最大长度
+const MAX_NAME_LENGTH: usize = 64;
+
+/// 实例名称的最大长度
+const MAX_INSTANCE_LENGTH: usize = 255;
+
+/// 解析后的深度链接
+///
+/// - `novacl://install-modpack?project=<id>&version=<id>`
+/// - `novacl://install-mod?project=<id>&version=<id>&instance=<name>`
+/// - `novacl://add-server?address=<host[:port]>&name=<name>`
+/// - `novacl://login-callback?code=<code>&state=<state>`
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "action", rename_all = "kebab-case")]
+pub enum DeepLink {
+    /// 安装 Modrinth 整合包
+    InstallModpack {
+        project: String,
+        version: Option<String>,
+    },
+    /// 将 Modrinth 模组安装到实例
+    InstallMod {
+        project: String,
+        version: Option<String>,
+        /// 实例名称，未指定时由用户选择
+        instance: Option<String>,
+    },
+    /// 添加服务器
+    AddServer {
+        /// `host` 或 `host:port`，IPv6 地址需要方括号
+        address: String,
+        name: Option<String>,
+    },
+    /// 通过自定义协议返回的 OAuth 回调
+    LoginCallback {
+        #[serde(skip_serializing)]
+        code: Option<String>,
+        state: String,
+        error: Option<String>,
+        error_description: Option<String>,
+    },
+}
+
+impl DeepLink {
+    /// 解析并校验 `novacl://` 链接
+    pub fn parse(input: &str) -> Result<Self, DeepLinkError> {
+        let url = Url::parse(input.trim()).map_err(|e| DeepLinkError::InvalidUrl(e.to_string()))?;
+        if url.scheme() != SCHEME {
+            return Err(DeepLinkError::UnsupportedScheme(url.scheme().to_string()));
+        }
+
+        let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        let params = Params(params);
+
+        match action(&url)?.as_str() {
+            "install-modpack" => Ok(DeepLink::InstallModpack {
+                project: params.id("project")?,
+                version: params.optional_id("version")?,
+            }),
+            "install-mod" => Ok(DeepLink::InstallMod {
+                project: params.id("project")?,
+                version: params.optional_id("version")?,
+                instance: params.optional_instance("instance")?,
+            }),
+            "add-server" => Ok(DeepLink::AddServer {
+                address: parse_server_address(params.required("address")?)?,
+                name: params.optional_name("name")?,
+            }),
+            "login-callback" => {
+                let code = params.optional("code").map(str::to_string);
+                let error = params.optional("error").map(str::to_string);
+                if code.is_none() && error.is_none() {
+                    return Err(DeepLinkError::MissingParameter("code".to_string()));
+                }
+                Ok(DeepLink::LoginCallback {
+                    code,
+                    state: params.required("state")?.to_string(),
+                    error,
+                    error_description: params.optional("error_description").map(str::to_string),
+                })
+            }
+            other => Err(DeepLinkError::UnknownAction(other.to_string())),
+        }
+    }
+
+    /// 操作名称，与链接中的写法一致
+    pub fn action(&self) -> &'static str {
+        match self {
+            DeepLink::InstallModpack { .. } => "install-modpack",
+            DeepLink::InstallMod { .. } => "install-mod",
+            DeepLink::AddServer { .. } => "add-server",
+            DeepLink::LoginCallback { .. } => "login-callback",
+        }
+    }
+}
+
+/// 操作名称：`novacl://<action>`、`novacl:<action>` 与 `novacl:///<action>` 均可
+fn action(url: &Url) -> Result<String, DeepLinkError> {
+    // novacl:<action> 没有主机部分，路径不可分段，直接按 / 拆分
+    let mut segments = url.path().split('/').filter(|segment| !segment.is_empty());
+
+    let action = match url.host_str().filter(|host| !host.is_empty()) {
+        Some(host) => host.to_string(),
+        None => segments
+            .next()
+            .map(str::to_string)
+            .ok_or_else(|| DeepLinkError::UnknownAction(String::new()))?,
+    };
+    if let Some(extra) = segments.next() {
+        return Err(DeepLinkError::UnknownAction(format!(
+            "{}/{}",
+            action, extra
+        )));
+    }
+    Ok(action.to_ascii_lowercase())
+}
+
+struct Params(HashMap<String, String>);
+
+impl Params {
+    fn optional(&self, key: &str) -> Option<&str> {
+        self.0
+            .get(key)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn required(&self, key: &str) -> Result<&str, DeepLinkError> {
+        self.optional(key)
+            .ok_or_else(|| DeepLinkError::MissingParameter(key.to_string()))
+    }
+
+    /// Modrinth 项目或版本 ID / slug
+    fn id(&self, key: &str) -> Result<String, DeepLinkError> {
+        let value = self.required(key)?;
+        let valid = value.len() <= MAX_ID_LENGTH
+            && value
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(invalid(key, "expected a project or version id"));
+        }
+        Ok(value.to_string())
+    }
+
+    fn optional_id(&self, key: &str) -> Result<Option<String>, DeepLinkError> {
+        match self.optional(key) {
+            Some(_) => self.id(key).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn optional_name(&self, key: &str) -> Result<Option<String>, DeepLinkError> {
+        let Some(value) = self.optional(key) else {
+            return Ok(None);
+        };
+        if value.chars().count() > MAX_NAME_LENGTH || value.chars().any(char::is_control) {
+            return Err(invalid(
+                key,
+                "name is too long or contains control characters",
+            ));
+        }
+        Ok(Some(value.to_string()))
+    }
+
+    /// 实例只能以名称指定，不接受路径
+    fn optional_instance(&self, key: &str) -> Result<Option<String>, DeepLinkError> {
+        let Some(value) = self.optional(key) else {
+            return Ok(None);
+        };
+        let valid = value.len() <= MAX_INSTANCE_LENGTH
+            && value != "."
+            && value != ".."
+            && !value
+                .chars()
+                .any(|c| c == '/' || c == '\\' || c == ':' || c.is_control());
+        if !valid {
+            return Err(invalid(key, "expected an instance name"));
+        }
+        Ok(Some(value.to_string()))
+    }
+}
+
+fn invalid(key: &str, reason: &str) -> DeepLinkError {
+    DeepLinkError::InvalidParameter(key.to_string(), reason.to_string())
+}
+
+/// 校验服务器地址，返回规范化后的 `host[:port]`
+pub fn parse_server_address(value: &str) -> Result<String, DeepLinkError> {
+    let invalid = |reason: &str| invalid("address", reason);
+
+    let (host, port) = if let Some(rest) = value.strip_prefix('[') {
+        let (host, rest) = rest
+            .split_once(']')
+            .ok_or_else(|| invalid("unterminated IPv6 address"))?;
+        if host.parse::<std::net::Ipv6Addr>().is_err() {
+            return Err(invalid("invalid IPv6 address"));
+        }
+        let port = match rest {
+            "" => None,
+            _ => Some(
+                rest.strip_prefix(':')
+                    .ok_or_else(|| invalid("unexpected characters after IPv6 address"))?,
+            ),
+        };
+        (format!("[{}]", host), port)
+    } else {
+        let (host, port) = match value.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (value, None),
+        };
+        let valid_host = !host.is_empty()
+            && host.len() <= 253
+            && host.split('.').all(|label| {
+                !label.is_empty()
+                    && label
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+            });
+        if !valid_host {
+            return Err(invalid("invalid host name"));
+        }
+        (host.to_ascii_lowercase(), port)
+    };
+
+    match port {
+        None => Ok(host),
+        Some(port) => match port.parse::<u16>() {
+            Ok(port) if port != 0 => Ok(format!("{}:{}", host, port)),
+            _ => Err(invalid("invalid port")),
+        },
+    }
+}
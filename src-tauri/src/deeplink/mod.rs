@@ -0,0 +1,9 @@
+/// 深度链接模块
+///
+/// 解析 `novacl://` 链接并路由到对应模块，安装类操作需要用户在界面上确认
+pub mod command;
+pub mod error;
+pub mod link;
+pub mod router;
+#[cfg(test)]
+mod test;
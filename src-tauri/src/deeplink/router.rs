@@ -0,0 +1,211 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
+use uuid::Uuid;
+
+use crate::deeplink::error::DeepLinkError;
+use crate::deeplink::link::{DeepLink, SCHEME};
+use crate::http::server::accept_callback;
+use crate::http::template::CallbackPage;
+
+/// 待确认请求的最大数量，超出时丢弃最早的请求
+pub const MAX_PENDING: usize = 16;
+
+/// 处理深度链接的模块
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Subsystem {
+    /// Modrinth 整合包与模组安装
+    Modrinth,
+    /// 服务器列表
+    Servers,
+    /// 账户登录
+    Auth,
+}
+
+impl DeepLink {
+    pub fn subsystem(&self) -> Subsystem {
+        match self {
+            DeepLink::InstallModpack { .. } | DeepLink::InstallMod { .. } => Subsystem::Modrinth,
+            DeepLink::AddServer { .. } => Subsystem::Servers,
+            DeepLink::LoginCallback { .. } => Subsystem::Auth,
+        }
+    }
+
+    /// 是否需要用户在界面上确认，登录回调由会话 state 校验
+    pub fn requires_confirmation(&self) -> bool {
+        !matches!(self, DeepLink::LoginCallback { .. })
+    }
+}
+
+/// 等待用户确认的深度链接，deeplink:request 事件
+#[derive(Serialize, Clone, Debug)]
+pub struct DeepLinkRequest {
+    pub id: String,
+    pub subsystem: Subsystem,
+    #[serde(flatten)]
+    pub link: DeepLink,
+    /// 收到时间（Unix 毫秒）
+    pub received_at: u64,
+}
+
+/// 路由结果
+#[derive(Debug)]
+pub enum Routed {
+    /// 等待用户确认
+    Confirm(DeepLinkRequest),
+    /// 交由登录模块处理的回调
+    LoginCallback(DeepLink),
+}
+
+/// 深度链接路由，保存等待确认的请求
+#[derive(Default)]
+pub struct DeepLinkRouter {
+    pending: Mutex<Vec<DeepLinkRequest>>,
+}
+
+impl DeepLinkRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn pending_guard(&self) -> std::sync::MutexGuard<'_, Vec<DeepLinkRequest>> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 路由已解析的链接，需要确认的链接加入待确认列表
+    ///
+    /// 与待确认列表中相同的链接不会重复加入
+    pub fn route(&self, link: DeepLink, received_at: u64) -> Routed {
+        if !link.requires_confirmation() {
+            return Routed::LoginCallback(link);
+        }
+
+        let mut pending = self.pending_guard();
+        if let Some(existing) = pending.iter().find(|request| request.link == link) {
+            return Routed::Confirm(existing.clone());
+        }
+        if pending.len() >= MAX_PENDING {
+            pending.remove(0);
+        }
+        let request = DeepLinkRequest {
+            id: Uuid::new_v4().to_string(),
+            subsystem: link.subsystem(),
+            link,
+            received_at,
+        };
+        pending.push(request.clone());
+        Routed::Confirm(request)
+    }
+
+    /// 等待确认的请求，按收到顺序排列
+    pub fn pending(&self) -> Vec<DeepLinkRequest> {
+        self.pending_guard().clone()
+    }
+
+    /// 移除待确认的请求并返回
+    pub fn resolve(&self, id: &str) -> Result<DeepLinkRequest, DeepLinkError> {
+        let mut pending = self.pending_guard();
+        let index = pending
+            .iter()
+            .position(|request| request.id == id)
+            .ok_or_else(|| DeepLinkError::UnknownRequest(id.to_string()))?;
+        Ok(pending.remove(index))
+    }
+}
+
+lazy_static::lazy_static! {
+    pub static ref DEEP_LINKS: DeepLinkRouter = DeepLinkRouter::new();
+}
+
+/// 将 `novacl://login-callback` 交给当前的 OAuth 会话
+async fn deliver_login_callback(
+    app_handle: &AppHandle,
+    link: DeepLink,
+) -> Result<(), DeepLinkError> {
+    let DeepLink::LoginCallback {
+        code,
+        state,
+        error,
+        error_description,
+    } = link
+    else {
+        return Ok(());
+    };
+
+    let query: HashMap<String, String> = [
+        ("code", code),
+        ("state", Some(state)),
+        ("error", error),
+        ("error_description", error_description),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| value.map(|value| (key.to_string(), value)))
+    .collect();
+
+    let path = format!("{}://login-callback", SCHEME);
+    match accept_callback(app_handle, &path, &query).await.page {
+        CallbackPage::Success => Ok(()),
+        // 提供方返回的错误已通过 oauth:error 通知前端
+        CallbackPage::Failure if query.contains_key("error") => Ok(()),
+        CallbackPage::Failure => Err(DeepLinkError::Rejected(
+            "state does not match the login session".to_string(),
+        )),
+        CallbackPage::Expired => Err(DeepLinkError::Rejected(
+            "no login session is waiting for a callback".to_string(),
+        )),
+    }
+}
+
+/// 解析并路由深度链接
+///
+/// 需要确认的链接 emit `deeplink:request`，失败时 emit `deeplink:error`
+pub async fn handle_url(
+    app_handle: &AppHandle,
+    url: &str,
+) -> Result<Option<DeepLinkRequest>, DeepLinkError> {
+    let result = match DeepLink::parse(url) {
+        Ok(link) => {
+            log::info!(target: "deeplink", "Received deep link: {}", link.action());
+            match DEEP_LINKS.route(link, chrono::Utc::now().timestamp_millis() as u64) {
+                Routed::Confirm(request) => {
+                    let _ = app_handle.emit("deeplink:request", &request);
+                    Ok(Some(request))
+                }
+                Routed::LoginCallback(link) => {
+                    deliver_login_callback(app_handle, link).await.map(|_| None)
+                }
+            }
+        }
+        Err(e) => Err(e),
+    };
+
+    if let Err(e) = &result {
+        log::warn!(target: "deeplink", "Rejected deep link: {}", e);
+        let _ = app_handle.emit("deeplink:error", e);
+    }
+    result
+}
+
+/// 处理启动参数中的深度链接，系统通过命令行参数传入链接
+///
+/// 应用已在运行时，单实例插件把第二个进程的参数转发到这里
+pub fn handle_launch_args(app_handle: &AppHandle, args: impl IntoIterator<Item = String>) {
+    let prefix = format!("{}:", SCHEME);
+    let urls: Vec<String> = args
+        .into_iter()
+        .filter(|arg| arg.to_ascii_lowercase().starts_with(&prefix))
+        .collect();
+    if urls.is_empty() {
+        return;
+    }
+
+    let app_handle = app_handle.clone();
+    tauri::async_runtime::spawn(async move {
+        for url in urls {
+            let _ = handle_url(&app_handle, &url).await;
+        }
+    });
+}
@@ -0,0 +1,150 @@
+use crate::deeplink::error::DeepLinkError;
+use crate::deeplink::link::{parse_server_address, DeepLink};
+use crate::deeplink::router::{DeepLinkRouter, Routed, Subsystem, MAX_PENDING};
+
+#[test]
+fn test_parse_deep_links() {
+    assert_eq!(
+        DeepLink::parse("novacl://install-modpack?project=AANobbMI&version=abc123").unwrap(),
+        DeepLink::InstallModpack {
+            project: "AANobbMI".to_string(),
+            version: Some("abc123".to_string()),
+        }
+    );
+    // novacl:<action> 与 novacl:///<action> 写法等价
+    for url in [
+        "novacl:install-mod?project=sodium&instance=Fabric%201.21",
+        "novacl:///install-mod/?project=sodium&instance=Fabric%201.21",
+        "NOVACL://Install-Mod?project=sodium&instance=Fabric+1.21",
+    ] {
+        assert_eq!(
+            DeepLink::parse(url).unwrap(),
+            DeepLink::InstallMod {
+                project: "sodium".to_string(),
+                version: None,
+                instance: Some("Fabric 1.21".to_string()),
+            },
+            "{}",
+            url
+        );
+    }
+    assert_eq!(
+        DeepLink::parse("novacl://add-server?address=Play.Example.com:25566&name=Example").unwrap(),
+        DeepLink::AddServer {
+            address: "play.example.com:25566".to_string(),
+            name: Some("Example".to_string()),
+        }
+    );
+
+    let callback = DeepLink::parse("novacl://login-callback?code=secret&state=s1").unwrap();
+    assert_eq!(callback.subsystem(), Subsystem::Auth);
+    assert!(!callback.requires_confirmation());
+    // 授权码不会发送给前端
+    let json = serde_json::to_value(&callback).unwrap();
+    assert_eq!(json["action"], "login-callback");
+    assert!(json.get("code").is_none());
+}
+
+#[test]
+fn test_reject_invalid_deep_links() {
+    let cases = [
+        ("not a url", "invalid_url"),
+        (
+            "https://example.com/install-mod?project=x",
+            "unsupported_scheme",
+        ),
+        ("novacl://uninstall?project=x", "unknown_action"),
+        ("novacl://install-mod/extra?project=x", "unknown_action"),
+        ("novacl://install-modpack", "missing_parameter"),
+        (
+            "novacl://install-modpack?project=../../etc",
+            "invalid_parameter",
+        ),
+        (
+            "novacl://install-mod?project=x&instance=../saves",
+            "invalid_parameter",
+        ),
+        (
+            "novacl://install-mod?project=x&instance=C:",
+            "invalid_parameter",
+        ),
+        ("novacl://add-server?address=host:0", "invalid_parameter"),
+        (
+            "novacl://add-server?address=bad%20host",
+            "invalid_parameter",
+        ),
+        ("novacl://login-callback?state=s1", "missing_parameter"),
+        ("novacl://login-callback?code=c", "missing_parameter"),
+    ];
+    for (url, kind) in cases {
+        let error = DeepLink::parse(url).unwrap_err();
+        assert_eq!(error.kind(), kind, "{}: {}", url, error);
+    }
+
+    // 错误信息不包含链接参数
+    let error = DeepLink::parse("novacl://login-callback?code=secret").unwrap_err();
+    assert!(!error.to_string().contains("secret"));
+}
+
+#[test]
+fn test_server_address() {
+    assert_eq!(
+        parse_server_address("mc.example.com").unwrap(),
+        "mc.example.com"
+    );
+    assert_eq!(
+        parse_server_address("127.0.0.1:25565").unwrap(),
+        "127.0.0.1:25565"
+    );
+    assert_eq!(parse_server_address("[::1]:25565").unwrap(), "[::1]:25565");
+    assert!(parse_server_address("[::1").is_err());
+    assert!(parse_server_address("::1").is_err());
+    assert!(parse_server_address("example..com").is_err());
+    assert!(parse_server_address("example.com:99999").is_err());
+}
+
+#[test]
+fn test_route_pending_requests() {
+    let router = DeepLinkRouter::new();
+    let link = DeepLink::parse("novacl://add-server?address=mc.example.com").unwrap();
+
+    let Routed::Confirm(request) = router.route(link.clone(), 1) else {
+        panic!("add-server should require confirmation");
+    };
+    assert_eq!(request.subsystem, Subsystem::Servers);
+    let json = serde_json::to_value(&request).unwrap();
+    assert_eq!(json["action"], "add-server");
+    assert_eq!(json["address"], "mc.example.com");
+    assert_eq!(json["subsystem"], "servers");
+
+    // 重复的链接复用已有请求
+    let Routed::Confirm(again) = router.route(link, 2) else {
+        panic!("add-server should require confirmation");
+    };
+    assert_eq!(again.id, request.id);
+    assert_eq!(router.pending().len(), 1);
+
+    let callback = DeepLink::parse("novacl://login-callback?code=c&state=s").unwrap();
+    assert!(matches!(
+        router.route(callback, 3),
+        Routed::LoginCallback(_)
+    ));
+    assert_eq!(router.pending().len(), 1);
+
+    assert_eq!(router.resolve(&request.id).unwrap().id, request.id);
+    assert!(matches!(
+        router.resolve(&request.id),
+        Err(DeepLinkError::UnknownRequest(_))
+    ));
+
+    for i in 0..MAX_PENDING + 2 {
+        let url = format!("novacl://install-modpack?project=p{}", i);
+        router.route(DeepLink::parse(&url).unwrap(), i as u64);
+    }
+    let pending = router.pending();
+    assert_eq!(pending.len(), MAX_PENDING);
+    assert!(matches!(
+        &pending[0].link,
+        DeepLink::InstallModpack { project, .. } if project == "p2"
+    ));
+}
@@ -0,0 +1,330 @@
+use std::fs::{self, File};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use chrono::Local;
+use serde::Serialize;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::diagnostic::redact::redact_toml;
+use crate::logging::viewer::list_log_files;
+use crate::system::ram::{get_ram_info, RamInfo};
+
+/// 最多打包的启动器日志文件数
+pub const MAX_LAUNCHER_LOGS: usize = 8;
+
+/// 每类崩溃报告最多打包的文件数
+pub const MAX_CRASH_REPORTS: usize = 3;
+
+/// 单个文件的最大大小，超出时只保留末尾
+pub const MAX_FILE_SIZE: u64 = 16 * 1024 * 1024;
+
+/// 诊断包格式版本
+const FORMAT_VERSION: u32 = 1;
+
+/// 打包的数据来源
+#[derive(Debug, Clone)]
+pub struct BundleSources {
+    /// 应用数据目录，包含 Config.toml 与 Profile.toml
+    pub app_data_dir: PathBuf,
+    pub logs_dir: Option<PathBuf>,
+    /// 选中的实例目录
+    pub instance_dir: Option<PathBuf>,
+}
+
+/// 诊断包中的文件
+#[derive(Serialize, Clone, Debug)]
+pub struct BundleEntry {
+    pub name: String,
+    pub size: u64,
+    /// 超出大小限制，只保留了末尾
+    pub truncated: bool,
+}
+
+/// 未能打包的文件
+#[derive(Serialize, Clone, Debug)]
+pub struct SkippedEntry {
+    pub name: String,
+    pub reason: String,
+}
+
+/// 系统信息
+#[derive(Serialize, Clone, Debug)]
+pub struct SystemFacts {
+    pub os: String,
+    pub os_family: String,
+    pub arch: String,
+    pub cpu_threads: Option<usize>,
+    pub ram: RamInfo,
+}
+
+impl SystemFacts {
+    pub fn collect() -> Self {
+        Self {
+            os: std::env::consts::OS.to_string(),
+            os_family: std::env::consts::FAMILY.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            cpu_threads: std::thread::available_parallelism()
+                .ok()
+                .map(|threads| threads.get()),
+            ram: get_ram_info(),
+        }
+    }
+}
+
+/// manifest.json
+#[derive(Serialize, Clone, Debug)]
+pub struct Manifest {
+    pub format_version: u32,
+    pub app_version: String,
+    /// RFC 3339 格式的本地时间
+    pub created_at: String,
+    pub instance: Option<String>,
+    pub files: Vec<BundleEntry>,
+    pub skipped: Vec<SkippedEntry>,
+}
+
+/// 诊断包创建结果
+#[derive(Serialize, Clone, Debug)]
+pub struct BundleSummary {
+    pub path: String,
+    pub size: u64,
+    pub files: Vec<BundleEntry>,
+    pub skipped: Vec<SkippedEntry>,
+}
+
+/// 写入中的诊断包
+struct BundleWriter {
+    zip: ZipWriter<File>,
+    files: Vec<BundleEntry>,
+    skipped: Vec<SkippedEntry>,
+}
+
+impl BundleWriter {
+    fn create(path: &Path) -> io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        Ok(Self {
+            zip: ZipWriter::new(File::create(path)?),
+            files: Vec::new(),
+            skipped: Vec::new(),
+        })
+    }
+
+    fn add_bytes(&mut self, name: &str, content: &[u8], truncated: bool) -> io::Result<()> {
+        // 已压缩的文件直接存储
+        let method = if name.ends_with(".gz") {
+            CompressionMethod::Stored
+        } else {
+            CompressionMethod::Deflated
+        };
+        self.zip.start_file(
+            name,
+            SimpleFileOptions::default().compression_method(method),
+        )?;
+        self.zip.write_all(content)?;
+        self.files.push(BundleEntry {
+            name: name.to_string(),
+            size: content.len() as u64,
+            truncated,
+        });
+        Ok(())
+    }
+
+    fn add_json<T: Serialize>(&mut self, name: &str, value: &T) -> io::Result<()> {
+        let content = serde_json::to_vec_pretty(value).map_err(io::Error::other)?;
+        self.add_bytes(name, &content, false)
+    }
+
+    /// 打包文件，超出大小限制时只保留末尾
+    fn add_file(&mut self, name: &str, path: &Path) -> io::Result<()> {
+        match read_tail(path, MAX_FILE_SIZE) {
+            Ok((content, truncated)) => self.add_bytes(name, &content, truncated),
+            Err(e) => {
+                self.skip(name, e);
+                Ok(())
+            }
+        }
+    }
+
+    /// 脱敏后打包配置文件
+    fn add_config(&mut self, name: &str, path: &Path, strip_tokens: bool) -> io::Result<()> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(e) => {
+                self.skip(name, e);
+                return Ok(());
+            }
+        };
+        match redact_toml(&content, strip_tokens) {
+            Ok(redacted) => self.add_bytes(name, redacted.as_bytes(), false),
+            Err(e) => {
+                self.skip(name, format!("failed to parse: {}", e));
+                Ok(())
+            }
+        }
+    }
+
+    fn skip(&mut self, name: &str, reason: impl ToString) {
+        self.skipped.push(SkippedEntry {
+            name: name.to_string(),
+            reason: reason.to_string(),
+        });
+    }
+}
+
+/// 读取文件，超出 `max` 时只读取末尾
+fn read_tail(path: &Path, max: u64) -> io::Result<(Vec<u8>, bool)> {
+    let mut file = File::open(path)?;
+    let len = file.metadata()?.len();
+    let truncated = len > max;
+    if truncated {
+        file.seek(SeekFrom::Start(len - max))?;
+    }
+    let mut content = Vec::new();
+    file.take(max).read_to_end(&mut content)?;
+    Ok((content, truncated))
+}
+
+/// 目录中文件名满足条件的文件，新的在前
+fn newest_files(dir: &Path, matches: impl Fn(&str) -> bool, limit: usize) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(PathBuf, SystemTime)> = entries
+        .flatten()
+        .filter(|entry| entry.file_name().to_str().is_some_and(&matches))
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok().filter(|m| m.is_file())?;
+            Some((
+                entry.path(),
+                metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            ))
+        })
+        .collect();
+    files.sort_by_key(|(_, modified)| std::cmp::Reverse(*modified));
+    files
+        .into_iter()
+        .take(limit)
+        .map(|(path, _)| path)
+        .collect()
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// 默认的诊断包路径：`<应用数据目录>/Diagnostics/NovaCL-Diagnostic-<时间>.zip`
+pub fn default_output(app_data_dir: &Path) -> PathBuf {
+    app_data_dir.join("Diagnostics").join(format!(
+        "NovaCL-Diagnostic-{}.zip",
+        Local::now().format("%Y%m%d-%H%M%S")
+    ))
+}
+
+/// 创建诊断包
+///
+/// 单个文件读取失败不会中断打包，记录在 manifest 的 skipped 中
+pub fn create_bundle(sources: &BundleSources, output: &Path) -> io::Result<BundleSummary> {
+    let mut bundle = BundleWriter::create(output)?;
+
+    // 启动器日志
+    if let Some(logs_dir) = &sources.logs_dir {
+        match list_log_files(logs_dir, None) {
+            Ok(logs) => {
+                for log in logs.into_iter().take(MAX_LAUNCHER_LOGS) {
+                    let name = format!("launcher-logs/{}", log.name);
+                    bundle.add_file(&name, &logs_dir.join(&log.name))?;
+                }
+            }
+            Err(e) => bundle.skip("launcher-logs", e),
+        }
+    }
+
+    // 配置文件
+    let app_data_dir = &sources.app_data_dir;
+    bundle.add_config(
+        "config/Config.toml",
+        &app_data_dir.join("Config.toml"),
+        false,
+    )?;
+    bundle.add_config(
+        "config/Profile.toml",
+        &app_data_dir.join("Profile.toml"),
+        true,
+    )?;
+    bundle.add_config(
+        "config/Collection.toml",
+        &app_data_dir.join("Collection.toml"),
+        false,
+    )?;
+
+    // 实例日志与崩溃报告
+    if let Some(instance_dir) = &sources.instance_dir {
+        bundle.add_config(
+            "instance/Instance.toml",
+            &instance_dir.join(".Nova").join("Instance.toml"),
+            false,
+        )?;
+        for log in ["latest.log", "debug.log"] {
+            let path = instance_dir.join("logs").join(log);
+            if path.is_file() {
+                bundle.add_file(&format!("instance/logs/{}", log), &path)?;
+            }
+        }
+        let crash_reports = newest_files(
+            &instance_dir.join("crash-reports"),
+            |name| name.ends_with(".txt"),
+            MAX_CRASH_REPORTS,
+        );
+        // JVM 崩溃时在游戏目录生成 hs_err_pid*.log
+        let jvm_crashes = newest_files(
+            instance_dir,
+            |name| name.starts_with("hs_err_pid") && name.ends_with(".log"),
+            MAX_CRASH_REPORTS,
+        );
+        for path in crash_reports {
+            bundle.add_file(
+                &format!("instance/crash-reports/{}", file_name(&path)),
+                &path,
+            )?;
+        }
+        for path in jvm_crashes {
+            bundle.add_file(&format!("instance/{}", file_name(&path)), &path)?;
+        }
+    }
+
+    bundle.add_json("system.json", &SystemFacts::collect())?;
+
+    let manifest = Manifest {
+        format_version: FORMAT_VERSION,
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        created_at: Local::now().to_rfc3339(),
+        instance: sources
+            .instance_dir
+            .as_deref()
+            .map(|dir| dir.to_string_lossy().into_owned()),
+        files: bundle.files.clone(),
+        skipped: bundle.skipped.clone(),
+    };
+    bundle.add_json("manifest.json", &manifest)?;
+
+    let BundleWriter {
+        zip,
+        files,
+        skipped,
+    } = bundle;
+    zip.finish().map_err(io::Error::other)?;
+
+    Ok(BundleSummary {
+        path: output.to_string_lossy().into_owned(),
+        size: fs::metadata(output)?.len(),
+        files,
+        skipped,
+    })
+}
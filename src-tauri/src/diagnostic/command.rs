@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+
+use crate::diagnostic::bundle::{create_bundle, default_output, BundleSources, BundleSummary};
+use crate::logging::logging::logs_dir;
+use crate::toml::manager::ConfigManager;
+
+/// 创建诊断包，用于附在问题反馈中
+///
+/// # 参数
+/// - `instance_path`: 需要一并打包日志与崩溃报告的实例目录
+/// - `output_path`: 输出的 zip 路径，默认为应用数据目录下的 Diagnostics
+#[tauri::command]
+pub async fn diagnostic_bundle_create(
+    instance_path: Option<String>,
+    output_path: Option<String>,
+) -> Result<BundleSummary, String> {
+    let app_data_dir = ConfigManager::instance().app_data_dir().to_path_buf();
+    let output = output_path
+        .map(PathBuf::from)
+        .unwrap_or_else(|| default_output(&app_data_dir));
+    let sources = BundleSources {
+        app_data_dir,
+        logs_dir: logs_dir(),
+        instance_dir: instance_path.map(PathBuf::from),
+    };
+
+    let summary = tokio::task::spawn_blocking(move || create_bundle(&sources, &output))
+        .await
+        .map_err(|e| format!("Failed to create diagnostic bundle: {}", e))?
+        .map_err(|e| format!("Failed to create diagnostic bundle: {}", e))?;
+
+    log::info!(
+        target: "diagnostic",
+        "Created diagnostic bundle {} ({} files, {} skipped)",
+        summary.path,
+        summary.files.len(),
+        summary.skipped.len()
+    );
+    Ok(summary)
+}
@@ -0,0 +1,8 @@
+/// 诊断包模块
+///
+/// 打包启动器日志、脱敏后的配置、实例日志与系统信息，便于附在问题反馈中
+pub mod bundle;
+pub mod command;
+pub mod redact;
+#[cfg(test)]
+mod test;
@@ -0,0 +1,80 @@
+use toml::Value;
+
+use crate::toml::crypto::decrypt_string;
+
+/// 替换敏感内容的占位符
+pub const REDACTED: &str = "<redacted>";
+
+/// 以 encrypted_field 存储的字段，密钥不可用时按名称识别
+const ENCRYPTED_KEYS: &[&str] = &[
+    "custom_proxy_account",
+    "custom_proxy_password",
+    "access_token",
+    "refresh_token",
+];
+
+/// 账户配置中直接删除的令牌
+const TOKEN_KEYS: &[&str] = &["access_token", "refresh_token"];
+
+/// 代理地址可能包含内网地址或账户信息
+fn is_proxy_uri(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    key.contains("proxy") && (key.ends_with("uri") || key.ends_with("url"))
+}
+
+/// 能用本机密钥解密的值即为加密字段
+fn is_encrypted(value: &str) -> bool {
+    decrypt_string(value).is_ok()
+}
+
+/// 打码加密字段与代理地址，空值保持为空以便区分是否设置
+pub fn redact_config(value: &mut Value) {
+    match value {
+        Value::Table(table) => {
+            for (key, value) in table.iter_mut() {
+                match value {
+                    Value::String(text)
+                        if !text.is_empty()
+                            && (ENCRYPTED_KEYS.contains(&key.as_str())
+                                || is_proxy_uri(key)
+                                || is_encrypted(text)) =>
+                    {
+                        *text = REDACTED.to_string();
+                    }
+                    other => redact_config(other),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_config),
+        _ => {}
+    }
+}
+
+/// 删除账户配置中的令牌，其余加密字段同样打码
+pub fn strip_profile_tokens(value: &mut Value) {
+    fn strip(value: &mut Value) {
+        match value {
+            Value::Table(table) => {
+                table.retain(|key, _| !TOKEN_KEYS.contains(&key));
+                table.iter_mut().for_each(|(_, value)| strip(value));
+            }
+            Value::Array(items) => items.iter_mut().for_each(strip),
+            _ => {}
+        }
+    }
+    strip(value);
+    redact_config(value);
+}
+
+/// 脱敏 TOML 配置文件内容
+///
+/// 无法解析时返回错误，不会原样输出
+pub fn redact_toml(content: &str, strip_tokens: bool) -> Result<String, String> {
+    let mut value: Value = toml::from_str(content).map_err(|e| e.to_string())?;
+    if strip_tokens {
+        strip_profile_tokens(&mut value);
+    } else {
+        redact_config(&mut value);
+    }
+    toml::to_string_pretty(&value).map_err(|e| e.to_string())
+}
@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+use std::path::{Component, Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+/// 登录页静态资源在打包资源中的目录
+pub const ASSETS_DIR: &str = "addons/auth/assets";
+
+/// 静态资源的缓存策略，过期后通过 ETag 重新验证
+pub const ASSET_CACHE_CONTROL: &str = "public, max-age=86400";
+
+/// 编译进程序的静态资源，资源目录缺失时使用
+#[cfg(feature = "embed-auth-assets")]
+const EMBEDDED_ASSETS: &[(&str, &[u8])] = &[
+    (
+        "background.webp",
+        include_bytes!("../../addons/auth/assets/background.webp"),
+    ),
+    (
+        "favicon.ico",
+        include_bytes!("../../addons/auth/assets/favicon.ico"),
+    ),
+    (
+        "logo.webp",
+        include_bytes!("../../addons/auth/assets/logo.webp"),
+    ),
+    (
+        "logodark.webp",
+        include_bytes!("../../addons/auth/assets/logodark.webp"),
+    ),
+];
+
+#[cfg(not(feature = "embed-auth-assets"))]
+const EMBEDDED_ASSETS: &[(&str, &[u8])] = &[];
+
+#[derive(Debug, Error)]
+pub enum AssetError {
+    #[error("Invalid asset path: {0}")]
+    InvalidPath(String),
+    #[error("Asset not found: {0}")]
+    NotFound(String),
+    #[error("Failed to read asset: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// 静态资源
+pub struct Asset {
+    pub content: Cow<'static, [u8]>,
+    pub mime: String,
+    /// 强校验 ETag，取内容 SHA-256 的前 16 字节
+    pub etag: String,
+}
+
+impl Asset {
+    fn new(requested: &str, content: Cow<'static, [u8]>) -> Self {
+        let digest = Sha256::digest(&content);
+        Self {
+            mime: mime_guess::from_path(requested)
+                .first_or_octet_stream()
+                .to_string(),
+            etag: format!("\"{}\"", hex::encode(&digest[..16])),
+            content,
+        }
+    }
+
+    /// If-None-Match 是否命中当前版本
+    pub fn matches(&self, if_none_match: &str) -> bool {
+        if_none_match
+            .split(',')
+            .map(|tag| tag.trim().trim_start_matches("W/"))
+            .any(|tag| tag == "*" || tag == self.etag)
+    }
+}
+
+/// 检查请求路径，只允许普通的相对路径分段
+fn relative_path(requested: &str) -> Result<PathBuf, AssetError> {
+    let invalid = || AssetError::InvalidPath(requested.to_string());
+    if requested.is_empty() || requested.contains(['\\', '\0']) {
+        return Err(invalid());
+    }
+
+    let path = Path::new(requested);
+    if !path
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(invalid());
+    }
+    Ok(path.to_path_buf())
+}
+
+/// 将请求路径解析到资源目录内的文件
+///
+/// 规范化后仍须位于资源目录内，防止通过符号链接等方式越界
+pub fn resolve_asset_path(root: &Path, requested: &str) -> Result<PathBuf, AssetError> {
+    let relative = relative_path(requested)?;
+    let not_found = |_| AssetError::NotFound(requested.to_string());
+
+    let root = root.canonicalize().map_err(not_found)?;
+    let path = root.join(relative).canonicalize().map_err(not_found)?;
+    if !path.starts_with(&root) {
+        return Err(AssetError::InvalidPath(requested.to_string()));
+    }
+    if !path.is_file() {
+        return Err(AssetError::NotFound(requested.to_string()));
+    }
+    Ok(path)
+}
+
+fn embedded_asset(requested: &str) -> Option<&'static [u8]> {
+    EMBEDDED_ASSETS
+        .iter()
+        .find(|(name, _)| *name == requested)
+        .map(|(_, content)| *content)
+}
+
+/// 读取静态资源，资源目录中不存在时回落到内置资源
+///
+/// - `root`: 资源目录，无法解析时传 `None`
+pub fn load_asset(root: Option<&Path>, requested: &str) -> Result<Asset, AssetError> {
+    relative_path(requested)?;
+
+    let resolved = match root {
+        Some(root) => resolve_asset_path(root, requested),
+        None => Err(AssetError::NotFound(requested.to_string())),
+    };
+    match resolved {
+        Ok(path) => Ok(Asset::new(requested, Cow::Owned(std::fs::read(path)?))),
+        Err(AssetError::NotFound(_)) => embedded_asset(requested)
+            .map(|content| Asset::new(requested, Cow::Borrowed(content)))
+            .ok_or_else(|| AssetError::NotFound(requested.to_string())),
+        Err(e) => Err(e),
+    }
+}
@@ -0,0 +1,220 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::{engine::general_purpose as b64, Engine as _};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// 当前 Unix 时间戳（秒）
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Cache-Control 解析结果
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CacheControl {
+    /// 禁止写入缓存
+    pub no_store: bool,
+    /// 每次使用前都必须重新验证
+    pub no_cache: bool,
+    /// 新鲜期（秒）
+    pub max_age: Option<u64>,
+}
+
+impl CacheControl {
+    /// 从响应头解析（头名称均为小写）
+    pub fn from_headers(headers: &HashMap<String, String>) -> Self {
+        let mut cc = CacheControl::default();
+
+        if let Some(value) = headers.get("cache-control") {
+            for directive in value.split(',') {
+                let directive = directive.trim().to_ascii_lowercase();
+                match directive.split_once('=') {
+                    Some(("max-age", secs)) => cc.max_age = secs.trim_matches('"').parse().ok(),
+                    _ if directive == "no-store" => cc.no_store = true,
+                    _ if directive == "no-cache" => cc.no_cache = true,
+                    _ => {}
+                }
+            }
+        }
+
+        // 没有 max-age 时回落到 Expires
+        if cc.max_age.is_none() {
+            if let Some(expires) = headers.get("expires") {
+                if let Ok(at) = chrono::DateTime::parse_from_rfc2822(expires) {
+                    let at = at.timestamp().max(0) as u64;
+                    cc.max_age = Some(at.saturating_sub(unix_now()));
+                }
+            }
+        }
+
+        cc
+    }
+}
+
+/// 不写入缓存的响应头：凭据与 Cookie 不能落盘，也不应在命中缓存时重放
+const UNCACHED_HEADERS: &[&str] = &[
+    "set-cookie",
+    "set-cookie2",
+    "authorization",
+    "proxy-authorization",
+    "www-authenticate",
+    "proxy-authenticate",
+];
+
+/// 去掉不写入缓存的响应头（头名称均为小写）
+fn cacheable_headers(headers: &HashMap<String, String>) -> HashMap<String, String> {
+    headers
+        .iter()
+        .filter(|(key, _)| !UNCACHED_HEADERS.contains(&key.as_str()))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect()
+}
+
+/// 缓存条目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// 请求 URL
+    pub url: String,
+    /// 状态码
+    pub status: u16,
+    /// 响应头
+    pub headers: HashMap<String, String>,
+    /// 响应体（Base64 编码）
+    pub body: String,
+    /// ETag
+    pub etag: Option<String>,
+    /// Last-Modified
+    pub last_modified: Option<String>,
+    /// 写入（或最后一次验证）时间（Unix 时间戳）
+    pub stored_at: u64,
+    /// 新鲜期（秒），None 表示每次都需要重新验证
+    pub max_age: Option<u64>,
+}
+
+impl CacheEntry {
+    /// 根据响应构建缓存条目，不可缓存时返回 None
+    pub fn from_response(
+        url: &str,
+        status: u16,
+        headers: &HashMap<String, String>,
+        body: &[u8],
+    ) -> Option<Self> {
+        if status != 200 {
+            return None;
+        }
+
+        let cc = CacheControl::from_headers(headers);
+        let etag = headers.get("etag").cloned();
+        let last_modified = headers.get("last-modified").cloned();
+
+        // 既没有新鲜期也没有验证器的响应缓存了也用不上
+        if cc.no_store || (cc.max_age.is_none() && etag.is_none() && last_modified.is_none()) {
+            return None;
+        }
+
+        Some(CacheEntry {
+            url: url.to_string(),
+            status,
+            headers: cacheable_headers(headers),
+            body: b64::STANDARD.encode(body),
+            etag,
+            last_modified,
+            stored_at: unix_now(),
+            max_age: if cc.no_cache { None } else { cc.max_age },
+        })
+    }
+
+    /// 是否仍在新鲜期内
+    pub fn is_fresh(&self, now: u64) -> bool {
+        match self.max_age {
+            Some(max_age) => now < self.stored_at.saturating_add(max_age),
+            None => false,
+        }
+    }
+
+    /// 用于重新验证的条件请求头
+    pub fn validators(&self) -> Vec<(&'static str, String)> {
+        let mut validators = Vec::new();
+        if let Some(etag) = &self.etag {
+            validators.push(("If-None-Match", etag.clone()));
+        }
+        if let Some(last_modified) = &self.last_modified {
+            validators.push(("If-Modified-Since", last_modified.clone()));
+        }
+        validators
+    }
+
+    /// 收到 304 后用新的响应头刷新条目
+    pub fn revalidated(&mut self, headers: &HashMap<String, String>) {
+        let cc = CacheControl::from_headers(headers);
+        for key in ["cache-control", "expires", "etag", "last-modified", "date"] {
+            if let Some(value) = headers.get(key) {
+                self.headers.insert(key.to_string(), value.clone());
+            }
+        }
+        if let Some(etag) = headers.get("etag") {
+            self.etag = Some(etag.clone());
+        }
+        if let Some(last_modified) = headers.get("last-modified") {
+            self.last_modified = Some(last_modified.clone());
+        }
+        if headers.contains_key("cache-control") || headers.contains_key("expires") {
+            self.max_age = if cc.no_cache { None } else { cc.max_age };
+        }
+        self.stored_at = unix_now();
+    }
+
+    /// 解码后的响应体
+    pub fn body_bytes(&self) -> Vec<u8> {
+        b64::STANDARD.decode(&self.body).unwrap_or_default()
+    }
+}
+
+/// 磁盘 HTTP 缓存
+pub struct HttpCache {
+    /// 缓存目录
+    dir: PathBuf,
+}
+
+impl HttpCache {
+    pub fn new(dir: PathBuf) -> Self {
+        HttpCache { dir }
+    }
+
+    /// 缓存条目路径：URL 的 SHA-256
+    fn entry_path(&self, url: &str) -> PathBuf {
+        let digest = Sha256::digest(url.as_bytes());
+        self.dir.join(format!("{}.json", hex::encode(digest)))
+    }
+
+    /// 读取缓存条目
+    pub fn load(&self, url: &str) -> Option<CacheEntry> {
+        let content = fs::read(self.entry_path(url)).ok()?;
+        let mut entry: CacheEntry = serde_json::from_slice(&content).ok()?;
+        // 旧版本写入的条目可能包含 Set-Cookie
+        entry.headers = cacheable_headers(&entry.headers);
+        // 防止哈希碰撞或手动篡改
+        (entry.url == url).then_some(entry)
+    }
+
+    /// 写入缓存条目
+    pub fn store(&self, entry: &CacheEntry) -> std::io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let content = serde_json::to_vec(entry)?;
+        fs::write(self.entry_path(&entry.url), content)
+    }
+
+    /// 清空缓存
+    pub fn clear(&self) -> std::io::Result<()> {
+        if self.dir.exists() {
+            fs::remove_dir_all(&self.dir)?;
+        }
+        Ok(())
+    }
+}
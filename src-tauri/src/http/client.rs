@@ -1,47 +1,219 @@
-use crate::http::types::{HttpError, HttpRequest, HttpResponse};
-use reqwest::{Client as ReqwestClient, Response};
+use crate::http::cache::{unix_now, CacheEntry, HttpCache};
+use crate::http::cookie::CookieJar;
+use crate::http::policy::{default_allowed_hosts, BlockedByPolicy, UrlPolicy};
+use crate::http::redact::redact_url;
+use crate::http::types::{HttpError, HttpErrorKind, HttpRequest, HttpResponse};
+use crate::toml::manager::ConfigManager;
+use reqwest::header::{HeaderMap, COOKIE, SET_COOKIE};
+use reqwest::{redirect, Client as ReqwestClient};
 use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+
+/// HTTP 客户端选项
+#[derive(Debug, Clone)]
+pub struct HttpClientOptions {
+    /// 缓存根目录，HTTP 缓存与 Cookie 均存放于此
+    pub cache_dir: PathBuf,
+    /// 启用 HTTP 缓存
+    pub use_cache: bool,
+    /// 启用 Cookie 持久化
+    pub use_cookie_jar: bool,
+    /// 允许持久化 Cookie 的域名
+    pub cookie_domains: Vec<String>,
+    /// 请求地址策略
+    pub url_policy: UrlPolicy,
+}
+
+impl HttpClientOptions {
+    /// 根据全局配置生成选项
+    pub fn from_config() -> Self {
+        let manager = ConfigManager::instance();
+        let cache_dir = manager.cache_dir();
+
+        match manager.load_global_config() {
+            Ok(config) => HttpClientOptions {
+                cache_dir,
+                use_cache: config.other.network.use_http_cache,
+                use_cookie_jar: config.other.network.use_cookie_jar,
+                cookie_domains: config.other.network.cookie_jar_domains,
+                url_policy: UrlPolicy::new(config.other.network.allowed_hosts),
+            },
+            Err(_) => HttpClientOptions {
+                cache_dir,
+                use_cache: true,
+                use_cookie_jar: false,
+                cookie_domains: Vec::new(),
+                url_policy: UrlPolicy::new(default_allowed_hosts()),
+            },
+        }
+    }
+}
 
 pub struct HttpClient {
     client: ReqwestClient,
+    cache: HttpCache,
+    cookie_jar: CookieJar,
+    use_cache: bool,
+    use_cookie_jar: bool,
+    url_policy: UrlPolicy,
+    /// 带请求 ID 的进行中请求
+    in_flight: Mutex<HashMap<String, Arc<Notify>>>,
 }
 
+/// 最大重定向次数
+const MAX_REDIRECTS: usize = 10;
+
 impl HttpClient {
     pub fn new() -> Self {
+        Self::with_options(HttpClientOptions::from_config())
+    }
+
+    pub fn with_options(options: HttpClientOptions) -> Self {
+        // 重定向目标同样需要通过地址策略检查
+        let redirect_policy = options.url_policy.clone();
         let client = ReqwestClient::builder()
+            .redirect(redirect::Policy::custom(move |attempt| {
+                if attempt.previous().len() >= MAX_REDIRECTS {
+                    attempt.error("Too many redirects")
+                } else if let Err(e) = redirect_policy.check(attempt.url()) {
+                    attempt.error(BlockedByPolicy(e))
+                } else {
+                    attempt.follow()
+                }
+            }))
             .build()
             .expect("Failed to create HTTP client");
-        HttpClient { client }
+        HttpClient {
+            client,
+            cache: HttpCache::new(options.cache_dir.join("http")),
+            cookie_jar: CookieJar::open(
+                options.cache_dir.join("cookies.json"),
+                options.cookie_domains,
+            ),
+            use_cache: options.use_cache,
+            use_cookie_jar: options.use_cookie_jar,
+            url_policy: options.url_policy,
+            in_flight: Mutex::new(HashMap::new()),
+        }
     }
 
-    async fn process_response(response: Response) -> Result<HttpResponse, HttpError> {
-        let status = response.status().as_u16();
+    /// 清空 HTTP 缓存
+    pub fn clear_cache(&self) -> std::io::Result<()> {
+        self.cache.clear()
+    }
+
+    /// 清空持久化的 Cookie
+    pub fn clear_cookies(&self) -> std::io::Result<()> {
+        self.cookie_jar.clear()
+    }
 
+    fn collect_headers(header_map: &HeaderMap) -> HashMap<String, String> {
         let mut headers = HashMap::new();
-        for (key, value) in response.headers() {
+        for (key, value) in header_map {
             if let Ok(value_str) = value.to_str() {
                 headers.insert(key.to_string(), value_str.to_string());
             }
         }
+        headers
+    }
 
-        let bytes = response.bytes().await?;
-        let body = match serde_json::from_slice(&bytes) {
+    fn build_response(status: u16, headers: HashMap<String, String>, bytes: &[u8]) -> HttpResponse {
+        let body = match serde_json::from_slice(bytes) {
             Ok(json) => Some(json),
             Err(_) => {
-                let text = String::from_utf8_lossy(&bytes).to_string();
+                let text = String::from_utf8_lossy(bytes).to_string();
                 Some(serde_json::Value::String(text))
             }
         };
 
-        Ok(HttpResponse {
+        HttpResponse {
             status,
             headers,
             body,
             text: None,
-        })
+        }
+    }
+
+    fn cached_response(entry: &CacheEntry) -> HttpResponse {
+        Self::build_response(entry.status, entry.headers.clone(), &entry.body_bytes())
+    }
+
+    /// 取消带请求 ID 的请求，请求不存在或已完成时返回 false
+    pub fn cancel(&self, request_id: &str) -> bool {
+        let in_flight = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+        match in_flight.get(request_id) {
+            Some(notify) => {
+                notify.notify_one();
+                true
+            }
+            None => false,
+        }
     }
 
+    /// 发送请求，带请求 ID 时可通过 `cancel` 取消
     pub async fn request(&self, req: &HttpRequest) -> Result<HttpResponse, HttpError> {
+        let Some(request_id) = &req.request_id else {
+            return self.send(req).await;
+        };
+
+        let notify = Arc::new(Notify::new());
+        {
+            let mut in_flight = self.in_flight.lock().unwrap_or_else(|e| e.into_inner());
+            if in_flight.contains_key(request_id) {
+                return Err(HttpError::new(
+                    HttpErrorKind::InvalidRequest,
+                    format!("Duplicate request id: {}", request_id),
+                ));
+            }
+            in_flight.insert(request_id.clone(), notify.clone());
+        }
+
+        let result = tokio::select! {
+            result = self.send(req) => result,
+            _ = notify.notified() => {
+                log::debug!(target: "http", "Request cancelled: {}", redact_url(&req.url));
+                Err(HttpError::cancelled())
+            }
+        };
+        self.in_flight
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(request_id);
+        result
+    }
+
+    async fn send(&self, req: &HttpRequest) -> Result<HttpResponse, HttpError> {
+        let parsed_url = self.url_policy.check_str(&req.url).map_err(|e| {
+            log::warn!(target: "http", "Blocked request: {}", e);
+            HttpError::new(
+                HttpErrorKind::BlockedByPolicy,
+                BlockedByPolicy(e).to_string(),
+            )
+        })?;
+
+        // 仅缓存不带凭据的 GET 请求
+        let cacheable = self.use_cache
+            && req.method.eq_ignore_ascii_case("GET")
+            && !req.headers.as_ref().is_some_and(|h| {
+                h.keys().any(|k| {
+                    k.eq_ignore_ascii_case("authorization") || k.eq_ignore_ascii_case("cookie")
+                })
+            });
+        let cached = if cacheable {
+            self.cache.load(&req.url)
+        } else {
+            None
+        };
+
+        if let Some(entry) = &cached {
+            if entry.is_fresh(unix_now()) {
+                log::trace!(target: "http", "Cache hit: {}", redact_url(&req.url));
+                return Ok(Self::cached_response(entry));
+            }
+        }
+
         let mut request_builder = match req.method.to_uppercase().as_str() {
             "GET" => self.client.get(&req.url),
             "POST" => self.client.post(&req.url),
@@ -51,10 +223,10 @@ impl HttpClient {
             "HEAD" => self.client.head(&req.url),
             "OPTIONS" => self.client.request(reqwest::Method::OPTIONS, &req.url),
             _ => {
-                return Err(HttpError {
-                    message: format!("Unsupported HTTP method: {}", req.method),
-                    code: None,
-                })
+                return Err(HttpError::new(
+                    HttpErrorKind::InvalidRequest,
+                    format!("Unsupported HTTP method: {}", req.method),
+                ))
             }
         };
 
@@ -93,8 +265,54 @@ impl HttpClient {
             }
         }
 
+        if let Some(entry) = &cached {
+            for (key, value) in entry.validators() {
+                request_builder = request_builder.header(key, value);
+            }
+        }
+
+        if self.use_cookie_jar {
+            if let Some(cookie) = self.cookie_jar.cookie_header(&parsed_url) {
+                request_builder = request_builder.header(COOKIE, cookie);
+            }
+        }
+
         let response = request_builder.send().await?;
-        Self::process_response(response).await
+        let status = response.status().as_u16();
+
+        if self.use_cookie_jar {
+            self.cookie_jar.store_set_cookies(
+                &parsed_url,
+                response
+                    .headers()
+                    .get_all(SET_COOKIE)
+                    .iter()
+                    .filter_map(|v| v.to_str().ok()),
+            );
+        }
+
+        let headers = Self::collect_headers(response.headers());
+        let bytes = response.bytes().await?;
+
+        // 304：缓存仍然有效，刷新后返回缓存内容
+        if let (304, Some(mut entry)) = (status, cached) {
+            log::trace!(target: "http", "Cache revalidated: {}", redact_url(&req.url));
+            entry.revalidated(&headers);
+            if let Err(e) = self.cache.store(&entry) {
+                log::warn!(target: "http", "Failed to update cache entry: {}", e);
+            }
+            return Ok(Self::cached_response(&entry));
+        }
+
+        if cacheable {
+            if let Some(entry) = CacheEntry::from_response(&req.url, status, &headers, &bytes) {
+                if let Err(e) = self.cache.store(&entry) {
+                    log::warn!(target: "http", "Failed to write cache entry: {}", e);
+                }
+            }
+        }
+
+        Ok(Self::build_response(status, headers, &bytes))
     }
 
     pub async fn get(
@@ -107,6 +325,7 @@ impl HttpClient {
             url: url.to_string(),
             headers,
             body: None,
+            request_id: None,
         })
         .await
     }
@@ -122,6 +341,7 @@ impl HttpClient {
             url: url.to_string(),
             headers,
             body,
+            request_id: None,
         })
         .await
     }
@@ -137,6 +357,7 @@ impl HttpClient {
             url: url.to_string(),
             headers,
             body,
+            request_id: None,
         })
         .await
     }
@@ -151,6 +372,7 @@ impl HttpClient {
             url: url.to_string(),
             headers,
             body: None,
+            request_id: None,
         })
         .await
     }
@@ -166,9 +388,28 @@ impl HttpClient {
             url: url.to_string(),
             headers,
             body,
+            request_id: None,
         })
         .await
     }
+
+    /// 下载二进制内容，不经过缓存
+    pub async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, HttpError> {
+        let parsed_url = self.url_policy.check_str(url).map_err(|e| {
+            log::warn!(target: "http", "Blocked request: {}", e);
+            HttpError::new(
+                HttpErrorKind::BlockedByPolicy,
+                BlockedByPolicy(e).to_string(),
+            )
+        })?;
+
+        let response = self.client.get(parsed_url).send().await?;
+        let status = response.status().as_u16();
+        if status >= 400 {
+            return Err(HttpError::from_status(status, None));
+        }
+        Ok(response.bytes().await?.to_vec())
+    }
 }
 
 impl Default for HttpClient {
@@ -1,18 +1,36 @@
 use crate::http::client::HttpClient;
-use crate::http::server::{get_server_status, start_server, stop_server};
+use crate::http::host;
+use crate::http::oauth::OAuthClientConfig;
+use crate::http::redact::{redact_opt_headers, redact_opt_json, redact_url};
+use crate::http::server::{
+    get_server_status, start_server, stop_server, DEFAULT_SESSION_TIMEOUT, OAUTH_SERVER,
+};
 use crate::http::types::{HttpError, HttpRequest, HttpResponse};
 use log::trace;
 use serde_json::json;
 use std::collections::HashMap;
+use std::time::Duration;
 
 // 创建一个全局的 HTTP 客户端实例
 lazy_static::lazy_static! {
     static ref HTTP_CLIENT: HttpClient = HttpClient::new();
 }
 
+/// 状态码为 4xx / 5xx 时返回 Status 错误
+fn checked(result: Result<HttpResponse, HttpError>) -> Result<HttpResponse, HttpError> {
+    result?.error_for_status()
+}
+
 #[tauri::command]
 pub async fn http_request(req: HttpRequest) -> Result<HttpResponse, HttpError> {
-    HTTP_CLIENT.request(&req).await
+    trace!(
+        "http_request: method={}, url={}, headers={:?}, body={:?}",
+        req.method,
+        redact_url(&req.url),
+        redact_opt_headers(&req.headers),
+        redact_opt_json(&req.body)
+    );
+    checked(HTTP_CLIENT.request(&req).await)
 }
 
 #[tauri::command]
@@ -20,8 +38,12 @@ pub async fn http_get(
     url: String,
     headers: Option<HashMap<String, String>>,
 ) -> Result<HttpResponse, HttpError> {
-    trace!("http_get: url={}, headers={:?}", url, headers);
-    HTTP_CLIENT.get(&url, headers).await
+    trace!(
+        "http_get: url={}, headers={:?}",
+        redact_url(&url),
+        redact_opt_headers(&headers)
+    );
+    checked(HTTP_CLIENT.get(&url, headers).await)
 }
 
 #[tauri::command]
@@ -32,11 +54,11 @@ pub async fn http_post(
 ) -> Result<HttpResponse, HttpError> {
     trace!(
         "http_post: url={}, headers={:?}, body={:?}",
-        url,
-        headers,
-        body
+        redact_url(&url),
+        redact_opt_headers(&headers),
+        redact_opt_json(&body)
     );
-    HTTP_CLIENT.post(&url, headers, body).await
+    checked(HTTP_CLIENT.post(&url, headers, body).await)
 }
 
 #[tauri::command]
@@ -47,11 +69,11 @@ pub async fn http_put(
 ) -> Result<HttpResponse, HttpError> {
     trace!(
         "http_put: url={}, headers={:?}, body={:?}",
-        url,
-        headers,
-        body
+        redact_url(&url),
+        redact_opt_headers(&headers),
+        redact_opt_json(&body)
     );
-    HTTP_CLIENT.put(&url, headers, body).await
+    checked(HTTP_CLIENT.put(&url, headers, body).await)
 }
 
 #[tauri::command]
@@ -59,8 +81,12 @@ pub async fn http_delete(
     url: String,
     headers: Option<HashMap<String, String>>,
 ) -> Result<HttpResponse, HttpError> {
-    trace!("http_delete: url={}, headers={:?}", url, headers);
-    HTTP_CLIENT.delete(&url, headers).await
+    trace!(
+        "http_delete: url={}, headers={:?}",
+        redact_url(&url),
+        redact_opt_headers(&headers)
+    );
+    checked(HTTP_CLIENT.delete(&url, headers).await)
 }
 
 #[tauri::command]
@@ -71,59 +97,131 @@ pub async fn http_patch(
 ) -> Result<HttpResponse, HttpError> {
     trace!(
         "http_patch: url={}, headers={:?}, body={:?}",
-        url,
-        headers,
-        body
+        redact_url(&url),
+        redact_opt_headers(&headers),
+        redact_opt_json(&body)
     );
-    HTTP_CLIENT.patch(&url, headers, body).await
+    checked(HTTP_CLIENT.patch(&url, headers, body).await)
 }
 
+/// 取消 http_request 发出的、带 request_id 的请求
+///
+/// # 返回值
+/// - 请求不存在或已完成时为 false
+#[tauri::command]
+pub fn http_cancel(request_id: String) -> bool {
+    HTTP_CLIENT.cancel(&request_id)
+}
+
+/// 清空 HTTP 缓存
+#[tauri::command]
+pub fn http_cache_clear() -> Result<(), String> {
+    HTTP_CLIENT
+        .clear_cache()
+        .map_err(|e| format!("Failed to clear HTTP cache: {}", e))
+}
+
+/// 清空持久化的 Cookie
+#[tauri::command]
+pub fn http_cookie_clear() -> Result<(), String> {
+    HTTP_CLIENT
+        .clear_cookies()
+        .map_err(|e| format!("Failed to clear cookies: {}", e))
+}
+
+/// 启动 OAuth 回调服务器
+///
+/// - `port`: 首选端口，传 0 由系统分配
+/// - `fallback_range`: 首选端口被占用时依次尝试的端口范围 [起, 止]
 #[tauri::command]
 pub async fn http_server_start(
     app_handle: tauri::AppHandle,
     port: u16,
-    lang: Option<String>, // 可选语言参数，前端不传则默认 "en"
+    fallback_range: Option<(u16, u16)>,
+    lang: Option<String>, // 可选语言参数，前端不传则使用配置中的语言
+    timeout_secs: Option<u64>,
 ) -> Result<serde_json::Value, String> {
-    let lang = lang.unwrap_or_else(|| "en".to_string());
+    let timeout = timeout_secs
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_SESSION_TIMEOUT);
+    let fallback = fallback_range.map(|(start, end)| start..=end);
+    let oauth = OAuthClientConfig::from_env()?;
 
     // 启动服务器
-    match start_server(app_handle, port, lang).await {
-        Ok(_) => {
-            // start_server 成功后会返回一个无用的 oneshot::Receiver
-            // 我们不需要等待它，直接返回成功
-            Ok(json!({
-                "status": "ok",
-                "message": "OAuth server started",
-                "port": port
-            }))
-        }
+    match start_server(app_handle, port, fallback, lang, oauth, timeout).await {
+        Ok(started) => Ok(json!({
+            "status": "ok",
+            "message": "OAuth server started",
+            "port": started.port,
+            "authorize_url": started.authorize_url
+        })),
         Err(e) => {
-            // 启动失败（比如端口被占用、服务器已在运行等）
+            // 启动失败（比如端口均被占用、服务器已在运行等）
             Err(format!("Failed to start server: {}", e))
         }
     }
 }
 
+/// 停止本地服务器
+///
+/// - `name`: 服务器名称，默认为 OAuth 回调服务器
 #[tauri::command]
-pub async fn http_server_stop() -> Result<serde_json::Value, String> {
-    match stop_server().await {
-        Ok(()) => Ok(json!({
-            "status": "ok",
-            "message": "OAuth server stopped successfully"
-        })),
-        Err(e) => Err(e), // stop_server 已经返回友好错误信息
+pub async fn http_server_stop(name: Option<String>) -> Result<serde_json::Value, String> {
+    match name.as_deref() {
+        None | Some(OAUTH_SERVER) => stop_server().await?,
+        Some(name) => host::stop(name).await?,
     }
+    Ok(json!({
+        "status": "ok",
+        "message": "Server stopped successfully"
+    }))
 }
 
+/// 本地服务器状态
+///
+/// `status`/`port` 对应 OAuth 回调服务器，`servers` 包含所有运行中的服务器
 #[tauri::command]
 pub async fn http_server_status() -> Result<serde_json::Value, String> {
+    let servers = host::statuses();
     match get_server_status().await {
         Some(port) => Ok(json!({
             "status": "running",
-            "port": port
+            "port": port,
+            "servers": servers
         })),
         None => Ok(json!({
-            "status": "stopped"
+            "status": "stopped",
+            "servers": servers
         })),
     }
 }
+
+/// 以已注册的路由组启动命名的本地服务器
+///
+/// - `groups`: 路由组名称，可通过 `http_route_groups` 获取
+/// - `fallback_range`: 首选端口被占用时依次尝试的端口范围 [起, 止]
+#[tauri::command]
+pub async fn http_host_start(
+    name: String,
+    groups: Vec<String>,
+    port: u16,
+    fallback_range: Option<(u16, u16)>,
+) -> Result<serde_json::Value, String> {
+    // OAuth 回调服务器需要授权会话，只能通过 http_server_start 启动
+    if name == OAUTH_SERVER {
+        return Err(format!("Server name {} is reserved", name));
+    }
+    let fallback = fallback_range.map(|(start, end)| start..=end);
+    let port = host::start(&name, &groups, port, fallback).await?;
+    Ok(json!({
+        "status": "ok",
+        "message": "Server started",
+        "port": port
+    }))
+}
+
+/// 已注册的路由组
+#[tauri::command]
+pub async fn http_route_groups() -> Result<Vec<String>, String> {
+    Ok(host::route_groups())
+}
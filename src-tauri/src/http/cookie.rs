@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::http::cache::unix_now;
+
+/// 持久化的单个 Cookie
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoredCookie {
+    pub name: String,
+    pub value: String,
+    /// 所属域名（不含前导点）
+    pub domain: String,
+    pub path: String,
+    /// 过期时间（Unix 时间戳），None 表示会话 Cookie
+    pub expires_at: Option<u64>,
+    pub secure: bool,
+    /// 未指定 Domain 属性时仅匹配完全相同的主机
+    pub host_only: bool,
+}
+
+impl StoredCookie {
+    /// 解析 Set-Cookie 头
+    pub fn parse(header: &str, url: &Url, now: u64) -> Option<Self> {
+        let host = url.host_str()?.to_ascii_lowercase();
+        let mut parts = header.split(';');
+
+        let (name, value) = parts.next()?.split_once('=')?;
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+
+        let mut cookie = StoredCookie {
+            name: name.to_string(),
+            value: value.trim().to_string(),
+            domain: host.clone(),
+            path: default_path(url),
+            expires_at: None,
+            secure: false,
+            host_only: true,
+        };
+
+        let mut max_age = None;
+        for attr in parts {
+            let (key, val) = match attr.split_once('=') {
+                Some((k, v)) => (k.trim().to_ascii_lowercase(), v.trim()),
+                None => (attr.trim().to_ascii_lowercase(), ""),
+            };
+            match key.as_str() {
+                "domain" if !val.is_empty() => {
+                    let domain = val.trim_start_matches('.').to_ascii_lowercase();
+                    // 不接受为其他站点设置的 Cookie
+                    if !domain_matches(&host, &domain) {
+                        return None;
+                    }
+                    cookie.domain = domain;
+                    cookie.host_only = false;
+                }
+                "path" if val.starts_with('/') => cookie.path = val.to_string(),
+                "max-age" => max_age = val.parse::<i64>().ok(),
+                "expires" => {
+                    if let Ok(at) = chrono::DateTime::parse_from_rfc2822(val) {
+                        cookie.expires_at = Some(at.timestamp().max(0) as u64);
+                    }
+                }
+                "secure" => cookie.secure = true,
+                _ => {}
+            }
+        }
+
+        // Max-Age 优先于 Expires
+        if let Some(secs) = max_age {
+            cookie.expires_at = Some(if secs <= 0 { 0 } else { now + secs as u64 });
+        }
+
+        Some(cookie)
+    }
+
+    fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+
+    fn matches(&self, url: &Url, now: u64) -> bool {
+        let Some(host) = url.host_str() else {
+            return false;
+        };
+        let host = host.to_ascii_lowercase();
+
+        let domain_ok = if self.host_only {
+            host == self.domain
+        } else {
+            domain_matches(&host, &self.domain)
+        };
+        let path = url.path();
+        let path_ok = path == self.path
+            || (path.starts_with(&self.path)
+                && (self.path.ends_with('/') || path[self.path.len()..].starts_with('/')));
+
+        domain_ok && path_ok && (!self.secure || url.scheme() == "https") && !self.is_expired(now)
+    }
+}
+
+/// host 是否属于 domain（相同或为其子域名）
+fn domain_matches(host: &str, domain: &str) -> bool {
+    host == domain || host.ends_with(&format!(".{}", domain))
+}
+
+/// RFC 6265 默认路径
+fn default_path(url: &Url) -> String {
+    let path = url.path();
+    match path.rfind('/') {
+        Some(0) | None => "/".to_string(),
+        Some(idx) => path[..idx].to_string(),
+    }
+}
+
+type SharedCookies = Arc<Mutex<Vec<StoredCookie>>>;
+
+lazy_static::lazy_static! {
+    /// 按存储文件共享的 Cookie，避免多个客户端各自持有副本并互相覆盖
+    static ref COOKIE_STORES: Mutex<HashMap<PathBuf, SharedCookies>> = Mutex::new(HashMap::new());
+}
+
+/// 读取存储文件，同一文件在进程内只读取一次
+fn shared_cookies(path: &PathBuf) -> SharedCookies {
+    let mut stores = COOKIE_STORES.lock().unwrap_or_else(|e| e.into_inner());
+    stores
+        .entry(path.clone())
+        .or_insert_with(|| {
+            let now = unix_now();
+            let cookies: Vec<StoredCookie> = fs::read(path)
+                .ok()
+                .and_then(|content| serde_json::from_slice(&content).ok())
+                .unwrap_or_default();
+            Arc::new(Mutex::new(
+                cookies.into_iter().filter(|c| !c.is_expired(now)).collect(),
+            ))
+        })
+        .clone()
+}
+
+/// 按域名白名单持久化的 Cookie 容器
+///
+/// 同一存储文件的容器共享 Cookie，白名单由各容器分别决定
+pub struct CookieJar {
+    /// 存储文件
+    path: PathBuf,
+    /// 允许持久化的域名（含子域名）
+    domains: Vec<String>,
+    cookies: SharedCookies,
+}
+
+impl CookieJar {
+    /// 打开（或新建）Cookie 容器
+    pub fn open(path: PathBuf, domains: Vec<String>) -> Self {
+        CookieJar {
+            cookies: shared_cookies(&path),
+            path,
+            domains: domains
+                .into_iter()
+                .map(|d| d.trim().trim_start_matches('.').to_ascii_lowercase())
+                .filter(|d| !d.is_empty())
+                .collect(),
+        }
+    }
+
+    /// 该 URL 是否在白名单内
+    pub fn is_allowed(&self, url: &Url) -> bool {
+        url.host_str()
+            .map(|host| {
+                let host = host.to_ascii_lowercase();
+                self.domains.iter().any(|d| domain_matches(&host, d))
+            })
+            .unwrap_or(false)
+    }
+
+    /// 生成请求用的 Cookie 头
+    pub fn cookie_header(&self, url: &Url) -> Option<String> {
+        if !self.is_allowed(url) {
+            return None;
+        }
+
+        let now = unix_now();
+        let cookies = self.cookies.lock().ok()?;
+        let pairs: Vec<String> = cookies
+            .iter()
+            .filter(|c| c.matches(url, now))
+            .map(|c| format!("{}={}", c.name, c.value))
+            .collect();
+
+        (!pairs.is_empty()).then(|| pairs.join("; "))
+    }
+
+    /// 保存响应中的 Set-Cookie
+    pub fn store_set_cookies<'a>(&self, url: &Url, headers: impl Iterator<Item = &'a str>) {
+        if !self.is_allowed(url) {
+            return;
+        }
+
+        let now = unix_now();
+        let Ok(mut cookies) = self.cookies.lock() else {
+            return;
+        };
+
+        let mut changed = false;
+        for header in headers {
+            let Some(cookie) = StoredCookie::parse(header, url, now) else {
+                continue;
+            };
+            cookies.retain(|c| {
+                !(c.name == cookie.name && c.domain == cookie.domain && c.path == cookie.path)
+            });
+            if !cookie.is_expired(now) {
+                cookies.push(cookie);
+            }
+            changed = true;
+        }
+
+        if changed {
+            if let Err(e) = self.persist(&cookies) {
+                log::warn!(target: "http", "Failed to persist cookies: {}", e);
+            }
+        }
+    }
+
+    /// 清空所有 Cookie
+    pub fn clear(&self) -> std::io::Result<()> {
+        if let Ok(mut cookies) = self.cookies.lock() {
+            cookies.clear();
+        }
+        if self.path.exists() {
+            fs::remove_file(&self.path)?;
+        }
+        Ok(())
+    }
+
+    fn persist(&self, cookies: &[StoredCookie]) -> std::io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // 会话 Cookie 不落盘
+        let persistent: Vec<&StoredCookie> =
+            cookies.iter().filter(|c| c.expires_at.is_some()).collect();
+        fs::write(&self.path, serde_json::to_vec_pretty(&persistent)?)
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::{HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::ops::RangeInclusive;
+use std::sync::{Mutex, RwLock};
+use std::time::Instant;
+
+use axum::http::StatusCode;
+use axum::response::{Html, IntoResponse};
+use axum::routing::MethodRouter;
+use axum::Router;
+use serde::Serialize;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio::task;
+
+type ServerHandle = task::JoinHandle<Result<(), Box<dyn std::error::Error + Send + Sync>>>;
+
+/// 路由组工厂，每次启动服务器时调用，需要的状态（如 AppHandle）在注册时捕获
+pub type RouteFactory = Box<dyn Fn() -> RouteGroup + Send + Sync>;
+
+lazy_static::lazy_static! {
+    static ref ROUTE_GROUPS: RwLock<HashMap<String, RouteFactory>> = RwLock::new(HashMap::new());
+    static ref SERVERS: Mutex<HashMap<String, RunningServer>> = Mutex::new(HashMap::new());
+}
+
+/// 一组路由，由子系统提供并挂载到本地服务器
+pub struct RouteGroup {
+    name: String,
+    paths: Vec<String>,
+    router: Router,
+}
+
+impl RouteGroup {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            paths: Vec::new(),
+            router: Router::new(),
+        }
+    }
+
+    /// 添加路由，路径会出现在服务器状态中
+    pub fn route(mut self, path: &str, method_router: MethodRouter) -> Self {
+        self.paths.push(path.to_string());
+        self.router = self.router.route(path, method_router);
+        self
+    }
+}
+
+/// 已挂载的路由
+#[derive(Serialize, Clone, Debug)]
+pub struct RouteInfo {
+    pub group: String,
+    pub path: String,
+}
+
+/// 服务器运行状态
+#[derive(Serialize, Clone, Debug)]
+pub struct ServerStatus {
+    pub name: String,
+    pub port: u16,
+    pub routes: Vec<RouteInfo>,
+    /// 启动时间（Unix 毫秒）
+    pub started_at: u64,
+    pub uptime_secs: u64,
+}
+
+struct RunningServer {
+    handle: ServerHandle,
+    shutdown_tx: mpsc::Sender<()>,
+    addr: SocketAddr,
+    routes: Vec<RouteInfo>,
+    started_at: u64,
+    started: Instant,
+}
+
+impl RunningServer {
+    fn status(&self, name: &str) -> ServerStatus {
+        ServerStatus {
+            name: name.to_string(),
+            port: self.addr.port(),
+            routes: self.routes.clone(),
+            started_at: self.started_at,
+            uptime_secs: self.started.elapsed().as_secs(),
+        }
+    }
+}
+
+/// 注册路由组，同名时覆盖，各子系统在应用启动时调用
+pub fn register_route_group<F>(name: &str, factory: F)
+where
+    F: Fn() -> RouteGroup + Send + Sync + 'static,
+{
+    if let Ok(mut groups) = ROUTE_GROUPS.write() {
+        groups.insert(name.to_string(), Box::new(factory));
+    }
+}
+
+/// 已注册的路由组名称
+pub fn route_groups() -> Vec<String> {
+    let mut names: Vec<String> = ROUTE_GROUPS
+        .read()
+        .map(|groups| groups.keys().cloned().collect())
+        .unwrap_or_default();
+    names.sort();
+    names
+}
+
+fn servers() -> std::sync::MutexGuard<'static, HashMap<String, RunningServer>> {
+    SERVERS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+async fn not_found_handler() -> impl IntoResponse {
+    (
+        StatusCode::NOT_FOUND,
+        Html("<html><body><h1>404 Not Found</h1></body></html>"),
+    )
+}
+
+// ================== Listener ==================
+
+/// 回环地址，按顺序尝试
+const LOOPBACK: [IpAddr; 2] = [
+    IpAddr::V4(Ipv4Addr::LOCALHOST),
+    IpAddr::V6(Ipv6Addr::LOCALHOST),
+];
+
+/// 在回环地址上绑定端口，没有 IPv4 回环时回落到 [::1]
+async fn bind_loopback(port: u16) -> std::io::Result<TcpListener> {
+    bind_first(&LOOPBACK, port).await
+}
+
+/// 依次尝试各地址，端口被占用时直接返回，不再尝试其他地址
+pub(crate) async fn bind_first(addrs: &[IpAddr], port: u16) -> std::io::Result<TcpListener> {
+    let mut last_error = None;
+    for addr in addrs {
+        match TcpListener::bind((*addr, port)).await {
+            Ok(listener) => return Ok(listener),
+            Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => return Err(e),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error.unwrap_or_else(|| std::io::Error::other("No address to bind")))
+}
+
+/// 依次尝试首选端口与备用端口范围
+/// 端口为 0 时由系统分配
+async fn bind_with_fallback(
+    port: u16,
+    fallback: Option<RangeInclusive<u16>>,
+) -> Result<TcpListener, String> {
+    let candidates = std::iter::once(port).chain(
+        fallback
+            .into_iter()
+            .flatten()
+            .filter(move |p| *p != port && *p != 0),
+    );
+
+    let mut last_error = None;
+    for candidate in candidates {
+        match bind_loopback(candidate).await {
+            Ok(listener) => return Ok(listener),
+            Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => {
+                log::debug!(target: "http", "Port {} is busy, trying next", candidate);
+                last_error = Some(e);
+            }
+            Err(e) => return Err(e.to_string()),
+        }
+    }
+
+    Err(last_error
+        .map(|e| e.to_string())
+        .unwrap_or_else(|| "No port available".to_string()))
+}
+
+// ================== Public API ==================
+
+/// 以已注册的路由组启动命名服务器，仅监听回环地址
+///
+/// 返回实际监听的端口
+pub async fn start(
+    name: &str,
+    groups: &[String],
+    port: u16,
+    fallback: Option<RangeInclusive<u16>>,
+) -> Result<u16, String> {
+    let groups = {
+        let registry = ROUTE_GROUPS
+            .read()
+            .map_err(|_| "Route registry is unavailable".to_string())?;
+        groups
+            .iter()
+            .map(|group| {
+                registry
+                    .get(group)
+                    .map(|factory| factory())
+                    .ok_or_else(|| format!("Unknown route group: {}", group))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    start_groups(name, groups, port, fallback).await
+}
+
+/// 以给定的路由组启动命名服务器，各服务器相互独立
+pub async fn start_groups(
+    name: &str,
+    groups: Vec<RouteGroup>,
+    port: u16,
+    fallback: Option<RangeInclusive<u16>>,
+) -> Result<u16, String> {
+    if is_running(name) {
+        return Err(format!("Server {} is already running", name));
+    }
+
+    // 合并前检查路径冲突，axum 遇到重复路由会 panic
+    let mut seen = HashSet::new();
+    let mut routes = Vec::new();
+    let mut app = Router::new();
+    for group in groups {
+        for path in &group.paths {
+            if !seen.insert(path.clone()) {
+                return Err(format!(
+                    "Route {} of group {} conflicts with another group",
+                    path, group.name
+                ));
+            }
+            routes.push(RouteInfo {
+                group: group.name.clone(),
+                path: path.clone(),
+            });
+        }
+        app = app.merge(group.router);
+    }
+
+    let app = app.fallback(not_found_handler);
+
+    let listener = bind_with_fallback(port, fallback).await?;
+    let addr = listener.local_addr().map_err(|e| e.to_string())?;
+
+    let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+    let server_name = name.to_string();
+    let handle = task::spawn(async move {
+        axum::serve(listener, app)
+            .with_graceful_shutdown(async move {
+                let _ = shutdown_rx.recv().await;
+            })
+            .await
+            .map_err(|e| {
+                log::error!(target: "http", "Local server {} failed: {}", server_name, e);
+                e.into()
+            })
+    });
+
+    let mut servers = servers();
+    // 绑定端口期间可能已有同名服务器启动
+    if servers.contains_key(name) {
+        let _ = shutdown_tx.try_send(());
+        return Err(format!("Server {} is already running", name));
+    }
+    servers.insert(
+        name.to_string(),
+        RunningServer {
+            handle,
+            shutdown_tx,
+            addr,
+            routes,
+            started_at: chrono::Utc::now().timestamp_millis() as u64,
+            started: Instant::now(),
+        },
+    );
+    log::info!(target: "http", "Local server {} listening on {}", name, addr);
+    Ok(addr.port())
+}
+
+/// 停止服务器并等待其退出
+pub async fn stop(name: &str) -> Result<(), String> {
+    let server = servers()
+        .remove(name)
+        .ok_or_else(|| format!("Server {} is not running", name))?;
+
+    let _ = server.shutdown_tx.send(()).await;
+    match server.handle.await {
+        Ok(Ok(())) => {
+            log::info!(target: "http", "Local server {} stopped", name);
+            Ok(())
+        }
+        Ok(Err(e)) => Err(format!("Server {} exited with error: {}", name, e)),
+        // panic 已由 panic hook 记录
+        Err(e) if e.is_panic() => Err(format!("Server {} panicked", name)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// 通知服务器关闭，不等待其退出，可在 handler 内调用
+pub fn stop_detached(name: &str) {
+    if let Some(server) = servers().remove(name) {
+        let _ = server.shutdown_tx.try_send(());
+        log::info!(target: "http", "Local server {} is shutting down", name);
+    }
+}
+
+pub fn is_running(name: &str) -> bool {
+    servers().contains_key(name)
+}
+
+/// 单个服务器的状态
+pub fn status(name: &str) -> Option<ServerStatus> {
+    servers().get(name).map(|server| server.status(name))
+}
+
+/// 服务器实际监听的地址，IPv4 回环不可用时为 [::1]
+pub fn local_addr(name: &str) -> Option<SocketAddr> {
+    servers().get(name).map(|server| server.addr)
+}
+
+/// 所有运行中服务器的状态，按名称排序
+pub fn statuses() -> Vec<ServerStatus> {
+    let mut statuses: Vec<ServerStatus> = servers()
+        .iter()
+        .map(|(name, server)| server.status(name))
+        .collect();
+    statuses.sort_by(|a, b| a.name.cmp(&b.name));
+    statuses
+}
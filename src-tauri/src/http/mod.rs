@@ -1,4 +1,14 @@
+pub mod assets;
+pub mod cache;
 pub mod client;
 pub mod command;
+pub mod cookie;
+pub mod host;
+pub mod oauth;
+pub mod policy;
+pub mod redact;
 pub mod server;
+pub mod template;
+#[cfg(test)]
+mod test;
 pub mod types;
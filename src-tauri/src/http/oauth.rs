@@ -0,0 +1,138 @@
+use std::net::SocketAddr;
+use std::sync::Mutex;
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+use crate::env::command::get_env_var;
+
+/// 微软授权端点
+pub const MS_AUTHORIZE_URL: &str =
+    "https://login.microsoftonline.com/consumers/oauth2/v2.0/authorize";
+
+/// 微软令牌端点
+pub const MS_TOKEN_URL: &str = "https://login.microsoftonline.com/consumers/oauth2/v2.0/token";
+
+/// 微软设备代码端点
+pub const MS_DEVICE_CODE_URL: &str =
+    "https://login.microsoftonline.com/consumers/oauth2/v2.0/devicecode";
+
+/// 请求的权限范围
+pub const MS_SCOPE: &str = "XboxLive.signin offline_access";
+
+/// 回调服务器上的授权回调路径
+pub const CALLBACK_PATH: &str = "/oauth/callback";
+
+/// RFC 7636 允许的 code_verifier 字符
+const UNRESERVED: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-._~";
+
+lazy_static::lazy_static! {
+    /// 通过 state 校验、等待换取令牌的授权码
+    static ref PENDING_GRANT: Mutex<Option<AuthorizationGrant>> = Mutex::new(None);
+}
+
+/// OAuth 客户端配置，从环境变量读取
+#[derive(Debug, Clone)]
+pub struct OAuthClientConfig {
+    pub client_id: String,
+    pub client_secret: Option<String>,
+}
+
+impl OAuthClientConfig {
+    pub fn from_env() -> Result<Self, String> {
+        let client_id = get_env_var("OAUTH_MS_CLIENT_ID")
+            .filter(|id| !id.is_empty())
+            .ok_or("OAUTH_MS_CLIENT_ID is not set")?;
+
+        Ok(OAuthClientConfig {
+            client_id,
+            client_secret: get_env_var("OAUTH_MS_CLIENT_SECRET").filter(|s| !s.is_empty()),
+        })
+    }
+
+    /// 回调服务器实际监听地址下的回调地址，例如 `http://127.0.0.1:36993/oauth/callback`
+    ///
+    /// 使用 IP 而不是 localhost，避免浏览器把 localhost 解析到未监听的协议族
+    pub fn redirect_uri(&self, addr: SocketAddr) -> String {
+        format!("http://{}{}", addr, CALLBACK_PATH)
+    }
+}
+
+/// PKCE 参数，verifier 只在后端保存
+#[derive(Debug, Clone)]
+pub struct Pkce {
+    pub code_verifier: String,
+    pub code_challenge: String,
+}
+
+impl Pkce {
+    pub fn generate() -> Self {
+        let code_verifier = random_string(64);
+        let code_challenge = challenge_for(&code_verifier);
+        Pkce {
+            code_verifier,
+            code_challenge,
+        }
+    }
+}
+
+/// S256：BASE64URL(SHA256(verifier))
+pub fn challenge_for(code_verifier: &str) -> String {
+    URL_SAFE_NO_PAD.encode(Sha256::digest(code_verifier.as_bytes()))
+}
+
+/// 生成随机的 state
+pub fn generate_state() -> String {
+    random_string(32)
+}
+
+fn random_string(len: usize) -> String {
+    let mut rng = rand::thread_rng();
+    (0..len)
+        .map(|_| UNRESERVED[rng.gen_range(0..UNRESERVED.len())] as char)
+        .collect()
+}
+
+/// 构造授权地址
+pub fn build_authorize_url(
+    config: &OAuthClientConfig,
+    redirect_uri: &str,
+    state: &str,
+    code_challenge: &str,
+) -> String {
+    let query = url::form_urlencoded::Serializer::new(String::new())
+        .append_pair("client_id", &config.client_id)
+        .append_pair("response_type", "code")
+        .append_pair("redirect_uri", redirect_uri)
+        .append_pair("response_mode", "query")
+        .append_pair("scope", MS_SCOPE)
+        .append_pair("state", state)
+        .append_pair("code_challenge", code_challenge)
+        .append_pair("code_challenge_method", "S256")
+        .finish();
+    format!("{}?{}", MS_AUTHORIZE_URL, query)
+}
+
+/// 已通过校验的授权码
+#[derive(Debug, Clone)]
+pub struct AuthorizationGrant {
+    pub code: String,
+    pub code_verifier: String,
+    pub redirect_uri: String,
+}
+
+/// 保存授权码，覆盖之前未使用的
+pub fn store_grant(grant: AuthorizationGrant) {
+    *PENDING_GRANT.lock().unwrap() = Some(grant);
+}
+
+/// 取出与 code 对应的授权码，只能取一次
+pub fn take_grant(code: &str) -> Option<AuthorizationGrant> {
+    let mut pending = PENDING_GRANT.lock().unwrap();
+    match &*pending {
+        Some(grant) if grant.code == code => pending.take(),
+        _ => None,
+    }
+}
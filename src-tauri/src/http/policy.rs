@@ -0,0 +1,135 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use url::{Host, Url};
+
+/// 被地址策略拦截的错误，用于在重定向错误链中识别
+#[derive(Debug)]
+pub struct BlockedByPolicy(pub String);
+
+impl std::fmt::Display for BlockedByPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Blocked by policy: {}", self.0)
+    }
+}
+
+impl std::error::Error for BlockedByPolicy {}
+
+/// 请求地址策略
+///
+/// - 仅允许 http / https
+/// - 回环、局域网等内网地址必须显式列入白名单
+/// - 白名单非空时，主机必须命中白名单（含子域名）
+#[derive(Debug, Clone, Default)]
+pub struct UrlPolicy {
+    /// 允许访问的主机，None 表示不限制公网主机
+    allowed_hosts: Option<Vec<String>>,
+}
+
+impl UrlPolicy {
+    /// 按白名单限制，白名单为空时不限制公网主机
+    pub fn new(allowed_hosts: Vec<String>) -> Self {
+        let allowed_hosts: Vec<String> = allowed_hosts
+            .into_iter()
+            .map(|h| h.trim().trim_start_matches('.').to_ascii_lowercase())
+            .filter(|h| !h.is_empty())
+            .collect();
+
+        UrlPolicy {
+            allowed_hosts: (!allowed_hosts.is_empty()).then_some(allowed_hosts),
+        }
+    }
+
+    /// 检查 URL 是否允许访问
+    pub fn check(&self, url: &Url) -> Result<(), String> {
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(format!("Scheme not allowed: {}", url.scheme()));
+        }
+
+        let host = url.host().ok_or("URL has no host")?;
+        let host_str = match &host {
+            // IPv6 字面量需要去掉方括号才能和白名单比较
+            Host::Ipv6(ip) => ip.to_string(),
+            _ => url.host_str().unwrap_or_default().to_ascii_lowercase(),
+        };
+
+        let listed = self
+            .allowed_hosts
+            .as_ref()
+            .map(|hosts| hosts.iter().any(|h| host_matches(&host_str, h)))
+            .unwrap_or(false);
+
+        let internal = match host {
+            Host::Domain(domain) => {
+                let domain = domain.to_ascii_lowercase();
+                domain == "localhost"
+                    || domain.ends_with(".localhost")
+                    || domain.ends_with(".local")
+            }
+            Host::Ipv4(ip) => is_internal_v4(&ip),
+            Host::Ipv6(ip) => is_internal_v6(&ip),
+        };
+
+        if internal && !listed {
+            return Err(format!("Local network address not allowed: {}", host_str));
+        }
+
+        if self.allowed_hosts.is_some() && !listed {
+            return Err(format!("Host not in allowlist: {}", host_str));
+        }
+
+        Ok(())
+    }
+
+    /// 解析并检查 URL
+    pub fn check_str(&self, url: &str) -> Result<Url, String> {
+        let parsed = Url::parse(url).map_err(|e| format!("Invalid URL: {}", e))?;
+        self.check(&parsed)?;
+        Ok(parsed)
+    }
+}
+
+/// host 是否命中白名单项（相同或为其子域名）
+fn host_matches(host: &str, allowed: &str) -> bool {
+    host == allowed || host.ends_with(&format!(".{}", allowed))
+}
+
+fn is_internal_v4(ip: &Ipv4Addr) -> bool {
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        // 100.64.0.0/10 运营商级 NAT
+        || (ip.octets()[0] == 100 && (ip.octets()[1] & 0xc0) == 64)
+}
+
+fn is_internal_v6(ip: &Ipv6Addr) -> bool {
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_internal_v4(&v4);
+    }
+    let first = ip.segments()[0];
+    ip.is_loopback()
+        || ip.is_unspecified()
+        // fc00::/7 唯一本地地址
+        || (first & 0xfe00) == 0xfc00
+        // fe80::/10 链路本地地址
+        || (first & 0xffc0) == 0xfe80
+}
+
+/// 默认允许访问的主机
+pub fn default_allowed_hosts() -> Vec<String> {
+    [
+        "modrinth.com",
+        "login.microsoftonline.com",
+        "login.live.com",
+        "xboxlive.com",
+        "minecraftservices.com",
+        "mojang.com",
+        "minecraft.net",
+        "bangbang93.com",
+        "uapis.cn",
+    ]
+    .iter()
+    .map(|h| h.to_string())
+    .collect()
+}
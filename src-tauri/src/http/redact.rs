@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+/// 替换敏感内容的占位符
+const MASK: &str = "***";
+
+/// 需要打码的请求头（小写）
+const SECRET_HEADERS: &[&str] = &[
+    "authorization",
+    "proxy-authorization",
+    "cookie",
+    "set-cookie",
+    "x-api-key",
+];
+
+/// 需要打码的 JSON / 表单 / 查询参数键（小写）
+const SECRET_KEYS: &[&str] = &[
+    "client_secret",
+    "refresh_token",
+    "access_token",
+    "id_token",
+    "accesstoken",
+    "clienttoken",
+    "identitytoken",
+    "rpsticket",
+    "usertokens",
+    "token",
+    "code",
+    "code_verifier",
+    "password",
+];
+
+fn is_secret_key(key: &str) -> bool {
+    SECRET_KEYS.contains(&key.to_ascii_lowercase().as_str())
+}
+
+/// 打码单个请求头的值，Bearer 等认证方案保留前缀
+fn mask_header_value(value: &str) -> String {
+    match value.split_once(' ') {
+        Some((scheme, _)) if !scheme.is_empty() && !scheme.contains('=') => {
+            format!("{} {}", scheme, MASK)
+        }
+        _ => MASK.to_string(),
+    }
+}
+
+/// 打码请求头
+pub fn redact_headers(headers: &HashMap<String, String>) -> HashMap<String, String> {
+    headers
+        .iter()
+        .map(|(key, value)| {
+            let lower = key.to_ascii_lowercase();
+            let value = if SECRET_HEADERS.contains(&lower.as_str()) {
+                mask_header_value(value)
+            } else {
+                value.clone()
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+/// 打码可选的请求头
+pub fn redact_opt_headers(
+    headers: &Option<HashMap<String, String>>,
+) -> Option<HashMap<String, String>> {
+    headers.as_ref().map(redact_headers)
+}
+
+/// 递归打码 JSON 中的敏感键
+pub fn redact_json(value: &Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.iter()
+                .map(|(key, value)| {
+                    let value = if is_secret_key(key) {
+                        Value::String(MASK.to_string())
+                    } else {
+                        redact_json(value)
+                    };
+                    (key.clone(), value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.iter().map(redact_json).collect()),
+        other => other.clone(),
+    }
+}
+
+/// 打码可选的请求体
+pub fn redact_opt_json(body: &Option<Value>) -> Option<Value> {
+    body.as_ref().map(redact_json)
+}
+
+/// 打码 URL 查询参数中的敏感值
+pub fn redact_url(url: &str) -> String {
+    let Ok(mut parsed) = url::Url::parse(url) else {
+        return url.to_string();
+    };
+
+    if parsed.query().is_none() {
+        return url.to_string();
+    }
+
+    let pairs: Vec<(String, String)> = parsed
+        .query_pairs()
+        .map(|(key, value)| {
+            let value = if is_secret_key(&key) {
+                MASK.to_string()
+            } else {
+                value.into_owned()
+            };
+            (key.into_owned(), value)
+        })
+        .collect();
+
+    parsed.query_pairs_mut().clear().extend_pairs(pairs);
+    parsed.to_string()
+}
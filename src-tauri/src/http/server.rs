@@ -1,30 +1,58 @@
+use crate::http::assets::{load_asset, AssetError, ASSETS_DIR, ASSET_CACHE_CONTROL};
+use crate::http::host::{self, RouteGroup};
+use crate::http::oauth::{
+    build_authorize_url, generate_state, store_grant, AuthorizationGrant, OAuthClientConfig, Pkce,
+    CALLBACK_PATH,
+};
+use crate::http::template::{configured_appearance, renderer, CallbackPage};
 use axum::extract::State;
-use axum::{extract::Request, http::StatusCode, response::IntoResponse, routing::get, Router};
-use once_cell::sync::OnceCell;
+use axum::http::{header, HeaderMap, Method};
+use axum::response::Response;
+use axum::{extract::Request, http::StatusCode, response::IntoResponse, routing::get};
 use serde::Serialize;
 use std::collections::HashMap;
-use std::net::SocketAddr;
+use std::ops::RangeInclusive;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use tauri::path::BaseDirectory;
 use tauri::{AppHandle, Emitter, Manager};
-use tokio::sync::mpsc;
 use tokio::sync::Mutex;
-use tokio::task;
 use url;
 
-// 类型定义
-type ServerHandle = task::JoinHandle<Result<(), Box<dyn std::error::Error + Send + Sync>>>;
-type ShutdownSender = mpsc::Sender<()>;
+/// OAuth 回调服务器名称
+pub const OAUTH_SERVER: &str = "oauth";
 
-struct AuthLaunchContext {
-    lang: String,
+/// OAuth 回调路由组
+pub const OAUTH_ROUTES: &str = "oauth";
+
+/// 登录页静态资源路由组
+pub const AUTH_ASSETS_ROUTES: &str = "auth-assets";
+
+/// 会话超时的默认值
+pub const DEFAULT_SESSION_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// 会话结束后服务器继续运行的时间，供回调页面加载静态资源
+const SHUTDOWN_GRACE: Duration = Duration::from_secs(10);
+
+/// 每次启动服务器或结束会话时递增，延迟关闭前用于判断期间是否开始了新的会话
+static SESSION_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// 单次授权会话，收到第一个有效回调或超时后结束
+struct AuthSession {
+    /// 回调页面语言，未指定时使用配置中的语言
+    lang: Option<String>,
+    /// 防 CSRF 的随机值，回调必须原样带回
+    state: String,
+    /// PKCE verifier，只在后端换取令牌时使用
+    code_verifier: String,
+    redirect_uri: String,
 }
 
-struct ServerState {
-    handle: ServerHandle,
-    shutdown_tx: ShutdownSender,
-    port: u16,
-    app_handle: Option<AppHandle>,
-    auth_context: Option<AuthLaunchContext>,
+/// 服务器启动结果
+pub struct StartedServer {
+    pub port: u16,
+    /// 带 state 与 code_challenge 的授权地址
+    pub authorize_url: String,
 }
 
 #[derive(Serialize, Clone)]
@@ -33,10 +61,145 @@ struct CodeReceivedPayload {
     query: HashMap<String, String>,
 }
 
-static GLOBAL_SERVER: OnceCell<Mutex<Option<ServerState>>> = OnceCell::new();
+#[derive(Serialize, Clone)]
+struct OAuthErrorPayload {
+    error: String,
+    error_description: Option<String>,
+}
+
+#[derive(Serialize, Clone)]
+struct OAuthTimeoutPayload {
+    timeout_secs: u64,
+}
+
+lazy_static::lazy_static! {
+    static ref AUTH_SESSION: Mutex<Option<AuthSession>> = Mutex::new(None);
+}
 
 // ================== Handler ==================
 
+/// 回调参数的校验结果
+#[derive(Debug, PartialEq, Eq)]
+pub enum CallbackCheck {
+    /// 缺少参数或 state 不匹配，会话继续等待
+    Rejected,
+    /// 授权服务返回错误
+    Denied {
+        error: String,
+        error_description: Option<String>,
+    },
+    /// 通过校验的授权码
+    Code(String),
+}
+
+/// 校验回调参数，state 必须与会话一致
+pub fn check_callback(expected_state: &str, query: &HashMap<String, String>) -> CallbackCheck {
+    if !query.contains_key("code") && !query.contains_key("error") {
+        return CallbackCheck::Rejected;
+    }
+    if query.get("state").map(String::as_str) != Some(expected_state) {
+        log::warn!(target: "http", "Rejected OAuth callback with mismatched state");
+        return CallbackCheck::Rejected;
+    }
+    if let Some(error) = query.get("error") {
+        log::warn!(target: "http", "OAuth provider returned error: {}", error);
+        return CallbackCheck::Denied {
+            error: error.clone(),
+            error_description: query.get("error_description").cloned(),
+        };
+    }
+    CallbackCheck::Code(query["code"].clone())
+}
+
+/// 回调处理结果
+pub struct CallbackOutcome {
+    pub page: CallbackPage,
+    pub status: StatusCode,
+    /// 页面上展示的附加说明
+    pub detail: Option<String>,
+    /// 会话指定的页面语言
+    pub lang: Option<String>,
+}
+
+/// 用当前会话校验回调，收到授权码或授权错误时结束会话
+///
+/// 返回会话指定的语言与校验结果，没有进行中的会话时返回 None
+pub async fn resolve_callback(
+    query: &HashMap<String, String>,
+) -> Option<(Option<String>, CallbackCheck)> {
+    let mut session_guard = AUTH_SESSION.lock().await;
+    let session = session_guard.as_ref()?;
+    let lang = session.lang.clone();
+
+    let check = check_callback(&session.state, query);
+    match &check {
+        CallbackCheck::Rejected => {}
+        CallbackCheck::Denied { .. } => finish_session(&mut session_guard),
+        CallbackCheck::Code(code) => {
+            store_grant(AuthorizationGrant {
+                code: code.clone(),
+                code_verifier: session.code_verifier.clone(),
+                redirect_uri: session.redirect_uri.clone(),
+            });
+            finish_session(&mut session_guard);
+        }
+    }
+    Some((lang, check))
+}
+
+/// 校验回调参数后 emit 事件，成功或失败的回调都会结束会话
+///
+/// 回环服务器与 `novacl://login-callback` 深度链接共用
+pub async fn accept_callback(
+    app_handle: &AppHandle,
+    path: &str,
+    query: &HashMap<String, String>,
+) -> CallbackOutcome {
+    let Some((lang, check)) = resolve_callback(query).await else {
+        return CallbackOutcome {
+            page: CallbackPage::Expired,
+            status: StatusCode::GONE,
+            detail: None,
+            lang: None,
+        };
+    };
+
+    let (page, status, detail) = match check {
+        CallbackCheck::Rejected => (CallbackPage::Failure, StatusCode::BAD_REQUEST, None),
+        CallbackCheck::Denied {
+            error,
+            error_description,
+        } => {
+            let _ = app_handle.emit(
+                "oauth:error",
+                OAuthErrorPayload {
+                    error: error.clone(),
+                    error_description: error_description.clone(),
+                },
+            );
+            let detail = error_description.unwrap_or(error);
+            (CallbackPage::Failure, StatusCode::OK, Some(detail))
+        }
+        CallbackCheck::Code(_) => {
+            let _ = app_handle.emit(
+                "oauth:code_received",
+                CodeReceivedPayload {
+                    path: path.to_string(),
+                    query: query.clone(),
+                },
+            );
+            (CallbackPage::Success, StatusCode::OK, None)
+        }
+    };
+
+    CallbackOutcome {
+        page,
+        status,
+        detail,
+        lang,
+    }
+}
+
 async fn auth_callback_handler(
     State(app_handle): State<AppHandle>,
     req: Request,
@@ -53,225 +216,225 @@ async fn auth_callback_handler(
         })
         .unwrap_or_default();
 
-    let global = GLOBAL_SERVER.get_or_init(|| Mutex::new(None));
-    let mut state_guard = global.lock().await;
-
-    // 只在有 code 时 emit 事件（不关闭服务器）
-    if let Some(server_state) = &mut *state_guard {
-        if let Some(app_handle) = &server_state.app_handle {
-            if query.contains_key("code") {
-                let _ = app_handle.emit(
-                    "oauth:code_received",
-                    CodeReceivedPayload {
-                        path: path.clone(),
-                        query: query.clone(),
-                    },
-                );
-            }
-        }
-    }
+    let CallbackOutcome {
+        page,
+        status,
+        detail,
+        lang: session_lang,
+    } = accept_callback(&app_handle, &path, &query).await;
+
+    // 前端未指定语言时使用配置中的语言
+    let (config_lang, theme) = configured_appearance();
+    let lang = session_lang.unwrap_or(config_lang);
+    let rendered = renderer(&app_handle).and_then(|r| {
+        r.render(page, &lang, &theme, detail.as_deref())
+            .map_err(|e| format!("Failed to render callback page: {}", e))
+    });
 
-    // 返回 addons/auth/index.html
-    let html_content = match &*state_guard {
-        Some(server_state) => {
-            // 先 resolve 资源路径
-            let index_path_result = app_handle
-                .path()
-                .resolve("addons/auth/index.html", BaseDirectory::Resource);
-
-            match index_path_result {
-                Ok(index_path) => {
-                    // 成功解析路径后，再读取文件
-                    match std::fs::read_to_string(&index_path) {
-                        Ok(mut content) => {
-                            let lang = server_state
-                                .auth_context
-                                .as_ref()
-                                .map(|ctx| ctx.lang.as_str())
-                                .unwrap_or("en");
-
-                            // 注入语言 meta 标签
-                            content = content.replace(
-                                "</head>",
-                                &format!("<meta name=\"lang\" content=\"{}\"></head>", lang),
-                            );
-
-                            content // 成功返回修改后的 HTML
-                        }
-                        Err(e) => {
-                            format!(
-                            "<html><body><h1>Error</h1><p>Failed to read auth/index.html: {}</p></body></html>",
-                            e
-                        )
-                        }
-                    }
-                }
-                Err(e) => {
-                    format!(
-                    "<html><body><h1>Error</h1><p>Failed to resolve resource path: {}</p></body></html>",
-                    e
-                )
-                }
-            }
-        }
-        None => {
-            "<html><body><h1>Error</h1><p>Server state not available</p></body></html>".to_string()
+    match rendered {
+        Ok(html) => (status, axum::response::Html(html)),
+        Err(e) => {
+            log::error!(target: "http", "{}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                axum::response::Html(
+                    "<html><body><h1>500 Internal Error</h1></body></html>".to_string(),
+                ),
+            )
         }
-    };
-
-    (StatusCode::OK, axum::response::Html(html_content))
-}
-
-// 新增：前端调用此路由关闭服务器
-async fn close_handler() -> impl IntoResponse {
-    let global = GLOBAL_SERVER.get_or_init(|| Mutex::new(None));
-    let state_guard = global.lock().await;
-
-    if let Some(server_state) = &*state_guard {
-        let tx = server_state.shutdown_tx.clone();
-        tokio::spawn(async move {
-            let _ = tx.send(()).await;
-        });
     }
-
-    (StatusCode::OK, "Server is shutting down...")
 }
 
 async fn assets_handler(
     State(app_handle): State<AppHandle>,
     axum::extract::Path(path): axum::extract::Path<String>,
-) -> impl IntoResponse {
-    let global = GLOBAL_SERVER.get_or_init(|| Mutex::new(None));
-    let state_guard = global.lock().await;
-
-    match &*state_guard {
-        Some(_server_state) => {
-            // 目标资源在打包后的相对路径：addons/auth/assets/xxx
-            // path 是用户请求的路径部分，例如 "logo.png" 或 "styles/main.css"
-            let resource_relative_path = format!("addons/auth/assets/{}", path);
-
-            // 先 resolve 资源路径
-            let asset_path_result = app_handle
-                .path()
-                .resolve(&resource_relative_path, BaseDirectory::Resource);
-
-            match asset_path_result {
-                Ok(asset_path) => {
-                    // 成功解析到打包资源路径，再读取文件内容（二进制）
-                    match std::fs::read(&asset_path) {
-                        Ok(content) => {
-                            let mime = mime_guess::from_path(&path)
-                                .first_or_octet_stream()
-                                .to_string();
-
-                            (
-                                StatusCode::OK,
-                                [(axum::http::header::CONTENT_TYPE, mime)],
-                                content,
-                            )
-                        }
-                        Err(e) => {
-                            // 读取失败（极少发生，除非资源损坏）
-                            let error_html = format!(
-                        "<html><body><h1>500 Internal Error</h1><p>Failed to read asset: {}</p></body></html>",
-                        e
-                    );
-                            (
-                                StatusCode::INTERNAL_SERVER_ERROR,
-                                [(axum::http::header::CONTENT_TYPE, "text/html".to_string())],
-                                error_html.into_bytes(),
-                            )
-                        }
-                    }
+    method: Method,
+    headers: HeaderMap,
+) -> Response {
+    // path 是用户请求的路径部分，例如 "logo.webp"，只能访问资源目录内的文件
+    let root = app_handle
+        .path()
+        .resolve(ASSETS_DIR, BaseDirectory::Resource)
+        .ok();
+
+    let asset = match load_asset(root.as_deref(), &path) {
+        Ok(asset) => asset,
+        Err(e) => {
+            let status = match e {
+                AssetError::InvalidPath(_) => {
+                    log::warn!(target: "http", "Rejected asset request: {}", e);
+                    StatusCode::BAD_REQUEST
                 }
-                Err(_) => {
-                    // 资源未打包进去或路径错误 → 404
-                    let error_html = "<html><body><h1>404 Not Found</h1></body></html>".to_string();
-                    (
-                        StatusCode::NOT_FOUND,
-                        [(axum::http::header::CONTENT_TYPE, "text/html".to_string())],
-                        error_html.into_bytes(),
-                    )
+                AssetError::NotFound(_) => StatusCode::NOT_FOUND,
+                AssetError::Io(_) => {
+                    log::error!(target: "http", "{}", e);
+                    StatusCode::INTERNAL_SERVER_ERROR
                 }
-            }
+            };
+            return (
+                status,
+                axum::response::Html(format!("<html><body><h1>{}</h1></body></html>", status)),
+            )
+                .into_response();
         }
-        None => (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            [(axum::http::header::CONTENT_TYPE, "text/html".to_string())],
-            "Server state not available".to_string().into_bytes(),
-        ),
+    };
+
+    let cache_headers = [
+        (header::ETAG, asset.etag.clone()),
+        (header::CACHE_CONTROL, ASSET_CACHE_CONTROL.to_string()),
+    ];
+
+    let not_modified = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| asset.matches(value));
+    if not_modified {
+        return (StatusCode::NOT_MODIFIED, cache_headers).into_response();
     }
-}
 
-async fn not_found_handler(_req: Request) -> impl IntoResponse {
+    let content_headers = [
+        (header::CONTENT_TYPE, asset.mime.clone()),
+        (header::CONTENT_LENGTH, asset.content.len().to_string()),
+    ];
+    if method == Method::HEAD {
+        return (StatusCode::OK, cache_headers, content_headers).into_response();
+    }
     (
-        StatusCode::NOT_FOUND,
-        axum::response::Html("<html><body><h1>404 Not Found</h1></body></html>".to_string()),
+        StatusCode::OK,
+        cache_headers,
+        content_headers,
+        asset.content.into_owned(),
     )
+        .into_response()
 }
 
-// ================== Public API ==================
+/// 结束会话，宽限期后关闭服务器，可在 handler 内调用
+///
+/// 会话立即失效，宽限期内的回调只会得到过期页面
+fn finish_session(session: &mut Option<AuthSession>) {
+    *session = None;
+    let generation = SESSION_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+    tokio::spawn(async move {
+        tokio::time::sleep(SHUTDOWN_GRACE).await;
+        if SESSION_GENERATION.load(Ordering::SeqCst) == generation {
+            host::stop_detached(OAUTH_SERVER);
+        }
+    });
+}
 
-pub async fn start_server(app_handle: AppHandle, port: u16, lang: String) -> Result<(), String> {
-    let global = GLOBAL_SERVER.get_or_init(|| Mutex::new(None));
-    let mut state = global.lock().await;
+/// 开始新的授权会话并替换未完成的会话，返回 state 与 code_challenge
+pub async fn begin_session(lang: Option<String>, redirect_uri: String) -> (String, String) {
+    let pkce = Pkce::generate();
+    let state = generate_state();
+    *AUTH_SESSION.lock().await = Some(AuthSession {
+        lang,
+        state: state.clone(),
+        code_verifier: pkce.code_verifier,
+        redirect_uri,
+    });
+    (state, pkce.code_challenge)
+}
 
-    if state.is_some() {
-        return Err("Server is already running".to_string());
+/// 结束 state 对应的会话，会话已完成或已被新的会话替换时返回 false
+pub async fn expire_session(session_state: &str) -> bool {
+    let mut session = AUTH_SESSION.lock().await;
+    let same_session = session
+        .as_ref()
+        .is_some_and(|session| session.state == session_state);
+    if same_session {
+        finish_session(&mut session);
     }
+    same_session
+}
 
-    let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
+/// 超时后关闭服务器并 emit `oauth:timeout`
+async fn session_timeout(app_handle: AppHandle, session_state: String, timeout: Duration) {
+    tokio::time::sleep(timeout).await;
 
-    let auth_context = AuthLaunchContext { lang };
+    if !expire_session(&session_state).await {
+        return;
+    }
+    log::info!(target: "http", "OAuth session timed out after {}s", timeout.as_secs());
+    let _ = app_handle.emit(
+        "oauth:timeout",
+        OAuthTimeoutPayload {
+            timeout_secs: timeout.as_secs(),
+        },
+    );
+}
 
-    let app_handle_clone = app_handle.clone();
+// ================== Routes ==================
 
-    let app = Router::new()
-        .route("/oauth/callback", get(auth_callback_handler))
-        .route("/close", get(close_handler)) // 新增
-        .route("/assets/*path", get(assets_handler))
-        .fallback(not_found_handler)
-        .with_state(app_handle_clone);
+/// 注册登录相关的路由组，应用启动时调用
+pub fn register_routes(app_handle: &AppHandle) {
+    let handle = app_handle.clone();
+    host::register_route_group(OAUTH_ROUTES, move || oauth_routes(&handle));
+    let handle = app_handle.clone();
+    host::register_route_group(AUTH_ASSETS_ROUTES, move || auth_assets_routes(&handle));
+}
 
-    let addr = SocketAddr::from(([0, 0, 0, 0], port));
-    let listener = tokio::net::TcpListener::bind(addr)
-        .await
-        .map_err(|e| e.to_string())?;
+/// OAuth 回调路由
+fn oauth_routes(app_handle: &AppHandle) -> RouteGroup {
+    RouteGroup::new(OAUTH_ROUTES).route(
+        CALLBACK_PATH,
+        get(auth_callback_handler).with_state(app_handle.clone()),
+    )
+}
 
-    let handle = task::spawn(async move {
-        axum::serve(listener, app)
-            .with_graceful_shutdown(async move {
-                let _ = shutdown_rx.recv().await;
-            })
-            .await
-            .map_err(|e| e.into())
-    });
+/// 登录页静态资源路由
+fn auth_assets_routes(app_handle: &AppHandle) -> RouteGroup {
+    RouteGroup::new(AUTH_ASSETS_ROUTES).route(
+        "/assets/*path",
+        get(assets_handler)
+            .head(assets_handler)
+            .with_state(app_handle.clone()),
+    )
+}
 
-    *state = Some(ServerState {
-        handle,
-        shutdown_tx,
-        port,
-        app_handle: Some(app_handle),
-        auth_context: Some(auth_context),
-    });
+// ================== Public API ==================
 
-    Ok(())
-}
+/// 启动 OAuth 回调服务器，仅监听回环地址
+///
+/// - `timeout`: 超时后自动关闭并 emit `oauth:timeout`
+///
+/// 返回实际监听的端口与授权地址
+pub async fn start_server(
+    app_handle: AppHandle,
+    port: u16,
+    fallback: Option<RangeInclusive<u16>>,
+    lang: Option<String>,
+    oauth: OAuthClientConfig,
+    timeout: Duration,
+) -> Result<StartedServer, String> {
+    // 取消上一次会话的延迟关闭；服务器仍在宽限期内时关闭后重新启动
+    SESSION_GENERATION.fetch_add(1, Ordering::SeqCst);
+    if AUTH_SESSION.lock().await.is_none() && host::is_running(OAUTH_SERVER) {
+        host::stop(OAUTH_SERVER).await?;
+    }
 
-pub async fn stop_server() -> Result<(), String> {
-    let global = GLOBAL_SERVER.get_or_init(|| Mutex::new(None));
-    let mut state = global.lock().await;
+    let groups = [OAUTH_ROUTES.to_string(), AUTH_ASSETS_ROUTES.to_string()];
+    let port = host::start(OAUTH_SERVER, &groups, port, fallback).await?;
+    let addr = host::local_addr(OAUTH_SERVER).ok_or("OAuth server stopped unexpectedly")?;
 
-    let server_state = state.take().ok_or("Server is not running")?;
+    // 回调地址依赖实际监听的地址，绑定成功后再生成授权参数
+    let redirect_uri = oauth.redirect_uri(addr);
+    let (state, code_challenge) = begin_session(lang, redirect_uri.clone()).await;
+    let authorize_url = build_authorize_url(&oauth, &redirect_uri, &state, &code_challenge);
 
-    let _ = server_state.shutdown_tx.send(()).await;
-    let _ = server_state.handle.await;
+    tokio::spawn(session_timeout(app_handle, state, timeout));
 
-    Ok(())
+    Ok(StartedServer {
+        port,
+        authorize_url,
+    })
+}
+
+/// 停止 OAuth 回调服务器并丢弃未完成的会话
+pub async fn stop_server() -> Result<(), String> {
+    *AUTH_SESSION.lock().await = None;
+    host::stop(OAUTH_SERVER).await
 }
 
 pub async fn get_server_status() -> Option<u16> {
-    let global = GLOBAL_SERVER.get_or_init(|| Mutex::new(None));
-    let state = global.lock().await;
-    state.as_ref().map(|s| s.port)
+    host::status(OAUTH_SERVER).map(|status| status.port)
 }
@@ -0,0 +1,200 @@
+use handlebars::{Handlebars, RenderError, TemplateError};
+use once_cell::sync::OnceCell;
+use serde::Serialize;
+use tauri::path::BaseDirectory;
+use tauri::{AppHandle, Manager};
+
+use crate::toml::manager::ConfigManager;
+
+/// 回调页面模板在打包资源中的路径
+pub const CALLBACK_TEMPLATE_PATH: &str = "addons/auth/callback.hbs";
+
+const CALLBACK_TEMPLATE: &str = "callback";
+
+const DEFAULT_LANGUAGE: &str = "en-US";
+const DEFAULT_THEME: &str = "auto";
+
+/// 已编译的模板，首次渲染时从资源目录读取
+static RENDERER: OnceCell<PageRenderer> = OnceCell::new();
+
+/// 回调页面类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CallbackPage {
+    /// 已收到授权码
+    Success,
+    /// 授权被拒绝或回调无效
+    Failure,
+    /// 会话已超时或已结束
+    Expired,
+}
+
+impl CallbackPage {
+    fn variant(self) -> &'static str {
+        match self {
+            CallbackPage::Success => "success",
+            CallbackPage::Failure => "failure",
+            CallbackPage::Expired => "expired",
+        }
+    }
+}
+
+/// 页面文案
+struct PageStrings {
+    title: &'static str,
+    heading: &'static str,
+    message: &'static str,
+    hint: &'static str,
+}
+
+fn page_strings(page: CallbackPage, lang: &str) -> PageStrings {
+    match (lang, page) {
+        ("zh-CN", CallbackPage::Success) => PageStrings {
+            title: "登录成功",
+            heading: "登录成功",
+            message: "授权已完成，请返回 Nova.CL 继续。",
+            hint: "现在可以关闭此页面。",
+        },
+        ("zh-CN", CallbackPage::Failure) => PageStrings {
+            title: "登录失败",
+            heading: "登录失败",
+            message: "授权未能完成，请返回 Nova.CL 重新登录。",
+            hint: "现在可以关闭此页面。",
+        },
+        ("zh-CN", CallbackPage::Expired) => PageStrings {
+            title: "登录已过期",
+            heading: "登录已过期",
+            message: "本次登录已超时或已结束，请在 Nova.CL 中重新发起登录。",
+            hint: "现在可以关闭此页面。",
+        },
+        (_, CallbackPage::Success) => PageStrings {
+            title: "Login Successful",
+            heading: "Login successful",
+            message: "Authorization is complete. Return to Nova.CL to continue.",
+            hint: "You can close this page now.",
+        },
+        (_, CallbackPage::Failure) => PageStrings {
+            title: "Login Failed",
+            heading: "Login failed",
+            message: "Authorization could not be completed. Return to Nova.CL and try again.",
+            hint: "You can close this page now.",
+        },
+        (_, CallbackPage::Expired) => PageStrings {
+            title: "Login Expired",
+            heading: "Login expired",
+            message: "This login has timed out or already finished. Start a new login in Nova.CL.",
+            hint: "You can close this page now.",
+        },
+    }
+}
+
+/// 页面语言，取值与 `validate_language` 一致
+///
+/// `auto` 与其他值按前缀匹配，例如 `zh`、`zh-TW` 视为 `zh-CN`
+pub fn resolve_language(lang: &str) -> &'static str {
+    if lang.to_ascii_lowercase().starts_with("zh") {
+        "zh-CN"
+    } else {
+        DEFAULT_LANGUAGE
+    }
+}
+
+/// 页面主题，取值与 `validate_theme` 一致，无效值视为 `auto`
+pub fn resolve_theme(theme: &str) -> &'static str {
+    match theme {
+        "light" => "light",
+        "dark" => "dark",
+        _ => DEFAULT_THEME,
+    }
+}
+
+#[derive(Serialize)]
+struct PageContext<'a> {
+    lang: &'a str,
+    theme: &'a str,
+    variant: &'a str,
+    title: &'a str,
+    heading: &'a str,
+    message: &'a str,
+    hint: &'a str,
+    detail: Option<&'a str>,
+}
+
+/// 回调页面渲染器
+pub struct PageRenderer {
+    registry: Handlebars<'static>,
+}
+
+impl PageRenderer {
+    pub fn from_source(source: &str) -> Result<Self, TemplateError> {
+        let mut registry = Handlebars::new();
+        registry.register_template_string(CALLBACK_TEMPLATE, source)?;
+        Ok(Self { registry })
+    }
+
+    /// 渲染页面
+    ///
+    /// - `lang`/`theme`: 原始配置值，渲染前会规范化
+    /// - `detail`: 附加说明，例如 OAuth 错误描述，会被转义
+    pub fn render(
+        &self,
+        page: CallbackPage,
+        lang: &str,
+        theme: &str,
+        detail: Option<&str>,
+    ) -> Result<String, RenderError> {
+        let lang = resolve_language(lang);
+        let strings = page_strings(page, lang);
+        self.registry.render(
+            CALLBACK_TEMPLATE,
+            &PageContext {
+                lang,
+                theme: resolve_theme(theme),
+                variant: page.variant(),
+                title: strings.title,
+                heading: strings.heading,
+                message: strings.message,
+                hint: strings.hint,
+                detail,
+            },
+        )
+    }
+}
+
+/// 编译进程序的模板，资源目录缺失时使用
+#[cfg(feature = "embed-auth-assets")]
+const EMBEDDED_TEMPLATE: Option<&str> = Some(include_str!("../../addons/auth/callback.hbs"));
+
+#[cfg(not(feature = "embed-auth-assets"))]
+const EMBEDDED_TEMPLATE: Option<&str> = None;
+
+fn read_template(app_handle: &AppHandle) -> Result<String, String> {
+    let path = app_handle
+        .path()
+        .resolve(CALLBACK_TEMPLATE_PATH, BaseDirectory::Resource)
+        .map_err(|e| format!("Failed to resolve {}: {}", CALLBACK_TEMPLATE_PATH, e))?;
+    std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+/// 获取缓存的渲染器，读取或编译失败时不缓存，下次请求重试
+pub fn renderer(app_handle: &AppHandle) -> Result<&'static PageRenderer, String> {
+    RENDERER.get_or_try_init(|| {
+        let source = match (read_template(app_handle), EMBEDDED_TEMPLATE) {
+            (Ok(source), _) => source,
+            (Err(e), Some(embedded)) => {
+                log::warn!(target: "http", "{}, using embedded template", e);
+                embedded.to_string()
+            }
+            (Err(e), None) => return Err(e),
+        };
+        PageRenderer::from_source(&source)
+            .map_err(|e| format!("Failed to compile {}: {}", CALLBACK_TEMPLATE_PATH, e))
+    })
+}
+
+/// 当前配置中的语言与主题
+pub fn configured_appearance() -> (String, String) {
+    match ConfigManager::instance().load_global_config() {
+        Ok(config) => (config.customize.language, config.customize.theme),
+        Err(_) => (DEFAULT_LANGUAGE.to_string(), DEFAULT_THEME.to_string()),
+    }
+}
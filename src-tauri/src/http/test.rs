@@ -0,0 +1,900 @@
+use std::collections::HashMap;
+use std::fs;
+
+use axum::routing::get;
+use url::Url;
+
+use crate::http::assets::{load_asset, resolve_asset_path, AssetError};
+use crate::http::cache::{unix_now, CacheControl, CacheEntry, HttpCache};
+use crate::http::client::{HttpClient, HttpClientOptions};
+use crate::http::cookie::{CookieJar, StoredCookie};
+use crate::http::host::{self, RouteGroup};
+use crate::http::oauth::{
+    build_authorize_url, challenge_for, store_grant, take_grant, AuthorizationGrant,
+    OAuthClientConfig, Pkce,
+};
+use crate::http::policy::{default_allowed_hosts, UrlPolicy};
+use crate::http::redact::{redact_opt_headers, redact_opt_json, redact_url};
+use crate::http::server::{
+    begin_session, check_callback, expire_session, resolve_callback, CallbackCheck,
+};
+use crate::http::template::{resolve_language, resolve_theme, CallbackPage, PageRenderer};
+use crate::http::types::{HttpError, HttpErrorKind, HttpRequest};
+
+const CALLBACK_TEMPLATE: &str = include_str!("../../addons/auth/callback.hbs");
+
+#[test]
+fn test_resolve_appearance() {
+    assert_eq!(resolve_language("zh-CN"), "zh-CN");
+    assert_eq!(resolve_language("zh"), "zh-CN");
+    assert_eq!(resolve_language("en-US"), "en-US");
+    assert_eq!(resolve_language("auto"), "en-US");
+    assert_eq!(resolve_theme("dark"), "dark");
+    assert_eq!(resolve_theme("unknown"), "auto");
+}
+
+#[test]
+fn test_render_callback_pages() {
+    let renderer = PageRenderer::from_source(CALLBACK_TEMPLATE).unwrap();
+
+    let success = renderer
+        .render(CallbackPage::Success, "zh-CN", "dark", None)
+        .unwrap();
+    assert!(success.contains(r#"<html lang="zh-CN" data-theme="dark">"#));
+    assert!(success.contains(r#"<main class="success">"#));
+    assert!(success.contains("登录成功"));
+    assert!(!success.contains(r#"class="detail""#));
+
+    // 错误描述来自回调参数，必须转义
+    let failure = renderer
+        .render(
+            CallbackPage::Failure,
+            "en-US",
+            "light",
+            Some("<script>alert(1)</script>"),
+        )
+        .unwrap();
+    assert!(failure.contains(r#"<main class="failure">"#));
+    assert!(failure.contains("Login failed"));
+    assert!(failure.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+    assert!(!failure.contains("<script>alert(1)</script>"));
+
+    let expired = renderer
+        .render(CallbackPage::Expired, "auto", "auto", None)
+        .unwrap();
+    assert!(expired.contains(r#"<html lang="en-US" data-theme="auto">"#));
+    assert!(expired.contains("Login expired"));
+}
+
+#[test]
+fn test_asset_path_traversal() {
+    let dir = std::env::temp_dir().join(format!("novacl-assets-{}", uuid::Uuid::new_v4()));
+    let root = dir.join("assets");
+    fs::create_dir_all(root.join("img")).unwrap();
+    fs::write(root.join("img/logo.webp"), b"logo").unwrap();
+    fs::write(dir.join("secret.txt"), b"secret").unwrap();
+
+    assert!(resolve_asset_path(&root, "img/logo.webp").is_ok());
+    for path in [
+        "../secret.txt",
+        "img/../../secret.txt",
+        "/etc/passwd",
+        "..\\secret.txt",
+        "",
+    ] {
+        assert!(
+            matches!(
+                resolve_asset_path(&root, path),
+                Err(AssetError::InvalidPath(_))
+            ),
+            "{}",
+            path
+        );
+    }
+    assert!(matches!(
+        resolve_asset_path(&root, "missing.webp"),
+        Err(AssetError::NotFound(_))
+    ));
+
+    // 指向资源目录外的符号链接同样拒绝
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(dir.join("secret.txt"), root.join("link.txt")).unwrap();
+        assert!(matches!(
+            resolve_asset_path(&root, "link.txt"),
+            Err(AssetError::InvalidPath(_))
+        ));
+    }
+
+    let asset = load_asset(Some(&root), "img/logo.webp").unwrap();
+    assert_eq!(asset.mime, "image/webp");
+    assert_eq!(&*asset.content, b"logo");
+    assert!(asset.matches(&asset.etag));
+    assert!(asset.matches(&format!("\"other\", W/{}", asset.etag)));
+    assert!(!asset.matches("\"other\""));
+
+    // 资源目录缺失时回落到内置资源
+    #[cfg(feature = "embed-auth-assets")]
+    assert!(load_asset(None, "logo.webp").is_ok());
+    assert!(matches!(
+        load_asset(None, "../secret.txt"),
+        Err(AssetError::InvalidPath(_))
+    ));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+fn text_group(name: &str, path: &str, body: &'static str) -> RouteGroup {
+    RouteGroup::new(name).route(path, get(move || async move { body }))
+}
+
+#[tokio::test]
+async fn test_named_servers() {
+    let preview = host::start_groups(
+        "test-preview",
+        vec![
+            text_group("skin", "/skin", "skin"),
+            text_group("pack", "/pack", "pack"),
+        ],
+        0,
+        None,
+    )
+    .await
+    .unwrap();
+    let links = host::start_groups(
+        "test-links",
+        vec![text_group("links", "/open", "open")],
+        0,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_ne!(preview, links);
+
+    // 同名服务器与冲突的路由都会被拒绝
+    assert!(host::start_groups("test-preview", Vec::new(), 0, None)
+        .await
+        .is_err());
+    assert!(host::start_groups(
+        "test-conflict",
+        vec![text_group("a", "/same", "a"), text_group("b", "/same", "b")],
+        0,
+        None,
+    )
+    .await
+    .is_err());
+    assert!(!host::is_running("test-conflict"));
+
+    let status = host::status("test-preview").unwrap();
+    assert_eq!(status.port, preview);
+    let routes: Vec<_> = status
+        .routes
+        .iter()
+        .map(|r| (r.group.as_str(), r.path.as_str()))
+        .collect();
+    assert_eq!(routes, vec![("skin", "/skin"), ("pack", "/pack")]);
+
+    let body = reqwest::get(format!("http://127.0.0.1:{}/pack", preview))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert_eq!(body, "pack");
+    let missing = reqwest::get(format!("http://127.0.0.1:{}/pack", links))
+        .await
+        .unwrap();
+    assert_eq!(missing.status(), 404);
+
+    // 各服务器独立停止
+    host::stop("test-preview").await.unwrap();
+    assert!(host::status("test-preview").is_none());
+    assert!(reqwest::get(format!("http://127.0.0.1:{}/open", links))
+        .await
+        .unwrap()
+        .status()
+        .is_success());
+    host::stop("test-links").await.unwrap();
+    assert!(host::stop("test-links").await.is_err());
+}
+
+#[tokio::test]
+async fn test_registered_route_groups() {
+    host::register_route_group("test-registered", || {
+        text_group("test-registered", "/registered", "registered")
+    });
+    assert!(host::route_groups().contains(&"test-registered".to_string()));
+
+    assert!(
+        host::start("test-unknown", &["test-missing".to_string()], 0, None)
+            .await
+            .is_err()
+    );
+    assert!(!host::is_running("test-unknown"));
+
+    let port = host::start("test-registry", &["test-registered".to_string()], 0, None)
+        .await
+        .unwrap();
+    let body = reqwest::get(format!("http://127.0.0.1:{}/registered", port))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert_eq!(body, "registered");
+    host::stop("test-registry").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_port_fallback() {
+    // 占用首选端口，服务器应回落到备用范围并报告实际端口
+    let busy = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let preferred = busy.local_addr().unwrap().port();
+    let fallback = preferred.saturating_add(1)..=preferred.saturating_add(20);
+
+    assert!(host::start_groups("test-busy", Vec::new(), preferred, None)
+        .await
+        .is_err());
+    assert!(!host::is_running("test-busy"));
+
+    let port = host::start_groups(
+        "test-fallback",
+        vec![text_group("ping", "/ping", "pong")],
+        preferred,
+        Some(fallback.clone()),
+    )
+    .await
+    .unwrap();
+    assert_ne!(port, preferred);
+    assert!(fallback.contains(&port));
+    assert_eq!(host::status("test-fallback").unwrap().port, port);
+    let addr = host::local_addr("test-fallback").unwrap();
+    assert_eq!(addr, std::net::SocketAddr::from(([127, 0, 0, 1], port)));
+    let body = reqwest::get(format!("http://{}/ping", addr))
+        .await
+        .unwrap()
+        .text()
+        .await
+        .unwrap();
+    assert_eq!(body, "pong");
+    host::stop("test-fallback").await.unwrap();
+
+    // 第一个地址不可用时尝试下一个，端口被占用时不换地址
+    let unavailable: std::net::IpAddr = "192.0.2.1".parse().unwrap();
+    let loopback: std::net::IpAddr = "127.0.0.1".parse().unwrap();
+    let listener = host::bind_first(&[unavailable, loopback], 0).await.unwrap();
+    assert_eq!(listener.local_addr().unwrap().ip(), loopback);
+    let error = host::bind_first(&[loopback, unavailable], preferred)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::AddrInUse);
+    drop(busy);
+}
+
+fn headers(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_cache_freshness() {
+    let cc = CacheControl::from_headers(&headers(&[("cache-control", "public, max-age=60")]));
+    assert_eq!(cc.max_age, Some(60));
+    assert!(!cc.no_store && !cc.no_cache);
+    assert!(CacheControl::from_headers(&headers(&[("cache-control", "no-store")])).no_store);
+    // 没有 max-age 时回落到 Expires，已过期的视为 0
+    let expired =
+        CacheControl::from_headers(&headers(&[("expires", "Thu, 01 Jan 1970 00:00:00 GMT")]));
+    assert_eq!(expired.max_age, Some(0));
+
+    let url = "https://api.modrinth.com/v2/project/sodium";
+    let entry = CacheEntry::from_response(
+        url,
+        200,
+        &headers(&[("cache-control", "max-age=60")]),
+        b"{}",
+    )
+    .unwrap();
+    assert!(entry.is_fresh(entry.stored_at + 59));
+    assert!(!entry.is_fresh(entry.stored_at + 60));
+    assert!(entry.validators().is_empty());
+
+    // 既没有新鲜期也没有验证器、no-store 与非 200 的响应不缓存
+    assert!(CacheEntry::from_response(url, 200, &HashMap::new(), b"{}").is_none());
+    assert!(CacheEntry::from_response(
+        url,
+        200,
+        &headers(&[("cache-control", "no-store"), ("etag", "\"a\"")]),
+        b"{}",
+    )
+    .is_none());
+    assert!(CacheEntry::from_response(url, 404, &headers(&[("etag", "\"a\"")]), b"").is_none());
+
+    // no-cache 每次都需要重新验证
+    let no_cache = CacheEntry::from_response(
+        url,
+        200,
+        &headers(&[("cache-control", "no-cache, max-age=60"), ("etag", "\"a\"")]),
+        b"{}",
+    )
+    .unwrap();
+    assert!(!no_cache.is_fresh(no_cache.stored_at));
+}
+
+#[test]
+fn test_cache_revalidation() {
+    let url = "https://api.modrinth.com/v2/tag/loader";
+    let mut entry = CacheEntry::from_response(
+        url,
+        200,
+        &headers(&[
+            ("etag", "\"v1\""),
+            ("last-modified", "Wed, 01 Jan 2025 00:00:00 GMT"),
+            ("set-cookie", "session=secret; Path=/"),
+            ("content-type", "application/json"),
+        ]),
+        b"[1,2]",
+    )
+    .unwrap();
+    assert_eq!(entry.max_age, None);
+    assert!(!entry.is_fresh(unix_now()));
+    assert_eq!(
+        entry.validators(),
+        vec![
+            ("If-None-Match", "\"v1\"".to_string()),
+            (
+                "If-Modified-Since",
+                "Wed, 01 Jan 2025 00:00:00 GMT".to_string()
+            ),
+        ]
+    );
+    // Set-Cookie 不写入缓存
+    assert!(!entry.headers.contains_key("set-cookie"));
+    assert_eq!(entry.headers["content-type"], "application/json");
+
+    // 304 带来新的验证器与新鲜期，响应体保持不变
+    entry.stored_at = 0;
+    entry.revalidated(&headers(&[
+        ("etag", "\"v2\""),
+        ("cache-control", "max-age=30"),
+        ("set-cookie", "session=other"),
+    ]));
+    assert_eq!(entry.etag.as_deref(), Some("\"v2\""));
+    assert_eq!(entry.max_age, Some(30));
+    assert!(entry.is_fresh(unix_now()));
+    assert!(!entry.headers.contains_key("set-cookie"));
+    assert_eq!(entry.body_bytes(), b"[1,2]");
+
+    let dir = std::env::temp_dir().join(format!("novacl-cache-{}", uuid::Uuid::new_v4()));
+    let cache = HttpCache::new(dir.clone());
+    cache.store(&entry).unwrap();
+    let loaded = cache.load(url).unwrap();
+    assert_eq!(loaded.etag, entry.etag);
+    assert!(cache.load("https://api.modrinth.com/v2/other").is_none());
+
+    // 旧版本写入的 Set-Cookie 在读取时去掉
+    let mut legacy = entry.clone();
+    legacy
+        .headers
+        .insert("set-cookie".to_string(), "session=secret".to_string());
+    cache.store(&legacy).unwrap();
+    assert!(!cache.load(url).unwrap().headers.contains_key("set-cookie"));
+
+    cache.clear().unwrap();
+    assert!(!dir.exists());
+}
+
+#[test]
+fn test_parse_set_cookie() {
+    let url = Url::parse("https://www.example.com/account/login").unwrap();
+    let now = 1_000;
+
+    let cookie = StoredCookie::parse("sid=abc; Path=/; Secure; Max-Age=60", &url, now).unwrap();
+    assert_eq!(cookie.name, "sid");
+    assert_eq!(cookie.value, "abc");
+    assert_eq!(cookie.domain, "www.example.com");
+    assert!(cookie.host_only);
+    assert!(cookie.secure);
+    assert_eq!(cookie.expires_at, Some(now + 60));
+
+    // 默认路径为请求路径的目录，Domain 属性允许子域名
+    let cookie = StoredCookie::parse("a=1; Domain=.Example.com", &url, now).unwrap();
+    assert_eq!(cookie.domain, "example.com");
+    assert_eq!(cookie.path, "/account");
+    assert!(!cookie.host_only);
+    assert_eq!(cookie.expires_at, None);
+
+    // Max-Age 优先于 Expires
+    let cookie = StoredCookie::parse(
+        "a=1; Expires=Wed, 01 Jan 2025 00:00:00 GMT; Max-Age=0",
+        &url,
+        now,
+    )
+    .unwrap();
+    assert_eq!(cookie.expires_at, Some(0));
+
+    // 为其他站点设置与没有名称的 Cookie 被拒绝
+    assert!(StoredCookie::parse("a=1; Domain=evil.com", &url, now).is_none());
+    assert!(StoredCookie::parse("=1", &url, now).is_none());
+    assert!(StoredCookie::parse("novalue", &url, now).is_none());
+}
+
+#[test]
+fn test_cookie_jar() {
+    let dir = std::env::temp_dir().join(format!("novacl-cookies-{}", uuid::Uuid::new_v4()));
+    let path = dir.join("cookies.json");
+    let jar = CookieJar::open(path.clone(), vec![".Example.com".to_string()]);
+
+    let login = Url::parse("https://auth.example.com/account/login").unwrap();
+    jar.store_set_cookies(
+        &login,
+        [
+            "host=1; Path=/; Max-Age=3600",
+            "shared=2; Domain=example.com; Path=/; Max-Age=3600",
+            "scoped=3; Path=/account; Max-Age=3600",
+            "secure=4; Path=/; Secure; Max-Age=3600",
+            "session=5; Path=/",
+            "gone=6; Path=/; Max-Age=0",
+        ]
+        .into_iter(),
+    );
+
+    let header = |url: &str| jar.cookie_header(&Url::parse(url).unwrap());
+    assert_eq!(
+        header("https://auth.example.com/account/me").as_deref(),
+        Some("host=1; shared=2; scoped=3; secure=4; session=5")
+    );
+    // host-only、路径与 Secure 的匹配
+    assert_eq!(
+        header("https://cdn.example.com/").as_deref(),
+        Some("shared=2")
+    );
+    assert_eq!(
+        header("https://auth.example.com/accounts").as_deref(),
+        Some("host=1; shared=2; secure=4; session=5")
+    );
+    assert_eq!(
+        header("http://auth.example.com/").as_deref(),
+        Some("host=1; shared=2; session=5")
+    );
+
+    // 白名单外的域名既不发送也不保存
+    let other = Url::parse("https://other.com/").unwrap();
+    jar.store_set_cookies(&other, ["x=1; Path=/; Max-Age=3600"].into_iter());
+    assert!(jar.cookie_header(&other).is_none());
+
+    // 同一文件的容器共享 Cookie，白名单分别生效
+    let shared = CookieJar::open(path.clone(), vec!["other.com".to_string()]);
+    assert!(shared.cookie_header(&login).is_none());
+    shared.store_set_cookies(&other, ["y=2; Path=/; Max-Age=3600"].into_iter());
+    assert_eq!(jar.cookie_header(&login).unwrap().matches('=').count(), 5);
+    assert_eq!(shared.cookie_header(&other).as_deref(), Some("y=2"));
+
+    // 过期 Cookie 覆盖同名 Cookie 即删除；会话 Cookie 不落盘
+    jar.store_set_cookies(&login, ["host=1; Path=/; Max-Age=0"].into_iter());
+    let persisted: Vec<StoredCookie> = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+    let mut names: Vec<_> = persisted.iter().map(|c| c.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, vec!["scoped", "secure", "shared", "y"]);
+
+    jar.clear().unwrap();
+    assert!(shared.cookie_header(&other).is_none());
+    assert!(!path.exists());
+    fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn test_url_policy() {
+    let open = UrlPolicy::new(Vec::new());
+    assert!(open.check_str("https://example.com/").is_ok());
+
+    for url in [
+        "http://127.0.0.1/",
+        "http://127.1.2.3:8080/",
+        "http://localhost/",
+        "http://printer.local/",
+        "http://10.0.0.1/",
+        "http://172.16.5.4/",
+        "http://192.168.1.1/",
+        "http://169.254.169.254/latest/meta-data",
+        "http://100.64.0.1/",
+        "http://0.0.0.0/",
+        "http://[::1]/",
+        "http://[::]/",
+        "http://[fe80::1]/",
+        "http://[fd00::1]/",
+        "http://[::ffff:127.0.0.1]/",
+        "http://[::ffff:192.168.1.1]/",
+    ] {
+        let error = open.check_str(url).unwrap_err();
+        assert!(error.contains("Local network"), "{}: {}", url, error);
+    }
+
+    for url in [
+        "ftp://example.com/",
+        "file:///etc/passwd",
+        "ws://example.com/",
+        "javascript:alert(1)",
+        "data:text/plain,hi",
+    ] {
+        assert!(open.check_str(url).is_err(), "{}", url);
+    }
+    assert!(open.check_str("not a url").is_err());
+
+    // 白名单非空时只允许命中的主机（含子域名）
+    let policy = UrlPolicy::new(vec![" .Modrinth.com ".to_string(), "127.0.0.1".to_string()]);
+    assert!(policy.check_str("https://modrinth.com/").is_ok());
+    assert!(policy.check_str("https://api.modrinth.com/v2").is_ok());
+    assert!(policy.check_str("https://evilmodrinth.com/").is_err());
+    assert!(policy.check_str("https://modrinth.com.evil.com/").is_err());
+    assert!(policy.check_str("https://example.com/").is_err());
+    // 显式列入白名单的内网地址允许访问
+    assert!(policy.check_str("http://127.0.0.1:25565/").is_ok());
+    assert!(policy.check_str("http://localhost/").is_err());
+
+    let defaults = UrlPolicy::new(default_allowed_hosts());
+    assert!(defaults
+        .check_str("https://api.minecraftservices.com/minecraft/profile")
+        .is_ok());
+    assert!(defaults.check_str("https://example.com/").is_err());
+}
+
+/// 只允许访问 127.0.0.1 的客户端，返回客户端与缓存目录
+fn loopback_client() -> (HttpClient, std::path::PathBuf) {
+    let cache_dir = std::env::temp_dir().join(format!("novacl-http-{}", uuid::Uuid::new_v4()));
+    let client = HttpClient::with_options(HttpClientOptions {
+        cache_dir: cache_dir.clone(),
+        use_cache: false,
+        use_cookie_jar: false,
+        cookie_domains: Vec::new(),
+        url_policy: UrlPolicy::new(vec!["127.0.0.1".to_string()]),
+    });
+    (client, cache_dir)
+}
+
+#[tokio::test]
+async fn test_redirect_policy() {
+    use axum::response::Redirect;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let router =
+        axum::Router::new()
+            .route("/ok", get(|| async { "ok" }))
+            .route(
+                "/internal",
+                get(|| async { Redirect::temporary("http://10.0.0.1/") }),
+            )
+            .route(
+                "/localhost",
+                get(move || async move {
+                    Redirect::temporary(&format!("http://localhost:{}/ok", port))
+                }),
+            )
+            .route(
+                "/scheme",
+                get(|| async { Redirect::temporary("file:///etc/passwd") }),
+            )
+            .route(
+                "/allowed",
+                get(move || async move {
+                    Redirect::temporary(&format!("http://127.0.0.1:{}/ok", port))
+                }),
+            );
+    tokio::spawn(async move { axum::serve(listener, router).await });
+
+    let (client, cache_dir) = loopback_client();
+    let url = |path: &str| format!("http://127.0.0.1:{}{}", port, path);
+
+    let response = client.get(&url("/allowed"), None).await.unwrap();
+    assert_eq!(response.body, Some(serde_json::json!("ok")));
+    for path in ["/internal", "/localhost"] {
+        let error = client.get(&url(path), None).await.unwrap_err();
+        assert_eq!(error.kind, HttpErrorKind::BlockedByPolicy, "{}", path);
+    }
+    // 非 http(s) 的重定向目标不会被跟随
+    let response = client.get(&url("/scheme"), None).await.unwrap();
+    assert_eq!(response.status, 307);
+    // 请求本身同样检查
+    let error = client.get("http://[::1]/", None).await.unwrap_err();
+    assert_eq!(error.kind, HttpErrorKind::BlockedByPolicy);
+
+    fs::remove_dir_all(&cache_dir).ok();
+}
+
+#[test]
+fn test_redact() {
+    let url = redact_url(
+        "https://login.live.com/oauth20_token.srf?code=M.abc&client_id=id&refresh_token=rt&state=s",
+    );
+    assert!(!url.contains("M.abc") && !url.contains("rt&"));
+    assert!(url.contains("client_id=id"));
+    assert!(url.contains("state=s"));
+    assert_eq!(redact_url("https://example.com/a"), "https://example.com/a");
+    assert_eq!(redact_url("not a url"), "not a url");
+
+    let headers = redact_opt_headers(&Some(headers(&[
+        ("Authorization", "Bearer eyJhbGciOi"),
+        ("Cookie", "sid=secret"),
+        ("X-Api-Key", "key"),
+        ("Accept", "application/json"),
+    ])))
+    .unwrap();
+    assert_eq!(headers["Authorization"], "Bearer ***");
+    assert_eq!(headers["Cookie"], "***");
+    assert_eq!(headers["X-Api-Key"], "***");
+    assert_eq!(headers["Accept"], "application/json");
+    assert!(redact_opt_headers(&None).is_none());
+
+    let body = redact_opt_json(&Some(serde_json::json!({
+        "client_id": "id",
+        "refresh_token": "rt",
+        "Properties": { "RpsTicket": "d=ticket", "UserTokens": ["xbl"] },
+        "agents": [{ "accessToken": "at", "name": "Minecraft" }],
+    })))
+    .unwrap();
+    let text = body.to_string();
+    for secret in ["\"rt\"", "d=ticket", "xbl", "\"at\""] {
+        assert!(!text.contains(secret), "{}", secret);
+    }
+    assert_eq!(body["client_id"], "id");
+    assert_eq!(body["agents"][0]["name"], "Minecraft");
+    assert!(redact_opt_json(&None).is_none());
+}
+
+#[tokio::test]
+async fn test_http_error_kinds() {
+    use axum::http::StatusCode;
+    use axum::response::Redirect;
+    use std::time::Duration;
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    let router = axum::Router::new()
+        .route(
+            "/slow",
+            get(|| async {
+                tokio::time::sleep(Duration::from_secs(5)).await;
+                "slow"
+            }),
+        )
+        .route("/loop", get(|| async { Redirect::temporary("/loop") }))
+        .route("/missing", get(|| async { (StatusCode::NOT_FOUND, "{}") }))
+        .route(
+            "/proxy",
+            get(|| async { StatusCode::PROXY_AUTHENTICATION_REQUIRED }),
+        )
+        .route("/broken", get(|| async { "{" }));
+    tokio::spawn(async move { axum::serve(listener, router).await });
+    let url = |path: &str| format!("http://127.0.0.1:{}{}", port, path);
+    let (client, cache_dir) = loopback_client();
+
+    let reqwest_client = reqwest::Client::builder()
+        .timeout(Duration::from_millis(100))
+        .build()
+        .unwrap();
+    let error = reqwest_client.get(url("/slow")).send().await.unwrap_err();
+    assert_eq!(HttpError::from(error).kind, HttpErrorKind::Timeout);
+
+    let error = reqwest_client
+        .get(url("/broken"))
+        .send()
+        .await
+        .unwrap()
+        .json::<serde_json::Value>()
+        .await
+        .unwrap_err();
+    assert_eq!(HttpError::from(error).kind, HttpErrorKind::BodyDecode);
+
+    // 释放端口后连接被拒绝
+    let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let closed_port = closed.local_addr().unwrap().port();
+    drop(closed);
+    let error = client
+        .get(&format!("http://127.0.0.1:{}/", closed_port), None)
+        .await
+        .unwrap_err();
+    assert_eq!(error.kind, HttpErrorKind::Connect);
+
+    // .invalid 保证无法解析
+    let error = reqwest_client
+        .get("http://no-such-host.invalid/")
+        .send()
+        .await
+        .unwrap_err();
+    assert_eq!(HttpError::from(error).kind, HttpErrorKind::Dns);
+
+    // 对明文服务发起 TLS 握手
+    let error = reqwest_client
+        .get(format!("https://127.0.0.1:{}/", port))
+        .send()
+        .await
+        .unwrap_err();
+    assert_eq!(HttpError::from(error).kind, HttpErrorKind::Tls);
+
+    // 测试服务不支持 CONNECT，隧道建立失败
+    let proxied_client = reqwest::Client::builder()
+        .proxy(reqwest::Proxy::all(url("")).unwrap())
+        .build()
+        .unwrap();
+    let error = proxied_client
+        .get("https://example.com/")
+        .send()
+        .await
+        .unwrap_err();
+    assert_eq!(HttpError::from(error).kind, HttpErrorKind::Proxy);
+
+    let error = client.get(&url("/loop"), None).await.unwrap_err();
+    assert_eq!(error.kind, HttpErrorKind::RedirectLoop);
+
+    // 请求本身成功，由命令转换为 Status 错误
+    let response = client.get(&url("/missing"), None).await.unwrap();
+    assert_eq!(response.status, 404);
+    let error = response.error_for_status().unwrap_err();
+    assert_eq!(error.kind, HttpErrorKind::Status);
+    assert_eq!(error.code, Some(404));
+    assert_eq!(error.body, Some(serde_json::json!({})));
+    let error = client
+        .get(&url("/proxy"), None)
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap_err();
+    assert_eq!(error.kind, HttpErrorKind::Proxy);
+
+    // 带请求 ID 的请求可以取消，ID 在完成后释放
+    let request = HttpRequest {
+        method: "GET".to_string(),
+        url: url("/slow"),
+        headers: None,
+        body: None,
+        request_id: Some("slow".to_string()),
+    };
+    let (result, cancelled) = tokio::join!(client.request(&request), async {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        client.cancel("slow")
+    });
+    assert!(cancelled);
+    assert_eq!(result.unwrap_err().kind, HttpErrorKind::Cancelled);
+    assert!(!client.cancel("slow"));
+
+    fs::remove_dir_all(&cache_dir).ok();
+}
+
+#[test]
+fn test_oauth_callback_state() {
+    let query = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<HashMap<_, _>>()
+    };
+
+    assert_eq!(
+        check_callback("abc", &query(&[("code", "c1"), ("state", "abc")])),
+        CallbackCheck::Code("c1".to_string())
+    );
+    assert_eq!(
+        check_callback("abc", &query(&[("code", "c1"), ("state", "xyz")])),
+        CallbackCheck::Rejected
+    );
+    assert_eq!(
+        check_callback("abc", &query(&[("code", "c1")])),
+        CallbackCheck::Rejected
+    );
+    assert_eq!(
+        check_callback("abc", &query(&[("state", "abc")])),
+        CallbackCheck::Rejected
+    );
+    // 错误回调同样需要 state
+    assert_eq!(
+        check_callback(
+            "abc",
+            &query(&[("error", "access_denied"), ("state", "xyz")])
+        ),
+        CallbackCheck::Rejected
+    );
+    assert_eq!(
+        check_callback(
+            "abc",
+            &query(&[("error", "access_denied"), ("state", "abc")])
+        ),
+        CallbackCheck::Denied {
+            error: "access_denied".to_string(),
+            error_description: None,
+        }
+    );
+}
+
+#[test]
+fn test_oauth_pkce() {
+    let pkce = Pkce::generate();
+    assert_eq!(pkce.code_verifier.len(), 64);
+    assert_eq!(challenge_for(&pkce.code_verifier), pkce.code_challenge);
+    // RFC 7636 附录 B 的示例
+    assert_eq!(
+        challenge_for("dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk"),
+        "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM"
+    );
+
+    let config = OAuthClientConfig {
+        client_id: "client".to_string(),
+        client_secret: None,
+    };
+    let redirect_uri = config.redirect_uri("127.0.0.1:36993".parse().unwrap());
+    assert_eq!(redirect_uri, "http://127.0.0.1:36993/oauth/callback");
+    assert_eq!(
+        config.redirect_uri("[::1]:36993".parse().unwrap()),
+        "http://[::1]:36993/oauth/callback"
+    );
+
+    let url = Url::parse(&build_authorize_url(
+        &config,
+        &redirect_uri,
+        "state",
+        &pkce.code_challenge,
+    ))
+    .unwrap();
+    let params: HashMap<_, _> = url.query_pairs().into_owned().collect();
+    assert_eq!(params["code_challenge"], pkce.code_challenge);
+    assert_eq!(params["code_challenge_method"], "S256");
+    assert_eq!(params["redirect_uri"], redirect_uri);
+    assert!(!params.contains_key("code_verifier"));
+}
+
+// 授权码与会话都是全局状态，放在同一个测试中顺序执行
+#[tokio::test]
+async fn test_oauth_session() {
+    let pkce = Pkce::generate();
+    let redirect_uri = "http://127.0.0.1:36993/oauth/callback".to_string();
+    store_grant(AuthorizationGrant {
+        code: "code".to_string(),
+        code_verifier: pkce.code_verifier.clone(),
+        redirect_uri: redirect_uri.clone(),
+    });
+    assert!(take_grant("other").is_none());
+    let grant = take_grant("code").unwrap();
+    assert_eq!(grant.code_verifier, pkce.code_verifier);
+    assert_eq!(grant.redirect_uri, redirect_uri);
+    // 授权码只能使用一次
+    assert!(take_grant("code").is_none());
+
+    let callback = |code: &str, state: &str| {
+        HashMap::from([
+            ("code".to_string(), code.to_string()),
+            ("state".to_string(), state.to_string()),
+        ])
+    };
+
+    // state 不匹配的回调不会结束会话
+    let (state, challenge) = begin_session(Some("en".to_string()), redirect_uri.clone()).await;
+    assert_eq!(
+        resolve_callback(&callback("forged", "other")).await,
+        Some((Some("en".to_string()), CallbackCheck::Rejected))
+    );
+    assert!(take_grant("forged").is_none());
+
+    // 回调只能使用一次，授权码带着会话的 verifier
+    assert_eq!(
+        resolve_callback(&callback("code", &state)).await,
+        Some((
+            Some("en".to_string()),
+            CallbackCheck::Code("code".to_string())
+        ))
+    );
+    assert!(resolve_callback(&callback("code", &state)).await.is_none());
+    let grant = take_grant("code").unwrap();
+    assert_eq!(challenge_for(&grant.code_verifier), challenge);
+    assert_eq!(grant.redirect_uri, redirect_uri);
+    assert!(!expire_session(&state).await);
+
+    // 超时只结束同一个会话，之后的回调视为过期
+    let (old_state, _) = begin_session(None, redirect_uri.clone()).await;
+    let (state, _) = begin_session(None, redirect_uri.clone()).await;
+    assert!(!expire_session(&old_state).await);
+    assert!(expire_session(&state).await);
+    assert!(resolve_callback(&callback("late", &state)).await.is_none());
+    assert!(take_grant("late").is_none());
+}
@@ -1,3 +1,4 @@
+use crate::http::policy::BlockedByPolicy;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -7,6 +8,9 @@ pub struct HttpRequest {
     pub url: String,
     pub headers: Option<HashMap<String, String>>,
     pub body: Option<serde_json::Value>,
+    /// 请求 ID，用于通过 http_cancel 取消请求
+    #[serde(default)]
+    pub request_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -17,17 +21,158 @@ pub struct HttpResponse {
     pub text: Option<String>,
 }
 
+/// 错误类型，序列化后的字符串可作为前端本地化的稳定键
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HttpErrorKind {
+    /// 域名解析失败
+    Dns,
+    /// 无法建立连接
+    Connect,
+    /// TLS 握手或证书错误
+    Tls,
+    /// 请求超时
+    Timeout,
+    /// 代理错误（含代理认证失败）
+    Proxy,
+    /// 重定向次数过多
+    RedirectLoop,
+    /// 服务器返回错误状态码
+    Status,
+    /// 响应体读取或解析失败
+    BodyDecode,
+    /// 请求被取消
+    Cancelled,
+    /// 被请求策略拦截
+    BlockedByPolicy,
+    /// 请求参数无效
+    InvalidRequest,
+    /// 其他错误
+    Unknown,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct HttpError {
+    /// 错误类型
+    pub kind: HttpErrorKind,
+    /// 错误信息
     pub message: String,
+    /// HTTP 状态码（如有）
     pub code: Option<u16>,
+    /// 响应体（如有）
+    pub body: Option<serde_json::Value>,
+}
+
+impl HttpError {
+    pub fn new(kind: HttpErrorKind, message: impl Into<String>) -> Self {
+        HttpError {
+            kind,
+            message: message.into(),
+            code: None,
+            body: None,
+        }
+    }
+
+    /// 由错误状态码的响应构建
+    pub fn from_status(status: u16, body: Option<serde_json::Value>) -> Self {
+        let kind = if status == 407 {
+            HttpErrorKind::Proxy
+        } else {
+            HttpErrorKind::Status
+        };
+
+        HttpError {
+            kind,
+            message: format!("HTTP status {}", status),
+            code: Some(status),
+            body,
+        }
+    }
+
+    /// 请求被取消
+    pub fn cancelled() -> Self {
+        Self::new(HttpErrorKind::Cancelled, "Request cancelled")
+    }
+}
+
+impl std::fmt::Display for HttpError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.code {
+            Some(code) => write!(f, "{} ({})", self.message, code),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+impl HttpResponse {
+    /// 状态码为 4xx / 5xx 时转换为错误
+    pub fn error_for_status(self) -> Result<Self, HttpError> {
+        if self.status >= 400 {
+            Err(HttpError::from_status(self.status, self.body))
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+/// 沿错误链查找连接阶段的具体原因
+///
+/// hyper-util 的 `ConnectError` 与代理隧道错误未公开导出，只能依据其固定的
+/// 错误信息识别；TLS 错误直接向下转型为 `native_tls::Error`
+fn connect_error_kind(error: &reqwest::Error) -> Option<HttpErrorKind> {
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(error);
+    while let Some(err) = source {
+        if err.is::<BlockedByPolicy>() {
+            return Some(HttpErrorKind::BlockedByPolicy);
+        }
+        if err.to_string().starts_with("tunnel error") {
+            return Some(HttpErrorKind::Proxy);
+        }
+        if err.to_string() == "dns error" {
+            return Some(HttpErrorKind::Dns);
+        }
+        if err.is::<native_tls::Error>() {
+            return Some(HttpErrorKind::Tls);
+        }
+        source = err.source();
+    }
+    None
+}
+
+/// 判断 reqwest 错误的具体类型
+fn classify(error: &reqwest::Error) -> HttpErrorKind {
+    let cause = connect_error_kind(error);
+    if cause == Some(HttpErrorKind::BlockedByPolicy) {
+        HttpErrorKind::BlockedByPolicy
+    } else if error.is_timeout() {
+        HttpErrorKind::Timeout
+    } else if error.is_redirect() {
+        HttpErrorKind::RedirectLoop
+    } else if let Some(status) = error.status() {
+        match status.as_u16() {
+            407 => HttpErrorKind::Proxy,
+            _ => HttpErrorKind::Status,
+        }
+    } else if error.is_connect() {
+        cause.unwrap_or(HttpErrorKind::Connect)
+    } else if error.is_decode() || error.is_body() {
+        HttpErrorKind::BodyDecode
+    } else if error.is_builder() {
+        HttpErrorKind::InvalidRequest
+    } else {
+        HttpErrorKind::Unknown
+    }
 }
 
 impl From<reqwest::Error> for HttpError {
     fn from(error: reqwest::Error) -> Self {
         HttpError {
+            kind: classify(&error),
             message: error.to_string(),
             code: error.status().map(|s| s.as_u16()),
+            body: None,
         }
     }
 }
@@ -1,15 +1,24 @@
+mod auth;
+mod deeplink;
+mod diagnostic;
 mod env;
 mod http;
 mod logging;
+mod modrinth;
 mod system;
 mod toml;
 
+use crate::auth::command::*;
+use crate::deeplink::command::*;
+use crate::diagnostic::command::*;
 use crate::env::command::*;
 use crate::http::command::*;
 use crate::logging::command::*;
 use crate::logging::logging::init_logging;
+use crate::modrinth::command::*;
 use crate::system::command::*;
 use crate::toml::command::*;
+use tauri::Manager;
 
 pub fn run() {
     // 生成 Tauri 上下文
@@ -20,6 +29,9 @@ pub fn run() {
         eprintln!("Failed to initialize logging: {}", e);
     }
 
+    // 记录 panic 并写入崩溃报告
+    logging::crash::install_panic_hook();
+
     // 加载 .env 文件
     if let Err(e) = env::init_env() {
         log::error!(target: "env", "Failed to load .env file: {}", e);
@@ -30,10 +42,34 @@ pub fn run() {
         log::error!(target: "toml", "Failed to initialize config: {}", e);
     }
 
+    // 配置加载后应用日志保留策略
+    if let Ok(config) = toml::get_global_config() {
+        logging::logging::apply_debug_config(&config.other.debug, None);
+    }
+
     tauri::Builder::default()
+        // 必须最先注册：应用已在运行时，把新进程的 novacl:// 链接转发给当前实例
+        .plugin(tauri_plugin_single_instance::init(|app, args, _cwd| {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.unminimize();
+                let _ = window.set_focus();
+            }
+            deeplink::router::handle_launch_args(app, args.into_iter().skip(1));
+        }))
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_updater::Builder::new().build())
+        .setup(|app| {
+            // 崩溃时通知前端
+            logging::crash::set_app_handle(app.handle().clone());
+            // 注册本地服务器的路由组
+            http::server::register_routes(app.handle());
+            // 后台刷新即将过期的微软账户令牌
+            auth::refresh::start_refresh_scheduler(app.handle().clone());
+            // 通过 novacl:// 链接启动时，链接位于命令行参数中
+            deeplink::router::handle_launch_args(app.handle(), std::env::args().skip(1));
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             http_request,
             http_get,
@@ -41,9 +77,14 @@ pub fn run() {
             http_put,
             http_delete,
             http_patch,
+            http_cancel,
+            http_cache_clear,
+            http_cookie_clear,
             http_server_start,
             http_server_stop,
             http_server_status,
+            http_host_start,
+            http_route_groups,
             get_global_config_cmd,
             save_global_config_cmd,
             get_profile_config_cmd,
@@ -56,6 +97,35 @@ pub fn run() {
             decrypt_string_cmd,
             encrypt_string_cmd,
             get_ram_info,
+            get_system_info,
+            // Modrinth 相关命令
+            modrinth_search,
+            modrinth_get_project,
+            modrinth_get_project_versions,
+            modrinth_get_version,
+            modrinth_get_version_from_hash,
+            modrinth_get_versions_from_hashes,
+            modrinth_get_game_versions,
+            modrinth_get_categories,
+            modrinth_get_loaders,
+            modrinth_identify_instance_files,
+            // 账户认证相关命令
+            msa_login_from_code,
+            msa_device_code_start,
+            msa_device_code_cancel,
+            yggdrasil_discover,
+            yggdrasil_login,
+            yggdrasil_select_profile,
+            yggdrasil_refresh,
+            yggdrasil_logout,
+            yggdrasil_signout,
+            authlib_injector_ensure,
+            offline_profile_create,
+            deep_link_handle,
+            deep_link_pending,
+            deep_link_resolve,
+            // 诊断相关命令
+            diagnostic_bundle_create,
             // 环境变量相关命令
             get_env_var,
             get_all_env_vars,
@@ -66,7 +136,20 @@ pub fn run() {
             log_info,
             log_warn,
             log_error,
+            log_batch,
+            log_get_levels,
+            log_set_levels,
+            log_list_files,
+            log_search,
+            log_tail_start,
+            log_tail_stop,
+            crash_report_pending,
+            crash_report_dismiss,
         ])
         .run(context)
-        .expect("error while running tauri application");
+        .unwrap_or_else(|e| {
+            log::error!(target: "app", "Error while running tauri application: {}", e);
+            logging::logging::flush_logs();
+            std::process::exit(1);
+        });
 }
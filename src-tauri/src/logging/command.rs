@@ -1,14 +1,67 @@
-use log::{debug, error, info, trace, warn};
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use log::{info, Level, Record};
+use serde::Deserialize;
+
+use crate::logging::crash::{self, CrashReport};
+use crate::logging::json::fields_from_json;
+use crate::logging::level::{self, LogLevels};
+use crate::logging::logging::{current_log_file, logs_dir, TIMESTAMP_KEY};
+use crate::logging::viewer::{self, LogFileInfo, LogPage, LogQuery};
+use crate::toml::manager::{get_global_config, save_global_config};
+
+/// 前端传入的结构化字段
+pub type LogFields = BTreeMap<String, serde_json::Value>;
+
+/// 前端批量提交的一条日志
+#[derive(Deserialize, Clone, Debug)]
+pub struct LogBatchRecord {
+    /// trace、debug、info、warn 或 error
+    pub level: String,
+    pub category: String,
+    pub message: String,
+    /// 日志产生时的时间（Unix 毫秒），为空时使用写入时的时间
+    pub timestamp: Option<i64>,
+    pub fields: Option<LogFields>,
+}
+
+/// 以 `ts::<category>` 为目标记录前端日志
+fn log_from_frontend(
+    level: Level,
+    category: &str,
+    message: &str,
+    timestamp: Option<i64>,
+    fields: Option<LogFields>,
+) {
+    if level > log::max_level() {
+        return;
+    }
+    let target = format!("ts::{}", category);
+    let mut fields = fields.unwrap_or_default();
+    if let Some(timestamp) = timestamp {
+        fields.insert(TIMESTAMP_KEY.to_string(), timestamp.into());
+    }
+    let fields = fields_from_json(fields);
+    log::logger().log(
+        &Record::builder()
+            .level(level)
+            .target(&target)
+            .args(format_args!("{}", message))
+            .key_values(&fields)
+            .build(),
+    );
+}
 
 /// 记录 TRACE 级别的日志
 ///
 /// # 参数
 /// - `category`: 日志分类
 /// - `message`: 日志内容
+/// - `fields`: 结构化字段，写入 JSON 日志
 #[tauri::command]
-pub fn log_trace(category: String, message: String) {
-    let target = format!("ts::{}", category);
-    trace!(target: &target, "{}", message);
+pub fn log_trace(category: String, message: String, fields: Option<LogFields>) {
+    log_from_frontend(Level::Trace, &category, &message, None, fields);
 }
 
 /// 记录 DEBUG 级别的日志
@@ -16,10 +69,10 @@ pub fn log_trace(category: String, message: String) {
 /// # 参数
 /// - `category`: 日志分类
 /// - `message`: 日志内容
+/// - `fields`: 结构化字段，写入 JSON 日志
 #[tauri::command]
-pub fn log_debug(category: String, message: String) {
-    let target = format!("ts::{}", category);
-    debug!(target: &target, "{}", message);
+pub fn log_debug(category: String, message: String, fields: Option<LogFields>) {
+    log_from_frontend(Level::Debug, &category, &message, None, fields);
 }
 
 /// 记录 INFO 级别的日志
@@ -27,10 +80,10 @@ pub fn log_debug(category: String, message: String) {
 /// # 参数
 /// - `category`: 日志分类
 /// - `message`: 日志内容
+/// - `fields`: 结构化字段，写入 JSON 日志
 #[tauri::command]
-pub fn log_info(category: String, message: String) {
-    let target = format!("ts::{}", category);
-    info!(target: &target, "{}", message);
+pub fn log_info(category: String, message: String, fields: Option<LogFields>) {
+    log_from_frontend(Level::Info, &category, &message, None, fields);
 }
 
 /// 记录 WARN 级别的日志
@@ -38,10 +91,10 @@ pub fn log_info(category: String, message: String) {
 /// # 参数
 /// - `category`: 日志分类
 /// - `message`: 日志内容
+/// - `fields`: 结构化字段，写入 JSON 日志
 #[tauri::command]
-pub fn log_warn(category: String, message: String) {
-    let target = format!("ts::{}", category);
-    warn!(target: &target, "{}", message);
+pub fn log_warn(category: String, message: String, fields: Option<LogFields>) {
+    log_from_frontend(Level::Warn, &category, &message, None, fields);
 }
 
 /// 记录 ERROR 级别的日志
@@ -49,8 +102,119 @@ pub fn log_warn(category: String, message: String) {
 /// # 参数
 /// - `category`: 日志分类
 /// - `message`: 日志内容
+/// - `fields`: 结构化字段，写入 JSON 日志
 #[tauri::command]
-pub fn log_error(category: String, message: String) {
-    let target = format!("ts::{}", category);
-    error!(target: &target, "{}", message);
+pub fn log_error(category: String, message: String, fields: Option<LogFields>) {
+    log_from_frontend(Level::Error, &category, &message, None, fields);
+}
+
+/// 批量记录日志，减少前端频繁记录日志时的调用次数
+///
+/// 在后台线程执行，不阻塞主线程；任一记录的等级无效时不记录任何日志
+///
+/// # 参数
+/// - `records`: 按产生顺序排列的日志
+#[tauri::command]
+pub async fn log_batch(records: Vec<LogBatchRecord>) -> Result<(), String> {
+    let levels = records
+        .iter()
+        .map(|record| {
+            Level::from_str(&record.level)
+                .map_err(|_| format!("Invalid log level: {}", record.level))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (record, level) in records.into_iter().zip(levels) {
+        log_from_frontend(
+            level,
+            &record.category,
+            &record.message,
+            record.timestamp,
+            record.fields,
+        );
+    }
+    Ok(())
+}
+
+/// 获取当前的日志等级
+#[tauri::command]
+pub fn log_get_levels() -> LogLevels {
+    level::current()
+}
+
+/// 设置全局与按目标的日志等级，立即生效
+///
+/// # 参数
+/// - `level`: 全局等级：off、error、warn、info、debug、trace
+/// - `targets`: 按目标设置的等级，例如 `{ "ts::*": "debug", "http": "trace" }`
+/// - `persist`: 是否写入调试配置，默认为 true；调试模式随全局等级是否为 trace 更新
+#[tauri::command]
+pub fn log_set_levels(
+    level: String,
+    targets: Option<BTreeMap<String, String>>,
+    persist: Option<bool>,
+) -> Result<LogLevels, String> {
+    let levels = LogLevels::parse(&level, &targets.unwrap_or_default())?;
+
+    if persist.unwrap_or(true) {
+        let mut config =
+            get_global_config().map_err(|e| format!("Failed to load global config: {}", e))?;
+        levels.write_config(&mut config.other.debug);
+        save_global_config(&config).map_err(|e| format!("Failed to save global config: {}", e))?;
+    }
+
+    info!(target: "logging", "Log level set to {:?}, targets: {:?}", levels.level, levels.targets);
+    level::set(levels.clone());
+    Ok(levels)
+}
+
+fn logs_dir_or_err() -> Result<std::path::PathBuf, String> {
+    logs_dir().ok_or_else(|| "Failed to get data directory".to_string())
+}
+
+/// 列出日志目录中的文件，新的在前
+#[tauri::command]
+pub fn log_list_files() -> Result<Vec<LogFileInfo>, String> {
+    let current = current_log_file();
+    viewer::list_log_files(&logs_dir_or_err()?, current.as_deref())
+        .map_err(|e| format!("Failed to list logs: {}", e))
+}
+
+/// 按等级、分类、正则与时间范围搜索文本日志，新的记录在前
+///
+/// # 参数
+/// - `query`: 搜索条件与分页
+#[tauri::command]
+pub async fn log_search(query: LogQuery) -> Result<LogPage, String> {
+    let dir = logs_dir_or_err()?;
+    tokio::task::spawn_blocking(move || viewer::search(&dir, &query))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// 开始跟踪当前日志，新记录通过 `log:lines` 事件发送
+///
+/// # 返回值
+/// - 跟踪的文件名
+#[tauri::command]
+pub fn log_tail_start(app_handle: tauri::AppHandle) -> Result<String, String> {
+    viewer::start_tail(app_handle)
+}
+
+/// 停止跟踪日志
+#[tauri::command]
+pub fn log_tail_stop() -> bool {
+    viewer::stop_tail()
+}
+
+/// 上次运行留下的、尚未被查看的崩溃报告
+#[tauri::command]
+pub fn crash_report_pending() -> Option<CrashReport> {
+    crash::pending_crash(&crash::crashes_dir())
+}
+
+/// 用户查看崩溃报告后清除提示
+#[tauri::command]
+pub fn crash_report_dismiss() -> Result<(), String> {
+    crash::dismiss_pending_crash(&crash::crashes_dir()).map_err(|e| e.to_string())
 }
@@ -0,0 +1,207 @@
+use std::backtrace::Backtrace;
+use std::cell::Cell;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::panic::PanicHookInfo;
+use std::path::{Path, PathBuf};
+
+use chrono::Local;
+use once_cell::sync::OnceCell;
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Emitter};
+
+use crate::logging::logging::{current_log_file, flush_logs};
+use crate::toml::manager::ConfigManager;
+
+/// 最多保留的崩溃报告数
+pub const MAX_CRASH_FILES: usize = 20;
+
+/// 记录上次崩溃、尚未被用户查看的报告
+const PENDING_FILE: &str = "pending.json";
+
+/// 用于在崩溃时通知前端
+static APP_HANDLE: OnceCell<AppHandle> = OnceCell::new();
+
+thread_local! {
+    /// 崩溃处理过程中再次 panic 时不再重复处理
+    static IN_HOOK: Cell<bool> = const { Cell::new(false) };
+}
+
+/// 崩溃报告，app:panic 事件
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct CrashReport {
+    /// 崩溃报告文件路径，写入失败时为空
+    pub file: String,
+    /// RFC 3339 格式的本地时间
+    pub time: String,
+    pub thread: String,
+    pub message: String,
+    /// 源码位置：`file:line:column`
+    pub location: Option<String>,
+}
+
+/// 崩溃报告目录
+pub fn crashes_dir() -> PathBuf {
+    ConfigManager::instance().app_data_dir().join("Crashes")
+}
+
+/// panic 是否会结束进程
+///
+/// 主线程 panic 后应用退出；其他线程的 panic 可能被 tokio 或 `catch_unwind` 恢复，不标记为待查看
+pub fn is_fatal(thread: &str) -> bool {
+    thread == "main"
+}
+
+/// 设置用于通知前端的句柄，在 setup 中调用
+pub fn set_app_handle(app_handle: AppHandle) {
+    let _ = APP_HANDLE.set(app_handle);
+}
+
+fn panic_message(info: &PanicHookInfo) -> String {
+    let payload = info.payload();
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string())
+}
+
+/// 安装 panic hook：记录日志与调用栈、写入崩溃报告并通知前端
+///
+/// 之前的 hook 仍会被调用
+pub fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        if IN_HOOK.with(|in_hook| in_hook.replace(true)) {
+            previous(info);
+            return;
+        }
+
+        let thread = std::thread::current();
+        let report = CrashReport {
+            file: String::new(),
+            time: Local::now().to_rfc3339(),
+            thread: thread.name().unwrap_or("<unnamed>").to_string(),
+            message: panic_message(info),
+            location: info.location().map(|location| location.to_string()),
+        };
+        let backtrace = Backtrace::force_capture();
+        let report = handle_crash(report, &backtrace.to_string());
+
+        if let Some(app_handle) = APP_HANDLE.get() {
+            let _ = app_handle.emit("app:panic", &report);
+        }
+
+        IN_HOOK.with(|in_hook| in_hook.set(false));
+        previous(info);
+    }));
+}
+
+/// 记录崩溃并写入崩溃报告，返回填好文件路径的报告
+pub fn handle_crash(mut report: CrashReport, backtrace: &str) -> CrashReport {
+    log::error!(
+        target: "panic",
+        "Thread '{}' panicked at {}: {}\n{}",
+        report.thread,
+        report.location.as_deref().unwrap_or("<unknown>"),
+        report.message,
+        backtrace
+    );
+
+    let pending = is_fatal(&report.thread);
+    match write_crash_report(&crashes_dir(), &report, backtrace, pending) {
+        Ok(path) => report.file = path.to_string_lossy().into_owned(),
+        Err(e) => log::error!(target: "panic", "Failed to write crash report: {}", e),
+    }
+    // panic 后进程可能立即退出，等待日志写入磁盘
+    flush_logs();
+    report
+}
+
+/// 写入崩溃报告，超出数量时删除最早的报告
+///
+/// - `pending`: 标记为待查看，下次启动时提示用户
+pub fn write_crash_report(
+    dir: &Path,
+    report: &CrashReport,
+    backtrace: &str,
+    pending: bool,
+) -> io::Result<PathBuf> {
+    fs::create_dir_all(dir)?;
+    let path = dir.join(format!(
+        "crash-{}.txt",
+        Local::now().format("%Y-%m-%d_%H-%M-%S%.3f")
+    ));
+
+    let mut content = String::new();
+    let _ = writeln!(content, "NovaCL crash report");
+    let _ = writeln!(content, "Time: {}", report.time);
+    let _ = writeln!(content, "Version: {}", env!("CARGO_PKG_VERSION"));
+    let _ = writeln!(
+        content,
+        "OS: {} {}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    );
+    let _ = writeln!(content, "Thread: {}", report.thread);
+    let _ = writeln!(
+        content,
+        "Location: {}",
+        report.location.as_deref().unwrap_or("<unknown>")
+    );
+    let _ = writeln!(content, "Message: {}", report.message);
+    if let Some(log_file) = current_log_file() {
+        let _ = writeln!(content, "Log file: {}", log_file.display());
+    }
+    let _ = writeln!(content, "\nBacktrace:\n{}", backtrace);
+    fs::write(&path, content)?;
+
+    if pending {
+        let report = CrashReport {
+            file: path.to_string_lossy().into_owned(),
+            ..report.clone()
+        };
+        let content = serde_json::to_vec_pretty(&report).map_err(io::Error::other)?;
+        fs::write(dir.join(PENDING_FILE), content)?;
+    }
+
+    prune_crash_reports(dir, MAX_CRASH_FILES)?;
+    Ok(path)
+}
+
+/// 删除最早的崩溃报告，只保留 `keep` 个
+fn prune_crash_reports(dir: &Path, keep: usize) -> io::Result<()> {
+    let mut reports: Vec<PathBuf> = fs::read_dir(dir)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("crash-") && name.ends_with(".txt"))
+        })
+        .collect();
+    // 文件名包含时间，按名称排序即按时间排序
+    reports.sort();
+    let excess = reports.len().saturating_sub(keep);
+    for path in reports.into_iter().take(excess) {
+        let _ = fs::remove_file(path);
+    }
+    Ok(())
+}
+
+/// 上次运行留下的、尚未被查看的崩溃报告
+pub fn pending_crash(dir: &Path) -> Option<CrashReport> {
+    let content = fs::read(dir.join(PENDING_FILE)).ok()?;
+    let report: CrashReport = serde_json::from_slice(&content).ok()?;
+    // 报告文件已被删除时不再提示
+    Path::new(&report.file).is_file().then_some(report)
+}
+
+/// 用户查看后清除待查看标记
+pub fn dismiss_pending_crash(dir: &Path) -> io::Result<()> {
+    match fs::remove_file(dir.join(PENDING_FILE)) {
+        Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
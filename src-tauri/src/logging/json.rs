@@ -0,0 +1,152 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Local, SecondsFormat};
+use log::kv::{self, Key, Value, VisitSource, VisitValue};
+use log::Record;
+use serde::{Deserialize, Serialize};
+
+use crate::logging::logging::{split_target, TIMESTAMP_KEY};
+
+/// JSON Lines 日志中的一条记录
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct JsonRecord {
+    /// RFC 3339 格式的本地时间
+    pub timestamp: String,
+    pub level: String,
+    /// TS 或 Rust
+    pub source: String,
+    pub category: String,
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// 线程名称，未命名时为线程 ID
+    pub thread: String,
+    pub message: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub fields: BTreeMap<String, serde_json::Value>,
+}
+
+impl JsonRecord {
+    pub fn from_record(record: &Record, time: DateTime<Local>) -> Self {
+        let (source, category) = split_target(record.target());
+        let thread = std::thread::current();
+        let mut fields = FieldCollector::default();
+        // 收集字段不会失败
+        let _ = record.key_values().visit(&mut fields);
+
+        Self {
+            timestamp: time.to_rfc3339_opts(SecondsFormat::Millis, false),
+            level: record.level().to_string(),
+            source: source.to_string(),
+            category: category.to_string(),
+            target: record.target().to_string(),
+            module_path: record.module_path().map(str::to_string),
+            file: record.file().map(str::to_string),
+            line: record.line(),
+            thread: thread
+                .name()
+                .map(str::to_string)
+                .unwrap_or_else(|| format!("{:?}", thread.id())),
+            message: record.args().to_string(),
+            fields: fields.0,
+        }
+    }
+}
+
+#[derive(Default)]
+struct FieldCollector(BTreeMap<String, serde_json::Value>);
+
+impl<'kvs> VisitSource<'kvs> for FieldCollector {
+    fn visit_pair(&mut self, key: Key<'kvs>, value: Value<'kvs>) -> Result<(), kv::Error> {
+        // 记录时间已写入 timestamp
+        if key.as_str() == TIMESTAMP_KEY {
+            return Ok(());
+        }
+        self.0.insert(key.to_string(), to_json(&value));
+        Ok(())
+    }
+}
+
+fn to_json(value: &Value) -> serde_json::Value {
+    let mut json = JsonValue(serde_json::Value::Null);
+    if value.visit(&mut json).is_err() {
+        return value.to_string().into();
+    }
+    json.0
+}
+
+/// 将 `log` 的值转换为 JSON，无法识别的类型以文本记录
+struct JsonValue(serde_json::Value);
+
+impl<'v> VisitValue<'v> for JsonValue {
+    fn visit_any(&mut self, value: Value) -> Result<(), kv::Error> {
+        self.0 = value.to_string().into();
+        Ok(())
+    }
+
+    fn visit_null(&mut self) -> Result<(), kv::Error> {
+        self.0 = serde_json::Value::Null;
+        Ok(())
+    }
+
+    fn visit_u64(&mut self, value: u64) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+
+    fn visit_i64(&mut self, value: i64) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+
+    fn visit_f64(&mut self, value: f64) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+
+    fn visit_bool(&mut self, value: bool) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+
+    fn visit_str(&mut self, value: &str) -> Result<(), kv::Error> {
+        self.0 = value.into();
+        Ok(())
+    }
+}
+
+/// 前端传入的字段，转换为 `log` 的键值对
+pub fn fields_from_json(fields: BTreeMap<String, serde_json::Value>) -> Vec<(String, FieldValue)> {
+    fields
+        .into_iter()
+        .map(|(key, value)| (key, FieldValue(value)))
+        .collect()
+}
+
+/// 前端传入的字段值
+pub struct FieldValue(serde_json::Value);
+
+impl kv::ToValue for FieldValue {
+    fn to_value(&self) -> Value<'_> {
+        match &self.0 {
+            serde_json::Value::Null => Value::null(),
+            serde_json::Value::Bool(value) => Value::from(*value),
+            serde_json::Value::Number(number) => {
+                if let Some(value) = number.as_i64() {
+                    Value::from(value)
+                } else if let Some(value) = number.as_u64() {
+                    Value::from(value)
+                } else {
+                    Value::from(number.as_f64().unwrap_or_default())
+                }
+            }
+            serde_json::Value::String(value) => Value::from(value.as_str()),
+            // 数组与对象以 JSON 文本记录
+            other => Value::from_display(other),
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use log::{LevelFilter, Metadata};
+use serde::Serialize;
+
+use crate::toml::types::DebugConfig;
+
+lazy_static::lazy_static! {
+    static ref LEVELS: RwLock<LogLevels> = RwLock::new(LogLevels::initial());
+}
+
+/// 全局与按目标设置的日志等级
+///
+/// 目标 `http` 同时匹配 `http` 与 `http::*`，`ts::*` 匹配所有前端日志，
+/// 匹配多个时以最长的为准
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct LogLevels {
+    #[serde(serialize_with = "serialize_level")]
+    pub level: LevelFilter,
+    #[serde(serialize_with = "serialize_targets")]
+    pub targets: BTreeMap<String, LevelFilter>,
+}
+
+impl LogLevels {
+    /// 配置加载前使用，调试构建记录全部日志
+    fn initial() -> Self {
+        Self {
+            level: if cfg!(debug_assertions) {
+                LevelFilter::Trace
+            } else {
+                LevelFilter::Info
+            },
+            targets: BTreeMap::new(),
+        }
+    }
+
+    /// 解析等级名称，目标为空或等级无效时返回错误
+    pub fn parse(level: &str, targets: &BTreeMap<String, String>) -> Result<Self, String> {
+        let mut parsed = BTreeMap::new();
+        for (target, target_level) in targets {
+            let target = normalize_target(target);
+            if target.is_empty() {
+                return Err("Log target must not be empty".to_string());
+            }
+            parsed.insert(target, parse_level(target_level)?);
+        }
+        Ok(Self {
+            level: parse_level(level)?,
+            targets: parsed,
+        })
+    }
+
+    /// 由调试配置生成，开启调试模式时全局等级为 Trace，无效的等级会被忽略
+    pub fn from_config(config: &DebugConfig) -> Self {
+        let level = if config.debug_mode {
+            LevelFilter::Trace
+        } else {
+            LevelFilter::from_str(&config.log_level).unwrap_or(LevelFilter::Info)
+        };
+        let targets = config
+            .log_targets
+            .iter()
+            .filter_map(|(target, level)| {
+                let target = normalize_target(target);
+                let level = LevelFilter::from_str(level).ok()?;
+                (!target.is_empty()).then_some((target, level))
+            })
+            .collect();
+        Self { level, targets }
+    }
+
+    /// 写回调试配置，调试模式随全局等级是否为 Trace 一并更新，避免覆盖写入的等级
+    pub fn write_config(&self, config: &mut DebugConfig) {
+        config.debug_mode = self.level == LevelFilter::Trace;
+        config.log_level = level_name(self.level).to_string();
+        config.log_targets = self
+            .targets
+            .iter()
+            .map(|(target, level)| (target.clone(), level_name(*level).to_string()))
+            .collect();
+    }
+
+    /// 目标对应的等级
+    pub fn level_for(&self, target: &str) -> LevelFilter {
+        self.targets
+            .iter()
+            .filter(|(pattern, _)| matches_target(pattern, target))
+            .max_by_key(|(pattern, _)| pattern.len())
+            .map(|(_, level)| *level)
+            .unwrap_or(self.level)
+    }
+
+    /// 所有等级中最详细的一个，用于 `log::set_max_level`
+    pub fn max_level(&self) -> LevelFilter {
+        self.targets.values().copied().fold(self.level, Ord::max)
+    }
+}
+
+fn parse_level(level: &str) -> Result<LevelFilter, String> {
+    LevelFilter::from_str(level.trim()).map_err(|_| format!("Invalid log level: {}", level))
+}
+
+fn level_name(level: LevelFilter) -> &'static str {
+    match level {
+        LevelFilter::Off => "off",
+        LevelFilter::Error => "error",
+        LevelFilter::Warn => "warn",
+        LevelFilter::Info => "info",
+        LevelFilter::Debug => "debug",
+        LevelFilter::Trace => "trace",
+    }
+}
+
+fn serialize_level<S: serde::Serializer>(
+    level: &LevelFilter,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(level_name(*level))
+}
+
+fn serialize_targets<S: serde::Serializer>(
+    targets: &BTreeMap<String, LevelFilter>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(
+        targets
+            .iter()
+            .map(|(target, level)| (target, level_name(*level))),
+    )
+}
+
+/// `ts::*` 与 `ts` 等价
+fn normalize_target(target: &str) -> String {
+    let target = target.trim();
+    target.strip_suffix("::*").unwrap_or(target).to_string()
+}
+
+fn matches_target(pattern: &str, target: &str) -> bool {
+    target
+        .strip_prefix(pattern)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+}
+
+/// 当前的日志等级
+pub fn current() -> LogLevels {
+    LEVELS.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// 替换日志等级，立即生效
+pub fn set(levels: LogLevels) {
+    log::set_max_level(levels.max_level());
+    *LEVELS.write().unwrap_or_else(|e| e.into_inner()) = levels;
+}
+
+/// 日志是否应被记录，供 fern 的 filter 调用
+pub fn enabled(metadata: &Metadata) -> bool {
+    metadata.level()
+        <= LEVELS
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .level_for(metadata.target())
+}
@@ -1,8 +1,104 @@
-use chrono::Local;
-use log::LevelFilter;
-use std::fs::{self, File};
+use chrono::{DateTime, Local, TimeZone};
+use log::kv::Key;
+use log::{LevelFilter, Record};
+use once_cell::sync::OnceCell;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use tauri::Config;
 
+use crate::logging::json::JsonRecord;
+use crate::logging::level::{self, LogLevels};
+use crate::logging::rotation::{
+    compress_closed_logs, LogPolicy, RotatingFile, JSON_EXTENSION, LOG_EXTENSION,
+};
+use crate::logging::writer::LogWriter;
+use crate::toml::types::DebugConfig;
+
+/// 当前的日志文件，fern 与配置更新共用
+static LOG_FILE: OnceCell<Arc<Mutex<RotatingFile>>> = OnceCell::new();
+
+/// 当前的 JSON Lines 日志文件
+static JSON_LOG_FILE: OnceCell<Arc<Mutex<RotatingFile>>> = OnceCell::new();
+
+/// 后台日志写入线程，文本日志与 JSON 日志共用
+static WRITER: OnceCell<LogWriter> = OnceCell::new();
+
+/// 记录时间的键，前端批量提交的日志带有产生时的时间（Unix 毫秒）
+pub const TIMESTAMP_KEY: &str = "@timestamp";
+
+/// 日志目录
+pub fn logs_dir() -> Option<PathBuf> {
+    dirs::data_dir().map(|dir| dir.join("NovaCL").join("Logs"))
+}
+
+/// 当前正在写入的日志文件
+pub fn current_log_file() -> Option<PathBuf> {
+    LOG_FILE.get().map(|file| {
+        file.lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .path()
+            .to_path_buf()
+    })
+}
+
+/// 等待已记录的日志写入磁盘
+pub fn flush_logs() {
+    if let Some(writer) = WRITER.get() {
+        writer.flush();
+    }
+}
+
+/// 记录的时间，没有指定时为当前时间
+pub fn record_time(record: &Record) -> DateTime<Local> {
+    record
+        .key_values()
+        .get(Key::from_str(TIMESTAMP_KEY))
+        .and_then(|value| value.to_i64())
+        .and_then(|millis| Local.timestamp_millis_opt(millis).single())
+        .unwrap_or_else(Local::now)
+}
+
+/// 解析日志目标，返回来源（TS/Rust）和分类
+pub fn split_target(target: &str) -> (&str, &str) {
+    match target.strip_prefix("ts::") {
+        // 前端调用的日志，格式：ts::category
+        Some(category) => ("TS", category),
+        // Rust 内部日志，格式：crate::module 或 module
+        None => ("Rust", target.split("::").last().unwrap_or(target)),
+    }
+}
+
+/// 应用调试配置中的日志等级与轮转、保留策略，并按新策略清理旧日志
+///
+/// - `previous`: 修改前的调试配置，配置中的日志等级未变化时保留运行时设置的等级
+///
+/// 不要在持有日志文件锁时记录日志
+pub fn apply_debug_config(config: &DebugConfig, previous: Option<&DebugConfig>) {
+    let levels = LogLevels::from_config(config);
+    if previous.map(LogLevels::from_config).as_ref() != Some(&levels) {
+        level::set(levels);
+    }
+
+    let policy = LogPolicy::from_config(config);
+    for file in [LOG_FILE.get(), JSON_LOG_FILE.get()].into_iter().flatten() {
+        let mut file = file.lock().unwrap_or_else(|e| e.into_inner());
+
+        // 开启压缩时一并压缩之前启动留下的日志
+        if policy.compress && !file.policy().compress {
+            let dir = file.dir().to_path_buf();
+            let kind = file.extension();
+            std::thread::spawn(move || {
+                if let Err(e) = compress_closed_logs(&dir, kind) {
+                    log::warn!(target: "logging", "Failed to compress old logs: {}", e);
+                }
+            });
+        }
+        file.set_policy(policy.clone());
+    }
+}
+
 /// 初始化日志系统
 ///
 /// # 参数
@@ -12,82 +108,67 @@ use tauri::Config;
 /// - `Result<(), Box<dyn std::error::Error>>`: 初始化结果
 pub fn init_logging(_config: &Config) -> Result<(), Box<dyn std::error::Error>> {
     // 使用 dirs 库获取应用数据目录
-    let app_data_dir = dirs::data_dir()
-        .ok_or("Failed to get data directory")?
-        .join("NovaCL");
-    let logs_dir = app_data_dir.join("Logs");
+    let logs_dir = logs_dir().ok_or("Failed to get data directory")?;
 
     // 创建日志目录
     fs::create_dir_all(&logs_dir)?;
 
-    // 生成日志文件名（格式：YYYY-MM-DD_HH-MM-SS.log）
-    let log_file_name = Local::now().format("%Y-%m-%d_%H-%M-%S.log").to_string();
-    let log_file_path = logs_dir.join(log_file_name);
+    // 日志文件名（格式：YYYY-MM-DD_HH-MM-SS.log），超出大小后轮转为 _1、_2……
+    // 同名的 .jsonl 文件记录结构化日志，配置加载后再通过 apply_debug_config 应用保留策略
+    let session = Local::now().format("%Y-%m-%d_%H-%M-%S").to_string();
+    let log_file = Arc::new(Mutex::new(RotatingFile::create(
+        &logs_dir,
+        &session,
+        LOG_EXTENSION,
+        LogPolicy::default(),
+    )?));
+    let _ = LOG_FILE.set(log_file.clone());
+    let json_log_file = Arc::new(Mutex::new(RotatingFile::create(
+        &logs_dir,
+        &session,
+        JSON_EXTENSION,
+        LogPolicy::default(),
+    )?));
+    let _ = JSON_LOG_FILE.set(json_log_file.clone());
+    let writer = WRITER.get_or_try_init(LogWriter::spawn)?;
 
-    // 配置 fern 日志器
-    #[cfg(debug_assertions)]
-    fern::Dispatch::new()
-        // 设置日志格式：[时间] [等级] [TS/Rust] [分类] 内容
+    // 文本日志格式：[时间] [等级] [TS/Rust] [分类] 内容
+    let text = fern::Dispatch::new()
         .format(|out, message, record| {
-            // 解析目标，获取来源（TS/Rust）和分类
-            let target = record.target();
-            let (source, category) = if target.starts_with("ts::") {
-                // 前端调用的日志，格式：ts::category
-                ("TS", &target[4..])
-            } else {
-                // Rust 内部日志，格式：crate::module 或 module
-                ("Rust", target.split("::").last().unwrap_or(target))
-            };
-
+            let (source, category) = split_target(record.target());
             out.finish(format_args!(
                 "[{}] [{}] [{}] [{}] {}",
-                Local::now().format("%Y-%m-%d %H:%M:%S"),
+                record_time(record).format("%Y-%m-%d %H:%M:%S"),
                 record.level(),
                 source,
                 category,
                 message
             ))
         })
-        // 设置默认日志级别为 Info
-        .level(LevelFilter::Trace)
-        // 输出到文件
-        .chain(File::create(log_file_path)?)
+        // 输出到文件，由后台线程写入
+        .chain(Box::new(writer.sink(log_file)) as Box<dyn Write + Send>)
         // 同时输出到控制台
-        .chain(std::io::stdout())
-        // 应用配置
-        .apply()?;
+        .chain(std::io::stdout());
 
-    #[cfg(not(debug_assertions))]
-    fern::Dispatch::new()
-        // 设置日志格式：[时间] [等级] [TS/Rust] [分类] 内容
-        .format(|out, message, record| {
-            // 解析目标，获取来源（TS/Rust）和分类
-            let target = record.target();
-            let (source, category) = if target.starts_with("ts::") {
-                // 前端调用的日志，格式：ts::category
-                ("TS", &target[4..])
-            } else {
-                // Rust 内部日志，格式：crate::module 或 module
-                ("Rust", target.split("::").last().unwrap_or(target))
-            };
-
-            out.finish(format_args!(
-                "[{}] [{}] [{}] [{}] {}",
-                Local::now().format("%Y-%m-%d %H:%M:%S"),
-                record.level(),
-                source,
-                category,
-                message
-            ))
+    // JSON Lines 日志，每行一条记录
+    let json = fern::Dispatch::new()
+        .format(|out, _message, record| {
+            let record = JsonRecord::from_record(record, record_time(record));
+            if let Ok(line) = serde_json::to_string(&record) {
+                out.finish(format_args!("{}", line))
+            }
         })
-        // 设置默认日志级别为 Info
-        .level(LevelFilter::Info)
-        // 输出到文件
-        .chain(File::create(log_file_path)?)
-        // 同时输出到控制台
-        .chain(std::io::stdout())
+        .chain(Box::new(writer.sink(json_log_file)) as Box<dyn Write + Send>);
+
+    // 配置 fern 日志器，等级在运行时由 level 模块决定
+    fern::Dispatch::new()
+        .level(LevelFilter::Trace)
+        .filter(level::enabled)
+        .chain(text)
+        .chain(json)
         // 应用配置
         .apply()?;
+    log::set_max_level(level::current().max_level());
 
     Ok(())
 }
@@ -1,2 +1,10 @@
-pub mod logging;
 pub mod command;
+pub mod crash;
+pub mod json;
+pub mod level;
+pub mod logging;
+pub mod rotation;
+#[cfg(test)]
+mod test;
+pub mod viewer;
+pub mod writer;
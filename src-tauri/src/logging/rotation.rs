@@ -0,0 +1,353 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, SystemTime};
+
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::toml::types::DebugConfig;
+
+const MB: u64 = 1024 * 1024;
+
+/// 文本日志扩展名
+pub const LOG_EXTENSION: &str = "log";
+
+/// JSON Lines 日志扩展名
+pub const JSON_EXTENSION: &str = "jsonl";
+
+/// 压缩后追加的扩展名
+pub const COMPRESSED_SUFFIX: &str = "gz";
+
+lazy_static::lazy_static! {
+    /// 正在写入的日志文件，保留策略不会删除
+    static ref ACTIVE_FILES: Mutex<HashSet<PathBuf>> = Mutex::new(HashSet::new());
+    /// 压缩与清理同一时间只进行一次，避免同一文件被压缩两次
+    static ref CLEANUP_LOCK: Mutex<()> = Mutex::new(());
+}
+
+fn active_files() -> MutexGuard<'static, HashSet<PathBuf>> {
+    ACTIVE_FILES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn cleanup_guard() -> MutexGuard<'static, ()> {
+    CLEANUP_LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// 轮转与保留策略
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogPolicy {
+    /// 单个文件的最大字节数，超出后在下一条记录前轮转
+    pub max_file_size: u64,
+    /// 最多保留的文件数，包含当前文件；文本日志与 JSON 日志分别计数
+    pub max_files: usize,
+    /// 最长保留时间
+    pub max_age: Option<Duration>,
+    /// 日志目录中所有种类日志合计的最大字节数
+    pub max_total_size: Option<u64>,
+    /// 压缩已关闭的日志
+    pub compress: bool,
+}
+
+impl Default for LogPolicy {
+    fn default() -> Self {
+        Self {
+            max_file_size: 10 * MB,
+            max_files: 30,
+            max_age: Some(Duration::from_secs(14 * 24 * 60 * 60)),
+            max_total_size: Some(200 * MB),
+            compress: false,
+        }
+    }
+}
+
+impl LogPolicy {
+    /// 由调试配置生成，0 表示不限制
+    pub fn from_config(config: &DebugConfig) -> Self {
+        Self {
+            max_file_size: config.log_max_file_mb.max(1) * MB,
+            max_files: config.log_max_files.max(1),
+            max_age: (config.log_max_age_days > 0)
+                .then(|| Duration::from_secs(config.log_max_age_days * 24 * 60 * 60)),
+            max_total_size: (config.log_max_total_mb > 0).then(|| config.log_max_total_mb * MB),
+            compress: config.log_compress,
+        }
+    }
+}
+
+/// 日志种类，即去掉压缩扩展名后的扩展名，不是日志文件时返回 None
+pub fn log_kind(path: &Path) -> Option<&'static str> {
+    let name = path.file_name()?.to_str()?;
+    let name = name
+        .strip_suffix(COMPRESSED_SUFFIX)
+        .and_then(|name| name.strip_suffix('.'))
+        .unwrap_or(name);
+    let extension = Path::new(name).extension()?;
+    [LOG_EXTENSION, JSON_EXTENSION]
+        .into_iter()
+        .find(|kind| extension == *kind)
+}
+
+/// 是否为日志文件（含压缩后的日志）
+pub fn is_log_file(path: &Path) -> bool {
+    log_kind(path).is_some()
+}
+
+/// 按大小轮转的日志文件
+///
+/// 同一次启动的文件共用前缀：`<session>.log`、`<session>_1.log`、`<session>_2.log`……
+pub struct RotatingFile {
+    dir: PathBuf,
+    session: String,
+    extension: &'static str,
+    index: u32,
+    path: PathBuf,
+    file: BufWriter<File>,
+    written: u64,
+    /// 上次 flush 后没有新的写入，fern 每条记录写完后都会 flush
+    at_boundary: bool,
+    policy: LogPolicy,
+}
+
+impl RotatingFile {
+    pub fn create(
+        dir: &Path,
+        session: &str,
+        extension: &'static str,
+        policy: LogPolicy,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(dir)?;
+        let path = dir.join(format!("{}.{}", session, extension));
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let written = file.metadata()?.len();
+        active_files().insert(path.clone());
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            session: session.to_string(),
+            extension,
+            index: 0,
+            path,
+            file: BufWriter::new(file),
+            written,
+            at_boundary: true,
+            policy,
+        })
+    }
+
+    /// 当前写入的文件
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 文件扩展名，区分文本日志与 JSON 日志
+    pub fn extension(&self) -> &'static str {
+        self.extension
+    }
+
+    pub fn policy(&self) -> &LogPolicy {
+        &self.policy
+    }
+
+    /// 更新策略，立即按新策略清理旧日志
+    pub fn set_policy(&mut self, policy: LogPolicy) {
+        self.policy = policy;
+        self.spawn_cleanup(None);
+    }
+
+    /// 关闭当前文件并开始写入下一个文件
+    pub fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+        let closed = self.path.clone();
+
+        self.index += 1;
+        self.path = self.dir.join(format!(
+            "{}_{}.{}",
+            self.session, self.index, self.extension
+        ));
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.file = BufWriter::new(file);
+        self.written = 0;
+
+        let mut active = active_files();
+        active.remove(&closed);
+        active.insert(self.path.clone());
+        drop(active);
+
+        self.spawn_cleanup(Some(closed));
+        Ok(())
+    }
+
+    /// 写入一条完整的记录，不刷新到磁盘
+    pub fn write_record(&mut self, record: &[u8]) -> io::Result<()> {
+        if self.written >= self.policy.max_file_size {
+            self.rotate()?;
+        }
+        self.file.write_all(record)?;
+        self.written += record.len() as u64;
+        self.at_boundary = true;
+        Ok(())
+    }
+
+    /// 在后台压缩刚关闭的文件并执行保留策略，避免阻塞日志写入
+    fn spawn_cleanup(&self, closed: Option<PathBuf>) {
+        let dir = self.dir.clone();
+        let policy = self.policy.clone();
+        std::thread::spawn(move || {
+            let _guard = cleanup_guard();
+            if policy.compress {
+                if let Some(closed) = closed {
+                    if let Err(e) = compress_file(&closed) {
+                        eprintln!("Failed to compress {}: {}", closed.display(), e);
+                    }
+                }
+            }
+            let active: Vec<PathBuf> = active_files().iter().cloned().collect();
+            if let Err(e) = apply_retention(&dir, &policy, &active, SystemTime::now()) {
+                eprintln!("Failed to clean up logs: {}", e);
+            }
+        });
+    }
+}
+
+impl Drop for RotatingFile {
+    fn drop(&mut self) {
+        active_files().remove(&self.path);
+    }
+}
+
+impl Write for RotatingFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        // 只在记录之间轮转，一条记录不会被拆到两个文件中
+        if self.at_boundary && self.written >= self.policy.max_file_size {
+            self.rotate()?;
+        }
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+        self.at_boundary = false;
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.at_boundary = true;
+        self.file.flush()
+    }
+}
+
+/// 将日志压缩为 `.log.gz` 或 `.jsonl.gz` 并删除原文件
+///
+/// 保留原文件的修改时间，保留策略与日志列表按修改时间排序
+pub fn compress_file(path: &Path) -> io::Result<PathBuf> {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(format!(".{}", COMPRESSED_SUFFIX));
+    let target = path.with_file_name(name);
+    let source = File::open(path)?;
+    let modified = source.metadata()?.modified()?;
+    let mut reader = BufReader::new(source);
+    let mut encoder = GzEncoder::new(
+        BufWriter::new(File::create(&target)?),
+        Compression::default(),
+    );
+    io::copy(&mut reader, &mut encoder)?;
+    let file = encoder.finish()?.into_inner().map_err(|e| e.into_error())?;
+    file.set_modified(modified)?;
+    fs::remove_file(path)?;
+    Ok(target)
+}
+
+/// 压缩目录中指定种类、未压缩且未在写入的日志，例如上次启动留下的日志
+///
+/// 与轮转后的后台清理互斥；单个文件压缩失败时记录日志并继续处理其他文件
+pub fn compress_closed_logs(dir: &Path, kind: &str) -> io::Result<Vec<PathBuf>> {
+    let _guard = cleanup_guard();
+    let active = active_files().clone();
+    let mut compressed = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let is_plain = path.extension().is_some_and(|ext| ext == kind);
+        if !is_plain || active.contains(&path) {
+            continue;
+        }
+        match compress_file(&path) {
+            Ok(target) => compressed.push(target),
+            Err(e) => {
+                log::warn!(target: "logging", "Failed to compress {}: {}", path.display(), e)
+            }
+        }
+    }
+    Ok(compressed)
+}
+
+/// 按保留策略删除旧日志，返回被删除的文件
+///
+/// 依次检查最长保留时间、文件数与总大小，`active` 中正在写入的文件不会被删除
+///
+/// 文件数按文本日志与 JSON 日志分别计算，总大小包含所有种类的日志
+pub fn apply_retention(
+    dir: &Path,
+    policy: &LogPolicy,
+    active: &[PathBuf],
+    now: SystemTime,
+) -> io::Result<Vec<PathBuf>> {
+    let mut logs = Vec::new();
+    let mut total = 0;
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if !is_log_file(&path) {
+            continue;
+        }
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        if active.contains(&path) {
+            total += metadata.len();
+            continue;
+        }
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        logs.push((path, modified, metadata.len()));
+    }
+    // 新的在前
+    logs.sort_by_key(|(_, modified, _)| std::cmp::Reverse(*modified));
+
+    // 每种日志的当前文件计入文件数
+    let mut kept: HashMap<&str, usize> = [LOG_EXTENSION, JSON_EXTENSION]
+        .into_iter()
+        .map(|kind| (kind, 1))
+        .collect();
+    let mut over_budget = false;
+    let mut removed = Vec::new();
+    for (path, modified, size) in logs {
+        let kind = log_kind(&path).unwrap_or(LOG_EXTENSION);
+        let kept = kept.entry(kind).or_insert(1);
+        let expired = policy
+            .max_age
+            .is_some_and(|max_age| now.duration_since(modified).is_ok_and(|age| age > max_age));
+        // 超出总大小后，更旧的日志也一并删除
+        over_budget = over_budget
+            || policy
+                .max_total_size
+                .is_some_and(|max_total| total + size > max_total);
+
+        if expired || over_budget || *kept >= policy.max_files {
+            // 后台清理可能同时进行，文件已被删除时忽略
+            match fs::remove_file(&path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+                _ => removed.push(path),
+            }
+        } else {
+            *kept += 1;
+            total += size;
+        }
+    }
+    Ok(removed)
+}
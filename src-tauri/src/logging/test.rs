@@ -0,0 +1,580 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use flate2::read::GzDecoder;
+use log::LevelFilter;
+
+use crate::logging::crash::{
+    dismiss_pending_crash, is_fatal, pending_crash, write_crash_report, CrashReport,
+    MAX_CRASH_FILES,
+};
+use crate::logging::json::{fields_from_json, JsonRecord};
+use crate::logging::level::LogLevels;
+use crate::logging::rotation::{
+    apply_retention, compress_closed_logs, compress_file, LogPolicy, RotatingFile, LOG_EXTENSION,
+};
+use crate::logging::viewer::{list_log_files, parse_line, search, LogQuery, LogTail};
+use crate::logging::writer::LogWriter;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn temp_logs_dir() -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("novacl-logs-{}", uuid::Uuid::new_v4()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn write_log(dir: &Path, name: &str, size: usize, modified: SystemTime) -> PathBuf {
+    let path = dir.join(name);
+    let file = File::create(&path).unwrap();
+    file.set_len(size as u64).unwrap();
+    file.set_modified(modified).unwrap();
+    path
+}
+
+fn unlimited() -> LogPolicy {
+    LogPolicy {
+        max_file_size: u64::MAX,
+        max_files: usize::MAX,
+        max_age: None,
+        max_total_size: None,
+        compress: false,
+    }
+}
+
+#[test]
+fn test_rotate_on_size() {
+    let dir = temp_logs_dir();
+    let policy = LogPolicy {
+        max_file_size: 16,
+        ..unlimited()
+    };
+    let mut file = RotatingFile::create(&dir, "session", LOG_EXTENSION, policy).unwrap();
+
+    // 一条记录分多次写入，不会被拆到两个文件中
+    file.write_all(b"0123456789").unwrap();
+    file.write_all(b"0123456789\n").unwrap();
+    file.flush().unwrap();
+    assert_eq!(file.path(), dir.join("session.log"));
+    file.write_all(b"0123456789\n").unwrap();
+    file.flush().unwrap();
+    assert_eq!(file.path(), dir.join("session_1.log"));
+
+    assert_eq!(
+        fs::read(dir.join("session.log")).unwrap(),
+        b"01234567890123456789\n"
+    );
+    assert_eq!(
+        fs::read(dir.join("session_1.log")).unwrap(),
+        b"0123456789\n"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_retention_policy() {
+    let dir = temp_logs_dir();
+    let now = SystemTime::now();
+    let active = write_log(&dir, "active.log", 10, now);
+    let recent = write_log(&dir, "recent.log", 10, now - DAY);
+    let older = write_log(&dir, "older.log.gz", 10, now - DAY * 2);
+    let expired = write_log(&dir, "expired.log", 10, now - DAY * 30);
+    let other = write_log(&dir, "notes.txt", 10, now - DAY * 30);
+
+    // 按时间
+    let policy = LogPolicy {
+        max_age: Some(DAY * 14),
+        ..unlimited()
+    };
+    let removed = apply_retention(&dir, &policy, std::slice::from_ref(&active), now).unwrap();
+    assert_eq!(removed, vec![expired]);
+
+    // 按数量，当前文件也计入
+    let policy = LogPolicy {
+        max_files: 2,
+        ..unlimited()
+    };
+    let removed = apply_retention(&dir, &policy, std::slice::from_ref(&active), now).unwrap();
+    assert_eq!(removed, vec![older]);
+
+    // 按总大小，当前文件不会被删除
+    let policy = LogPolicy {
+        max_total_size: Some(15),
+        ..unlimited()
+    };
+    let removed = apply_retention(&dir, &policy, std::slice::from_ref(&active), now).unwrap();
+    assert_eq!(removed, vec![recent]);
+    assert!(active.exists());
+    assert!(other.exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_compress_file() {
+    let dir = temp_logs_dir();
+    let path = dir.join("session.log");
+    fs::write(&path, "[2025-01-01 00:00:00] [INFO] [Rust] [test] hello\n").unwrap();
+
+    let compressed = compress_file(&path).unwrap();
+    assert_eq!(compressed, dir.join("session.log.gz"));
+    assert!(!path.exists());
+
+    let mut content = String::new();
+    GzDecoder::new(File::open(&compressed).unwrap())
+        .read_to_string(&mut content)
+        .unwrap();
+    assert_eq!(
+        content,
+        "[2025-01-01 00:00:00] [INFO] [Rust] [test] hello\n"
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_compress_closed_logs() {
+    let dir = temp_logs_dir();
+    let now = SystemTime::now();
+    // 两个正在写入的文件，例如轮转后的当前文件与另一个写入器的文件
+    let first = RotatingFile::create(&dir, "first", LOG_EXTENSION, unlimited()).unwrap();
+    let second = RotatingFile::create(&dir, "second", LOG_EXTENSION, unlimited()).unwrap();
+    write_log(&dir, "old.log", 10, now - DAY);
+    write_log(&dir, "old.jsonl", 10, now - DAY);
+    // 无法压缩的文件不影响其他文件
+    fs::create_dir(dir.join("broken.log")).unwrap();
+
+    let compressed = compress_closed_logs(&dir, LOG_EXTENSION).unwrap();
+    assert_eq!(compressed, vec![dir.join("old.log.gz")]);
+    assert!(first.path().exists() && second.path().exists());
+    assert!(dir.join("old.jsonl").exists());
+
+    drop((first, second));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_log_levels_per_target() {
+    let targets = targets_of(&[
+        ("ts::*", "debug"),
+        ("http", "trace"),
+        ("http::cache", "warn"),
+    ]);
+    let levels = LogLevels::parse("Info", &targets).unwrap();
+
+    assert_eq!(levels.level_for("ts::launcher"), LevelFilter::Debug);
+    assert_eq!(levels.level_for("http"), LevelFilter::Trace);
+    assert_eq!(levels.level_for("http::server"), LevelFilter::Trace);
+    assert_eq!(levels.level_for("http::cache"), LevelFilter::Warn);
+    assert_eq!(levels.level_for("https"), LevelFilter::Info);
+    assert_eq!(levels.level_for("toml"), LevelFilter::Info);
+    assert_eq!(levels.max_level(), LevelFilter::Trace);
+
+    assert!(LogLevels::parse("verbose", &BTreeMap::new()).is_err());
+    assert!(LogLevels::parse("info", &targets_of(&[("", "debug")])).is_err());
+}
+
+#[test]
+fn test_log_levels_config_round_trip() {
+    let mut config = crate::toml::types::GlobalConfig::default().other.debug;
+    let levels = LogLevels::parse("warn", &targets_of(&[("ts::*", "trace")])).unwrap();
+    levels.write_config(&mut config);
+    assert_eq!(config.log_level, "warn");
+    assert_eq!(config.log_targets, targets_of(&[("ts", "trace")]));
+    assert_eq!(LogLevels::from_config(&config), levels);
+
+    // 调试模式下全局等级为 Trace，按目标的设置仍然生效
+    config.debug_mode = true;
+    let levels = LogLevels::from_config(&config);
+    assert_eq!(levels.level, LevelFilter::Trace);
+    assert_eq!(levels.level_for("ts::launcher"), LevelFilter::Trace);
+
+    // 写入较低的等级时关闭调试模式，重新加载后仍是写入的等级
+    let levels = LogLevels::parse("info", &BTreeMap::new()).unwrap();
+    levels.write_config(&mut config);
+    assert!(!config.debug_mode);
+    assert_eq!(LogLevels::from_config(&config), levels);
+    LogLevels::parse("trace", &BTreeMap::new())
+        .unwrap()
+        .write_config(&mut config);
+    assert!(config.debug_mode);
+}
+
+fn targets_of(targets: &[(&str, &str)]) -> BTreeMap<String, String> {
+    targets
+        .iter()
+        .map(|(target, level)| (target.to_string(), level.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_retention_by_kind() {
+    let dir = temp_logs_dir();
+    let now = SystemTime::now();
+    let active = write_log(&dir, "session.log", 10, now);
+    let active_json = write_log(&dir, "session.jsonl", 10, now);
+    let old = write_log(&dir, "old.log", 10, now - DAY);
+    let old_json = write_log(&dir, "old.jsonl.gz", 10, now - DAY);
+
+    let recent_json = write_log(&dir, "recent.jsonl", 10, now - DAY / 2);
+    let active_files = [active.clone(), active_json.clone()];
+
+    // 总大小包含两种日志：当前文件 20 字节，只能再保留 10 字节
+    let policy = LogPolicy {
+        max_total_size: Some(30),
+        ..unlimited()
+    };
+    let mut removed = apply_retention(&dir, &policy, &active_files, now).unwrap();
+    removed.sort();
+    assert_eq!(removed, vec![old_json.clone(), old.clone()]);
+    assert!(recent_json.exists());
+
+    // 文件数按种类分别计算
+    write_log(&dir, "old.log", 10, now - DAY);
+    let policy = LogPolicy {
+        max_files: 2,
+        ..unlimited()
+    };
+    assert!(apply_retention(&dir, &policy, &active_files, now)
+        .unwrap()
+        .is_empty());
+    let policy = LogPolicy {
+        max_files: 1,
+        ..unlimited()
+    };
+    let mut removed = apply_retention(&dir, &policy, &active_files, now).unwrap();
+    removed.sort();
+    assert_eq!(removed, vec![old, recent_json]);
+    assert!(active.exists() && active_json.exists());
+
+    let compressed = compress_file(&active_json).unwrap();
+    assert_eq!(compressed, dir.join("session.jsonl.gz"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_json_record_fields() {
+    let fields = [
+        ("instance", serde_json::json!("1.20.1")),
+        ("attempt", serde_json::json!(2)),
+        ("ok", serde_json::json!(false)),
+        ("mods", serde_json::json!(["a", "b"])),
+    ]
+    .into_iter()
+    .map(|(key, value)| (key.to_string(), value))
+    .collect();
+    let fields = fields_from_json(fields);
+    let record = log::Record::builder()
+        .level(log::Level::Warn)
+        .target("ts::launcher")
+        .module_path(Some("novacl_lib::logging::command"))
+        .line(Some(42))
+        .args(format_args!("Launch failed"))
+        .key_values(&fields)
+        .build();
+
+    let json = JsonRecord::from_record(&record, chrono::Local::now());
+    assert_eq!(json.level, "WARN");
+    assert_eq!(json.source, "TS");
+    assert_eq!(json.category, "launcher");
+    assert_eq!(json.line, Some(42));
+    assert_eq!(json.message, "Launch failed");
+    assert_eq!(json.fields["instance"], "1.20.1");
+    assert_eq!(json.fields["attempt"], 2);
+    assert_eq!(json.fields["ok"], false);
+    assert_eq!(json.fields["mods"], r#"["a","b"]"#);
+
+    let line = serde_json::to_string(&json).unwrap();
+    assert!(!line.contains('\n'));
+    assert_eq!(serde_json::from_str::<JsonRecord>(&line).unwrap(), json);
+}
+
+const SAMPLE_LOG: &str = "\
+[2025-01-01 10:00:00] [INFO] [Rust] [toml] Config loaded
+[2025-01-01 10:00:01] [WARN] [TS] [launcher] Java not found
+[2025-01-01 10:00:02] [ERROR] [Rust] [http] Request failed
+  caused by: timeout
+[2025-01-01 10:00:03] [INFO] [TS] [launcher] Retrying
+";
+
+#[test]
+fn test_parse_log_line() {
+    let record = parse_line("[2025-01-01 10:00:00] [INFO] [TS] [ui] Clicked [start]").unwrap();
+    assert_eq!(record.timestamp, "2025-01-01 10:00:00");
+    assert_eq!(record.level, "INFO");
+    assert_eq!(record.source, "TS");
+    assert_eq!(record.category, "ui");
+    assert_eq!(record.message, "Clicked [start]");
+
+    assert!(parse_line("  caused by: timeout").is_none());
+    assert!(parse_line("[not a time] [INFO] [Rust] [toml] x").is_none());
+}
+
+#[test]
+fn test_search_logs() {
+    let dir = temp_logs_dir();
+    let now = SystemTime::now();
+    fs::write(dir.join("old.log"), SAMPLE_LOG).unwrap();
+    File::open(dir.join("old.log"))
+        .unwrap()
+        .set_modified(now - DAY)
+        .unwrap();
+    fs::write(
+        dir.join("new.log"),
+        "[2025-01-02 09:00:00] [ERROR] [TS] [launcher] Crashed\n",
+    )
+    .unwrap();
+    compress_file(&dir.join("old.log")).unwrap();
+    fs::write(dir.join("new.jsonl"), "{}\n").unwrap();
+
+    let files = list_log_files(&dir, Some(&dir.join("new.log"))).unwrap();
+    let names: Vec<&str> = files.iter().map(|file| file.name.as_str()).collect();
+    assert_eq!(names.len(), 3);
+    assert!(files
+        .iter()
+        .any(|file| file.name == "new.log" && file.current));
+    assert!(files
+        .iter()
+        .any(|file| file.name == "old.log.gz" && file.compressed));
+
+    // 新的在前，多行消息合并为一条
+    let query = LogQuery {
+        count_total: true,
+        ..LogQuery::default()
+    };
+    let page = search(&dir, &query).unwrap();
+    assert_eq!(page.total, Some(5));
+    assert_eq!(page.records[0].message, "Crashed");
+    assert_eq!(
+        page.records[2].message,
+        "Request failed\n  caused by: timeout"
+    );
+    assert_eq!(page.records[2].line, 3);
+
+    let query = LogQuery {
+        levels: Some(vec!["warn".to_string(), "error".to_string()]),
+        pattern: Some("(?i)java|crash".to_string()),
+        ..LogQuery::default()
+    };
+    let page = search(&dir, &query).unwrap();
+    let messages: Vec<&str> = page.records.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(messages, vec!["Crashed", "Java not found"]);
+
+    let query = LogQuery {
+        category: Some("launcher".to_string()),
+        offset: 1,
+        limit: Some(1),
+        ..LogQuery::default()
+    };
+    let page = search(&dir, &query).unwrap();
+    assert_eq!(page.total, None);
+    assert_eq!(page.records[0].message, "Retrying");
+    assert!(page.has_more);
+    let query = LogQuery {
+        count_total: true,
+        ..query
+    };
+    assert_eq!(search(&dir, &query).unwrap().total, Some(3));
+
+    let from = chrono::NaiveDate::from_ymd_opt(2025, 1, 1)
+        .unwrap()
+        .and_hms_opt(10, 0, 1)
+        .unwrap()
+        .and_local_timezone(chrono::Local)
+        .unwrap()
+        .timestamp_millis();
+    let query = LogQuery {
+        files: Some(vec!["old.log.gz".to_string()]),
+        from: Some(from),
+        to: Some(from + 2000),
+        ..LogQuery::default()
+    };
+    assert_eq!(search(&dir, &query).unwrap().records.len(), 2);
+
+    let query = LogQuery {
+        files: Some(vec!["../old.log.gz".to_string()]),
+        ..LogQuery::default()
+    };
+    assert!(search(&dir, &query).is_err());
+    let query = LogQuery {
+        pattern: Some("(".to_string()),
+        ..LogQuery::default()
+    };
+    assert!(search(&dir, &query).is_err());
+
+    // 取满一页后不再读取更早的文件，统计总数时才会读到损坏的文件
+    fs::write(dir.join("broken.log.gz"), "not gzip").unwrap();
+    File::open(dir.join("broken.log.gz"))
+        .unwrap()
+        .set_modified(now - DAY * 2)
+        .unwrap();
+    let query = LogQuery {
+        limit: Some(1),
+        ..LogQuery::default()
+    };
+    let page = search(&dir, &query).unwrap();
+    assert_eq!(page.records[0].message, "Crashed");
+    assert!(page.has_more);
+    let query = LogQuery {
+        count_total: true,
+        ..query
+    };
+    assert!(search(&dir, &query).is_err());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_tail_log() {
+    let dir = temp_logs_dir();
+    let path = dir.join("session.log");
+    fs::write(
+        &path,
+        "[2025-01-01 10:00:00] [INFO] [Rust] [toml] Before tail\n",
+    )
+    .unwrap();
+
+    let mut tail = LogTail::open(&path).unwrap();
+    assert!(tail.poll().unwrap().is_empty());
+
+    let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(
+        b"[2025-01-01 10:00:01] [WARN] [Rust] [http] First\n  detail\n[2025-01-01 10:00:02] [INFO]",
+    )
+    .unwrap();
+    // 最后一行还没写完，之前的记录可能还有后续行
+    assert!(tail.poll().unwrap().is_empty());
+
+    file.write_all(b" [Rust] [http] Second\n").unwrap();
+    let records = tail.poll().unwrap();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].message, "First\n  detail");
+    assert_eq!(records[1].message, "Second");
+    assert_eq!(records[1].line, 3);
+
+    // 轮转前写入、还没读到的记录不会丢失
+    file.write_all(b"[2025-01-01 10:00:03] [INFO] [Rust] [http] Before rotation\n")
+        .unwrap();
+    file.write_all(b"[2025-01-01 10:00:04] [INFO] [Rust] [http] Last")
+        .unwrap();
+    let next = dir.join("session_1.log");
+    fs::write(
+        &next,
+        "[2025-01-01 10:00:05] [INFO] [Rust] [http] Rotated\n",
+    )
+    .unwrap();
+    let records = tail.switch_to(&next);
+    let messages: Vec<_> = records.iter().map(|r| r.message.as_str()).collect();
+    assert_eq!(messages, vec!["Before rotation", "Last"]);
+    assert_eq!(records[0].file, "session.log");
+    assert_eq!(tail.poll().unwrap()[0].message, "Rotated");
+
+    // 旧文件已被压缩时从压缩文件中读完
+    let mut file = fs::OpenOptions::new().append(true).open(&next).unwrap();
+    file.write_all(b"[2025-01-01 10:00:06] [INFO] [Rust] [http] Compressed\n")
+        .unwrap();
+    drop(file);
+    compress_file(&next).unwrap();
+    let records = tail.switch_to(&dir.join("session_2.log"));
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].message, "Compressed");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_crash_report() {
+    let dir = temp_logs_dir();
+    let report = CrashReport {
+        file: String::new(),
+        time: "2025-01-01T10:00:00+08:00".to_string(),
+        thread: "tokio-runtime-worker".to_string(),
+        message: "index out of bounds".to_string(),
+        location: Some("src/http/server.rs:42:5".to_string()),
+    };
+
+    // 工作线程的 panic 可能已被恢复，只写报告不提示
+    assert!(!is_fatal(&report.thread));
+    assert!(is_fatal("main"));
+    write_crash_report(&dir, &report, "", false).unwrap();
+    assert!(pending_crash(&dir).is_none());
+
+    let path = write_crash_report(&dir, &report, "0: novacl_lib::run", true).unwrap();
+    let content = fs::read_to_string(&path).unwrap();
+    assert!(content.contains("Message: index out of bounds"));
+    assert!(content.contains("Location: src/http/server.rs:42:5"));
+    assert!(content.contains("0: novacl_lib::run"));
+
+    let pending = pending_crash(&dir).unwrap();
+    assert_eq!(pending.file, path.to_string_lossy());
+    assert_eq!(pending.message, report.message);
+
+    dismiss_pending_crash(&dir).unwrap();
+    assert!(pending_crash(&dir).is_none());
+    dismiss_pending_crash(&dir).unwrap();
+
+    // 超出数量时删除最早的报告
+    for _ in 0..MAX_CRASH_FILES + 2 {
+        write_crash_report(&dir, &report, "", true).unwrap();
+        std::thread::sleep(Duration::from_millis(2));
+    }
+    let reports = fs::read_dir(&dir)
+        .unwrap()
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("crash-"))
+        .count();
+    assert_eq!(reports, MAX_CRASH_FILES);
+    assert!(!path.exists());
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_async_writer() {
+    let dir = temp_logs_dir();
+    let policy = LogPolicy {
+        max_file_size: 16,
+        ..unlimited()
+    };
+    let file = Arc::new(Mutex::new(
+        RotatingFile::create(&dir, "session", LOG_EXTENSION, policy).unwrap(),
+    ));
+    let writer = LogWriter::spawn().unwrap();
+    let mut sink = writer.sink(file.clone());
+
+    // flush 前的写入属于同一条记录
+    sink.write_all(b"0123456789").unwrap();
+    sink.write_all(b"0123456789\n").unwrap();
+    sink.flush().unwrap();
+    writer.flush();
+    assert_eq!(
+        fs::read(dir.join("session.log")).unwrap(),
+        b"01234567890123456789\n"
+    );
+
+    for i in 0..3 {
+        sink.write_all(format!("record {}\n", i).as_bytes())
+            .unwrap();
+        sink.flush().unwrap();
+    }
+    // 没有 flush 的内容不会提交
+    sink.write_all(b"partial").unwrap();
+    writer.flush();
+    assert_eq!(
+        fs::read_to_string(dir.join("session_1.log")).unwrap(),
+        "record 0\nrecord 1\n"
+    );
+    assert_eq!(
+        fs::read_to_string(dir.join("session_2.log")).unwrap(),
+        "record 2\n"
+    );
+    assert_eq!(file.lock().unwrap().path(), dir.join("session_2.log"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,525 @@
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
+
+use chrono::{Local, NaiveDateTime, TimeZone};
+use flate2::read::GzDecoder;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tauri::async_runtime::JoinHandle;
+use tauri::{AppHandle, Emitter};
+
+use crate::logging::logging::current_log_file;
+use crate::logging::rotation::{log_kind, COMPRESSED_SUFFIX, LOG_EXTENSION};
+
+/// 文本日志中的时间格式
+const TIMESTAMP_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+/// 单页最多返回的记录数
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+/// 跟踪日志时检查新内容的间隔
+const TAIL_INTERVAL: Duration = Duration::from_millis(500);
+
+lazy_static::lazy_static! {
+    static ref TAIL_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+}
+
+/// 日志目录中的文件
+#[derive(Serialize, Clone, Debug)]
+pub struct LogFileInfo {
+    pub name: String,
+    pub size: u64,
+    /// 修改时间（Unix 毫秒）
+    pub modified: u64,
+    /// log 或 jsonl
+    pub kind: String,
+    pub compressed: bool,
+    /// 是否为正在写入的文件
+    pub current: bool,
+}
+
+/// 解析后的一条文本日志，多行消息会合并为一条
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+pub struct LogRecord {
+    pub file: String,
+    /// 记录起始行号，从 1 开始
+    pub line: usize,
+    pub timestamp: String,
+    pub level: String,
+    pub source: String,
+    pub category: String,
+    pub message: String,
+}
+
+/// 日志搜索条件，未设置的条件不参与过滤
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct LogQuery {
+    /// 只搜索这些文件，默认为全部文本日志
+    pub files: Option<Vec<String>>,
+    /// 日志等级，例如 `["WARN", "ERROR"]`，不区分大小写
+    pub levels: Option<Vec<String>>,
+    /// 分类，完全匹配
+    pub category: Option<String>,
+    /// 匹配消息的正则表达式
+    pub pattern: Option<String>,
+    /// 起始时间（Unix 毫秒，包含）
+    pub from: Option<i64>,
+    /// 结束时间（Unix 毫秒，不包含）
+    pub to: Option<i64>,
+    pub offset: usize,
+    /// 每页数量，默认为 200，最大为 MAX_PAGE_SIZE
+    pub limit: Option<usize>,
+    /// 统计匹配的记录总数，需要读取全部文件；不统计时取满一页后停止
+    pub count_total: bool,
+}
+
+/// 搜索结果的一页，新的记录在前
+#[derive(Serialize, Clone, Debug)]
+pub struct LogPage {
+    pub records: Vec<LogRecord>,
+    /// 匹配的记录总数，仅在 `count_total` 时统计
+    pub total: Option<usize>,
+    pub offset: usize,
+    pub has_more: bool,
+}
+
+/// 列出日志目录中的文件，新的在前
+pub fn list_log_files(dir: &Path, current: Option<&Path>) -> io::Result<Vec<LogFileInfo>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let Some(kind) = log_kind(&path) else {
+            continue;
+        };
+        let metadata = entry.metadata()?;
+        if !metadata.is_file() {
+            continue;
+        }
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|time| time.duration_since(SystemTime::UNIX_EPOCH).ok())
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or(0);
+        files.push(LogFileInfo {
+            name: entry.file_name().to_string_lossy().into_owned(),
+            size: metadata.len(),
+            modified,
+            kind: kind.to_string(),
+            compressed: path.extension().is_some_and(|ext| ext == COMPRESSED_SUFFIX),
+            current: current.is_some_and(|current| current == path),
+        });
+    }
+    files.sort_by(|a, b| b.modified.cmp(&a.modified).then(b.name.cmp(&a.name)));
+    Ok(files)
+}
+
+/// 日志目录中的文件，名称不能包含路径
+pub fn resolve_log_file(dir: &Path, name: &str) -> Result<PathBuf, String> {
+    let valid = !name.is_empty()
+        && Path::new(name).file_name().is_some_and(|file| file == name)
+        && log_kind(Path::new(name)).is_some();
+    if !valid {
+        return Err(format!("Invalid log file name: {}", name));
+    }
+    let path = dir.join(name);
+    if !path.is_file() {
+        return Err(format!("Log file not found: {}", name));
+    }
+    Ok(path)
+}
+
+/// 解析一行文本日志：`[时间] [等级] [TS/Rust] [分类] 内容`
+///
+/// 不是记录开头的行返回 None，属于上一条记录的消息
+pub fn parse_line(line: &str) -> Option<LogRecord> {
+    let (timestamp, rest) = take_field(line)?;
+    NaiveDateTime::parse_from_str(timestamp, TIMESTAMP_FORMAT).ok()?;
+    let (level, rest) = take_field(rest)?;
+    let (source, rest) = take_field(rest)?;
+    let (category, message) = take_field(rest)?;
+    Some(LogRecord {
+        file: String::new(),
+        line: 0,
+        timestamp: timestamp.to_string(),
+        level: level.to_string(),
+        source: source.to_string(),
+        category: category.to_string(),
+        message: message.to_string(),
+    })
+}
+
+fn take_field(input: &str) -> Option<(&str, &str)> {
+    let (field, rest) = input.strip_prefix('[')?.split_once(']')?;
+    Some((field, rest.strip_prefix(' ').unwrap_or(rest)))
+}
+
+/// 增量解析文本日志，供读取整个文件与跟踪新内容共用
+#[derive(Default)]
+pub struct RecordParser {
+    file: String,
+    line: usize,
+    pending: Option<LogRecord>,
+}
+
+impl RecordParser {
+    pub fn new(file: &str) -> Self {
+        Self {
+            file: file.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// 解析一行，返回已完整的上一条记录
+    pub fn push(&mut self, line: &str) -> Option<LogRecord> {
+        self.line += 1;
+        let line = line.trim_end_matches(['\r', '\n']);
+        match parse_line(line) {
+            Some(mut record) => {
+                record.file = self.file.clone();
+                record.line = self.line;
+                self.pending.replace(record)
+            }
+            None => {
+                // 没有记录开头时（例如文件被截断）丢弃该行
+                if let Some(pending) = &mut self.pending {
+                    pending.message.push('\n');
+                    pending.message.push_str(line);
+                }
+                None
+            }
+        }
+    }
+
+    /// 取出最后一条记录
+    pub fn finish(&mut self) -> Option<LogRecord> {
+        self.pending.take()
+    }
+}
+
+/// 逐条读取文本日志中的记录，支持 `.log.gz`，不会把整个文件读入内存
+pub fn for_each_record(path: &Path, mut f: impl FnMut(LogRecord)) -> io::Result<()> {
+    let file = File::open(path)?;
+    let reader: Box<dyn Read> = if path.extension().is_some_and(|ext| ext == COMPRESSED_SUFFIX) {
+        Box::new(GzDecoder::new(file))
+    } else {
+        Box::new(file)
+    };
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let mut parser = RecordParser::new(&name);
+    for line in BufReader::new(reader).split(b'\n') {
+        // 日志中可能有非 UTF-8 内容
+        let line = String::from_utf8_lossy(&line?).into_owned();
+        if let Some(record) = parser.push(&line) {
+            f(record);
+        }
+    }
+    if let Some(record) = parser.finish() {
+        f(record);
+    }
+    Ok(())
+}
+
+/// 编译后的搜索条件
+struct Filter {
+    levels: Option<Vec<String>>,
+    category: Option<String>,
+    pattern: Option<Regex>,
+    from: Option<i64>,
+    to: Option<i64>,
+}
+
+impl Filter {
+    fn new(query: &LogQuery) -> Result<Self, String> {
+        let pattern = query
+            .pattern
+            .as_deref()
+            .filter(|pattern| !pattern.is_empty())
+            .map(Regex::new)
+            .transpose()
+            .map_err(|e| format!("Invalid pattern: {}", e))?;
+        Ok(Self {
+            levels: query.levels.as_ref().map(|levels| {
+                levels
+                    .iter()
+                    .map(|level| level.to_ascii_uppercase())
+                    .collect()
+            }),
+            category: query
+                .category
+                .clone()
+                .filter(|category| !category.is_empty()),
+            pattern,
+            from: query.from,
+            to: query.to,
+        })
+    }
+
+    fn matches(&self, record: &LogRecord) -> bool {
+        if let Some(levels) = &self.levels {
+            if !levels.contains(&record.level) {
+                return false;
+            }
+        }
+        if let Some(category) = &self.category {
+            if &record.category != category {
+                return false;
+            }
+        }
+        if self.from.is_some() || self.to.is_some() {
+            let Some(time) = record_time(record) else {
+                return false;
+            };
+            if self.from.is_some_and(|from| time < from) || self.to.is_some_and(|to| time >= to) {
+                return false;
+            }
+        }
+        self.pattern
+            .as_ref()
+            .is_none_or(|pattern| pattern.is_match(&record.message))
+    }
+}
+
+/// 记录时间（Unix 毫秒），日志使用本地时间
+fn record_time(record: &LogRecord) -> Option<i64> {
+    let time = NaiveDateTime::parse_from_str(&record.timestamp, TIMESTAMP_FORMAT).ok()?;
+    Local
+        .from_local_datetime(&time)
+        .earliest()
+        .map(|time| time.timestamp_millis())
+}
+
+/// 搜索文本日志，文件与记录均按新的在前排列
+pub fn search(dir: &Path, query: &LogQuery) -> Result<LogPage, String> {
+    let filter = Filter::new(query)?;
+    let limit = query.limit.unwrap_or(200).clamp(1, MAX_PAGE_SIZE);
+
+    let files: Vec<PathBuf> = match &query.files {
+        Some(names) => names
+            .iter()
+            .map(|name| resolve_log_file(dir, name))
+            .collect::<Result<_, _>>()?,
+        None => list_log_files(dir, None)
+            .map_err(|e| format!("Failed to list logs: {}", e))?
+            .into_iter()
+            .filter(|file| file.kind == LOG_EXTENSION)
+            .map(|file| dir.join(file.name))
+            .collect(),
+    };
+
+    // 多取一条用于判断是否还有下一页
+    let wanted = query.offset.saturating_add(limit).saturating_add(1);
+    // 较新的文件中已匹配的记录数
+    let mut matched = 0;
+    let mut records = Vec::new();
+    for path in files {
+        if matched >= wanted && !query.count_total {
+            break;
+        }
+
+        // 文件内新的记录在后，只保留最后 needed 条匹配的记录
+        let needed = wanted.saturating_sub(matched);
+        let mut newest = VecDeque::new();
+        let mut count = 0;
+        let read = for_each_record(&path, |record| {
+            if !filter.matches(&record) {
+                return;
+            }
+            count += 1;
+            if needed == 0 {
+                return;
+            }
+            if newest.len() == needed {
+                newest.pop_front();
+            }
+            newest.push_back(record);
+        });
+        match read {
+            Ok(()) => {}
+            // 列出后可能已被清理
+            Err(e) if e.kind() == io::ErrorKind::NotFound => continue,
+            Err(e) => return Err(format!("Failed to read {}: {}", path.display(), e)),
+        }
+
+        for (index, record) in (matched..).zip(newest.into_iter().rev()) {
+            if index >= query.offset {
+                records.push(record);
+            }
+        }
+        matched += count;
+    }
+
+    let has_more = records.len() > limit;
+    records.truncate(limit);
+    Ok(LogPage {
+        records,
+        total: query.count_total.then_some(matched),
+        offset: query.offset,
+        has_more,
+    })
+}
+
+/// 跟踪正在写入的日志文件，读取新增的完整行
+pub struct LogTail {
+    path: PathBuf,
+    position: u64,
+    parser: RecordParser,
+    partial: Vec<u8>,
+}
+
+impl LogTail {
+    /// 从文件末尾开始跟踪
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let position = fs::metadata(path)?.len();
+        Ok(Self::at(path, position))
+    }
+
+    fn at(path: &Path, position: u64) -> Self {
+        let name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Self {
+            path: path.to_path_buf(),
+            position,
+            parser: RecordParser::new(&name),
+            partial: Vec::new(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 日志轮转后先读完旧文件，再从新文件开头继续跟踪，返回旧文件剩余的记录
+    pub fn switch_to(&mut self, path: &Path) -> Vec<LogRecord> {
+        // 轮转前写入的内容可能还没读到，开启压缩时旧文件可能已被压缩
+        let mut records = match self.poll() {
+            Ok(records) => records,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                self.poll_compressed().unwrap_or_default()
+            }
+            Err(_) => Vec::new(),
+        };
+        // 旧文件不会再写入，末尾没有换行的内容也是完整的记录
+        if !self.partial.is_empty() {
+            let line = String::from_utf8_lossy(&std::mem::take(&mut self.partial)).into_owned();
+            records.extend(self.parser.push(&line));
+        }
+        records.extend(self.parser.finish());
+        *self = Self::at(path, 0);
+        records
+    }
+
+    /// 从压缩后的旧文件中读取剩余内容
+    fn poll_compressed(&mut self) -> io::Result<Vec<LogRecord>> {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(format!(".{}", COMPRESSED_SUFFIX));
+        let mut decoder = GzDecoder::new(File::open(self.path.with_file_name(name))?);
+        io::copy(&mut (&mut decoder).take(self.position), &mut io::sink())?;
+        let mut buf = Vec::new();
+        decoder.read_to_end(&mut buf)?;
+        self.position += buf.len() as u64;
+        Ok(self.feed(&buf))
+    }
+
+    /// 读取新增内容，返回已完整的记录
+    ///
+    /// 每条记录写入后都会 flush，读到完整的行时即视为最后一条记录已完整
+    pub fn poll(&mut self) -> io::Result<Vec<LogRecord>> {
+        let mut file = File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        if len < self.position {
+            // 文件被截断，从头读取
+            self.position = 0;
+            self.partial.clear();
+        }
+        if len == self.position {
+            return Ok(Vec::new());
+        }
+
+        file.seek(SeekFrom::Start(self.position))?;
+        let mut buf = Vec::new();
+        file.take(len - self.position).read_to_end(&mut buf)?;
+        self.position += buf.len() as u64;
+        Ok(self.feed(&buf))
+    }
+
+    /// 解析新读到的内容，返回已完整的记录
+    fn feed(&mut self, buf: &[u8]) -> Vec<LogRecord> {
+        self.partial.extend_from_slice(buf);
+
+        let mut records = Vec::new();
+        while let Some(end) = self.partial.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.partial.drain(..=end).collect();
+            records.extend(self.parser.push(&String::from_utf8_lossy(&line)));
+        }
+        if self.partial.is_empty() {
+            records.extend(self.parser.finish());
+        }
+        records
+    }
+}
+
+/// 开始跟踪当前日志，新记录通过 `log:lines` 事件发送，返回跟踪的文件名
+///
+/// 已在跟踪时重新开始
+pub fn start_tail(app_handle: AppHandle) -> Result<String, String> {
+    let path = current_log_file().ok_or("Logging is not initialized")?;
+    let mut tail = LogTail::open(&path).map_err(|e| e.to_string())?;
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    let task = tauri::async_runtime::spawn(async move {
+        let mut interval = tokio::time::interval(TAIL_INTERVAL);
+        loop {
+            interval.tick().await;
+            let mut records = Vec::new();
+            // 日志轮转后读完旧文件再切换到新文件
+            if let Some(current) = current_log_file() {
+                if current != tail.path() {
+                    records.extend(tail.switch_to(&current));
+                }
+            }
+            // 文件可能刚被轮转或清理，下次再读
+            if let Ok(new_records) = tail.poll() {
+                records.extend(new_records);
+            }
+            if !records.is_empty() {
+                let _ = app_handle.emit("log:lines", &records);
+            }
+        }
+    });
+
+    if let Some(previous) = TAIL_TASK
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .replace(task)
+    {
+        previous.abort();
+    }
+    Ok(name)
+}
+
+/// 停止跟踪，未在跟踪时返回 false
+pub fn stop_tail() -> bool {
+    match TAIL_TASK.lock().unwrap_or_else(|e| e.into_inner()).take() {
+        Some(task) => {
+            task.abort();
+            true
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,119 @@
+use std::io::{self, Write};
+use std::mem;
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::logging::rotation::RotatingFile;
+
+/// 等待写入的最大记录数，超出后记录日志的线程会等待写入线程
+pub const QUEUE_CAPACITY: usize = 8192;
+
+/// 等待写入线程完成写入的最长时间
+const FLUSH_TIMEOUT: Duration = Duration::from_secs(2);
+
+const THREAD_NAME: &str = "log-writer";
+
+enum Message {
+    Record(Arc<Mutex<RotatingFile>>, Vec<u8>),
+    Flush(SyncSender<()>),
+}
+
+/// 后台日志写入线程
+///
+/// 记录完整后才发送给写入线程；队列为空时才刷新到磁盘，繁忙时多条记录一起写入
+#[derive(Clone)]
+pub struct LogWriter {
+    sender: SyncSender<Message>,
+}
+
+impl LogWriter {
+    pub fn spawn() -> io::Result<Self> {
+        let (sender, receiver) = mpsc::sync_channel(QUEUE_CAPACITY);
+        std::thread::Builder::new()
+            .name(THREAD_NAME.to_string())
+            .spawn(move || run(receiver))?;
+        Ok(Self { sender })
+    }
+
+    /// 写入指定文件的 fern 输出
+    pub fn sink(&self, file: Arc<Mutex<RotatingFile>>) -> AsyncLogFile {
+        AsyncLogFile {
+            file,
+            buf: Vec::new(),
+            sender: self.sender.clone(),
+        }
+    }
+
+    /// 等待已提交的记录写入磁盘，例如崩溃或退出前
+    pub fn flush(&self) {
+        // 写入线程自身 panic 时无法等待
+        if std::thread::current().name() == Some(THREAD_NAME) {
+            return;
+        }
+        let (done, wait) = mpsc::sync_channel(1);
+        if self.sender.send(Message::Flush(done)).is_ok() {
+            let _ = wait.recv_timeout(FLUSH_TIMEOUT);
+        }
+    }
+}
+
+fn run(receiver: Receiver<Message>) {
+    let mut dirty: Vec<Arc<Mutex<RotatingFile>>> = Vec::new();
+    while let Ok(message) = receiver.recv() {
+        let mut waiting = Vec::new();
+        for message in std::iter::once(message).chain(receiver.try_iter()) {
+            match message {
+                Message::Record(file, record) => {
+                    let result = file
+                        .lock()
+                        .unwrap_or_else(|e| e.into_inner())
+                        .write_record(&record);
+                    // 写入线程中不能记录日志
+                    if let Err(e) = result {
+                        eprintln!("Failed to write log: {}", e);
+                    }
+                    if !dirty.iter().any(|dirty| Arc::ptr_eq(dirty, &file)) {
+                        dirty.push(file);
+                    }
+                }
+                Message::Flush(done) => waiting.push(done),
+            }
+        }
+
+        for file in dirty.drain(..) {
+            if let Err(e) = file.lock().unwrap_or_else(|e| e.into_inner()).flush() {
+                eprintln!("Failed to flush log: {}", e);
+            }
+        }
+        for done in waiting {
+            let _ = done.send(());
+        }
+    }
+}
+
+/// 供 fern 写入的日志文件，写入在后台线程完成
+///
+/// fern 每条记录写完后都会 flush，此时整条记录提交给写入线程
+pub struct AsyncLogFile {
+    file: Arc<Mutex<RotatingFile>>,
+    buf: Vec<u8>,
+    sender: SyncSender<Message>,
+}
+
+impl Write for AsyncLogFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        let record = mem::take(&mut self.buf);
+        self.sender
+            .send(Message::Record(self.file.clone(), record))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "log writer stopped"))
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use crate::http::client::{HttpClient, HttpClientOptions};
+use crate::http::policy::UrlPolicy;
+use crate::http::types::{HttpError, HttpErrorKind, HttpRequest};
+use crate::modrinth::types::{
+    CategoryTag, GameVersionTag, HashAlgorithm, LoaderTag, Project, SearchQuery, SearchResult,
+    Version, VersionFilter,
+};
+
+/// Modrinth API 地址
+pub const MODRINTH_API_BASE: &str = "https://api.modrinth.com/v2";
+
+/// Modrinth 要求的 User-Agent：项目/版本（联系方式）
+pub const USER_AGENT: &str = concat!(
+    "NEXORA-Studios/NovaCL/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/NEXORA-Studios/NovaCL)"
+);
+
+/// 未返回 X-Ratelimit-Reset 时 429 的默认等待时间
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
+
+/// 速率限制状态
+#[derive(Debug, Default, Clone, Copy)]
+struct RateLimit {
+    /// 当前窗口剩余请求数
+    remaining: Option<u32>,
+    /// 窗口重置时间
+    reset_at: Option<Instant>,
+}
+
+/// Modrinth v2 API 客户端
+pub struct ModrinthClient {
+    http: HttpClient,
+    base_url: String,
+    rate_limit: Mutex<RateLimit>,
+}
+
+impl ModrinthClient {
+    pub fn new(base_url: &str, options: HttpClientOptions) -> Self {
+        ModrinthClient {
+            http: HttpClient::with_options(options),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            rate_limit: Mutex::new(RateLimit::default()),
+        }
+    }
+
+    /// 根据全局配置创建，仅允许访问 Modrinth
+    pub fn from_config() -> Self {
+        let options = HttpClientOptions {
+            url_policy: UrlPolicy::new(vec!["modrinth.com".to_string()]),
+            ..HttpClientOptions::from_config()
+        };
+        Self::new(MODRINTH_API_BASE, options)
+    }
+
+    /// 当前窗口请求数耗尽时等待到重置
+    async fn wait_for_rate_limit(&self) {
+        let wait = {
+            let Ok(state) = self.rate_limit.lock() else {
+                return;
+            };
+            match (state.remaining, state.reset_at) {
+                (Some(0), Some(reset_at)) => reset_at.checked_duration_since(Instant::now()),
+                _ => None,
+            }
+        };
+
+        if let Some(wait) = wait {
+            log::debug!(target: "modrinth", "Rate limit reached, waiting {:?}", wait);
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// 根据响应头更新速率限制状态
+    fn update_rate_limit(&self, headers: &HashMap<String, String>, status: u16) {
+        let remaining = headers
+            .get("x-ratelimit-remaining")
+            .and_then(|v| v.trim().parse::<u32>().ok());
+        let reset = headers
+            .get("x-ratelimit-reset")
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+
+        if let Ok(mut state) = self.rate_limit.lock() {
+            if status == 429 {
+                state.remaining = Some(0);
+                state.reset_at = Some(Instant::now() + reset.unwrap_or(DEFAULT_RETRY_AFTER));
+            } else {
+                state.remaining = remaining;
+                state.reset_at = reset.map(|r| Instant::now() + r);
+            }
+        }
+    }
+
+    /// 拼接请求地址，路径段逐段编码，避免 ID 中的保留字符改写路径或查询
+    fn url(&self, segments: &[&str], query: &[(&str, String)]) -> Result<String, HttpError> {
+        let invalid = |message: String| HttpError::new(HttpErrorKind::InvalidRequest, message);
+        if let Some(segment) = segments.iter().find(|s| matches!(**s, "" | "." | "..")) {
+            return Err(invalid(format!("Invalid path segment: {:?}", segment)));
+        }
+
+        let mut url = url::Url::parse(&self.base_url).map_err(|e| invalid(e.to_string()))?;
+        url.path_segments_mut()
+            .map_err(|_| invalid(format!("Invalid base URL: {}", self.base_url)))?
+            .extend(segments);
+        if !query.is_empty() {
+            url.query_pairs_mut().extend_pairs(query);
+        }
+        Ok(url.to_string())
+    }
+
+    /// 发送请求并解析为指定类型，遇到 429 时等待后重试一次
+    async fn send<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        url: String,
+        body: Option<serde_json::Value>,
+    ) -> Result<T, HttpError> {
+        let mut headers = HashMap::new();
+        headers.insert("User-Agent".to_string(), USER_AGENT.to_string());
+        let request = HttpRequest {
+            method: method.to_string(),
+            url,
+            headers: Some(headers),
+            body,
+            request_id: None,
+        };
+
+        let mut retried = false;
+        let response = loop {
+            self.wait_for_rate_limit().await;
+
+            let response = self.http.request(&request).await?;
+            self.update_rate_limit(&response.headers, response.status);
+
+            if response.status == 429 && !retried {
+                retried = true;
+                continue;
+            }
+            break response.error_for_status()?;
+        };
+
+        let body = response.body.unwrap_or(serde_json::Value::Null);
+        serde_json::from_value(body).map_err(|e| {
+            HttpError::new(
+                HttpErrorKind::BodyDecode,
+                format!("Failed to decode Modrinth response: {}", e),
+            )
+        })
+    }
+
+    async fn get<T: DeserializeOwned>(
+        &self,
+        segments: &[&str],
+        query: &[(&str, String)],
+    ) -> Result<T, HttpError> {
+        let url = self.url(segments, query)?;
+        self.send("GET", url, None).await
+    }
+
+    /// 搜索项目
+    pub async fn search(&self, query: &SearchQuery) -> Result<SearchResult, HttpError> {
+        let mut params = Vec::new();
+        if let Some(q) = &query.query {
+            params.push(("query", q.clone()));
+        }
+        if !query.facets.is_empty() {
+            params.push(("facets", json!(query.facets).to_string()));
+        }
+        if let Some(index) = &query.index {
+            params.push(("index", index.clone()));
+        }
+        if let Some(offset) = query.offset {
+            params.push(("offset", offset.to_string()));
+        }
+        if let Some(limit) = query.limit {
+            params.push(("limit", limit.to_string()));
+        }
+        self.get(&["search"], &params).await
+    }
+
+    /// 获取项目
+    pub async fn project(&self, id_or_slug: &str) -> Result<Project, HttpError> {
+        self.get(&["project", id_or_slug], &[]).await
+    }
+
+    /// 批量获取项目
+    pub async fn projects(&self, ids: &[String]) -> Result<Vec<Project>, HttpError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        self.get(&["projects"], &[("ids", json!(ids).to_string())])
+            .await
+    }
+
+    /// 获取项目的版本列表
+    pub async fn project_versions(
+        &self,
+        id_or_slug: &str,
+        filter: &VersionFilter,
+    ) -> Result<Vec<Version>, HttpError> {
+        let mut params = Vec::new();
+        if !filter.loaders.is_empty() {
+            params.push(("loaders", json!(filter.loaders).to_string()));
+        }
+        if !filter.game_versions.is_empty() {
+            params.push(("game_versions", json!(filter.game_versions).to_string()));
+        }
+        if let Some(featured) = filter.featured {
+            params.push(("featured", featured.to_string()));
+        }
+        self.get(&["project", id_or_slug, "version"], &params).await
+    }
+
+    /// 获取版本
+    pub async fn version(&self, id: &str) -> Result<Version, HttpError> {
+        self.get(&["version", id], &[]).await
+    }
+
+    /// 根据文件哈希获取版本
+    pub async fn version_from_hash(
+        &self,
+        hash: &str,
+        algorithm: HashAlgorithm,
+    ) -> Result<Version, HttpError> {
+        self.get(
+            &["version_file", hash],
+            &[("algorithm", algorithm.as_str().to_string())],
+        )
+        .await
+    }
+
+    /// 根据一批文件哈希获取版本，返回 哈希 -> 版本，未识别的哈希不会出现在结果中
+    pub async fn versions_from_hashes(
+        &self,
+        hashes: &[String],
+        algorithm: HashAlgorithm,
+    ) -> Result<HashMap<String, Version>, HttpError> {
+        if hashes.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let url = self.url(&["version_files"], &[])?;
+        let body = json!({
+            "hashes": hashes,
+            "algorithm": algorithm.as_str(),
+        });
+        self.send("POST", url, Some(body)).await
+    }
+
+    /// 根据一批文件哈希获取符合加载器与游戏版本的最新版本，返回 哈希 -> 最新版本
+    pub async fn latest_versions_from_hashes(
+        &self,
+        hashes: &[String],
+        algorithm: HashAlgorithm,
+        loaders: &[String],
+        game_versions: &[String],
+    ) -> Result<HashMap<String, Version>, HttpError> {
+        if hashes.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let url = self.url(&["version_files", "update"], &[])?;
+        let body = json!({
+            "hashes": hashes,
+            "algorithm": algorithm.as_str(),
+            "loaders": loaders,
+            "game_versions": game_versions,
+        });
+        self.send("POST", url, Some(body)).await
+    }
+
+    /// 获取游戏版本标签
+    pub async fn game_versions(&self) -> Result<Vec<GameVersionTag>, HttpError> {
+        self.get(&["tag", "game_version"], &[]).await
+    }
+
+    /// 获取分类标签
+    pub async fn categories(&self) -> Result<Vec<CategoryTag>, HttpError> {
+        self.get(&["tag", "category"], &[]).await
+    }
+
+    /// 获取加载器标签
+    pub async fn loaders(&self) -> Result<Vec<LoaderTag>, HttpError> {
+        self.get(&["tag", "loader"], &[]).await
+    }
+}
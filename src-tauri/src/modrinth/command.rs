@@ -0,0 +1,120 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::http::types::{HttpError, HttpErrorKind};
+use crate::modrinth::client::ModrinthClient;
+use crate::modrinth::identify::{identify_files, scan_instance, IdentifiedFile};
+use crate::modrinth::types::{
+    CategoryTag, GameVersionTag, HashAlgorithm, LoaderTag, Project, SearchQuery, SearchResult,
+    Version, VersionFilter,
+};
+use crate::toml::manager::{get_instance_config, ConfigManager};
+
+// 全局 Modrinth 客户端实例，共享速率限制状态
+lazy_static::lazy_static! {
+    static ref MODRINTH_CLIENT: ModrinthClient = ModrinthClient::from_config();
+}
+
+/// 搜索项目
+#[tauri::command]
+pub async fn modrinth_search(query: SearchQuery) -> Result<SearchResult, HttpError> {
+    MODRINTH_CLIENT.search(&query).await
+}
+
+/// 获取项目
+#[tauri::command]
+pub async fn modrinth_get_project(id: String) -> Result<Project, HttpError> {
+    MODRINTH_CLIENT.project(&id).await
+}
+
+/// 获取项目的版本列表
+#[tauri::command]
+pub async fn modrinth_get_project_versions(
+    id: String,
+    filter: Option<VersionFilter>,
+) -> Result<Vec<Version>, HttpError> {
+    MODRINTH_CLIENT
+        .project_versions(&id, &filter.unwrap_or_default())
+        .await
+}
+
+/// 获取版本
+#[tauri::command]
+pub async fn modrinth_get_version(id: String) -> Result<Version, HttpError> {
+    MODRINTH_CLIENT.version(&id).await
+}
+
+/// 根据文件哈希获取版本
+#[tauri::command]
+pub async fn modrinth_get_version_from_hash(
+    hash: String,
+    algorithm: Option<HashAlgorithm>,
+) -> Result<Version, HttpError> {
+    MODRINTH_CLIENT
+        .version_from_hash(&hash, algorithm.unwrap_or(HashAlgorithm::Sha1))
+        .await
+}
+
+/// 根据一批文件哈希获取版本
+#[tauri::command]
+pub async fn modrinth_get_versions_from_hashes(
+    hashes: Vec<String>,
+    algorithm: Option<HashAlgorithm>,
+) -> Result<HashMap<String, Version>, HttpError> {
+    MODRINTH_CLIENT
+        .versions_from_hashes(&hashes, algorithm.unwrap_or(HashAlgorithm::Sha1))
+        .await
+}
+
+/// 获取游戏版本标签
+#[tauri::command]
+pub async fn modrinth_get_game_versions() -> Result<Vec<GameVersionTag>, HttpError> {
+    MODRINTH_CLIENT.game_versions().await
+}
+
+/// 获取分类标签
+#[tauri::command]
+pub async fn modrinth_get_categories() -> Result<Vec<CategoryTag>, HttpError> {
+    MODRINTH_CLIENT.categories().await
+}
+
+/// 获取加载器标签
+#[tauri::command]
+pub async fn modrinth_get_loaders() -> Result<Vec<LoaderTag>, HttpError> {
+    MODRINTH_CLIENT.loaders().await
+}
+
+/// 识别实例中的模组、资源包与光影包
+#[tauri::command]
+pub async fn modrinth_identify_instance_files(
+    instance_path: String,
+    force_refresh: Option<bool>,
+) -> Result<Vec<IdentifiedFile>, HttpError> {
+    let path = PathBuf::from(&instance_path);
+    let files = tokio::task::spawn_blocking(move || scan_instance(&path))
+        .await
+        .map_err(|e| {
+            HttpError::new(
+                HttpErrorKind::Unknown,
+                format!("Failed to scan instance: {}", e),
+            )
+        })?
+        .map_err(|e| {
+            HttpError::new(
+                HttpErrorKind::InvalidRequest,
+                format!("Failed to scan instance: {}", e),
+            )
+        })?;
+
+    // 没有实例配置时仍然可以识别，只是无法检查更新
+    let instance = get_instance_config(&instance_path).ok();
+
+    identify_files(
+        &MODRINTH_CLIENT,
+        files,
+        instance.as_ref(),
+        &ConfigManager::instance().cache_dir(),
+        force_refresh.unwrap_or(false),
+    )
+    .await
+}
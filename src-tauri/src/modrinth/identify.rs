@@ -0,0 +1,361 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use sha1::Sha1;
+use sha2::{Digest, Sha512};
+
+use crate::http::cache::unix_now;
+use crate::http::types::HttpError;
+use crate::modrinth::client::ModrinthClient;
+use crate::modrinth::types::{HashAlgorithm, Project, Version};
+use crate::toml::types::InstanceConfig;
+
+/// 需要识别的实例子目录
+pub const SCANNED_FOLDERS: &[&str] = &["mods", "resourcepacks", "shaderpacks"];
+
+/// 哈希缓存有效期（秒）
+const CACHE_TTL: u64 = 6 * 60 * 60;
+
+/// 单次批量请求的哈希数量
+const BATCH_SIZE: usize = 100;
+
+lazy_static::lazy_static! {
+    /// 保护 hash_index.json 的读取、合并与写入，避免同时扫描时互相覆盖
+    static ref HASH_INDEX_LOCK: Mutex<()> = Mutex::new(());
+}
+
+/// 项目摘要
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectInfo {
+    pub id: String,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub icon_url: Option<String>,
+    pub project_type: String,
+}
+
+impl From<&Project> for ProjectInfo {
+    fn from(project: &Project) -> Self {
+        ProjectInfo {
+            id: project.id.clone(),
+            slug: project.slug.clone(),
+            title: project.title.clone(),
+            description: project.description.clone(),
+            icon_url: project.icon_url.clone(),
+            project_type: project.project_type.clone(),
+        }
+    }
+}
+
+/// 版本摘要
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionInfo {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub version_number: String,
+    pub version_type: String,
+    pub date_published: String,
+}
+
+impl From<&Version> for VersionInfo {
+    fn from(version: &Version) -> Self {
+        VersionInfo {
+            id: version.id.clone(),
+            project_id: version.project_id.clone(),
+            name: version.name.clone(),
+            version_number: version.version_number.clone(),
+            version_type: version.version_type.clone(),
+            date_published: version.date_published.clone(),
+        }
+    }
+}
+
+/// 本地文件
+#[derive(Debug, Clone)]
+pub struct LocalFile {
+    /// 所在子目录
+    pub folder: String,
+    pub file_name: String,
+    pub path: PathBuf,
+    pub size: u64,
+    pub sha1: String,
+    pub sha512: String,
+}
+
+/// 识别结果
+#[derive(Debug, Clone, Serialize)]
+pub struct IdentifiedFile {
+    /// 所在子目录：mods | resourcepacks | shaderpacks
+    pub folder: String,
+    pub file_name: String,
+    pub path: String,
+    pub size: u64,
+    pub sha1: String,
+    pub sha512: String,
+    /// 所属项目，未在 Modrinth 找到时为空
+    pub project: Option<ProjectInfo>,
+    /// 当前版本
+    pub version: Option<VersionInfo>,
+    /// 符合实例加载器与游戏版本的最新版本
+    pub latest_version: Option<VersionInfo>,
+    /// 是否有可用更新
+    pub update_available: bool,
+}
+
+/// 哈希缓存条目，未找到的文件同样缓存，避免重复查询
+///
+/// 最新版本取决于实例的加载器与游戏版本，每次识别时单独查询，不写入缓存
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HashIndexEntry {
+    project: Option<ProjectInfo>,
+    version: Option<VersionInfo>,
+    /// 查询时间（Unix 时间戳）
+    checked_at: u64,
+}
+
+/// SHA-1 -> Modrinth 元数据 的磁盘缓存
+struct HashIndex {
+    path: PathBuf,
+    entries: HashMap<String, HashIndexEntry>,
+}
+
+impl HashIndex {
+    fn open(path: PathBuf) -> Self {
+        let entries = fs::read(&path)
+            .ok()
+            .and_then(|content| serde_json::from_slice(&content).ok())
+            .unwrap_or_default();
+        HashIndex { path, entries }
+    }
+
+    /// 获取仍在有效期内的条目
+    fn get_fresh(&self, sha1: &str, now: u64) -> Option<&HashIndexEntry> {
+        self.entries
+            .get(sha1)
+            .filter(|entry| now < entry.checked_at.saturating_add(CACHE_TTL))
+    }
+
+    /// 将新条目合并到磁盘上的最新内容后保存，保留其他扫描写入的条目
+    fn merge_and_save(&self, updated: &HashMap<String, HashIndexEntry>) -> io::Result<()> {
+        let _guard = HASH_INDEX_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+        let mut latest = HashIndex::open(self.path.clone());
+        latest
+            .entries
+            .extend(updated.iter().map(|(k, v)| (k.clone(), v.clone())));
+
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&self.path, serde_json::to_vec(&latest.entries)?)
+    }
+}
+
+/// 计算文件的 SHA-1 与 SHA-512
+pub fn hash_file(path: &Path) -> io::Result<(String, String)> {
+    let mut file = File::open(path)?;
+    let mut sha1 = Sha1::new();
+    let mut sha512 = Sha512::new();
+    let mut buffer = [0u8; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        sha1.update(&buffer[..read]);
+        sha512.update(&buffer[..read]);
+    }
+
+    Ok((hex::encode(sha1.finalize()), hex::encode(sha512.finalize())))
+}
+
+/// 扫描实例中的 mods / resourcepacks / shaderpacks 并计算哈希
+///
+/// 无法读取的文件（例如被游戏占用）记录日志后跳过，不影响其他文件
+pub fn scan_instance(instance_path: &Path) -> io::Result<Vec<LocalFile>> {
+    let mut files = Vec::new();
+
+    for folder in SCANNED_FOLDERS {
+        let dir = instance_path.join(folder);
+        if !dir.is_dir() {
+            continue;
+        }
+
+        for entry in fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            // 解压后的光影包等文件夹无法通过哈希识别
+            if !path.is_file() {
+                continue;
+            }
+
+            let hashed = hash_file(&path).and_then(|hashes| Ok((hashes, entry.metadata()?.len())));
+            let ((sha1, sha512), size) = match hashed {
+                Ok(hashed) => hashed,
+                Err(e) => {
+                    log::warn!(target: "modrinth", "Skipped unreadable file {}: {}", path.display(), e);
+                    continue;
+                }
+            };
+            files.push(LocalFile {
+                folder: folder.to_string(),
+                file_name: entry.file_name().to_string_lossy().to_string(),
+                size,
+                path,
+                sha1,
+                sha512,
+            });
+        }
+    }
+
+    Ok(files)
+}
+
+/// 检查更新时使用的加载器，按目录区分
+fn update_loaders(folder: &str, instance: &InstanceConfig) -> Vec<String> {
+    let loaders: &[&str] = match folder {
+        "mods" => match instance.loader_type.as_str() {
+            "vanilla" | "" => &[],
+            // Quilt 兼容 Fabric 模组
+            "quilt" => &["quilt", "fabric"],
+            loader => return vec![loader.to_string()],
+        },
+        "resourcepacks" => &["minecraft"],
+        "shaderpacks" => &["iris", "optifine", "canvas", "vanilla"],
+        _ => &[],
+    };
+    loaders.iter().map(|l| l.to_string()).collect()
+}
+
+/// 识别实例中的文件
+///
+/// - `instance`: 实例配置，缺失时跳过更新检查
+/// - `force_refresh`: 忽略哈希缓存重新查询
+pub async fn identify_files(
+    client: &ModrinthClient,
+    files: Vec<LocalFile>,
+    instance: Option<&InstanceConfig>,
+    cache_dir: &Path,
+    force_refresh: bool,
+) -> Result<Vec<IdentifiedFile>, HttpError> {
+    let now = unix_now();
+    let mut index = HashIndex::open(cache_dir.join("modrinth").join("hash_index.json"));
+
+    let pending: Vec<&LocalFile> = files
+        .iter()
+        .filter(|f| force_refresh || index.get_fresh(&f.sha1, now).is_none())
+        .collect();
+
+    if !pending.is_empty() {
+        // 1. 哈希 -> 当前版本
+        let mut versions = HashMap::new();
+        let hashes: Vec<String> = pending.iter().map(|f| f.sha1.clone()).collect();
+        for chunk in hashes.chunks(BATCH_SIZE) {
+            versions.extend(
+                client
+                    .versions_from_hashes(chunk, HashAlgorithm::Sha1)
+                    .await?,
+            );
+        }
+
+        // 2. 项目名称、图标
+        let project_ids: Vec<String> = versions
+            .values()
+            .map(|v| v.project_id.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let mut projects = HashMap::new();
+        for chunk in project_ids.chunks(BATCH_SIZE) {
+            for project in client.projects(chunk).await? {
+                projects.insert(project.id.clone(), ProjectInfo::from(&project));
+            }
+        }
+
+        let mut updated = HashMap::new();
+        for file in &pending {
+            let version = versions.get(&file.sha1);
+            updated.insert(
+                file.sha1.clone(),
+                HashIndexEntry {
+                    project: version.and_then(|v| projects.get(&v.project_id).cloned()),
+                    version: version.map(VersionInfo::from),
+                    checked_at: now,
+                },
+            );
+        }
+
+        if let Err(e) = index.merge_and_save(&updated) {
+            log::warn!(target: "modrinth", "Failed to save hash index: {}", e);
+        }
+        index.entries.extend(updated);
+    }
+
+    // 3. 按目录检查更新，包括命中缓存的文件
+    let mut latest = HashMap::new();
+    if let Some(instance) = instance {
+        for folder in SCANNED_FOLDERS {
+            let loaders = update_loaders(folder, instance);
+            if loaders.is_empty() {
+                continue;
+            }
+            let matched: Vec<String> = files
+                .iter()
+                .filter(|f| {
+                    f.folder == *folder
+                        && index
+                            .entries
+                            .get(&f.sha1)
+                            .is_some_and(|e| e.version.is_some())
+                })
+                .map(|f| f.sha1.clone())
+                .collect();
+            for chunk in matched.chunks(BATCH_SIZE) {
+                latest.extend(
+                    client
+                        .latest_versions_from_hashes(
+                            chunk,
+                            HashAlgorithm::Sha1,
+                            &loaders,
+                            std::slice::from_ref(&instance.mc_version),
+                        )
+                        .await?,
+                );
+            }
+        }
+    }
+
+    Ok(files
+        .into_iter()
+        .map(|file| {
+            let entry = index.entries.get(&file.sha1);
+            let version = entry.and_then(|e| e.version.clone());
+            let latest_version = latest.get(&file.sha1).map(VersionInfo::from);
+            let update_available = match (&version, &latest_version) {
+                (Some(current), Some(latest)) => {
+                    latest.id != current.id && latest.date_published > current.date_published
+                }
+                _ => false,
+            };
+
+            IdentifiedFile {
+                folder: file.folder,
+                file_name: file.file_name,
+                path: file.path.to_string_lossy().to_string(),
+                size: file.size,
+                sha1: file.sha1,
+                sha512: file.sha512,
+                project: entry.and_then(|e| e.project.clone()),
+                version,
+                latest_version,
+                update_available,
+            }
+        })
+        .collect())
+}
@@ -0,0 +1,9 @@
+/// Modrinth 模块
+///
+/// 封装 Modrinth v2 API，提供类型化的响应并遵守 X-Ratelimit-* 速率限制
+pub mod client;
+pub mod command;
+pub mod identify;
+#[cfg(test)]
+mod test;
+pub mod types;
@@ -0,0 +1,394 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+
+use crate::http::client::HttpClientOptions;
+use crate::http::policy::UrlPolicy;
+use crate::http::types::HttpErrorKind;
+use crate::modrinth::client::{ModrinthClient, USER_AGENT};
+use crate::modrinth::types::{HashAlgorithm, SearchQuery, VersionFilter};
+
+/// 录制的 Modrinth 响应
+const SEARCH: &str = include_str!("fixtures/search.json");
+const PROJECT: &str = include_str!("fixtures/project.json");
+const VERSIONS: &str = include_str!("fixtures/versions.json");
+const VERSION_FILES: &str = include_str!("fixtures/version_files.json");
+const GAME_VERSIONS: &str = include_str!("fixtures/game_versions.json");
+const CATEGORIES: &str = include_str!("fixtures/categories.json");
+const LOADERS: &str = include_str!("fixtures/loaders.json");
+
+/// 本地回放服务器记录的请求
+#[derive(Default)]
+struct Recorded {
+    queries: Mutex<Vec<HashMap<String, String>>>,
+    user_agents: Mutex<Vec<String>>,
+    bodies: Mutex<Vec<serde_json::Value>>,
+    project_ids: Mutex<Vec<String>>,
+    loader_hits: AtomicUsize,
+}
+
+type Shared = Arc<Recorded>;
+
+fn fixture(body: &'static str) -> impl IntoResponse {
+    (
+        [
+            ("content-type", "application/json"),
+            ("x-ratelimit-limit", "300"),
+            ("x-ratelimit-remaining", "299"),
+            ("x-ratelimit-reset", "60"),
+        ],
+        body,
+    )
+}
+
+fn record(state: &Shared, headers: &HeaderMap, query: HashMap<String, String>) {
+    let ua = headers
+        .get("user-agent")
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    state.user_agents.lock().unwrap().push(ua);
+    state.queries.lock().unwrap().push(query);
+}
+
+async fn start_fixture_server() -> (String, Shared) {
+    let state: Shared = Arc::default();
+
+    let app = Router::new()
+        .route(
+            "/v2/search",
+            get(
+                |State(s): State<Shared>,
+                 h: HeaderMap,
+                 Query(q): Query<HashMap<String, String>>| async move {
+                    record(&s, &h, q);
+                    fixture(SEARCH)
+                },
+            ),
+        )
+        .route(
+            "/v2/project/:id",
+            get(
+                |State(s): State<Shared>,
+                 Path(id): Path<String>,
+                 h: HeaderMap,
+                 Query(q): Query<HashMap<String, String>>| async move {
+                    s.project_ids.lock().unwrap().push(id);
+                    record(&s, &h, q);
+                    fixture(PROJECT)
+                },
+            ),
+        )
+        .route(
+            "/v2/project/:id/version",
+            get(
+                |State(s): State<Shared>,
+                 h: HeaderMap,
+                 Query(q): Query<HashMap<String, String>>| async move {
+                    record(&s, &h, q);
+                    fixture(VERSIONS)
+                },
+            ),
+        )
+        .route(
+            "/v2/version_files",
+            post(
+                |State(s): State<Shared>, Json(body): Json<serde_json::Value>| async move {
+                    s.bodies.lock().unwrap().push(body);
+                    fixture(VERSION_FILES)
+                },
+            ),
+        )
+        .route(
+            "/v2/tag/game_version",
+            get(|| async { fixture(GAME_VERSIONS) }),
+        )
+        .route("/v2/tag/category", get(|| async { fixture(CATEGORIES) }))
+        .route(
+            "/v2/tag/loader",
+            get(|State(s): State<Shared>| async move {
+                // 第一次请求模拟触发速率限制
+                if s.loader_hits.fetch_add(1, Ordering::SeqCst) == 0 {
+                    return (
+                        StatusCode::TOO_MANY_REQUESTS,
+                        [("x-ratelimit-remaining", "0"), ("x-ratelimit-reset", "0")],
+                        "",
+                    )
+                        .into_response();
+                }
+                fixture(LOADERS).into_response()
+            }),
+        )
+        .route(
+            "/v2/version/:id",
+            get(|| async {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(serde_json::json!({ "error": "not_found" })),
+                )
+            }),
+        )
+        .with_state(state.clone());
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+
+    (format!("http://127.0.0.1:{}/v2", port), state)
+}
+
+fn test_client(base_url: &str) -> ModrinthClient {
+    let options = HttpClientOptions {
+        cache_dir: std::env::temp_dir().join("NovaCLTestModrinth"),
+        use_cache: false,
+        use_cookie_jar: false,
+        cookie_domains: Vec::new(),
+        url_policy: UrlPolicy::new(vec!["127.0.0.1".to_string()]),
+    };
+    ModrinthClient::new(base_url, options)
+}
+
+#[tokio::test]
+async fn test_search_with_facets() {
+    let (base_url, state) = start_fixture_server().await;
+    let client = test_client(&base_url);
+
+    let result = client
+        .search(&SearchQuery {
+            query: Some("sodium".to_string()),
+            facets: vec![
+                vec!["project_type:mod".to_string()],
+                vec!["versions:1.20.1".to_string()],
+            ],
+            index: None,
+            offset: Some(0),
+            limit: Some(1),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.total_hits, 1);
+    assert_eq!(result.hits[0].slug.as_deref(), Some("sodium"));
+
+    let queries = state.queries.lock().unwrap();
+    assert_eq!(queries[0]["query"], "sodium");
+    assert_eq!(
+        queries[0]["facets"],
+        r#"[["project_type:mod"],["versions:1.20.1"]]"#
+    );
+    assert_eq!(state.user_agents.lock().unwrap()[0], USER_AGENT);
+}
+
+#[tokio::test]
+async fn test_project_and_versions() {
+    let (base_url, state) = start_fixture_server().await;
+    let client = test_client(&base_url);
+
+    let project = client.project("sodium").await.unwrap();
+    assert_eq!(project.id, "AANobbMI");
+    assert_eq!(project.loaders, vec!["fabric", "quilt"]);
+
+    let versions = client
+        .project_versions(
+            "sodium",
+            &VersionFilter {
+                loaders: vec!["fabric".to_string()],
+                game_versions: vec!["1.20.1".to_string()],
+                featured: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        versions[0].files[0].filename,
+        "sodium-fabric-0.5.11+mc1.20.1.jar"
+    );
+
+    let queries = state.queries.lock().unwrap();
+    assert_eq!(queries[1]["loaders"], r#"["fabric"]"#);
+    assert_eq!(queries[1]["game_versions"], r#"["1.20.1"]"#);
+}
+
+#[tokio::test]
+async fn test_reserved_characters_in_ids() {
+    let (base_url, state) = start_fixture_server().await;
+    let client = test_client(&base_url);
+
+    // 保留字符被编码进单个路径段，不会变成查询参数或其他接口
+    client.project("abc?x=1").await.unwrap();
+    client.project("a/../../tag/loader").await.unwrap();
+    assert_eq!(
+        *state.project_ids.lock().unwrap(),
+        vec!["abc?x=1", "a/../../tag/loader"]
+    );
+    assert!(state.queries.lock().unwrap().iter().all(|q| q.is_empty()));
+    assert_eq!(state.loader_hits.load(Ordering::SeqCst), 0);
+
+    let error = client.version("..").await.unwrap_err();
+    assert_eq!(error.kind, HttpErrorKind::InvalidRequest);
+}
+
+#[tokio::test]
+async fn test_version_files_by_hash() {
+    let (base_url, state) = start_fixture_server().await;
+    let client = test_client(&base_url);
+
+    let hash = "6c2ed2d7cb9d1b3e8d8ba1c3d4d0e8e6bd4f2e2c".to_string();
+    let versions = client
+        .versions_from_hashes(std::slice::from_ref(&hash), HashAlgorithm::Sha1)
+        .await
+        .unwrap();
+    assert_eq!(versions[&hash].project_id, "AANobbMI");
+
+    let bodies = state.bodies.lock().unwrap();
+    assert_eq!(bodies[0]["algorithm"], "sha1");
+    assert_eq!(bodies[0]["hashes"][0], hash);
+}
+
+#[tokio::test]
+async fn test_tags_and_rate_limit_retry() {
+    let (base_url, state) = start_fixture_server().await;
+    let client = test_client(&base_url);
+
+    assert_eq!(client.game_versions().await.unwrap().len(), 3);
+    assert_eq!(client.categories().await.unwrap()[0].name, "optimization");
+
+    // 429 后应等待重置并自动重试
+    let loaders = client.loaders().await.unwrap();
+    assert_eq!(loaders[0].name, "fabric");
+    assert_eq!(state.loader_hits.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn test_status_error() {
+    let (base_url, _state) = start_fixture_server().await;
+    let client = test_client(&base_url);
+
+    let error = client.version("missing").await.unwrap_err();
+    assert_eq!(error.code, Some(404));
+    assert_eq!(error.body.unwrap()["error"], "not_found");
+}
+
+#[tokio::test]
+async fn test_identify_instance_files() {
+    use crate::modrinth::identify::{hash_file, identify_files, scan_instance};
+    use crate::toml::types::InstanceConfig;
+
+    // 回放服务器：任何已知哈希都映射到录制的版本
+    let version: serde_json::Value =
+        serde_json::from_str::<serde_json::Value>(VERSIONS).unwrap()[0].clone();
+    let mut newer = version.clone();
+    newer["id"] = "newerVer".into();
+    newer["date_published"] = "2025-01-01T00:00:00Z".into();
+    let project: serde_json::Value = serde_json::from_str(PROJECT).unwrap();
+
+    let app = Router::new()
+        .route(
+            "/v2/version_files",
+            post(move |Json(body): Json<serde_json::Value>| {
+                let version = version.clone();
+                async move {
+                    let mut result = serde_json::Map::new();
+                    for hash in body["hashes"].as_array().unwrap() {
+                        result.insert(hash.as_str().unwrap().to_string(), version.clone());
+                    }
+                    Json(result)
+                }
+            }),
+        )
+        .route(
+            "/v2/version_files/update",
+            post(move |Json(body): Json<serde_json::Value>| {
+                let newer = newer.clone();
+                async move {
+                    // 只有 Fabric 1.20.1 有新版本
+                    let mut result = serde_json::Map::new();
+                    if body["loaders"][0] == "fabric" && body["game_versions"][0] == "1.20.1" {
+                        for hash in body["hashes"].as_array().unwrap() {
+                            result.insert(hash.as_str().unwrap().to_string(), newer.clone());
+                        }
+                    }
+                    Json(result)
+                }
+            }),
+        )
+        .route(
+            "/v2/projects",
+            get(move || {
+                let project = project.clone();
+                async move { Json(serde_json::json!([project])) }
+            }),
+        );
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let port = listener.local_addr().unwrap().port();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    let client = test_client(&format!("http://127.0.0.1:{}/v2", port));
+
+    let root = std::env::temp_dir().join("NovaCLTestIdentify");
+    let _ = std::fs::remove_dir_all(&root);
+    let instance_path = root.join("instance");
+    std::fs::create_dir_all(instance_path.join("mods")).unwrap();
+    std::fs::write(instance_path.join("mods").join("sodium.jar"), b"fake jar").unwrap();
+
+    let (sha1, _) = hash_file(&instance_path.join("mods").join("sodium.jar")).unwrap();
+    assert_eq!(sha1, "d8d8453b5a674113789bdab6b4f0dd25b0b43b92");
+
+    let cache_dir = root.join("cache");
+    let identify = |instance: Option<InstanceConfig>| {
+        let client = &client;
+        let files = scan_instance(&instance_path).unwrap();
+        let cache_dir = cache_dir.clone();
+        async move {
+            identify_files(client, files, instance.as_ref(), &cache_dir, false)
+                .await
+                .unwrap()
+        }
+    };
+    let instance = |mc_version: &str| InstanceConfig {
+        loader_type: "fabric".to_string(),
+        mc_version: mc_version.to_string(),
+        ..InstanceConfig::default()
+    };
+
+    // 先在缺少实例配置时扫描，结果不应影响之后的更新检查
+    let identified = identify(None).await;
+    assert!(identified[0].latest_version.is_none());
+
+    let identified = identify(Some(instance("1.20.1"))).await;
+
+    assert_eq!(identified.len(), 1);
+    assert_eq!(identified[0].folder, "mods");
+    assert_eq!(identified[0].project.as_ref().unwrap().title, "Sodium");
+    assert_eq!(identified[0].version.as_ref().unwrap().id, "yaoBL9D9");
+    assert!(identified[0].update_available);
+    assert!(root
+        .join("cache")
+        .join("modrinth")
+        .join("hash_index.json")
+        .exists());
+
+    // 同一文件在其他游戏版本的实例中使用缓存的项目信息，但独立检查更新
+    let identified = identify(Some(instance("1.21"))).await;
+    assert_eq!(identified[0].version.as_ref().unwrap().id, "yaoBL9D9");
+    assert!(identified[0].latest_version.is_none());
+    assert!(!identified[0].update_available);
+
+    let identified = identify(Some(instance("1.20.1"))).await;
+    assert_eq!(
+        identified[0].latest_version.as_ref().unwrap().id,
+        "newerVer"
+    );
+    assert!(identified[0].update_available);
+
+    std::fs::remove_dir_all(&root).unwrap();
+}
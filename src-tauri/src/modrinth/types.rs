@@ -0,0 +1,213 @@
+use serde::{Deserialize, Serialize};
+
+// ---------------------- Search ----------------------
+
+/// 搜索参数
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SearchQuery {
+    /// 搜索关键词
+    pub query: Option<String>,
+    /// 筛选条件，外层为 AND，内层为 OR，例如 [["categories:forge"], ["versions:1.20.1"]]
+    #[serde(default)]
+    pub facets: Vec<Vec<String>>,
+    /// 排序方式
+    pub index: Option<String>, // relevance | downloads | follows | newest | updated
+    /// 偏移量
+    pub offset: Option<u32>,
+    /// 数量限制
+    pub limit: Option<u32>,
+}
+
+/// 搜索结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub hits: Vec<SearchHit>,
+    pub offset: u32,
+    pub limit: u32,
+    pub total_hits: u32,
+}
+
+/// 单个搜索结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchHit {
+    pub project_id: String,
+    pub project_type: String,
+    pub slug: Option<String>,
+    pub author: String,
+    pub title: String,
+    pub description: String,
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(default)]
+    pub display_categories: Vec<String>,
+    #[serde(default)]
+    pub versions: Vec<String>,
+    pub downloads: u64,
+    pub follows: u64,
+    pub icon_url: Option<String>,
+    pub date_created: String,
+    pub date_modified: String,
+    pub latest_version: Option<String>,
+    pub license: String,
+    pub client_side: String, // required | optional | unsupported | unknown
+    pub server_side: String, // required | optional | unsupported | unknown
+    #[serde(default)]
+    pub gallery: Vec<String>,
+    pub featured_gallery: Option<String>,
+    pub color: Option<u32>,
+}
+
+// ---------------------- Project ----------------------
+
+/// 项目许可证
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectLicense {
+    pub id: String,
+    pub name: String,
+    pub url: Option<String>,
+}
+
+/// 项目
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Project {
+    pub id: String,
+    pub slug: String,
+    pub project_type: String, // mod | modpack | resourcepack | shader | datapack | plugin
+    pub team: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    #[serde(default)]
+    pub categories: Vec<String>,
+    #[serde(default)]
+    pub additional_categories: Vec<String>,
+    pub client_side: String,
+    pub server_side: String,
+    pub status: String,
+    pub downloads: u64,
+    pub followers: u64,
+    pub icon_url: Option<String>,
+    pub color: Option<u32>,
+    pub published: String,
+    pub updated: String,
+    #[serde(default)]
+    pub versions: Vec<String>,
+    #[serde(default)]
+    pub game_versions: Vec<String>,
+    #[serde(default)]
+    pub loaders: Vec<String>,
+    pub license: Option<ProjectLicense>,
+    pub issues_url: Option<String>,
+    pub source_url: Option<String>,
+    pub wiki_url: Option<String>,
+    pub discord_url: Option<String>,
+}
+
+// ---------------------- Version ----------------------
+
+/// 文件哈希
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileHashes {
+    pub sha1: String,
+    pub sha512: String,
+}
+
+/// 版本文件
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionFile {
+    pub hashes: FileHashes,
+    pub url: String,
+    pub filename: String,
+    pub primary: bool,
+    pub size: u64,
+    pub file_type: Option<String>,
+}
+
+/// 版本依赖
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionDependency {
+    pub version_id: Option<String>,
+    pub project_id: Option<String>,
+    pub file_name: Option<String>,
+    pub dependency_type: String, // required | optional | incompatible | embedded
+}
+
+/// 版本
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Version {
+    pub id: String,
+    pub project_id: String,
+    pub author_id: String,
+    pub name: String,
+    pub version_number: String,
+    pub changelog: Option<String>,
+    pub date_published: String,
+    pub downloads: u64,
+    pub version_type: String, // release | beta | alpha
+    pub status: Option<String>,
+    pub featured: bool,
+    #[serde(default)]
+    pub game_versions: Vec<String>,
+    #[serde(default)]
+    pub loaders: Vec<String>,
+    #[serde(default)]
+    pub files: Vec<VersionFile>,
+    #[serde(default)]
+    pub dependencies: Vec<VersionDependency>,
+}
+
+/// 项目版本筛选
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VersionFilter {
+    #[serde(default)]
+    pub loaders: Vec<String>,
+    #[serde(default)]
+    pub game_versions: Vec<String>,
+    pub featured: Option<bool>,
+}
+
+/// 哈希算法
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum HashAlgorithm {
+    Sha1,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha1 => "sha1",
+            HashAlgorithm::Sha512 => "sha512",
+        }
+    }
+}
+
+// ---------------------- Tags ----------------------
+
+/// 游戏版本标签
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameVersionTag {
+    pub version: String,
+    pub version_type: String, // release | snapshot | alpha | beta
+    pub date: String,
+    pub major: bool,
+}
+
+/// 分类标签
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CategoryTag {
+    pub icon: String,
+    pub name: String,
+    pub project_type: String,
+    pub header: String,
+}
+
+/// 加载器标签
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoaderTag {
+    pub icon: String,
+    pub name: String,
+    #[serde(default)]
+    pub supported_project_types: Vec<String>,
+}
@@ -1,7 +1,24 @@
-use crate::system::ram;
+use crate::system::{info, ram};
+use crate::toml::manager::get_collection_config;
 use tauri::command;
 
 #[command]
 pub fn get_ram_info() -> ram::RamInfo {
     ram::get_ram_info()
 }
+
+/// 获取系统信息快照，包括集合中各文件夹所在磁盘的空间
+#[command]
+pub async fn get_system_info() -> Result<info::SystemInfo, String> {
+    // 集合配置读取失败时不影响其他信息
+    let folders = match get_collection_config() {
+        Ok(config) => config.folders,
+        Err(e) => {
+            log::warn!(target: "system", "Failed to load collection config: {}", e);
+            Vec::new()
+        }
+    };
+    tokio::task::spawn_blocking(move || info::get_system_info(&folders))
+        .await
+        .map_err(|e| e.to_string())
+}
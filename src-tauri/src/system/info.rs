@@ -0,0 +1,368 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::Serialize;
+use sysinfo::{CpuRefreshKind, Disks, System};
+
+use crate::system::ram::{get_ram_info, RamInfo};
+use crate::toml::types::FolderConfig;
+
+/// 操作系统
+#[derive(Debug, Clone, Serialize)]
+pub struct OsInfo {
+    /// 例如 Windows、Darwin、Ubuntu
+    pub name: Option<String>,
+    pub version: Option<String>,
+    /// 例如 Windows 11 Pro、macOS 15.1 Sequoia
+    pub long_version: Option<String>,
+    pub kernel_version: Option<String>,
+    /// windows、macos、linux
+    pub family: String,
+    /// 启动器编译的架构
+    pub arch: String,
+    /// 系统的 CPU 架构，在 Rosetta 下与 arch 不同
+    pub cpu_arch: String,
+}
+
+/// CPU
+#[derive(Debug, Clone, Serialize)]
+pub struct CpuInfo {
+    pub brand: String,
+    pub vendor: String,
+    /// 物理核心数
+    pub cores: Option<usize>,
+    /// 逻辑线程数
+    pub threads: usize,
+    /// MHz
+    pub frequency: u64,
+}
+
+/// 磁盘
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskInfo {
+    pub mount_point: String,
+    pub file_system: String,
+    /// HDD、SSD 或 Unknown
+    pub kind: String,
+    pub removable: bool,
+    /// bytes
+    pub total: u64,
+    pub available: u64,
+}
+
+/// 集合文件夹所在的磁盘
+#[derive(Debug, Clone, Serialize)]
+pub struct FolderDiskInfo {
+    pub name: String,
+    pub path: String,
+    /// 找不到所在磁盘时为空
+    pub disk: Option<DiskInfo>,
+}
+
+/// 显卡
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GpuInfo {
+    pub vendor: Option<String>,
+    pub model: String,
+}
+
+/// 运行环境
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RuntimeInfo {
+    /// 在 Apple Silicon 上通过 Rosetta 2 运行 x86_64 版本
+    pub rosetta: bool,
+    pub wsl: bool,
+    pub flatpak: bool,
+}
+
+/// 系统信息快照
+#[derive(Debug, Clone, Serialize)]
+pub struct SystemInfo {
+    pub os: OsInfo,
+    pub cpu: CpuInfo,
+    pub ram: RamInfo,
+    pub disks: Vec<FolderDiskInfo>,
+    /// 无法识别时为空
+    pub gpus: Vec<GpuInfo>,
+    pub runtime: RuntimeInfo,
+}
+
+/// 收集系统信息，会调用系统命令查询显卡，耗时可能超过一秒
+///
+/// # 参数
+/// - `folders`: 集合中的文件夹，查询其所在磁盘的空间
+pub fn get_system_info(folders: &[FolderConfig]) -> SystemInfo {
+    SystemInfo {
+        os: get_os_info(),
+        cpu: get_cpu_info(),
+        ram: get_ram_info(),
+        disks: get_folder_disks(folders),
+        gpus: get_gpus(),
+        runtime: get_runtime_info(),
+    }
+}
+
+pub fn get_os_info() -> OsInfo {
+    OsInfo {
+        name: System::name(),
+        version: System::os_version(),
+        long_version: System::long_os_version(),
+        kernel_version: System::kernel_version(),
+        family: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        cpu_arch: System::cpu_arch(),
+    }
+}
+
+pub fn get_cpu_info() -> CpuInfo {
+    let mut sys = System::new();
+    sys.refresh_cpu_list(CpuRefreshKind::nothing().with_frequency());
+    let cpu = sys.cpus().first();
+
+    CpuInfo {
+        brand: cpu
+            .map(|cpu| cpu.brand().trim().to_string())
+            .unwrap_or_default(),
+        vendor: cpu
+            .map(|cpu| cpu.vendor_id().to_string())
+            .unwrap_or_default(),
+        cores: System::physical_core_count(),
+        threads: sys.cpus().len(),
+        frequency: cpu.map(|cpu| cpu.frequency()).unwrap_or_default(),
+    }
+}
+
+pub fn get_folder_disks(folders: &[FolderConfig]) -> Vec<FolderDiskInfo> {
+    let disks: Vec<DiskInfo> = Disks::new_with_refreshed_list()
+        .iter()
+        .map(|disk| DiskInfo {
+            mount_point: disk.mount_point().to_string_lossy().into_owned(),
+            file_system: disk.file_system().to_string_lossy().into_owned(),
+            kind: disk.kind().to_string(),
+            removable: disk.is_removable(),
+            total: disk.total_space(),
+            available: disk.available_space(),
+        })
+        .collect();
+
+    folders
+        .iter()
+        .map(|folder| FolderDiskInfo {
+            name: folder.name.clone(),
+            path: folder.path.clone(),
+            disk: find_disk(&disks, &normalize_path(Path::new(&folder.path))).cloned(),
+        })
+        .collect()
+}
+
+/// 解析符号链接，文件夹不存在时使用最近的已存在的上级目录
+fn normalize_path(path: &Path) -> PathBuf {
+    let Some(path) = path.ancestors().find_map(|dir| dir.canonicalize().ok()) else {
+        return path.to_path_buf();
+    };
+    // Windows 上 canonicalize 返回 \\?\C:\... 形式，与挂载点 C:\ 不匹配
+    match path.to_str().and_then(|path| path.strip_prefix(r"\\?\")) {
+        Some(stripped) if !stripped.starts_with("UNC") => PathBuf::from(stripped),
+        _ => path,
+    }
+}
+
+/// 路径所在的磁盘，即挂载点最长的匹配
+pub fn find_disk<'a>(disks: &'a [DiskInfo], path: &Path) -> Option<&'a DiskInfo> {
+    disks
+        .iter()
+        .filter(|disk| path.starts_with(&disk.mount_point))
+        .max_by_key(|disk| Path::new(&disk.mount_point).components().count())
+}
+
+/// 运行命令并返回标准输出，失败时返回 None
+fn command_output(command: &mut Command) -> Option<String> {
+    #[cfg(windows)]
+    {
+        use std::os::windows::process::CommandExt;
+        // CREATE_NO_WINDOW，避免弹出控制台窗口
+        command.creation_flags(0x08000000);
+    }
+    let output = command.output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+#[cfg(target_os = "linux")]
+pub fn get_gpus() -> Vec<GpuInfo> {
+    let gpus = command_output(Command::new("lspci").arg("-mm"))
+        .map(|output| parse_lspci(&output))
+        .unwrap_or_default();
+    if !gpus.is_empty() {
+        return gpus;
+    }
+
+    // 没有 lspci 时（例如 Flatpak 沙盒中）读取 DRM 设备的 PCI ID
+    let Ok(entries) = std::fs::read_dir("/sys/class/drm") else {
+        return Vec::new();
+    };
+    let mut gpus = Vec::new();
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        // 只取 card0、card1……，跳过 card0-HDMI-A-1 等输出接口
+        if !name
+            .strip_prefix("card")
+            .is_some_and(|index| index.chars().all(|c| c.is_ascii_digit()))
+        {
+            continue;
+        }
+        let device = entry.path().join("device");
+        let read_id = |file: &str| {
+            let id = std::fs::read_to_string(device.join(file)).ok()?;
+            u16::from_str_radix(id.trim().trim_start_matches("0x"), 16).ok()
+        };
+        if let (Some(vendor), Some(device)) = (read_id("vendor"), read_id("device")) {
+            let gpu = GpuInfo {
+                vendor: pci_vendor_name(vendor).map(str::to_string),
+                model: format!("{:04x}:{:04x}", vendor, device),
+            };
+            if !gpus.contains(&gpu) {
+                gpus.push(gpu);
+            }
+        }
+    }
+    gpus
+}
+
+#[cfg(target_os = "windows")]
+pub fn get_gpus() -> Vec<GpuInfo> {
+    let script = "Get-CimInstance Win32_VideoController | ForEach-Object { $_.AdapterCompatibility + '|' + $_.Name }";
+    command_output(Command::new("powershell").args([
+        "-NoProfile",
+        "-NonInteractive",
+        "-Command",
+        script,
+    ]))
+    .map(|output| parse_cim_video_controllers(&output))
+    .unwrap_or_default()
+}
+
+#[cfg(target_os = "macos")]
+pub fn get_gpus() -> Vec<GpuInfo> {
+    command_output(Command::new("system_profiler").arg("SPDisplaysDataType"))
+        .map(|output| parse_system_profiler(&output))
+        .unwrap_or_default()
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows", target_os = "macos")))]
+pub fn get_gpus() -> Vec<GpuInfo> {
+    Vec::new()
+}
+
+#[cfg(any(target_os = "linux", test))]
+fn pci_vendor_name(id: u16) -> Option<&'static str> {
+    match id {
+        0x10de => Some("NVIDIA"),
+        0x1002 => Some("AMD"),
+        0x8086 => Some("Intel"),
+        0x1af4 => Some("Red Hat"),
+        0x15ad => Some("VMware"),
+        _ => None,
+    }
+}
+
+/// 解析 `lspci -mm` 的输出，每行：`槽位 "类别" "厂商" "设备" ...`
+#[cfg(any(target_os = "linux", test))]
+pub fn parse_lspci(output: &str) -> Vec<GpuInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split('"').skip(1).step_by(2).collect();
+            let [class, vendor, model, ..] = fields[..] else {
+                return None;
+            };
+            let is_gpu = ["VGA", "3D", "Display"]
+                .iter()
+                .any(|name| class.contains(name));
+            is_gpu.then(|| GpuInfo {
+                vendor: Some(vendor.to_string()),
+                model: model.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 解析 Win32_VideoController 的 `厂商|名称`，每行一个
+#[cfg(any(target_os = "windows", test))]
+pub fn parse_cim_video_controllers(output: &str) -> Vec<GpuInfo> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (vendor, model) = line.trim().split_once('|')?;
+            (!model.is_empty()).then(|| GpuInfo {
+                vendor: (!vendor.is_empty()).then(|| vendor.to_string()),
+                model: model.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// 解析 `system_profiler SPDisplaysDataType` 的输出
+#[cfg(any(target_os = "macos", test))]
+pub fn parse_system_profiler(output: &str) -> Vec<GpuInfo> {
+    let mut gpus: Vec<GpuInfo> = Vec::new();
+    for line in output.lines().map(str::trim) {
+        if let Some(model) = line.strip_prefix("Chipset Model:") {
+            gpus.push(GpuInfo {
+                vendor: None,
+                model: model.trim().to_string(),
+            });
+        } else if let Some(vendor) = line.strip_prefix("Vendor:") {
+            // 例如 `Vendor: NVIDIA (0x10de)`，去掉 PCI ID
+            let vendor = vendor.split(" (0x").next().unwrap_or(vendor).trim();
+            if let Some(gpu) = gpus.last_mut() {
+                gpu.vendor = Some(vendor.to_string());
+            }
+        }
+    }
+    gpus
+}
+
+pub fn get_runtime_info() -> RuntimeInfo {
+    RuntimeInfo {
+        rosetta: is_rosetta(),
+        wsl: is_wsl(),
+        flatpak: is_flatpak(),
+    }
+}
+
+#[cfg(target_os = "macos")]
+fn is_rosetta() -> bool {
+    // 转译运行时 sysctl.proc_translated 为 1，原生运行时为 0，Intel Mac 上不存在
+    command_output(Command::new("sysctl").args(["-n", "sysctl.proc_translated"]))
+        .is_some_and(|output| output.trim() == "1")
+}
+
+#[cfg(not(target_os = "macos"))]
+fn is_rosetta() -> bool {
+    false
+}
+
+#[cfg(target_os = "linux")]
+fn is_wsl() -> bool {
+    std::env::var_os("WSL_DISTRO_NAME").is_some()
+        || std::fs::read_to_string("/proc/sys/kernel/osrelease")
+            .is_ok_and(|release| release.to_lowercase().contains("microsoft"))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_wsl() -> bool {
+    false
+}
+
+#[cfg(target_os = "linux")]
+fn is_flatpak() -> bool {
+    std::env::var_os("FLATPAK_ID").is_some() || Path::new("/.flatpak-info").exists()
+}
+
+#[cfg(not(target_os = "linux"))]
+fn is_flatpak() -> bool {
+    false
+}
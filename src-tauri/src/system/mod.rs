@@ -1,2 +1,5 @@
 pub mod command;
+pub mod info;
 pub mod ram;
+#[cfg(test)]
+mod test;
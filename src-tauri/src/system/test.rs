@@ -0,0 +1,83 @@
+use std::path::Path;
+
+use crate::system::info::{
+    find_disk, parse_cim_video_controllers, parse_lspci, parse_system_profiler, DiskInfo, GpuInfo,
+};
+
+fn gpu(vendor: &str, model: &str) -> GpuInfo {
+    GpuInfo {
+        vendor: Some(vendor.to_string()),
+        model: model.to_string(),
+    }
+}
+
+fn disk(mount_point: &str) -> DiskInfo {
+    DiskInfo {
+        mount_point: mount_point.to_string(),
+        file_system: "ext4".to_string(),
+        kind: "SSD".to_string(),
+        removable: false,
+        total: 0,
+        available: 0,
+    }
+}
+
+#[test]
+fn test_parse_gpus() {
+    let lspci = r#"00:02.0 "VGA compatible controller" "Intel Corporation" "UHD Graphics 620" -r07 "Lenovo" "ThinkPad"
+00:14.0 "USB controller" "Intel Corporation" "Sunrise Point-LP USB 3.0 xHCI Controller" -r21 "Lenovo" ""
+01:00.0 "3D controller" "NVIDIA Corporation" "GP108M [GeForce MX150]" -ra1 "Lenovo" """#;
+    assert_eq!(
+        parse_lspci(lspci),
+        vec![
+            gpu("Intel Corporation", "UHD Graphics 620"),
+            gpu("NVIDIA Corporation", "GP108M [GeForce MX150]"),
+        ]
+    );
+
+    let cim = "NVIDIA|NVIDIA GeForce RTX 3060\r\n|Microsoft Basic Display Adapter\r\n\r\n";
+    assert_eq!(
+        parse_cim_video_controllers(cim),
+        vec![
+            gpu("NVIDIA", "NVIDIA GeForce RTX 3060"),
+            GpuInfo {
+                vendor: None,
+                model: "Microsoft Basic Display Adapter".to_string(),
+            },
+        ]
+    );
+
+    let system_profiler = "Graphics/Displays:
+
+    Intel UHD Graphics 630:
+
+      Chipset Model: Intel UHD Graphics 630
+      Type: GPU
+      Vendor: Intel
+
+    AMD Radeon Pro 5500M:
+
+      Chipset Model: AMD Radeon Pro 5500M
+      Bus: PCIe
+      Vendor: AMD (0x1002)
+";
+    assert_eq!(
+        parse_system_profiler(system_profiler),
+        vec![
+            gpu("Intel", "Intel UHD Graphics 630"),
+            gpu("AMD", "AMD Radeon Pro 5500M"),
+        ]
+    );
+}
+
+#[test]
+fn test_find_disk() {
+    let disks = [disk("/"), disk("/home"), disk("/mnt/games")];
+    let mount_point =
+        |path: &str| find_disk(&disks, Path::new(path)).map(|disk| disk.mount_point.as_str());
+    assert_eq!(mount_point("/home/steve/.minecraft"), Some("/home"));
+    // 按路径组件匹配，/mnt/games2 不在 /mnt/games 上
+    assert_eq!(mount_point("/mnt/games2/.minecraft"), Some("/"));
+    assert_eq!(mount_point("/mnt/games/.minecraft"), Some("/mnt/games"));
+    assert!(find_disk(&disks[1..], Path::new("/opt")).is_none());
+}
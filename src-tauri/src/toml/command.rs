@@ -1,5 +1,6 @@
 use tauri::command;
 
+use crate::logging::logging::apply_debug_config;
 use crate::toml::crypto::{decrypt_string, encrypt_string};
 use crate::toml::manager::{
     delete_instance_config, get_collection_config, get_global_config, get_instance_config,
@@ -17,7 +18,13 @@ pub fn get_global_config_cmd() -> Result<GlobalConfig, String> {
 /// 保存全局配置
 #[command]
 pub fn save_global_config_cmd(config: GlobalConfig) -> Result<(), String> {
-    save_global_config(&config).map_err(|e| format!("Failed to save global config: {}", e))
+    let previous = get_global_config().ok();
+    save_global_config(&config).map_err(|e| format!("Failed to save global config: {}", e))?;
+    apply_debug_config(
+        &config.other.debug,
+        previous.as_ref().map(|previous| &previous.other.debug),
+    );
+    Ok(())
 }
 
 /// 获取账户配置
@@ -1,3 +1,5 @@
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
 use thiserror::Error;
 
 /// 配置相关的错误类型
@@ -35,7 +37,6 @@ pub enum ConfigError {
 
     /// 配置项无效错误
     #[error("Invalid config value for field {0}: {1}")]
-    #[allow(dead_code)]
     InvalidValue(String, String),
 
     /// 配置版本不兼容错误
@@ -143,8 +144,34 @@ pub enum ProfileConfigError {
 
     /// 账户已存在错误
     #[error("Profile already exists: {0}")]
-    #[allow(dead_code)]
     ProfileExists(String),
+
+    /// 无效的玩家名称错误
+    #[error("Invalid profile name: {0}")]
+    InvalidProfileName(String),
+}
+
+impl ProfileConfigError {
+    /// 错误类型，可作为前端本地化的稳定键
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ProfileConfigError::ConfigError(_) => "config",
+            ProfileConfigError::ProfileNotFound(_) => "profile_not_found",
+            ProfileConfigError::InvalidProfileType(_) => "invalid_profile_type",
+            ProfileConfigError::ProfileExists(_) => "profile_exists",
+            ProfileConfigError::InvalidProfileName(_) => "invalid_profile_name",
+        }
+    }
+}
+
+/// 序列化为 { kind, message } 供前端展示
+impl Serialize for ProfileConfigError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("ProfileConfigError", 2)?;
+        state.serialize_field("kind", self.kind())?;
+        state.serialize_field("message", &self.to_string())?;
+        state.end()
+    }
 }
 
 /// 实例配置特定错误
@@ -1,6 +1,6 @@
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 
 use once_cell::sync::Lazy;
 use toml::ser::to_string_pretty;
@@ -8,6 +8,7 @@ use toml::ser::to_string_pretty;
 use crate::toml::crypto::init_encryption_key;
 use crate::toml::error::{ConfigError, GlobalConfigError, InstanceConfigError, ProfileConfigError};
 use crate::toml::types::{CollectionConfig, GlobalConfig, InstanceConfig, ProfileConfig};
+use crate::toml::utils::write_atomic;
 
 /// 配置管理器
 pub struct ConfigManager {
@@ -17,6 +18,8 @@ pub struct ConfigManager {
     global_config: RwLock<Option<GlobalConfig>>,
     /// 账户配置缓存
     profile_config: RwLock<Option<ProfileConfig>>,
+    /// 账户配置“读取-修改-保存”的写锁
+    profile_update_lock: Mutex<()>,
     /// 集合配置缓存
     collection_config: RwLock<Option<CollectionConfig>>,
 }
@@ -36,6 +39,7 @@ static CONFIG_MANAGER: Lazy<ConfigManager> = Lazy::new(|| {
         app_data_dir,
         global_config: RwLock::new(None),
         profile_config: RwLock::new(None),
+        profile_update_lock: Mutex::new(()),
         collection_config: RwLock::new(None),
     }
 });
@@ -64,11 +68,21 @@ impl ConfigManager {
     }
 
     /// 获取应用数据目录
-    #[allow(dead_code)]
     pub fn app_data_dir(&self) -> &Path {
         &self.app_data_dir
     }
 
+    /// 获取缓存目录
+    /// 未自定义时位于应用数据目录下的 Cache
+    pub fn cache_dir(&self) -> PathBuf {
+        match self.load_global_config() {
+            Ok(config) if !config.other.launcher.cache_dir.trim().is_empty() => {
+                PathBuf::from(config.other.launcher.cache_dir.trim())
+            }
+            _ => self.app_data_dir.join("Cache"),
+        }
+    }
+
     /// 获取全局配置文件路径
     pub fn global_config_path(&self) -> PathBuf {
         self.app_data_dir.join("Config.toml")
@@ -164,7 +178,8 @@ impl ConfigManager {
     pub fn save_profile_config(&self, config: &ProfileConfig) -> Result<(), ProfileConfigError> {
         let config_path = self.profile_config_path();
         let content = to_string_pretty(config).map_err(ConfigError::from)?;
-        fs::write(&config_path, content).map_err(ConfigError::from)?;
+        // 账户配置含令牌，写入中断不应留下损坏的文件
+        write_atomic(&config_path, &content)?;
 
         // 更新缓存
         if let Ok(mut guard) = self.profile_config.write() {
@@ -174,6 +189,21 @@ impl ConfigManager {
         Ok(())
     }
 
+    /// 在写锁内读取、修改并保存账户配置，避免并发修改互相覆盖
+    pub fn update_profile_config<F, R>(&self, f: F) -> Result<R, ProfileConfigError>
+    where
+        F: FnOnce(&mut ProfileConfig) -> R,
+    {
+        let _lock = self
+            .profile_update_lock
+            .lock()
+            .unwrap_or_else(|e| e.into_inner());
+        let mut config = self.load_profile_config()?;
+        let result = f(&mut config);
+        self.save_profile_config(&config)?;
+        Ok(result)
+    }
+
     /// 加载集合配置
     pub fn load_collection_config(&self) -> Result<CollectionConfig, ConfigError> {
         // 尝试从缓存获取
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 use super::crypto::encrypted_field;
+use crate::http::policy::default_allowed_hosts;
 
 // ---------------------- Global Config ----------------------
 
@@ -179,6 +181,18 @@ pub struct NetworkConfig {
     /// 自定义代理密码
     #[serde(with = "encrypted_field")]
     pub custom_proxy_password: String,
+    /// 使用 HTTP 缓存
+    #[serde(default = "default_true")]
+    pub use_http_cache: bool,
+    /// 使用 Cookie 持久化
+    #[serde(default)]
+    pub use_cookie_jar: bool,
+    /// 允许持久化 Cookie 的域名
+    #[serde(default)]
+    pub cookie_jar_domains: Vec<String>,
+    /// 允许前端通过 http_* 命令访问的主机，留空则不限制公网主机
+    #[serde(default = "default_allowed_hosts")]
+    pub allowed_hosts: Vec<String>,
 }
 
 /// 调试配置
@@ -186,6 +200,27 @@ pub struct NetworkConfig {
 pub struct DebugConfig {
     /// 调试模式
     pub debug_mode: bool,
+    /// 单个日志文件的最大大小（MB），超出后轮转
+    #[serde(default = "default_log_max_file_mb")]
+    pub log_max_file_mb: u64,
+    /// 最多保留的日志文件数，文本日志与 JSON 日志分别计数
+    #[serde(default = "default_log_max_files")]
+    pub log_max_files: usize,
+    /// 日志最长保留天数，0 表示不限制
+    #[serde(default = "default_log_max_age_days")]
+    pub log_max_age_days: u64,
+    /// 日志目录的最大总大小（MB），包含文本日志与 JSON 日志，0 表示不限制
+    #[serde(default = "default_log_max_total_mb")]
+    pub log_max_total_mb: u64,
+    /// 使用 gzip 压缩已关闭的日志
+    #[serde(default)]
+    pub log_compress: bool,
+    /// 全局日志等级：off、error、warn、info、debug、trace，调试模式下为 trace
+    #[serde(default = "default_log_level")]
+    pub log_level: String,
+    /// 按目标设置的日志等级，例如 `ts::*`、`http`、`toml`
+    #[serde(default)]
+    pub log_targets: BTreeMap<String, String>,
 }
 
 // ---------------------- Profile Config ----------------------
@@ -286,6 +321,31 @@ pub struct CollectionConfig {
 
 // ---------------------- Default Implementations ----------------------
 
+/// 新增字段的 serde 默认值，保证旧配置文件可以继续读取
+fn default_true() -> bool {
+    true
+}
+
+fn default_log_max_file_mb() -> u64 {
+    10
+}
+
+fn default_log_max_files() -> usize {
+    30
+}
+
+fn default_log_max_age_days() -> u64 {
+    14
+}
+
+fn default_log_max_total_mb() -> u64 {
+    200
+}
+
+fn default_log_level() -> String {
+    "info".to_string()
+}
+
 impl Default for GlobalConfig {
     fn default() -> Self {
         Self {
@@ -348,9 +408,20 @@ impl Default for GlobalConfig {
                     custom_proxy_uri: "".to_string(),
                     custom_proxy_account: "".to_string(),
                     custom_proxy_password: "".to_string(),
+                    use_http_cache: true,
+                    use_cookie_jar: false,
+                    cookie_jar_domains: Vec::new(),
+                    allowed_hosts: default_allowed_hosts(),
                 },
                 debug: DebugConfig {
                     debug_mode: false,
+                    log_max_file_mb: default_log_max_file_mb(),
+                    log_max_files: default_log_max_files(),
+                    log_max_age_days: default_log_max_age_days(),
+                    log_max_total_mb: default_log_max_total_mb(),
+                    log_compress: false,
+                    log_level: default_log_level(),
+                    log_targets: BTreeMap::new(),
                 },
             },
         }
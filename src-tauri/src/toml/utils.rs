@@ -1,4 +1,5 @@
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
@@ -45,6 +46,26 @@ pub fn backup_config_file(path: &Path) -> Result<PathBuf, ConfigError> {
     Ok(backup_path)
 }
 
+/// 原子写入文件：先写入同目录的临时文件，再重命名覆盖
+pub fn write_atomic(path: &Path, content: impl AsRef<[u8]>) -> Result<(), ConfigError> {
+    let file_name = path
+        .file_name()
+        .and_then(|s| s.to_str())
+        .unwrap_or("config");
+    let temp_path = path.with_file_name(format!(".{}.tmp", file_name));
+
+    let mut file = fs::File::create(&temp_path)?;
+    file.write_all(content.as_ref())?;
+    file.sync_all()?;
+    drop(file);
+
+    fs::rename(&temp_path, path).inspect_err(|_| {
+        let _ = fs::remove_file(&temp_path);
+    })?;
+
+    Ok(())
+}
+
 /// 恢复配置文件备份
 #[allow(dead_code)]
 pub fn restore_config_backup(backup_path: &Path, target_path: &Path) -> Result<(), ConfigError> {
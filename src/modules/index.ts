@@ -23,6 +23,10 @@ export {
 export { toml as TauriTOML } from "./tauri/Toml";
 export { system as TauriSystem } from "./tauri/System";
 export { logging as TauriLogging } from "./tauri/Logging";
+export { deepLink as TauriDeepLink } from "./tauri/DeepLink";
+export { diagnostic as TauriDiagnostic } from "./tauri/Diagnostic";
 // Minecraft
 export { uuid as McUuid } from "./minecraft/Uuid";
-export * as McMsa from "./minecraft/Msa";
\ No newline at end of file
+export * as McMsa from "./minecraft/Msa";
+export * as McYggdrasil from "./minecraft/Yggdrasil";
+export * as McOffline from "./minecraft/Offline";
\ No newline at end of file
@@ -1,224 +1,95 @@
-import { IMinecraftTypes } from "@/types";
-import { MsaLoginResult } from "@/types/minecraft/Msa";
-import { TauriHTTPClient } from "..";
+import { invoke } from "@tauri-apps/api/core";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
+import { IMinecraftTypes, ITauriTypes } from "@/types";
 
 export class MsaLoginError extends Error {
     code: number;
     step: number;
+    kind: IMinecraftTypes.Msa.MsaError["kind"] | "unknown";
 
-    constructor(message: string, code = -1, step = -1) {
+    constructor(message: string, code = -1, step = -1, kind: MsaLoginError["kind"] = "unknown") {
         super(message);
         this.name = "MsaLoginError";
         this.code = code;
         this.step = step;
+        this.kind = kind;
     }
 }
 
-export async function login(
-    key: { code?: string; refresh_token?: string },
-    grant_type: string,
-    updateStepFn: (step: number) => void
-): Promise<MsaLoginResult> {
-    const $env = import.meta.env;
-    const payload = key.code ? { code: key.code } : key.refresh_token ? { refresh_token: key.refresh_token } : {};
-
-    // Step 1: MSA Token
-    updateStepFn(1);
-    let msaResp: IMinecraftTypes.Msa.MsaTokenResponse;
-
-    try {
-        msaResp = (
-            await TauriHTTPClient.post<IMinecraftTypes.Msa.MsaTokenResponse>(
-                "https://login.microsoftonline.com/consumers/oauth2/v2.0/token",
-                { "Content-Type": "application/x-www-form-urlencoded" },
-                {
-                    client_id: $env.OAUTH_MS_CLIENT_ID,
-                    client_secret: $env.OAUTH_MS_CLIENT_SECRET,
-                    redirect_uri: $env.OAUTH_REDIRECT_URI_BASE + $env.OAUTH_MS_REDIRECT_URI,
-                    ...payload,
-                    grant_type,
-                    scope: "XboxLive.signin offline_access",
-                }
-            )
-        ).body;
-    } catch (e: any) {
-        // 只做一件事：语义转换
-        throw new MsaLoginError(e?.message || "Failed to request MSA token", e?.code ?? -1, 1);
-    }
-
-    if ("error" in msaResp) {
-        throw new MsaLoginError(msaResp.error_description || "MSA token error", msaResp.error_codes?.[0] ?? -1, 1);
-    }
-
-    const ACCESS_TOKEN = (msaResp as IMinecraftTypes.Msa.MsaTokenSuccess).access_token;
-    const REFRESH_TOKEN = (msaResp as IMinecraftTypes.Msa.MsaTokenSuccess).refresh_token;
-    const MSA_EXPIRES_IN = (msaResp as IMinecraftTypes.Msa.MsaTokenSuccess).expires_in;
-
-    // Step 2: XBL Token
-    updateStepFn(2);
-    let xblResp: IMinecraftTypes.Msa.XblTokenSuccess;
-    try {
-        xblResp = (
-            await TauriHTTPClient.post<IMinecraftTypes.Msa.XblTokenSuccess>(
-                "https://user.auth.xboxlive.com/user/authenticate",
-                { "Content-Type": "application/json" },
-                {
-                    Properties: {
-                        AuthMethod: "RPS",
-                        SiteName: "user.auth.xboxlive.com",
-                        RpsTicket: `d=${ACCESS_TOKEN}`,
-                    },
-                    RelyingParty: "http://auth.xboxlive.com",
-                    TokenType: "JWT",
-                }
-            )
-        ).body;
-    } catch {
-        try {
-            // fallback（微软历史遗留的迷惑行为）
-            xblResp = (
-                await TauriHTTPClient.post<IMinecraftTypes.Msa.XblTokenSuccess>(
-                    "https://user.auth.xboxlive.com/user/authenticate",
-                    { "Content-Type": "application/json" },
-                    {
-                        Properties: {
-                            AuthMethod: "RPS",
-                            SiteName: "user.auth.xboxlive.com",
-                            RpsTicket: ACCESS_TOKEN,
-                        },
-                        RelyingParty: "http://auth.xboxlive.com",
-                        TokenType: "JWT",
-                    }
-                )
-            ).body;
-        } catch (e: any) {
-            throw new MsaLoginError(e?.message || "Failed to request XBL token", e?.code ?? -1, 2);
-        }
-    }
-
-    const XBL_TOKEN = (xblResp as IMinecraftTypes.Msa.XblTokenSuccess).Token;
-    const UHS = (xblResp as IMinecraftTypes.Msa.XblTokenSuccess).DisplayClaims.xui[0].uhs;
-
-    // Step 3: XSTS
-    updateStepFn(3);
-    let xstsResp: IMinecraftTypes.Msa.XstsTokenSuccess;
-
-    try {
-        xstsResp = (
-            await TauriHTTPClient.post<IMinecraftTypes.Msa.XstsTokenSuccess>(
-                "https://xsts.auth.xboxlive.com/xsts/authorize",
-                { "Content-Type": "application/json" },
-                {
-                    Properties: {
-                        SandboxId: "RETAIL",
-                        UserTokens: [XBL_TOKEN],
-                    },
-                    RelyingParty: "rp://api.minecraftservices.com/",
-                    TokenType: "JWT",
-                }
-            )
-        ).body;
-    } catch (e: any) {
-        throw new MsaLoginError(e?.message || "Failed to request XSTS token", e?.code ?? -1, 3);
-    }
-
-    if ((xstsResp as IMinecraftTypes.Msa.XstsTokenSuccess).DisplayClaims.xui[0].uhs !== UHS) {
-        throw new MsaLoginError("UHS mismatch between XBL and XSTS");
-    }
-
-    // Step 4: Minecraft Token
-    updateStepFn(4);
-    let mcTokenResp: IMinecraftTypes.Msa.MinecraftTokenResponse;
-
-    try {
-        mcTokenResp = (
-            await TauriHTTPClient.post<IMinecraftTypes.Msa.MinecraftTokenResponse>(
-                "https://api.minecraftservices.com/authentication/login_with_xbox",
-                { "Content-Type": "application/json" },
-                {
-                    identityToken: `XBL3.0 x=${UHS};${(xstsResp as IMinecraftTypes.Msa.XstsTokenSuccess).Token}`,
-                }
-            )
-        ).body;
-    } catch (e: any) {
-        throw new MsaLoginError(e?.message || "Failed to request Minecraft token", e?.code ?? -1, 4);
-    }
-
-    if ("errorMessage" in mcTokenResp) {
-        throw new MsaLoginError(mcTokenResp.errorMessage);
-    }
+function toLoginError(e: any): MsaLoginError {
+    const error = e as IMinecraftTypes.Msa.MsaError;
+    // 与步骤无关的错误归到第一步展示
+    return new MsaLoginError(error?.message || String(e), error?.code ?? -1, Math.max(error?.step ?? 1, 1), error?.kind);
+}
 
-    const MC_ACCESS_TOKEN = mcTokenResp.access_token;
-    const MC_EXPIRES_IN = mcTokenResp.expires_in;
+/**
+ * 使用授权码完成微软登录，令牌交换与账户保存均在后端完成
+ * @returns 已保存并设为当前使用的账户
+ */
+export async function loginFromCode(code: string, updateStepFn: (step: number) => void): Promise<ITauriTypes.TOML.MsaProfile> {
+    const unlisten = await listen<IMinecraftTypes.Msa.MsaStepPayload>("msa:step", (event) => {
+        updateStepFn(event.payload.index);
+    });
 
-    // Step 5: License Check
-    updateStepFn(5);
-    let storeResp: IMinecraftTypes.Msa.MCStoreResponse;
     try {
-        storeResp = (
-            await TauriHTTPClient.get<IMinecraftTypes.Msa.MCStoreResponse>("https://api.minecraftservices.com/entitlements/mcstore", {
-                "Content-Type": "application/json",
-                Authorization: `Bearer ${MC_ACCESS_TOKEN}`,
-            })
-        ).body;
+        return await invoke<ITauriTypes.TOML.MsaProfile>("msa_login_from_code", { code });
     } catch (e: any) {
-        throw new MsaLoginError(e?.message || "Failed to request MCStore", e?.code ?? -1, 5);
-    }
-
-    if ("errorType" in storeResp) {
-        throw new MsaLoginError(`Mojang API Error: ${storeResp.errorType}`, -1, 5);
-    }
-
-    const items = (storeResp as IMinecraftTypes.Msa.MCStoreSuccess).items ?? [];
-    const hasJava = items.some((i) => i.name === "product_minecraft" || i.name === "game_minecraft");
-
-    if (!hasJava) {
-        throw new MsaLoginError("Minecraft Java Edition not purchased", -1, 5);
+        throw toLoginError(e);
+    } finally {
+        unlisten();
     }
+}
 
-    // Step 6: Profile
-    updateStepFn(6);
-    let profileResp: IMinecraftTypes.Msa.MinecraftProfileResponse;
+/**
+ * 使用设备代码完成微软登录，适用于无法打开本地回调的环境
+ * @param onCode 获取到用户代码后回调，用于展示给用户
+ * @returns 已保存并设为当前使用的账户
+ */
+export async function loginWithDeviceCode(
+    onCode: (device: IMinecraftTypes.Msa.DeviceCode) => void,
+    updateStepFn: (step: number) => void
+): Promise<ITauriTypes.TOML.MsaProfile> {
+    const unlisteners: UnlistenFn[] = [];
     try {
-        profileResp = (
-            await TauriHTTPClient.get<IMinecraftTypes.Msa.MinecraftProfileResponse>("https://api.minecraftservices.com/minecraft/profile", {
-                "Content-Type": "application/json",
-                Authorization: `Bearer ${MC_ACCESS_TOKEN}`,
-            })
-        ).body;
-    } catch (e: any) {
-        throw new MsaLoginError(e?.message || "Failed to request Minecraft profile", e?.code ?? -1, 6);
-    }
-
-    if ("errorType" in profileResp) {
-        throw new MsaLoginError(`Mojang API Error: ${profileResp.errorType}`, -1, 6);
+        const result = new Promise<ITauriTypes.TOML.MsaProfile>(async (resolve, reject) => {
+            unlisteners.push(
+                await listen<IMinecraftTypes.Msa.MsaStepPayload>("msa:step", (event) => updateStepFn(event.payload.index)),
+                await listen<ITauriTypes.TOML.MsaProfile>("msa:device_code_success", (event) => resolve(event.payload)),
+                await listen<IMinecraftTypes.Msa.MsaError>("msa:device_code_error", (event) => reject(toLoginError(event.payload)))
+            );
+            try {
+                onCode(await invoke<IMinecraftTypes.Msa.DeviceCode>("msa_device_code_start"));
+            } catch (e: any) {
+                reject(toLoginError(e));
+            }
+        });
+        return await result;
+    } finally {
+        unlisteners.forEach((unlisten) => unlisten());
     }
-
-    const uuid = [
-        (profileResp as IMinecraftTypes.Msa.MinecraftProfileSuccess).id.slice(0, 8),
-        (profileResp as IMinecraftTypes.Msa.MinecraftProfileSuccess).id.slice(8, 12),
-        (profileResp as IMinecraftTypes.Msa.MinecraftProfileSuccess).id.slice(12, 16),
-        (profileResp as IMinecraftTypes.Msa.MinecraftProfileSuccess).id.slice(16, 20),
-        (profileResp as IMinecraftTypes.Msa.MinecraftProfileSuccess).id.slice(20),
-    ].join("-");
-
-    return {
-        msaExpiresIn: MSA_EXPIRES_IN,
-        mcExpiresIn: MC_EXPIRES_IN,
-        msaAccessToken: ACCESS_TOKEN,
-        msaRefreshToken: REFRESH_TOKEN,
-        mcAccessToken: MC_ACCESS_TOKEN,
-        uuid,
-        name: (profileResp as IMinecraftTypes.Msa.MinecraftProfileSuccess).name,
-        skins: (profileResp as IMinecraftTypes.Msa.MinecraftProfileSuccess).skins,
-        capes: (profileResp as IMinecraftTypes.Msa.MinecraftProfileSuccess).capes,
-    };
 }
 
-export async function loginFromCode(code: string, updateStepFn: (step: number) => void): Promise<MsaLoginResult> {
-    return login({ code }, "authorization_code", updateStepFn);
+/** 取消正在进行的设备代码登录 */
+export async function cancelDeviceCodeLogin(): Promise<void> {
+    await invoke("msa_device_code_cancel");
 }
 
-export async function loginFromRefreshToken(refresh_token: string, updateStepFn: (step: number) => void): Promise<MsaLoginResult> {
-    return login({ refresh_token }, "refresh_token", updateStepFn);
+/**
+ * 监听后台令牌刷新结果
+ * @returns 取消监听
+ */
+export async function listenTokenRefresh(
+    onRefreshed: (payload: IMinecraftTypes.Msa.AccountRefreshedPayload) => void,
+    onFailed: (payload: IMinecraftTypes.Msa.AccountRefreshFailedPayload) => void
+): Promise<UnlistenFn> {
+    const unlistenRefreshed = await listen<IMinecraftTypes.Msa.AccountRefreshedPayload>("account:refreshed", (event) =>
+        onRefreshed(event.payload)
+    );
+    const unlistenFailed = await listen<IMinecraftTypes.Msa.AccountRefreshFailedPayload>("account:refresh_failed", (event) =>
+        onFailed(event.payload)
+    );
+    return () => {
+        unlistenRefreshed();
+        unlistenFailed();
+    };
 }
@@ -0,0 +1,17 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ITauriTypes } from "@/types";
+
+/**
+ * 创建离线账户并设为当前账户，名称校验与 UUID 生成均在后端完成
+ * @param uuid 自定义 UUID，未指定时使用标准离线 UUID
+ * @param skinPath 本地皮肤 PNG（64x64 或 64x32）
+ * @throws {ITauriTypes.TOML.ProfileConfigError} 名称无效或账户已存在
+ */
+export async function createOfflineProfile(
+    name: string,
+    uuid?: string,
+    skinPath?: string,
+    slim?: boolean
+): Promise<ITauriTypes.TOML.LegacyProfile> {
+    return await invoke("offline_profile_create", { name, uuid, skinPath, slim });
+}
@@ -1,10 +1,13 @@
 import SparkMD5 from "spark-md5";
 
 export function createOfflineUUID(name: string) {
-    const hash = SparkMD5.hash("OfflinePlayer:" + name);
+    const md5 = SparkMD5.hash("OfflinePlayer:" + name);
+    // 与 Java 的 UUID.nameUUIDFromBytes 一致：版本位为 3，变体位为 10xx
+    const variant = ((parseInt(md5[16], 16) & 0x3) | 0x8).toString(16);
+    const hash = `${md5.slice(0, 12)}3${md5.slice(13, 16)}${variant}${md5.slice(17)}`;
 
     return {
-        dash: `${hash.slice(0, 8)}-${hash.slice(8, 12)}-3${hash.slice(13, 16)}-a${hash.slice(17, 20)}-${hash.slice(20)}`,
+        dash: `${hash.slice(0, 8)}-${hash.slice(8, 12)}-${hash.slice(12, 16)}-${hash.slice(16, 20)}-${hash.slice(20)}`,
         raw: hash,
     };
 }
@@ -0,0 +1,43 @@
+import { invoke } from "@tauri-apps/api/core";
+import { IMinecraftTypes, ITauriTypes } from "@/types";
+
+/** 解析皮肤站地址（支持 ALI）并获取元数据 */
+export async function discover(url: string): Promise<IMinecraftTypes.Yggdrasil.YggdrasilServer> {
+    return await invoke("yggdrasil_discover", { url });
+}
+
+/**
+ * 登录皮肤站
+ * @returns 单角色账户直接保存；多角色账户需要再调用 selectProfile
+ */
+export async function login(url: string, username: string, password: string): Promise<IMinecraftTypes.Yggdrasil.YggdrasilLoginResult> {
+    return await invoke("yggdrasil_login", { url, username, password });
+}
+
+/** 为多角色账户选择角色并保存 */
+export async function selectProfile(profileId: string): Promise<ITauriTypes.TOML.YggdrasilProfile> {
+    return await invoke("yggdrasil_select_profile", { profileId });
+}
+
+/** 确保账户令牌有效，必要时刷新 */
+export async function refresh(guid: string): Promise<ITauriTypes.TOML.YggdrasilProfile> {
+    return await invoke("yggdrasil_refresh", { guid });
+}
+
+/** 吊销账户令牌，不删除账户 */
+export async function logout(guid: string): Promise<void> {
+    await invoke("yggdrasil_logout", { guid });
+}
+
+/** 吊销该账户在皮肤站的所有令牌 */
+export async function signout(url: string, username: string, password: string): Promise<void> {
+    await invoke("yggdrasil_signout", { url, username, password });
+}
+
+/**
+ * 确保 authlib-injector 已下载
+ * @param checkUpdate 是否检查更新，默认按全局配置
+ */
+export async function ensureAuthlibInjector(checkUpdate?: boolean): Promise<IMinecraftTypes.Yggdrasil.AuthlibInjector> {
+    return await invoke("authlib_injector_ensure", { checkUpdate });
+}
@@ -0,0 +1,49 @@
+import { invoke } from "@tauri-apps/api/core";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
+import { ITauriTypes } from "@/types";
+
+export class DeepLink {
+    /**
+     * 处理 novacl:// 链接，例如用户粘贴的链接
+     * @returns 需要确认的请求，登录回调返回 null
+     */
+    static async handle(url: string) {
+        return await invoke<ITauriTypes.DeepLink.DeepLinkRequest | null>("deep_link_handle", { url });
+    }
+
+    /**
+     * 获取等待确认的链接，界面启动前收到的链接也会保留在这里
+     */
+    static async getPending() {
+        return await invoke<ITauriTypes.DeepLink.DeepLinkRequest[]>("deep_link_pending");
+    }
+
+    /**
+     * 用户确认或拒绝后移除请求
+     */
+    static async resolve(id: string) {
+        return await invoke<ITauriTypes.DeepLink.DeepLinkRequest>("deep_link_resolve", { id });
+    }
+
+    /**
+     * 监听新的深度链接请求与解析失败
+     * @returns 取消监听的函数
+     */
+    static async listen(
+        onRequest: (request: ITauriTypes.DeepLink.DeepLinkRequest) => void,
+        onError?: (error: ITauriTypes.DeepLink.DeepLinkError) => void
+    ): Promise<UnlistenFn> {
+        const unlisteners = [await listen<ITauriTypes.DeepLink.DeepLinkRequest>("deeplink:request", (event) => onRequest(event.payload))];
+        if (onError) {
+            unlisteners.push(await listen<ITauriTypes.DeepLink.DeepLinkError>("deeplink:error", (event) => onError(event.payload)));
+        }
+        return () => unlisteners.forEach((unlisten) => unlisten());
+    }
+}
+
+export const deepLink = {
+    handle: DeepLink.handle,
+    getPending: DeepLink.getPending,
+    resolve: DeepLink.resolve,
+    listen: DeepLink.listen,
+};
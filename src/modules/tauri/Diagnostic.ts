@@ -0,0 +1,17 @@
+import { invoke } from "@tauri-apps/api/core";
+import { ITauriTypes } from "@/types";
+
+export class Diagnostic {
+    /**
+     * 创建诊断包，包含启动器日志、脱敏后的配置与系统信息
+     * @param instancePath 需要一并打包日志与崩溃报告的实例目录
+     * @param outputPath 输出的 zip 路径，默认为应用数据目录下的 Diagnostics
+     */
+    static async createBundle(instancePath?: string, outputPath?: string) {
+        return await invoke<ITauriTypes.Diagnostic.BundleSummary>("diagnostic_bundle_create", { instancePath, outputPath });
+    }
+}
+
+export const diagnostic = {
+    createBundle: Diagnostic.createBundle,
+};
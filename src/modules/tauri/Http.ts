@@ -5,7 +5,7 @@ import { listen, UnlistenFn, Event } from "@tauri-apps/api/event";
 // HTTP 客户端类
 export class HttpClient {
     /**
-     * 通用请求方法
+     * 通用请求方法，状态码为 4xx / 5xx 时以 kind 为 status 的 HttpError 拒绝（其他请求方法相同）
      */
     static async request<T>(req: ITauriTypes.HTTP.HttpRequest): Promise<ITauriTypes.HTTP.HttpResponse<T>> {
         try {
@@ -75,18 +75,48 @@ export class HttpClient {
             throw error as ITauriTypes.HTTP.HttpError;
         }
     }
+
+    /**
+     * 取消带 request_id 的请求，被取消的请求以 cancelled 错误结束
+     * @returns 请求不存在或已完成时为 false
+     */
+    static async cancel(requestId: string): Promise<boolean> {
+        return await invoke<boolean>("http_cancel", { requestId });
+    }
+
+    /**
+     * 清空 HTTP 缓存
+     */
+    static async clearCache(): Promise<void> {
+        await invoke("http_cache_clear");
+    }
+
+    /**
+     * 清空持久化的 Cookie
+     */
+    static async clearCookies(): Promise<void> {
+        await invoke("http_cookie_clear");
+    }
 }
 
 // HTTP 服务器类
 export class HttpServer {
     private static _listener: UnlistenFn | null = null;
+    private static _failureListeners: UnlistenFn[] = [];
 
     /**
-     * 启动 OAuth HTTP 服务器
-     * @param port 监听端口
+     * 启动 OAuth HTTP 服务器（仅监听回环地址）
+     * @param port 监听端口，传 0 由系统分配
      * @param lang 可选语言
+     * @param fallbackRange 端口被占用时依次尝试的端口范围 [起, 止]
+     * @param timeoutSecs 会话超时秒数，超时后服务器自动关闭并触发 oauth:timeout
      */
-    static async start(port: number, lang?: string): Promise<ITauriTypes.HTTP.HttpServerStartResult> {
+    static async start(
+        port: number,
+        lang?: string,
+        fallbackRange?: [number, number],
+        timeoutSecs?: number
+    ): Promise<ITauriTypes.HTTP.HttpServerStartResult> {
         // 检查是否已经在运行
         const status = await this.getStatus();
         if (status.status === "running") {
@@ -100,7 +130,9 @@ export class HttpServer {
         try {
             const response = await invoke<ITauriTypes.HTTP.HttpServerStartResult>("http_server_start", {
                 port,
-                lang, // lang 为 undefined 时后端会使用默认 "en"
+                fallbackRange,
+                lang, // lang 为 undefined 时后端会使用配置中的语言
+                timeoutSecs,
             });
             return response;
         } catch (error) {
@@ -111,15 +143,17 @@ export class HttpServer {
 
     /**
      * 停止 HTTP 服务器
+     * @param name 服务器名称，默认为 OAuth 回调服务器
      */
-    static async stop(): Promise<ITauriTypes.HTTP.HttpServerStopResult> {
+    static async stop(name?: string): Promise<ITauriTypes.HTTP.HttpServerStopResult> {
         const status = await this.getStatus();
-        if (status.status === "stopped") {
+        const running = name === undefined ? status.status === "running" : status.servers?.some((server) => server.name === name);
+        if (!running) {
             return { status: "ok", message: "HTTP Server already stopped" };
         }
 
         try {
-            const response = await invoke<ITauriTypes.HTTP.HttpServerStopResult>("http_server_stop");
+            const response = await invoke<ITauriTypes.HTTP.HttpServerStopResult>("http_server_stop", { name });
             return response;
         } catch (error) {
             console.tError({ category: "HTTP Server", message: `Failed to stop HTTP server: ${error}` });
@@ -127,6 +161,34 @@ export class HttpServer {
         }
     }
 
+    /**
+     * 以已注册的路由组启动命名的本地服务器（仅监听回环地址）
+     * @param name 服务器名称，不同名称的服务器相互独立
+     * @param groups 路由组名称，见 getRouteGroups
+     * @param port 监听端口，传 0 由系统分配
+     * @param fallbackRange 端口被占用时依次尝试的端口范围 [起, 止]
+     */
+    static async startHost(
+        name: string,
+        groups: string[],
+        port: number,
+        fallbackRange?: [number, number]
+    ): Promise<ITauriTypes.HTTP.HttpHostStartResult> {
+        try {
+            return await invoke<ITauriTypes.HTTP.HttpHostStartResult>("http_host_start", { name, groups, port, fallbackRange });
+        } catch (error) {
+            console.tError({ category: "HTTP Server", message: `Failed to start server ${name}: ${error}` });
+            throw error;
+        }
+    }
+
+    /**
+     * 获取已注册的路由组
+     */
+    static async getRouteGroups(): Promise<string[]> {
+        return await invoke<string[]>("http_route_groups");
+    }
+
     /**
      * 获取服务器运行状态
      */
@@ -164,13 +226,35 @@ export class HttpServer {
     }
 
     /**
-     * 取消 OAuth 事件监听（可选，手动清理）
+     * 监听 OAuth 失败事件（提供方返回错误 / 会话超时）
+     * @param onError 提供方重定向带回 error 时的回调
+     * @param onTimeout 会话超时的回调
+     */
+    static async listenOAuthFailure(
+        onError: (payload: ITauriTypes.HTTP.OAuthErrorPayload) => void | Promise<void>,
+        onTimeout: (payload: ITauriTypes.HTTP.OAuthTimeoutPayload) => void | Promise<void>
+    ): Promise<void> {
+        HttpServer._failureListeners.forEach((unlisten) => unlisten());
+        HttpServer._failureListeners = [
+            await listen<ITauriTypes.HTTP.OAuthErrorPayload>("oauth:error", (event) => {
+                onError(event.payload);
+            }),
+            await listen<ITauriTypes.HTTP.OAuthTimeoutPayload>("oauth:timeout", (event) => {
+                onTimeout(event.payload);
+            }),
+        ];
+    }
+
+    /**
+     * 取消所有 OAuth 事件监听（可选，手动清理）
      */
     static unlistenOAuthCode() {
         if (this._listener) {
             this._listener();
             this._listener = null;
         }
+        HttpServer._failureListeners.forEach((unlisten) => unlisten());
+        HttpServer._failureListeners = [];
     }
 }
 
@@ -182,13 +266,19 @@ export const httpClient = {
     put: HttpClient.put,
     delete: HttpClient.delete,
     patch: HttpClient.patch,
+    cancel: HttpClient.cancel,
+    clearCache: HttpClient.clearCache,
+    clearCookies: HttpClient.clearCookies,
 };
 
 // 导出便捷的服务器方法
 export const httpServer = {
     start: HttpServer.start,
     stop: HttpServer.stop,
+    startHost: HttpServer.startHost,
+    getRouteGroups: HttpServer.getRouteGroups,
     getStatus: HttpServer.getStatus,
     listenOAuthCode: HttpServer.listenOAuthCode,
+    listenOAuthFailure: HttpServer.listenOAuthFailure,
     unlistenOAuthCode: HttpServer.unlistenOAuthCode,
 };
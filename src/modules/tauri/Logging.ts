@@ -1,4 +1,5 @@
 import { invoke } from "@tauri-apps/api/core";
+import { listen, UnlistenFn } from "@tauri-apps/api/event";
 import { ITauriTypes } from "@/types";
 
 /**
@@ -6,69 +7,191 @@ import { ITauriTypes } from "@/types";
  */
 export class TauriLogging {
     /**
-     * 记录 TRACE 级别的日志
+     * 批量提交前等待的时间（毫秒）
+     */
+    static readonly BATCH_DELAY = 200;
+
+    /**
+     * 队列达到此数量时立即提交
+     */
+    static readonly BATCH_SIZE = 100;
+
+    /**
+     * 等待批量提交的日志
+     */
+    private static queue: ITauriTypes.Logging.LogBatchRecord[] = [];
+
+    private static timer: ReturnType<typeof setTimeout> | null = null;
+
+    /**
+     * 将日志加入队列，ERROR 日志与队列已满时立即提交
+     */
+    private static async enqueue(level: ITauriTypes.Logging.LogLevel, options: ITauriTypes.Logging.LogOptions): Promise<void> {
+        TauriLogging.queue.push({
+            level,
+            category: options.category,
+            message: options.message,
+            timestamp: Date.now(),
+            fields: options.fields,
+        });
+        if (level === "error" || TauriLogging.queue.length >= TauriLogging.BATCH_SIZE) {
+            await TauriLogging.flush();
+        } else if (TauriLogging.timer === null) {
+            TauriLogging.timer = setTimeout(() => void TauriLogging.flush(), TauriLogging.BATCH_DELAY);
+        }
+    }
+
+    /**
+     * 立即提交队列中的日志
+     *
+     * @returns Promise<void>
+     */
+    static async flush(): Promise<void> {
+        if (TauriLogging.timer !== null) {
+            clearTimeout(TauriLogging.timer);
+            TauriLogging.timer = null;
+        }
+        if (TauriLogging.queue.length === 0) {
+            return;
+        }
+        const records = TauriLogging.queue;
+        TauriLogging.queue = [];
+        await invoke("log_batch", { records });
+    }
+
+    /**
+     * 记录 TRACE 级别的日志，与其他日志一起批量提交
      *
      * @param options 日志选项
      * @returns Promise<void>
      */
     static async trace(options: ITauriTypes.Logging.LogOptions): Promise<void> {
-        await invoke("log_trace", {
-            category: options.category,
-            message: options.message,
-        });
+        await TauriLogging.enqueue("trace", options);
     }
 
     /**
-     * 记录 DEBUG 级别的日志
+     * 记录 DEBUG 级别的日志，与其他日志一起批量提交
      *
      * @param options 日志选项
      * @returns Promise<void>
      */
     static async debug(options: ITauriTypes.Logging.LogOptions): Promise<void> {
-        await invoke("log_debug", {
-            category: options.category,
-            message: options.message,
-        });
+        await TauriLogging.enqueue("debug", options);
     }
 
     /**
-     * 记录 INFO 级别的日志
+     * 记录 INFO 级别的日志，与其他日志一起批量提交
      *
      * @param options 日志选项
      * @returns Promise<void>
      */
     static async info(options: ITauriTypes.Logging.LogOptions): Promise<void> {
-        await invoke("log_info", {
-            category: options.category,
-            message: options.message,
-        });
+        await TauriLogging.enqueue("info", options);
     }
 
     /**
-     * 记录 WARN 级别的日志
+     * 记录 WARN 级别的日志，与其他日志一起批量提交
      *
      * @param options 日志选项
      * @returns Promise<void>
      */
     static async warn(options: ITauriTypes.Logging.LogOptions): Promise<void> {
-        await invoke("log_warn", {
-            category: options.category,
-            message: options.message,
-        });
+        await TauriLogging.enqueue("warn", options);
     }
 
     /**
-     * 记录 ERROR 级别的日志
+     * 记录 ERROR 级别的日志，与其他日志一起批量提交
      *
      * @param options 日志选项
      * @returns Promise<void>
      */
     static async error(options: ITauriTypes.Logging.LogOptions): Promise<void> {
-        await invoke("log_error", {
-            category: options.category,
-            message: options.message,
+        await TauriLogging.enqueue("error", options);
+    }
+
+    /**
+     * 获取当前的日志等级
+     *
+     * @returns 全局与按目标设置的日志等级
+     */
+    static async getLevels(): Promise<ITauriTypes.Logging.LogLevels> {
+        return await invoke<ITauriTypes.Logging.LogLevels>("log_get_levels");
+    }
+
+    /**
+     * 设置日志等级，立即生效
+     *
+     * @param levels 全局与按目标设置的日志等级
+     * @param persist 是否写入调试配置，默认为 true；调试模式随全局等级是否为 trace 更新
+     * @returns 生效后的日志等级
+     */
+    static async setLevels(levels: ITauriTypes.Logging.LogLevels, persist: boolean = true): Promise<ITauriTypes.Logging.LogLevels> {
+        return await invoke<ITauriTypes.Logging.LogLevels>("log_set_levels", {
+            level: levels.level,
+            targets: levels.targets,
+            persist,
         });
     }
+
+    /**
+     * 列出日志目录中的文件，新的在前
+     *
+     * @returns 日志文件列表
+     */
+    static async listFiles(): Promise<ITauriTypes.Logging.LogFileInfo[]> {
+        return await invoke<ITauriTypes.Logging.LogFileInfo[]>("log_list_files");
+    }
+
+    /**
+     * 搜索文本日志，新的记录在前
+     *
+     * @param query 搜索条件与分页
+     * @returns 搜索结果的一页
+     */
+    static async search(query: ITauriTypes.Logging.LogQuery = {}): Promise<ITauriTypes.Logging.LogPage> {
+        return await invoke<ITauriTypes.Logging.LogPage>("log_search", { query });
+    }
+
+    /**
+     * 跟踪当前日志，新记录到达时调用回调
+     *
+     * @param onRecords 新记录的回调
+     * @returns 停止跟踪的函数
+     */
+    static async tail(onRecords: (records: ITauriTypes.Logging.LogRecord[]) => void): Promise<UnlistenFn> {
+        const unlisten = await listen<ITauriTypes.Logging.LogRecord[]>("log:lines", (event) => onRecords(event.payload));
+        await invoke<string>("log_tail_start");
+        return () => {
+            unlisten();
+            void invoke<boolean>("log_tail_stop");
+        };
+    }
+
+    /**
+     * 获取上次运行留下的、尚未被查看的崩溃报告
+     *
+     * @returns 崩溃报告，没有时返回 null
+     */
+    static async getPendingCrash(): Promise<ITauriTypes.Logging.CrashReport | null> {
+        return await invoke<ITauriTypes.Logging.CrashReport | null>("crash_report_pending");
+    }
+
+    /**
+     * 用户查看崩溃报告后清除提示
+     */
+    static async dismissCrash(): Promise<void> {
+        await invoke("crash_report_dismiss");
+    }
+
+    /**
+     * 监听后端 panic
+     *
+     * @param onPanic 崩溃报告的回调
+     * @returns 取消监听的函数
+     */
+    static async onPanic(onPanic: (report: ITauriTypes.Logging.CrashReport) => void): Promise<UnlistenFn> {
+        return await listen<ITauriTypes.Logging.CrashReport>("app:panic", (event) => onPanic(event.payload));
+    }
 }
 
 export const logging = {
@@ -77,4 +200,13 @@ export const logging = {
     info: TauriLogging.info,
     warn: TauriLogging.warn,
     error: TauriLogging.error,
+    flush: TauriLogging.flush,
+    getLevels: TauriLogging.getLevels,
+    setLevels: TauriLogging.setLevels,
+    listFiles: TauriLogging.listFiles,
+    search: TauriLogging.search,
+    tail: TauriLogging.tail,
+    getPendingCrash: TauriLogging.getPendingCrash,
+    dismissCrash: TauriLogging.dismissCrash,
+    onPanic: TauriLogging.onPanic,
 }
\ No newline at end of file
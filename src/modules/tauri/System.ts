@@ -5,8 +5,13 @@ export class System {
     static async getRamInfo() {
         return await invoke<ITauriTypes.System.RamInfo>("get_ram_info");
     }
+
+    static async getSystemInfo() {
+        return await invoke<ITauriTypes.System.SystemInfo>("get_system_info");
+    }
 }
 
 export const system = {
     getRamInfo: System.getRamInfo,
+    getSystemInfo: System.getSystemInfo,
 };
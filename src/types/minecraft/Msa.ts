@@ -1,110 +1,76 @@
-export interface MojangAPIError {
-    errorMessage: string;
-    path: string;
-}
-
-export interface MsaTokenSuccess {
-    token_type: string;
-    scope: string;
-    expires_in: number;
-    ext_expires_in: number;
-    access_token: string;
-    refresh_token: string;
-}
-
-export interface MsaTokenError {
-    correlation_id: string;
-    error: string;
-    error_codes: number[];
-    error_description: string;
-    error_uri: string;
-    timestamp: string;
-    trace_id: string;
-}
-
-export interface MsaTokenResponse extends MsaTokenSuccess, MsaTokenError {}
+/** 登录步骤 */
+export type MsaStep = "msa_token" | "xbox_live" | "xsts" | "minecraft_token" | "entitlements" | "profile";
 
-export interface XblTokenSuccess {
-    IssueInstant: string;
-    NotAfter: string;
-    Token: string;
-    DisplayClaims: {
-        xui: [
-            {
-                uhs: string;
-            },
-        ];
-    };
+/** msa:step 事件 */
+export interface MsaStepPayload {
+    step: MsaStep;
+    /** 步骤序号（1-6） */
+    index: number;
+    total: number;
 }
 
-export interface XstsTokenSuccess {
-    IssueInstant: string;
-    NotAfter: string;
-    Token: string;
-    DisplayClaims: {
-        xui: [
-            {
-                uhs: string;
-            },
-        ];
-    };
-}
-
-export interface MinecraftTokenSuccess {
-    username: string;
-    roles: any[];
-    access_token: string;
-    token_type: "Bearer";
+/** 设备代码授权信息 */
+export interface DeviceCode {
+    /** 用户需要输入的代码 */
+    user_code: string;
+    /** 输入代码的地址 */
+    verification_uri: string;
+    /** 有效期（秒） */
     expires_in: number;
+    /** 轮询间隔（秒） */
+    interval: number;
+    message: string;
 }
 
-export type MinecraftTokenResponse = MinecraftTokenSuccess | MojangAPIError;
-
-export interface MCStoreSuccess {
-    items: { name: string; signature: string }[];
-    signatures: string;
-    keyId: 1;
-}
-
-export type MCStoreResponse = MCStoreSuccess | MojangAPIError;
-
-export interface MinecraftProfileSuccess {
-    id: string;
+/** account:refreshed 事件 */
+export interface AccountRefreshedPayload {
+    guid: string;
+    uuid: string;
     name: string;
-    skins: {
-        id: string;
-        state: string;
-        url: string;
-        variant: string;
-        alias: string;
-    }[];
-    capes: {
-        id: string;
-        state: string;
-        url: string;
-    }[];
+    /** MC 令牌过期时间（毫秒） */
+    mc_expires_at: number;
 }
 
-export interface MinecraftProfileError {
-    path: string;
-    errorType: string;
-    error: number[];
-    errorMessage: string;
-    developerMessage: string;
+/** account:refresh_failed 事件 */
+export interface AccountRefreshFailedPayload {
+    guid: string;
+    uuid: string;
+    name: string;
+    error: MsaError;
+    /** 刷新令牌已失效，需要重新登录；重新登录前不再尝试刷新 */
+    needs_login: boolean;
 }
 
-export type MinecraftProfileResponse = MinecraftProfileSuccess | MinecraftProfileError;
-
-export interface MsaLoginResult {
-    msaExpiresIn: number
-    mcExpiresIn: number;
-    msaAccessToken: string;
-    msaRefreshToken: string;
-
-    mcAccessToken: string;
+/** XSTS 拒绝授权的原因 */
+export type XstsError =
+    | "banned"
+    | "guardian_permission_required"
+    | "no_xbox_account"
+    | "terms_not_accepted"
+    | "region_unavailable"
+    | "adult_verification_required"
+    | "child_account"
+    | "unknown";
 
-    uuid: string;
-    name: string;
-    skins: any[];
-    capes: any[];
+/** 后端返回的登录错误 */
+export interface MsaError {
+    kind:
+        | "invalid_grant"
+        | "not_configured"
+        | "http"
+        | "oauth"
+        | "authorization_declined"
+        | "device_code_expired"
+        | "xsts"
+        | "uhs_mismatch"
+        | "not_owned"
+        | "no_profile"
+        | "invalid_response"
+        | "config";
+    /** 出错的步骤（1-6），与步骤无关时为 0 */
+    step: number;
+    message: string;
+    /** HTTP 状态码或 XErr */
+    code?: number;
+    xsts?: XstsError;
 }
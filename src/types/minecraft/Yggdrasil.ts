@@ -0,0 +1,63 @@
+import type { Profile } from "../tauri/Toml";
+
+/** 游戏角色 */
+export interface GameProfile {
+    /** 无符号 UUID */
+    id: string;
+    name: string;
+}
+
+/** 皮肤站元数据 */
+export interface ServerMetadata {
+    meta: {
+        serverName?: string;
+        implementationName?: string;
+        implementationVersion?: string;
+        links: {
+            homepage?: string;
+            register?: string;
+        };
+        /** 是否支持使用角色名登录 */
+        "feature.non_email_login": boolean;
+    };
+    skinDomains: string[];
+    signaturePublickey?: string;
+}
+
+/** 已解析 API 地址的皮肤站 */
+export interface YggdrasilServer {
+    /** API 根地址，以 / 结尾 */
+    api_root: string;
+    metadata: ServerMetadata;
+}
+
+/** 皮肤站登录结果 */
+export type YggdrasilLoginResult = { status: "saved"; profile: Profile } | { status: "select_profile"; profiles: GameProfile[] };
+
+/** 后端返回的皮肤站错误 */
+export interface YggdrasilError {
+    kind:
+        | "invalid_url"
+        | "http"
+        | "server"
+        | "no_profile"
+        | "no_pending_login"
+        | "unknown_profile"
+        | "invalid_response"
+        | "checksum_mismatch"
+        | "config";
+    message: string;
+    /** HTTP 状态码 */
+    code?: number;
+    /** 皮肤站返回的错误，如 ForbiddenOperationException */
+    error?: string;
+}
+
+/** 本地 authlib-injector 状态 */
+export interface AuthlibInjector {
+    version: string;
+    build_number: number;
+    path: string;
+    /** 本次是否下载了新版本 */
+    updated: boolean;
+}
@@ -1 +1,2 @@
-export * as Msa from "./Msa";
\ No newline at end of file
+export * as Msa from "./Msa";
+export * as Yggdrasil from "./Yggdrasil";
\ No newline at end of file
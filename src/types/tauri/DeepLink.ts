@@ -0,0 +1,29 @@
+/** 处理深度链接的模块 */
+export type DeepLinkSubsystem = "modrinth" | "servers" | "auth";
+
+/** 解析后的深度链接 */
+export type DeepLink =
+    | { action: "install-modpack"; project: string; version?: string }
+    | { action: "install-mod"; project: string; version?: string; instance?: string }
+    | { action: "add-server"; address: string; name?: string };
+
+/** 等待用户确认的深度链接，deeplink:request 事件 */
+export type DeepLinkRequest = DeepLink & {
+    id: string;
+    subsystem: DeepLinkSubsystem;
+    /** 收到时间（Unix 毫秒） */
+    received_at: number;
+};
+
+/** 深度链接错误，deeplink:error 事件 */
+export interface DeepLinkError {
+    kind:
+        | "invalid_url"
+        | "unsupported_scheme"
+        | "unknown_action"
+        | "missing_parameter"
+        | "invalid_parameter"
+        | "unknown_request"
+        | "rejected";
+    message: string;
+}
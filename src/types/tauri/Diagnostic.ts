@@ -0,0 +1,22 @@
+/** 诊断包中的文件 */
+export interface BundleEntry {
+    name: string;
+    size: number;
+    /** 超出大小限制，只保留了末尾 */
+    truncated: boolean;
+}
+
+/** 未能打包的文件 */
+export interface SkippedEntry {
+    name: string;
+    reason: string;
+}
+
+/** 诊断包创建结果 */
+export interface BundleSummary {
+    /** zip 文件路径 */
+    path: string;
+    size: number;
+    files: BundleEntry[];
+    skipped: SkippedEntry[];
+}
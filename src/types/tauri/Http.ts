@@ -4,6 +4,8 @@ export interface HttpRequest {
     url: string;
     headers?: Record<string, string>;
     body?: any;
+    /** 请求 ID，用于通过 HttpClient.cancel 取消请求 */
+    request_id?: string;
 }
 
 export interface HttpResponse<T extends any = any> {
@@ -13,9 +15,28 @@ export interface HttpResponse<T extends any = any> {
     text?: string;
 }
 
+/** 错误类型，可作为本地化的稳定键 */
+export type HttpErrorKind =
+    | "dns"
+    | "connect"
+    | "tls"
+    | "timeout"
+    | "proxy"
+    | "redirect_loop"
+    | "status"
+    | "body_decode"
+    | "cancelled"
+    | "blocked_by_policy"
+    | "invalid_request"
+    | "unknown";
+
 export interface HttpError {
+    kind: HttpErrorKind;
     message: string;
+    /** HTTP 状态码（如有） */
     code?: number;
+    /** 响应体（如有） */
+    body?: any;
 }
 
 // HTTP 服务器相关类型
@@ -24,7 +45,10 @@ export interface HttpError {
 export interface HttpServerStartResult {
     status: "ok";
     message: string;
+    /** 实际监听的端口 */
     port: number;
+    /** 带 state 与 PKCE challenge 的授权地址，服务器已在运行时为空 */
+    authorize_url?: string;
 }
 
 export interface HttpServerStopResult {
@@ -33,8 +57,32 @@ export interface HttpServerStopResult {
 }
 
 export interface HttpServerStatusResult {
+    /** OAuth 回调服务器的状态 */
     status: "running" | "stopped";
     port?: number; // running 时才有
+    /** 所有运行中的本地服务器 */
+    servers?: LocalServerStatus[];
+}
+
+export interface LocalRouteInfo {
+    /** 路由组名称 */
+    group: string;
+    path: string;
+}
+
+export interface LocalServerStatus {
+    name: string;
+    port: number;
+    routes: LocalRouteInfo[];
+    /** 启动时间（Unix 毫秒） */
+    started_at: number;
+    uptime_secs: number;
+}
+
+export interface HttpHostStartResult {
+    status: "ok";
+    message: string;
+    port: number;
 }
 
 export interface OAuthCodeReceivedPayload {
@@ -42,6 +90,15 @@ export interface OAuthCodeReceivedPayload {
     query: Record<string, string>; // 查询参数，如 { code: "...", state: "..." }
 }
 
+export interface OAuthErrorPayload {
+    error: string; // 例如 "access_denied"
+    error_description?: string;
+}
+
+export interface OAuthTimeoutPayload {
+    timeout_secs: number;
+}
+
 export interface HttpServerEvent<T = any> {
     event: string;
     payload: T;
@@ -15,4 +15,141 @@ export interface LogOptions {
      * 日志内容
      */
     message: string;
+    /**
+     * 结构化字段，写入 JSON 日志
+     */
+    fields?: Record<string, unknown>;
+}
+
+/**
+ * 批量提交的一条日志
+ */
+export interface LogBatchRecord extends LogOptions {
+    level: LogLevel;
+    /**
+     * 日志产生时的时间（Unix 毫秒）
+     */
+    timestamp: number;
+}
+
+/**
+ * 可设置的日志等级，off 表示关闭
+ */
+export type LogLevelFilter = LogLevel | "off";
+
+/**
+ * 全局与按目标设置的日志等级
+ */
+export interface LogLevels {
+    /**
+     * 全局等级
+     */
+    level: LogLevelFilter;
+    /**
+     * 按目标设置的等级，例如 `ts::*`、`http`、`toml`，匹配多个时以最长的为准
+     */
+    targets: Record<string, LogLevelFilter>;
+}
+
+/**
+ * 日志目录中的文件
+ */
+export interface LogFileInfo {
+    name: string;
+    size: number;
+    /**
+     * 修改时间（Unix 毫秒）
+     */
+    modified: number;
+    kind: "log" | "jsonl";
+    compressed: boolean;
+    /**
+     * 是否为正在写入的文件
+     */
+    current: boolean;
+}
+
+/**
+ * 解析后的一条文本日志
+ */
+export interface LogRecord {
+    file: string;
+    /**
+     * 记录起始行号，从 1 开始
+     */
+    line: number;
+    timestamp: string;
+    level: string;
+    source: "TS" | "Rust";
+    category: string;
+    /**
+     * 多行消息以换行符连接
+     */
+    message: string;
+}
+
+/**
+ * 日志搜索条件，未设置的条件不参与过滤
+ */
+export interface LogQuery {
+    /**
+     * 只搜索这些文件，默认为全部文本日志
+     */
+    files?: string[];
+    levels?: LogLevel[];
+    category?: string;
+    /**
+     * 匹配消息的正则表达式
+     */
+    pattern?: string;
+    /**
+     * 起始时间（Unix 毫秒，包含）
+     */
+    from?: number;
+    /**
+     * 结束时间（Unix 毫秒，不包含）
+     */
+    to?: number;
+    offset?: number;
+    /**
+     * 每页数量，默认为 200，最大为 1000
+     */
+    limit?: number;
+    /**
+     * 统计匹配的记录总数，需要读取全部文件；不统计时取满一页后停止
+     */
+    count_total?: boolean;
+}
+
+/**
+ * 搜索结果的一页，新的记录在前
+ */
+export interface LogPage {
+    records: LogRecord[];
+    /**
+     * 匹配的记录总数，仅在 count_total 时统计
+     */
+    total: number | null;
+    offset: number;
+    has_more: boolean;
+}
+
+/**
+ * 崩溃报告，app:panic 事件
+ */
+export interface CrashReport {
+    /**
+     * 崩溃报告文件路径，写入失败时为空
+     */
+    file: string;
+    /**
+     * RFC 3339 格式的本地时间
+     */
+    time: string;
+    thread: string;
+    message: string;
+    /**
+     * 源码位置：`file:line:column`
+     */
+    location: string | null;
 }
@@ -4,4 +4,96 @@ export interface RamInfo {
     available: number;
     free: number;
     usage_percent: number;
+}
+
+export interface OsInfo {
+    /**
+     * 例如 Windows、Darwin、Ubuntu
+     */
+    name: string | null;
+    version: string | null;
+    /**
+     * 例如 Windows 11 Pro、macOS 15.1 Sequoia
+     */
+    long_version: string | null;
+    kernel_version: string | null;
+    /**
+     * windows、macos、linux
+     */
+    family: string;
+    /**
+     * 启动器编译的架构
+     */
+    arch: string;
+    /**
+     * 系统的 CPU 架构，在 Rosetta 下与 arch 不同
+     */
+    cpu_arch: string;
+}
+
+export interface CpuInfo {
+    brand: string;
+    vendor: string;
+    /**
+     * 物理核心数
+     */
+    cores: number | null;
+    /**
+     * 逻辑线程数
+     */
+    threads: number;
+    /**
+     * MHz
+     */
+    frequency: number;
+}
+
+export interface DiskInfo {
+    mount_point: string;
+    file_system: string;
+    kind: string;
+    removable: boolean;
+    total: number;
+    available: number;
+}
+
+/**
+ * 集合文件夹所在的磁盘
+ */
+export interface FolderDiskInfo {
+    name: string;
+    path: string;
+    /**
+     * 找不到所在磁盘时为 null
+     */
+    disk: DiskInfo | null;
+}
+
+export interface GpuInfo {
+    vendor: string | null;
+    model: string;
+}
+
+export interface RuntimeInfo {
+    /**
+     * 在 Apple Silicon 上通过 Rosetta 2 运行 x86_64 版本
+     */
+    rosetta: boolean;
+    wsl: boolean;
+    flatpak: boolean;
+}
+
+/**
+ * 系统信息快照
+ */
+export interface SystemInfo {
+    os: OsInfo;
+    cpu: CpuInfo;
+    ram: RamInfo;
+    disks: FolderDiskInfo[];
+    /**
+     * 无法识别时为空
+     */
+    gpus: GpuInfo[];
+    runtime: RuntimeInfo;
 }
\ No newline at end of file
@@ -90,11 +90,29 @@ export interface NetworkConfig {
     custom_proxy_uri: string;
     custom_proxy_account: string;
     custom_proxy_password: string;
+    use_http_cache: boolean;
+    use_cookie_jar: boolean;
+    cookie_jar_domains: string[];
+    allowed_hosts: string[];
 }
 
 /** 调试配置 */
 export interface DebugConfig {
     debug_mode: boolean;
+    /** 单个日志文件的最大大小（MB） */
+    log_max_file_mb: number;
+    /** 最多保留的日志文件数，文本日志与 JSON 日志分别计数 */
+    log_max_files: number;
+    /** 日志最长保留天数，0 表示不限制 */
+    log_max_age_days: number;
+    /** 日志目录的最大总大小（MB），包含文本日志与 JSON 日志，0 表示不限制 */
+    log_max_total_mb: number;
+    /** 使用 gzip 压缩已关闭的日志 */
+    log_compress: boolean;
+    /** 全局日志等级，调试模式下为 trace */
+    log_level: string;
+    /** 按目标设置的日志等级，例如 `ts::*`、`http` */
+    log_targets: Record<string, string>;
 }
 
 /** 其他配置 */
@@ -170,6 +188,12 @@ export interface YggdrasilProfile extends BaseProfile {
 
 export type Profile = MsaProfile | LegacyProfile | YggdrasilProfile;
 
+/** 后端返回的账户配置错误 */
+export interface ProfileConfigError {
+    kind: "config" | "profile_not_found" | "invalid_profile_type" | "profile_exists" | "invalid_profile_name";
+    message: string;
+}
+
 /** 账户配置 */
 export interface ProfileConfig {
     profile: Profile[];
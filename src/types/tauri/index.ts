@@ -1,4 +1,6 @@
 export * as HTTP from "./Http";
 export * as TOML from "./Toml";
 export * as System from "./System";
-export * as Logging from "./Logging";
\ No newline at end of file
+export * as Logging from "./Logging";
+export * as DeepLink from "./DeepLink";
+export * as Diagnostic from "./Diagnostic";
\ No newline at end of file
@@ -19,7 +19,7 @@ export default defineConfig(async () => {
         },
 
         // Frontend Env Prefix
-        envPrefix: ["NOVA_", "OAUTH_"],
+        envPrefix: ["NOVA_"],
 
         // Tauri Configuration
         clearScreen: false,